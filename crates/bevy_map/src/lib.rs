@@ -58,15 +58,17 @@ pub use bevy_map_derive::MapEntity;
 /// - [`Tileset`] - Tile atlas configuration
 /// - [`EntityInstance`] - Placed entities with properties
 /// - [`MapProject`] - Self-contained map format
+/// - [`WorldProject`] - All levels of a project plus world layout
 pub mod core {
     pub use bevy_map_core::*;
 }
 
 // Core type re-exports at crate root
 pub use bevy_map_core::{
-    CollisionData, CollisionShape, EditorProject, EntityInstance, Layer, LayerData, LayerType,
-    Level, MapProject, MapProjectBuilder, OneWayDirection, PhysicsBody, TileProperties, Tileset,
-    TilesetImage, Value, OCCUPIED_CELL,
+    CollisionData, CollisionShape, EditorProject, EntityInstance, IntGridValue, Layer, LayerData,
+    LayerType, Level, LevelSelector, MapProject, MapProjectBuilder, OneWayDirection, PhysicsBody,
+    RegionShape, ResizeAnchor, TileProperties, Tileset, TilesetImage, Value, WorldProject,
+    OCCUPIED_CELL,
 };
 
 // =============================================================================
@@ -164,10 +166,15 @@ pub mod runtime {
 #[cfg(feature = "runtime")]
 pub use bevy_map_runtime::{
     attach_dialogues, complete_sprite_loads, spawn_map_project, spawn_sprite_components, Dialogue,
-    EntityProperties, EntityRegistry, MapCollider, MapCollisionPlugin, MapEntityExt,
-    MapEntityMarker, MapEntityType, MapHandle, MapLoadError, MapProjectLoader, MapRoot,
-    MapRuntimePlugin, MapSpawnedEvent, SpawnMapEvent, SpawnMapProjectEvent, SpriteSlot,
-    TilesetTextures,
+    EntityProperties, EntityRefs, EntityRegistry, EntitySize, HotReloadMode, LayerProperties,
+    LevelHandle, LevelProperties, LevelTransitionEvent, MapChunkSettings, MapCollider,
+    MapCollisionPlugin, MapEditCommands, MapEntities, MapEntityExt, MapEntityMarker, MapEntityType,
+    MapHandle, MapHotReloadExt, MapHotReloadSettings, MapIntGrids, MapLayerSettings, MapLayers,
+    MapLevelData, MapLoadError, MapNavigation, MapNavigationPlugin, MapProjectLoader, MapQuery,
+    MapRegion, MapRegionPlugin, MapRoot, MapRuntimePlugin, MapSpawnedEvent, MapTilesChanged,
+    RegionEnterEvent, RegionExitEvent, RegionOccupant, RegionOccupants, SpawnMapEvent,
+    SpawnMapProjectEvent, SpriteSlot, StreamedWorld, StreamingAnchor, TileFrameAnimation,
+    TilesetProperties, TilesetTextures, WorldProjectLoader, WorldStreamingPlugin,
 };
 
 // =============================================================================
@@ -206,7 +213,7 @@ pub mod prelude {
     // Core types
     pub use crate::{
         CollisionData, CollisionShape, EntityInstance, Layer, LayerData, Level, MapProject,
-        Tileset, Value,
+        Tileset, Value, WorldProject,
     };
 
     // Animation
@@ -224,8 +231,9 @@ pub mod prelude {
     // Runtime (if enabled)
     #[cfg(feature = "runtime")]
     pub use crate::{
-        spawn_map_project, EntityRegistry, MapEntityExt, MapHandle, MapRoot, MapRuntimePlugin,
-        SpawnMapEvent, SpawnMapProjectEvent, TilesetTextures,
+        spawn_map_project, EntityRegistry, LevelHandle, LevelTransitionEvent, MapEntityExt,
+        MapHandle, MapRoot, MapRuntimePlugin, SpawnMapEvent, SpawnMapProjectEvent, StreamedWorld,
+        StreamingAnchor, TilesetTextures, WorldStreamingPlugin,
     };
 
     // Integration (if enabled)
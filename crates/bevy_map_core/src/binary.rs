@@ -0,0 +1,391 @@
+//! Compact binary encoding of map files
+//!
+//! Pretty-printed JSON spends most of its bytes on tile arrays, one
+//! `Option<u32>` per cell. The binary format keeps the JSON document for
+//! everything else, moves long integer arrays (tiles and IntGrid values) into
+//! run-length encoded columns, and compresses the result with zstd:
+//!
+//! ```text
+//! "BMAP" | format version (u16, little endian) | zstd(postcard(container))
+//! ```
+//!
+//! Any serde type round-trips exactly as it would through JSON, so editor
+//! projects, `MapProject` and `WorldProject` files can all be stored this way.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::io::Read;
+
+/// Magic bytes at the start of every binary map file
+pub const BINARY_MAGIC: &[u8; 4] = b"BMAP";
+
+/// Current version of the binary format
+pub const BINARY_FORMAT_VERSION: u16 = 1;
+
+/// Arrays shorter than this stay in the JSON document
+const MIN_COLUMN_LEN: usize = 16;
+
+/// Error type for binary encoding failures
+#[derive(Debug)]
+pub enum BinaryError {
+    /// The data does not start with [`BINARY_MAGIC`]
+    NotBinary,
+    /// The data was written by a newer format version
+    UnsupportedVersion(u16),
+    /// The container could not be encoded or decoded
+    Encoding(String),
+    /// The JSON document could not be converted
+    Json(serde_json::Error),
+}
+
+impl std::fmt::Display for BinaryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BinaryError::NotBinary => write!(f, "Not a binary map file"),
+            BinaryError::UnsupportedVersion(version) => {
+                write!(f, "Unsupported binary format version {}", version)
+            }
+            BinaryError::Encoding(e) => write!(f, "Encoding error: {}", e),
+            BinaryError::Json(e) => write!(f, "JSON error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for BinaryError {}
+
+impl From<serde_json::Error> for BinaryError {
+    fn from(e: serde_json::Error) -> Self {
+        BinaryError::Json(e)
+    }
+}
+
+/// JSON document with its long integer arrays taken out
+#[derive(Serialize, Deserialize)]
+struct Container {
+    /// Compact JSON, with each column's array replaced by `null`
+    document: String,
+    columns: Vec<Column>,
+}
+
+/// A run-length encoded array of optional integers
+#[derive(Serialize, Deserialize)]
+struct Column {
+    /// JSON pointer to the array in the document
+    path: String,
+    runs: Vec<(u32, Option<u32>)>,
+}
+
+/// Check if data starts with the binary map magic
+pub fn is_binary(bytes: &[u8]) -> bool {
+    bytes.starts_with(BINARY_MAGIC)
+}
+
+/// Encode a value in the binary map format
+pub fn to_binary<T: Serialize>(value: &T) -> Result<Vec<u8>, BinaryError> {
+    let mut document = serde_json::to_value(value)?;
+    let mut columns = Vec::new();
+    extract_columns(&mut document, &mut String::new(), &mut columns);
+    // Only layer data is stored as columns, so decoding can check their length
+    columns.retain(|column| {
+        let cells = layer_cell_count(&document, &column.path);
+        if cells.is_some() && cells == run_total(&column.runs) {
+            return true;
+        }
+        if let Some(slot) = document.pointer_mut(&column.path) {
+            *slot = JsonValue::Array(expand_runs(&column.runs));
+        }
+        false
+    });
+
+    let container = Container {
+        document: serde_json::to_string(&document)?,
+        columns,
+    };
+    let payload =
+        postcard::to_allocvec(&container).map_err(|e| BinaryError::Encoding(e.to_string()))?;
+
+    let mut bytes = Vec::with_capacity(payload.len() / 2);
+    bytes.extend_from_slice(BINARY_MAGIC);
+    bytes.extend_from_slice(&BINARY_FORMAT_VERSION.to_le_bytes());
+    ruzstd::encoding::compress(
+        payload.as_slice(),
+        &mut bytes,
+        ruzstd::encoding::CompressionLevel::Fastest,
+    );
+    Ok(bytes)
+}
+
+/// Decode a value from the binary map format
+pub fn from_binary<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, BinaryError> {
+    Ok(serde_json::from_value(binary_to_json(bytes)?)?)
+}
+
+/// Decode binary map data into its JSON document
+pub fn binary_to_json(bytes: &[u8]) -> Result<JsonValue, BinaryError> {
+    if !is_binary(bytes) || bytes.len() < 6 {
+        return Err(BinaryError::NotBinary);
+    }
+    let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+    if version > BINARY_FORMAT_VERSION {
+        return Err(BinaryError::UnsupportedVersion(version));
+    }
+
+    let mut payload = Vec::new();
+    ruzstd::decoding::StreamingDecoder::new(&bytes[6..])
+        .map_err(|e| BinaryError::Encoding(e.to_string()))?
+        .read_to_end(&mut payload)
+        .map_err(|e| BinaryError::Encoding(e.to_string()))?;
+    let container: Container =
+        postcard::from_bytes(&payload).map_err(|e| BinaryError::Encoding(e.to_string()))?;
+
+    let mut document: JsonValue = serde_json::from_str(&container.document)?;
+    for column in container.columns {
+        let Some(cells) = layer_cell_count(&document, &column.path) else {
+            return Err(BinaryError::Encoding(format!(
+                "Column {} is not the data of a layer",
+                column.path
+            )));
+        };
+        // Counts come from the file, so they are checked before allocating
+        if run_total(&column.runs) != Some(cells) {
+            return Err(BinaryError::Encoding(format!(
+                "Column {} does not hold the {} cells of its level",
+                column.path, cells
+            )));
+        }
+        let Some(slot) = document.pointer_mut(&column.path) else {
+            return Err(BinaryError::Encoding(format!(
+                "Column {} has no place in the document",
+                column.path
+            )));
+        };
+        *slot = JsonValue::Array(expand_runs(&column.runs));
+    }
+    Ok(document)
+}
+
+/// Move long arrays of optional `u32`s out of the document
+fn extract_columns(value: &mut JsonValue, path: &mut String, columns: &mut Vec<Column>) {
+    match value {
+        JsonValue::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                let len = path.len();
+                path.push('/');
+                path.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                extract_columns(child, path, columns);
+                path.truncate(len);
+            }
+        }
+        JsonValue::Array(items) => {
+            if let Some(runs) = encode_runs(items) {
+                columns.push(Column {
+                    path: path.clone(),
+                    runs,
+                });
+                *value = JsonValue::Null;
+                return;
+            }
+            for (index, child) in items.iter_mut().enumerate() {
+                let len = path.len();
+                path.push('/');
+                path.push_str(&index.to_string());
+                extract_columns(child, path, columns);
+                path.truncate(len);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Run-length encode an array if it is long and only holds `null` or `u32`s
+fn encode_runs(items: &[JsonValue]) -> Option<Vec<(u32, Option<u32>)>> {
+    if items.len() < MIN_COLUMN_LEN {
+        return None;
+    }
+    let mut runs: Vec<(u32, Option<u32>)> = Vec::new();
+    for item in items {
+        let cell = match item {
+            JsonValue::Null => None,
+            JsonValue::Number(n) => Some(n.as_u64().and_then(|n| u32::try_from(n).ok())?),
+            _ => return None,
+        };
+        match runs.last_mut() {
+            Some((count, value)) if *value == cell && *count < u32::MAX => *count += 1,
+            _ => runs.push((1, cell)),
+        }
+    }
+    Some(runs)
+}
+
+/// Get the number of cells of the level whose layer data a column replaces
+///
+/// Returns `None` unless the path points into a layer's `data`, e.g.
+/// `/level/layers/0/data/Tiles/tiles`.
+fn layer_cell_count(document: &JsonValue, path: &str) -> Option<usize> {
+    let (level_path, layer_path) = path.rsplit_once("/layers/")?;
+    let segments: Vec<&str> = layer_path.split('/').collect();
+    let [index, "data", _, _] = segments.as_slice() else {
+        return None;
+    };
+    index.parse::<usize>().ok()?;
+
+    let level = document.pointer(level_path)?;
+    let width = usize::try_from(level.get("width")?.as_u64()?).ok()?;
+    let height = usize::try_from(level.get("height")?.as_u64()?).ok()?;
+    width.checked_mul(height)
+}
+
+/// Get the number of cells in a column, or `None` if it overflows
+fn run_total(runs: &[(u32, Option<u32>)]) -> Option<usize> {
+    runs.iter().try_fold(0usize, |total, &(count, _)| {
+        total.checked_add(count as usize)
+    })
+}
+
+fn expand_runs(runs: &[(u32, Option<u32>)]) -> Vec<JsonValue> {
+    runs.iter()
+        .flat_map(|&(count, cell)| {
+            std::iter::repeat(cell.map_or(JsonValue::Null, JsonValue::from)).take(count as usize)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EditorProject, EntityInstance, Layer, Level, MapProject, Tileset, WorldProject};
+
+    fn project() -> MapProject {
+        let tileset = Tileset::new("Ground".to_string(), "tiles.png".to_string(), 16, 8, 8);
+        let mut level = Level::new("Big".to_string(), 200, 150);
+        level.add_layer(Layer::new_tile_layer(
+            "Ground".to_string(),
+            tileset.id,
+            200,
+            150,
+        ));
+        for x in 0..200 {
+            level.set_tile(0, x, 0, Some(1));
+            level.set_tile(
+                0,
+                x,
+                1,
+                Some(crate::tile_with_flips(x % 7, x % 2 == 0, false)),
+            );
+        }
+        let mut npc = EntityInstance::new("Npc".to_string(), [8.0, 24.0]);
+        npc.set_int("health", 10);
+        level.add_entity(npc);
+        let mut project = MapProject::new(level, vec![tileset]);
+        // Long integer arrays outside layer data stay in the document
+        let ids: Vec<u32> = (0..32).collect();
+        project.autotile_config = Some(serde_json::json!({ "terrain_sets": [], "ids": ids }));
+        project
+    }
+
+    #[test]
+    fn test_binary_round_trip_matches_json() {
+        let project = project();
+        let bytes = to_binary(&project).unwrap();
+        assert!(is_binary(&bytes));
+
+        let decoded: MapProject = from_binary(&bytes).unwrap();
+        assert_eq!(
+            serde_json::to_value(&decoded).unwrap(),
+            serde_json::to_value(&project).unwrap()
+        );
+
+        // Much smaller than the pretty-printed JSON the editor writes
+        let json = serde_json::to_vec_pretty(&project).unwrap();
+        assert!(bytes.len() * 20 < json.len());
+    }
+
+    #[test]
+    fn test_binary_round_trip_world() {
+        let world = WorldProject::from(project());
+        let decoded: WorldProject = from_binary(&to_binary(&world).unwrap()).unwrap();
+        assert_eq!(
+            serde_json::to_value(&decoded).unwrap(),
+            serde_json::to_value(&world).unwrap()
+        );
+
+        // Editor project files decode through the same path as their JSON
+        let json = serde_json::json!({
+            "version": 1,
+            "schema": { "data_types": {} },
+            "levels": [serde_json::to_value(&world.levels[0]).unwrap()],
+            "tilesets": [],
+        });
+        let decoded: EditorProject = from_binary(&to_binary(&json).unwrap()).unwrap();
+        assert_eq!(decoded.levels[0].name, "Big");
+        assert_eq!(binary_to_json(&to_binary(&json).unwrap()).unwrap(), json);
+    }
+
+    #[test]
+    fn test_binary_rejects_other_data() {
+        let json = serde_json::to_vec(&project()).unwrap();
+        assert!(!is_binary(&json));
+        assert!(matches!(
+            from_binary::<MapProject>(&json),
+            Err(BinaryError::NotBinary)
+        ));
+
+        let mut bytes = to_binary(&project()).unwrap();
+        bytes[4..6].copy_from_slice(&(BINARY_FORMAT_VERSION + 1).to_le_bytes());
+        assert!(matches!(
+            from_binary::<MapProject>(&bytes),
+            Err(BinaryError::UnsupportedVersion(_))
+        ));
+    }
+
+    /// Re-encode binary data after editing its container
+    fn edit_container(bytes: &[u8], edit: impl FnOnce(&mut Container)) -> Vec<u8> {
+        let mut payload = Vec::new();
+        ruzstd::decoding::StreamingDecoder::new(&bytes[6..])
+            .unwrap()
+            .read_to_end(&mut payload)
+            .unwrap();
+        let mut container: Container = postcard::from_bytes(&payload).unwrap();
+        edit(&mut container);
+
+        let payload = postcard::to_allocvec(&container).unwrap();
+        let mut edited = bytes[..6].to_vec();
+        ruzstd::encoding::compress(
+            payload.as_slice(),
+            &mut edited,
+            ruzstd::encoding::CompressionLevel::Fastest,
+        );
+        edited
+    }
+
+    #[test]
+    fn test_binary_rejects_corrupt_run_counts() {
+        let bytes = to_binary(&project()).unwrap();
+
+        let inflated = edit_container(&bytes, |container| {
+            assert_eq!(container.columns.len(), 1);
+            container.columns[0].runs[0].0 = u32::MAX;
+        });
+        assert!(matches!(
+            from_binary::<MapProject>(&inflated),
+            Err(BinaryError::Encoding(_))
+        ));
+
+        let truncated = edit_container(&bytes, |container| {
+            container.columns[0].runs.pop();
+        });
+        assert!(matches!(
+            from_binary::<MapProject>(&truncated),
+            Err(BinaryError::Encoding(_))
+        ));
+
+        let misplaced = edit_container(&bytes, |container| {
+            container.columns[0].path = "/autotile_config/ids".to_string();
+        });
+        assert!(matches!(
+            from_binary::<MapProject>(&misplaced),
+            Err(BinaryError::Encoding(_))
+        ));
+    }
+}
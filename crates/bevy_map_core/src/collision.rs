@@ -86,6 +86,61 @@ impl CollisionShape {
             CollisionShape::Polygon { .. } => "Polygon",
         }
     }
+
+    /// Get this shape as it appears on a flipped tile
+    ///
+    /// Flips follow the Tiled convention: the diagonal flip (swap X and Y) is
+    /// applied first, then the horizontal and vertical flips.
+    pub fn flipped(&self, flip_x: bool, flip_y: bool, flip_diagonal: bool) -> Self {
+        let transpose = |v: [f32; 2]| {
+            if flip_diagonal {
+                [v[1], v[0]]
+            } else {
+                v
+            }
+        };
+        // Mirror a span [start, start + len] in normalized 0-1 space
+        let mirror = |start: f32, len: f32, flip: bool| {
+            if flip {
+                1.0 - start - len
+            } else {
+                start
+            }
+        };
+
+        match self {
+            CollisionShape::None | CollisionShape::Full => self.clone(),
+            CollisionShape::Rectangle { offset, size } => {
+                let (offset, size) = (transpose(*offset), transpose(*size));
+                CollisionShape::Rectangle {
+                    offset: [
+                        mirror(offset[0], size[0], flip_x),
+                        mirror(offset[1], size[1], flip_y),
+                    ],
+                    size,
+                }
+            }
+            CollisionShape::Circle { offset, radius } => {
+                let offset = transpose(*offset);
+                CollisionShape::Circle {
+                    offset: [
+                        mirror(offset[0], 0.0, flip_x),
+                        mirror(offset[1], 0.0, flip_y),
+                    ],
+                    radius: *radius,
+                }
+            }
+            CollisionShape::Polygon { points } => CollisionShape::Polygon {
+                points: points
+                    .iter()
+                    .map(|p| {
+                        let p = transpose(*p);
+                        [mirror(p[0], 0.0, flip_x), mirror(p[1], 0.0, flip_y)]
+                    })
+                    .collect(),
+            },
+        }
+    }
 }
 
 /// Physics body type
@@ -140,10 +195,42 @@ impl OneWayDirection {
             OneWayDirection::Right => "Right (Pass from left)",
         }
     }
+
+    /// Get this direction as it applies to a flipped tile
+    ///
+    /// Uses the same flip order as [`CollisionShape::flipped`].
+    pub fn flipped(&self, flip_x: bool, flip_y: bool, flip_diagonal: bool) -> Self {
+        use OneWayDirection::*;
+        let mut direction = *self;
+        if flip_diagonal {
+            direction = match direction {
+                Top => Left,
+                Left => Top,
+                Bottom => Right,
+                Right => Bottom,
+                None => None,
+            };
+        }
+        if flip_x {
+            direction = match direction {
+                Left => Right,
+                Right => Left,
+                other => other,
+            };
+        }
+        if flip_y {
+            direction = match direction {
+                Top => Bottom,
+                Bottom => Top,
+                other => other,
+            };
+        }
+        direction
+    }
 }
 
 /// Collision data for a tile or entity
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct CollisionData {
     /// The collision shape
     #[serde(default)]
@@ -232,6 +319,17 @@ impl CollisionData {
     pub fn is_empty(&self) -> bool {
         !self.has_collision()
     }
+
+    /// Get this collision data as it applies to a flipped tile
+    ///
+    /// Mirrors both the shape and the one-way direction.
+    pub fn flipped(&self, flip_x: bool, flip_y: bool, flip_diagonal: bool) -> Self {
+        Self {
+            shape: self.shape.flipped(flip_x, flip_y, flip_diagonal),
+            one_way: self.one_way.flipped(flip_x, flip_y, flip_diagonal),
+            ..self.clone()
+        }
+    }
 }
 
 #[cfg(test)]
@@ -273,4 +371,59 @@ mod tests {
         let parsed: CollisionShape = serde_json::from_str(&json).unwrap();
         assert_eq!(shape, parsed);
     }
+
+    #[test]
+    fn test_collision_shape_flipped() {
+        let rect = CollisionShape::rectangle([0.0, 0.5], [0.25, 0.5]);
+        assert_eq!(
+            rect.flipped(true, false, false),
+            CollisionShape::rectangle([0.75, 0.5], [0.25, 0.5])
+        );
+        assert_eq!(
+            rect.flipped(false, true, false),
+            CollisionShape::rectangle([0.0, 0.0], [0.25, 0.5])
+        );
+        assert_eq!(
+            rect.flipped(false, false, true),
+            CollisionShape::rectangle([0.5, 0.0], [0.5, 0.25])
+        );
+
+        let circle = CollisionShape::circle([0.25, 0.5], 0.25);
+        assert_eq!(
+            circle.flipped(true, false, false),
+            CollisionShape::circle([0.75, 0.5], 0.25)
+        );
+
+        let polygon = CollisionShape::polygon(vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]);
+        assert_eq!(
+            polygon.flipped(true, false, false),
+            CollisionShape::polygon(vec![[1.0, 0.0], [0.0, 0.0], [1.0, 1.0]])
+        );
+
+        assert_eq!(
+            CollisionShape::Full.flipped(true, true, true),
+            CollisionShape::Full
+        );
+    }
+
+    #[test]
+    fn test_collision_data_flipped() {
+        let data = CollisionData::full().with_one_way(OneWayDirection::Top);
+        assert_eq!(
+            data.flipped(false, true, false).one_way,
+            OneWayDirection::Bottom
+        );
+        assert_eq!(
+            data.flipped(true, false, false).one_way,
+            OneWayDirection::Top
+        );
+        assert_eq!(
+            data.flipped(false, false, true).one_way,
+            OneWayDirection::Left
+        );
+        assert_eq!(
+            data.flipped(true, false, true).one_way,
+            OneWayDirection::Right
+        );
+    }
 }
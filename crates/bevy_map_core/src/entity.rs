@@ -1,6 +1,6 @@
 //! Entity instance for placed objects in the world
 
-use crate::Value;
+use crate::{EntityTemplate, RegionShape, Value};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
@@ -14,6 +14,18 @@ pub struct EntityInstance {
     pub type_name: String,
     /// Position in world coordinates [x, y]
     pub position: [f32; 2],
+    /// Rotation in degrees, counter-clockwise
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub rotation: f32,
+    /// Scale factors [x, y]
+    #[serde(default = "unit_scale", skip_serializing_if = "is_unit_scale")]
+    pub scale: [f32; 2],
+    /// Width and height in world units, for types marked resizable in the schema
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<[f32; 2]>,
+    /// Shape of the covered area, for region entity types
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<RegionShape>,
     /// If this is an instance of a template, the template ID
     #[serde(skip_serializing_if = "Option::is_none")]
     pub template_id: Option<Uuid>,
@@ -25,6 +37,18 @@ pub struct EntityInstance {
     pub component_overrides: ComponentOverrides,
 }
 
+fn is_zero(value: &f32) -> bool {
+    *value == 0.0
+}
+
+fn unit_scale() -> [f32; 2] {
+    [1.0, 1.0]
+}
+
+fn is_unit_scale(scale: &[f32; 2]) -> bool {
+    *scale == unit_scale()
+}
+
 /// Instance-level overrides for component configurations
 ///
 /// Each field is optional. When present, it overrides the corresponding
@@ -56,6 +80,24 @@ impl ComponentOverrides {
         self.input = None;
         self.sprite = None;
     }
+
+    /// Layer these overrides over `base`, field by field
+    ///
+    /// Values set here win; anything left unset falls back to `base`.
+    pub fn merged_over(&self, base: &ComponentOverrides) -> ComponentOverrides {
+        ComponentOverrides {
+            physics: merge_option(&self.physics, &base.physics, PhysicsOverrides::merged_over),
+            input: merge_option(&self.input, &base.input, InputOverrides::merged_over),
+            sprite: merge_option(&self.sprite, &base.sprite, SpriteOverrides::merged_over),
+        }
+    }
+}
+
+fn merge_option<T: Clone>(over: &Option<T>, base: &Option<T>, merge: fn(&T, &T) -> T) -> Option<T> {
+    match (over, base) {
+        (Some(over), Some(base)) => Some(merge(over, base)),
+        (over, base) => over.clone().or_else(|| base.clone()),
+    }
 }
 
 /// Physics-related instance overrides
@@ -87,6 +129,16 @@ impl PhysicsOverrides {
             && self.restitution.is_none()
             && self.linear_damping.is_none()
     }
+
+    /// Layer these overrides over `base`, field by field
+    pub fn merged_over(&self, base: &PhysicsOverrides) -> PhysicsOverrides {
+        PhysicsOverrides {
+            gravity_scale: self.gravity_scale.or(base.gravity_scale),
+            friction: self.friction.or(base.friction),
+            restitution: self.restitution.or(base.restitution),
+            linear_damping: self.linear_damping.or(base.linear_damping),
+        }
+    }
 }
 
 /// Input-related instance overrides
@@ -120,6 +172,17 @@ impl InputOverrides {
             && self.deceleration.is_none()
             && self.max_fall_speed.is_none()
     }
+
+    /// Layer these overrides over `base`, field by field
+    pub fn merged_over(&self, base: &InputOverrides) -> InputOverrides {
+        InputOverrides {
+            speed: self.speed.or(base.speed),
+            jump_force: self.jump_force.or(base.jump_force),
+            acceleration: self.acceleration.or(base.acceleration),
+            deceleration: self.deceleration.or(base.deceleration),
+            max_fall_speed: self.max_fall_speed.or(base.max_fall_speed),
+        }
+    }
 }
 
 /// Sprite-related instance overrides
@@ -127,6 +190,9 @@ impl InputOverrides {
 /// These override values from the entity type's SpriteConfig.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SpriteOverrides {
+    /// Override sprite sheet (lets templates of one type look different)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sprite_sheet_id: Option<Uuid>,
     /// Override sprite scale
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub scale: Option<f32>,
@@ -138,7 +204,19 @@ pub struct SpriteOverrides {
 impl SpriteOverrides {
     /// Check if no sprite values are overridden
     pub fn is_empty(&self) -> bool {
-        self.scale.is_none() && self.default_animation.is_none()
+        self.sprite_sheet_id.is_none() && self.scale.is_none() && self.default_animation.is_none()
+    }
+
+    /// Layer these overrides over `base`, field by field
+    pub fn merged_over(&self, base: &SpriteOverrides) -> SpriteOverrides {
+        SpriteOverrides {
+            sprite_sheet_id: self.sprite_sheet_id.or(base.sprite_sheet_id),
+            scale: self.scale.or(base.scale),
+            default_animation: self
+                .default_animation
+                .clone()
+                .or_else(|| base.default_animation.clone()),
+        }
     }
 }
 
@@ -149,6 +227,10 @@ impl EntityInstance {
             id: Uuid::new_v4(),
             type_name,
             position,
+            rotation: 0.0,
+            scale: unit_scale(),
+            size: None,
+            region: None,
             template_id: None,
             properties: HashMap::new(),
             component_overrides: ComponentOverrides::default(),
@@ -161,12 +243,48 @@ impl EntityInstance {
             id: Uuid::new_v4(),
             type_name,
             position,
+            rotation: 0.0,
+            scale: unit_scale(),
+            size: None,
+            region: None,
             template_id: Some(template_id),
             properties: HashMap::new(),
             component_overrides: ComponentOverrides::default(),
         }
     }
 
+    /// Set the width and height of a sized (area) entity
+    pub fn with_size(mut self, width: f32, height: f32) -> Self {
+        self.size = Some([width, height]);
+        self
+    }
+
+    /// Make this a region entity with the given shape
+    pub fn with_region(mut self, shape: RegionShape) -> Self {
+        self.region = Some(shape);
+        self
+    }
+
+    /// Check if this entity is a region covering an area
+    pub fn is_region(&self) -> bool {
+        self.region.is_some()
+    }
+
+    /// Check whether a local-space point is inside this entity's region
+    ///
+    /// The point is relative to the entity's position, before its rotation
+    /// and scale. Always false for entities that aren't regions.
+    pub fn region_contains(&self, point: [f32; 2]) -> bool {
+        self.region
+            .as_ref()
+            .is_some_and(|shape| shape.contains(self.size.unwrap_or_default(), point))
+    }
+
+    /// Get the rotation in radians, counter-clockwise
+    pub fn rotation_radians(&self) -> f32 {
+        self.rotation.to_radians()
+    }
+
     /// Get a display name for this entity
     pub fn get_display_name(&self) -> String {
         self.properties
@@ -216,6 +334,65 @@ impl EntityInstance {
     pub fn set_bool(&mut self, key: &str, value: bool) {
         self.properties.insert(key.to_string(), Value::Bool(value));
     }
+
+    /// Check if this instance stores its own value for a property
+    ///
+    /// For template instances, properties that aren't overridden are
+    /// inherited from the template.
+    pub fn is_overridden(&self, key: &str) -> bool {
+        self.properties.contains_key(key)
+    }
+
+    /// Get the effective value of a property, falling back to the template
+    pub fn property_value<'a>(
+        &'a self,
+        key: &str,
+        template: Option<&'a EntityTemplate>,
+    ) -> Option<&'a Value> {
+        self.properties
+            .get(key)
+            .or_else(|| template.and_then(|t| t.properties.get(key)))
+    }
+
+    /// Drop this instance's value for a property so it inherits again
+    pub fn revert_property(&mut self, key: &str) -> Option<Value> {
+        self.properties.remove(key)
+    }
+
+    /// Get the effective component overrides, layered over the template's
+    pub fn effective_overrides(&self, template: Option<&EntityTemplate>) -> ComponentOverrides {
+        match template {
+            Some(template) => self
+                .component_overrides
+                .merged_over(&template.component_overrides),
+            None => self.component_overrides.clone(),
+        }
+    }
+
+    /// Bake the template's values into a copy of this instance
+    ///
+    /// The copy keeps its `template_id` but no longer needs the template to
+    /// be read correctly.
+    pub fn resolve(&self, template: &EntityTemplate) -> EntityInstance {
+        let mut properties = template.properties.clone();
+        properties.extend(
+            self.properties
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+        EntityInstance {
+            id: self.id,
+            type_name: self.type_name.clone(),
+            position: self.position,
+            rotation: self.rotation,
+            scale: self.scale,
+            size: self.size,
+            region: self.region.clone(),
+            template_id: self.template_id,
+            properties,
+            component_overrides: self.effective_overrides(Some(template)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -241,4 +418,27 @@ mod tests {
         assert_eq!(entity.template_id, Some(template_id));
         assert_eq!(entity.type_name, "Enemy");
     }
+
+    #[test]
+    fn test_entity_transform_fields() {
+        // Older files have no rotation, scale or size
+        let json = r#"{"id":"00000000-0000-0000-0000-000000000001","type_name":"Spikes","position":[8.0,8.0]}"#;
+        let entity: EntityInstance = serde_json::from_str(json).unwrap();
+        assert_eq!(entity.rotation, 0.0);
+        assert_eq!(entity.scale, [1.0, 1.0]);
+        assert_eq!(entity.size, None);
+
+        // Defaults are left out when saving
+        let saved = serde_json::to_string(&entity).unwrap();
+        assert!(!saved.contains("rotation") && !saved.contains("scale") && !saved.contains("size"));
+
+        let mut zone = EntityInstance::new("Trigger".to_string(), [0.0, 0.0]).with_size(64.0, 32.0);
+        zone.rotation = 90.0;
+        zone.scale = [2.0, 1.0];
+        let loaded: EntityInstance =
+            serde_json::from_str(&serde_json::to_string(&zone).unwrap()).unwrap();
+        assert_eq!(loaded.size, Some([64.0, 32.0]));
+        assert_eq!(loaded.scale, [2.0, 1.0]);
+        assert!((loaded.rotation_radians() - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    }
 }
@@ -45,6 +45,33 @@ impl EntityTypeConfig {
     pub fn has_any(&self) -> bool {
         self.physics.is_some() || self.input.is_some() || self.sprite.is_some()
     }
+
+    /// Create a new EntityTypeConfig with instance-level overrides applied
+    ///
+    /// Components the type doesn't configure stay unset, except for a sprite
+    /// whose sheet is supplied by the overrides.
+    pub fn with_overrides(&self, overrides: &crate::ComponentOverrides) -> EntityTypeConfig {
+        let sprite = match (&self.sprite, &overrides.sprite) {
+            (Some(sprite), Some(sprite_overrides)) => Some(sprite.with_overrides(sprite_overrides)),
+            (None, Some(sprite_overrides)) if sprite_overrides.sprite_sheet_id.is_some() => {
+                Some(SpriteConfig::default().with_overrides(sprite_overrides))
+            }
+            (sprite, _) => sprite.clone(),
+        };
+        EntityTypeConfig {
+            physics: match (&self.physics, &overrides.physics) {
+                (Some(physics), Some(physics_overrides)) => {
+                    Some(physics.with_overrides(physics_overrides))
+                }
+                (physics, _) => physics.clone(),
+            },
+            input: match (&self.input, &overrides.input) {
+                (Some(input), Some(input_overrides)) => Some(input.with_overrides(input_overrides)),
+                (input, _) => input.clone(),
+            },
+            sprite,
+        }
+    }
 }
 
 // ============================================================================
@@ -441,13 +468,13 @@ impl SpriteConfig {
     pub fn with_overrides(&self, overrides: &crate::SpriteOverrides) -> SpriteConfig {
         SpriteConfig {
             // Overridable fields
+            sprite_sheet_id: overrides.sprite_sheet_id.or(self.sprite_sheet_id),
             scale: overrides.scale.or(self.scale),
             default_animation: overrides
                 .default_animation
                 .clone()
                 .or_else(|| self.default_animation.clone()),
             // Non-overridable fields (copy from type config)
-            sprite_sheet_id: self.sprite_sheet_id,
             offset: self.offset,
             flip_with_direction: self.flip_with_direction,
         }
@@ -522,4 +549,28 @@ mod tests {
         let circle = ColliderConfig::new_circle(8.0);
         assert_eq!(circle.display_name(), "Circle");
     }
+
+    #[test]
+    fn test_sprite_sheet_override() {
+        let sheet = Uuid::new_v4();
+        let overrides = crate::ComponentOverrides {
+            sprite: Some(crate::SpriteOverrides {
+                sprite_sheet_id: Some(sheet),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        // A sheet override supplies a sprite even if the type has none
+        let config = EntityTypeConfig::new().with_overrides(&overrides);
+        assert_eq!(config.sprite.unwrap().sprite_sheet_id, Some(sheet));
+
+        let base = EntityTypeConfig {
+            sprite: Some(SpriteConfig::with_sprite_sheet(Uuid::new_v4()).with_scale(2.0)),
+            ..Default::default()
+        };
+        let sprite = base.with_overrides(&overrides).sprite.unwrap();
+        assert_eq!(sprite.sprite_sheet_id, Some(sheet));
+        assert_eq!(sprite.scale, Some(2.0));
+    }
 }
@@ -1,5 +1,6 @@
-//! Layer types for tile and object layers
+//! Layer types for tile, object, image, group and IntGrid layers
 
+use crate::Value;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
@@ -37,6 +38,20 @@ pub fn tile_flip_y(tile: u32) -> bool {
     tile & TILE_FLIP_Y != 0
 }
 
+/// Check if a tile has diagonal flip (X/Y swapped, used for 90° rotations)
+#[inline]
+pub fn tile_flip_diagonal(tile: u32) -> bool {
+    tile & TILE_FLIP_DIAGONAL != 0
+}
+
+/// Check if a tile value is the sentinel for a cell covered by a multi-cell tile
+///
+/// Must be checked before decoding, since `OCCUPIED_CELL` has all flip bits set.
+#[inline]
+pub fn is_occupied_cell(tile: u32) -> bool {
+    tile == OCCUPIED_CELL
+}
+
 /// Create a tile value with flip flags
 #[inline]
 pub fn tile_with_flips(index: u32, flip_x: bool, flip_y: bool) -> u32 {
@@ -77,6 +92,27 @@ pub struct Layer {
     pub visible: bool,
     #[serde(default = "default_opacity")]
     pub opacity: f32,
+    /// Horizontal parallax factor (1.0 scrolls with the map, 0.0 stays fixed to the camera)
+    #[serde(default = "default_parallax")]
+    pub parallax_x: f32,
+    /// Vertical parallax factor (1.0 scrolls with the map, 0.0 stays fixed to the camera)
+    #[serde(default = "default_parallax")]
+    pub parallax_y: f32,
+    /// Horizontal offset in pixels
+    #[serde(default)]
+    pub offset_x: f32,
+    /// Vertical offset in pixels (positive moves the layer up)
+    #[serde(default)]
+    pub offset_y: f32,
+    /// Tint color multiplied into the layer (hex format, e.g., "#FF8080" or "#FF808080")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tint: Option<String>,
+    /// ID of the group layer this layer is nested in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<Uuid>,
+    /// Custom properties defined by the schema's `layer_properties`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub properties: HashMap<String, Value>,
     pub data: LayerData,
 }
 
@@ -84,34 +120,79 @@ fn default_opacity() -> f32 {
     1.0
 }
 
+fn default_parallax() -> f32 {
+    1.0
+}
+
 impl Layer {
-    /// Create a new tile layer with the given tileset
-    pub fn new_tile_layer(name: String, tileset_id: Uuid, width: u32, height: u32) -> Self {
-        let size = (width * height) as usize;
+    /// Create a visible, unmodified layer with the given data
+    fn with_data(name: String, data: LayerData) -> Self {
         Self {
             id: Uuid::new_v4(),
             name,
             visible: true,
             opacity: 1.0,
-            data: LayerData::Tiles {
+            parallax_x: 1.0,
+            parallax_y: 1.0,
+            offset_x: 0.0,
+            offset_y: 0.0,
+            tint: None,
+            parent_id: None,
+            properties: HashMap::new(),
+            data,
+        }
+    }
+
+    /// Create a new tile layer with the given tileset
+    pub fn new_tile_layer(name: String, tileset_id: Uuid, width: u32, height: u32) -> Self {
+        let size = (width * height) as usize;
+        Self::with_data(
+            name,
+            LayerData::Tiles {
                 tileset_id,
                 tiles: vec![None; size],
                 occupied_cells: HashMap::new(),
             },
-        }
+        )
     }
 
     /// Create a new object layer
     pub fn new_object_layer(name: String) -> Self {
-        Self {
-            id: Uuid::new_v4(),
+        Self::with_data(
             name,
-            visible: true,
-            opacity: 1.0,
-            data: LayerData::Objects {
+            LayerData::Objects {
                 entities: Vec::new(),
             },
-        }
+        )
+    }
+
+    /// Create a new image layer showing a single picture
+    pub fn new_image_layer(name: String, path: String) -> Self {
+        Self::with_data(
+            name,
+            LayerData::Image {
+                path,
+                repeat_x: false,
+                repeat_y: false,
+            },
+        )
+    }
+
+    /// Create a new empty group layer
+    pub fn new_group_layer(name: String) -> Self {
+        Self::with_data(name, LayerData::Group {})
+    }
+
+    /// Create a new IntGrid layer with all cells empty and no palette values
+    pub fn new_int_grid_layer(name: String, width: u32, height: u32) -> Self {
+        let size = (width * height) as usize;
+        Self::with_data(
+            name,
+            LayerData::IntGrid {
+                values: vec![0; size],
+                palette: Vec::new(),
+            },
+        )
     }
 
     /// Get the type of this layer
@@ -119,6 +200,9 @@ impl Layer {
         match &self.data {
             LayerData::Tiles { .. } => LayerType::Tiles,
             LayerData::Objects { .. } => LayerType::Objects,
+            LayerData::Image { .. } => LayerType::Image,
+            LayerData::Group { .. } => LayerType::Group,
+            LayerData::IntGrid { .. } => LayerType::IntGrid,
         }
     }
 
@@ -126,9 +210,62 @@ impl Layer {
     pub fn tileset_id(&self) -> Option<Uuid> {
         match &self.data {
             LayerData::Tiles { tileset_id, .. } => Some(*tileset_id),
-            LayerData::Objects { .. } => None,
+            _ => None,
         }
     }
+
+    /// Check if this is a group layer
+    pub fn is_group(&self) -> bool {
+        matches!(self.data, LayerData::Group { .. })
+    }
+
+    /// Get the palette entry for a value if this is an IntGrid layer
+    pub fn int_grid_value_def(&self, value: u32) -> Option<&IntGridValue> {
+        match &self.data {
+            LayerData::IntGrid { palette, .. } => palette.iter().find(|v| v.value == value),
+            _ => None,
+        }
+    }
+
+    /// Check if the layer scrolls at a different speed than the map
+    pub fn has_parallax(&self) -> bool {
+        self.parallax_x != 1.0 || self.parallax_y != 1.0
+    }
+
+    /// Get the layer's displacement for a camera position
+    ///
+    /// `camera_x`/`camera_y` are relative to the map origin. The result is the
+    /// pixel offset plus the parallax shift, so a layer with factor 0.5 moves at
+    /// half the camera's speed and a layer with factor 0.0 follows the camera.
+    pub fn parallax_offset(&self, camera_x: f32, camera_y: f32) -> (f32, f32) {
+        (
+            self.offset_x + camera_x * (1.0 - self.parallax_x),
+            self.offset_y + camera_y * (1.0 - self.parallax_y),
+        )
+    }
+
+    /// Get the tint as RGBA floats, or white if unset or invalid
+    pub fn tint_rgba(&self) -> [f32; 4] {
+        self.tint
+            .as_deref()
+            .and_then(parse_hex_rgba)
+            .unwrap_or([1.0, 1.0, 1.0, 1.0])
+    }
+}
+
+/// Parse a hex color like `#RRGGBB` or `#RRGGBBAA` into RGBA floats
+fn parse_hex_rgba(hex: &str) -> Option<[f32; 4]> {
+    let hex = hex.trim_start_matches('#');
+    if hex.len() != 6 && hex.len() != 8 {
+        return None;
+    }
+    let channel = |i: usize| {
+        hex.get(i..i + 2)
+            .and_then(|c| u8::from_str_radix(c, 16).ok())
+            .map(|c| c as f32 / 255.0)
+    };
+    let alpha = if hex.len() == 8 { channel(6)? } else { 1.0 };
+    Some([channel(0)?, channel(2)?, channel(4)?, alpha])
 }
 
 /// The type of a layer
@@ -136,6 +273,45 @@ impl Layer {
 pub enum LayerType {
     Tiles,
     Objects,
+    Image,
+    Group,
+    IntGrid,
+}
+
+/// A named, colored value of an IntGrid layer's palette
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntGridValue {
+    /// Value stored in the cells (0 is reserved for empty cells)
+    pub value: u32,
+    /// Name of the value, e.g. "water" or "ladder"
+    pub name: String,
+    /// Overlay color in the editor (hex format, e.g., "#3080FF")
+    pub color: String,
+}
+
+impl IntGridValue {
+    /// Create a palette entry
+    pub fn new(value: u32, name: String, color: String) -> Self {
+        Self { value, name, color }
+    }
+
+    /// Get the color as RGBA floats, or opaque white if invalid
+    pub fn color_rgba(&self) -> [f32; 4] {
+        parse_hex_rgba(&self.color).unwrap_or([1.0, 1.0, 1.0, 1.0])
+    }
+}
+
+/// Display state of a layer combined with all of its parent groups
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EffectiveLayer {
+    /// Visible only if the layer and every parent group are visible
+    pub visible: bool,
+    /// Product of the layer's and its parent groups' opacities
+    pub opacity: f32,
+    /// Sum of the layer's and its parent groups' horizontal offsets
+    pub offset_x: f32,
+    /// Sum of the layer's and its parent groups' vertical offsets
+    pub offset_y: f32,
 }
 
 /// The data contained in a layer
@@ -158,6 +334,37 @@ pub enum LayerData {
         /// Entity IDs placed on this layer
         entities: Vec<Uuid>,
     },
+    /// Image layer showing a single background or foreground picture
+    ///
+    /// The image's top-left corner sits at the level's top-left corner. Repeated
+    /// axes are tiled across the whole level.
+    Image {
+        /// Path to the image (relative to assets directory)
+        path: String,
+        /// Repeat the image horizontally
+        #[serde(default)]
+        repeat_x: bool,
+        /// Repeat the image vertically
+        #[serde(default)]
+        repeat_y: bool,
+    },
+    /// Group layer nesting other layers
+    ///
+    /// Children reference the group through `Layer::parent_id` and directly
+    /// follow it in the level's layer list. The group's visibility, opacity
+    /// and offset apply to all of its children.
+    Group {},
+    /// IntGrid layer of small integer values for gameplay data
+    ///
+    /// Holds values such as "water" or "no-spawn" zones without any tile art.
+    /// The editor shows each value as a colored overlay.
+    IntGrid {
+        /// Cell values in row-major order, 0 means empty
+        values: Vec<u32>,
+        /// Named, colored values that can be painted on this layer
+        #[serde(default)]
+        palette: Vec<IntGridValue>,
+    },
 }
 
 #[cfg(test)]
@@ -195,4 +402,71 @@ mod tests {
         assert!(layer.visible);
         assert_eq!(layer.layer_type(), LayerType::Objects);
     }
+
+    #[test]
+    fn test_new_int_grid_layer() {
+        let mut layer = Layer::new_int_grid_layer("Gameplay".to_string(), 4, 3);
+        assert_eq!(layer.layer_type(), LayerType::IntGrid);
+        assert!(layer.tileset_id().is_none());
+
+        if let LayerData::IntGrid { values, palette } = &mut layer.data {
+            assert_eq!(values.len(), 12);
+            assert!(values.iter().all(|v| *v == 0));
+            palette.push(IntGridValue::new(
+                2,
+                "water".to_string(),
+                "#3080FF".to_string(),
+            ));
+        } else {
+            panic!("Expected IntGrid layer");
+        }
+
+        let water = layer.int_grid_value_def(2).unwrap();
+        assert_eq!(water.name, "water");
+        assert_eq!(water.color_rgba()[3], 1.0);
+        assert!(layer.int_grid_value_def(1).is_none());
+    }
+
+    #[test]
+    fn test_tile_flip_flags() {
+        let tile = 42 | TILE_FLIP_X | TILE_FLIP_DIAGONAL;
+        assert_eq!(tile_index(tile), 42);
+        assert!(tile_flip_x(tile));
+        assert!(!tile_flip_y(tile));
+        assert!(tile_flip_diagonal(tile));
+
+        // The occupied sentinel has every flip bit set, so it must be checked first
+        assert!(is_occupied_cell(OCCUPIED_CELL));
+        assert!(!is_occupied_cell(tile));
+        assert!(tile_flip_diagonal(OCCUPIED_CELL));
+    }
+
+    #[test]
+    fn test_layer_parallax_and_tint() {
+        let mut layer = Layer::new_tile_layer("Sky".to_string(), Uuid::new_v4(), 4, 4);
+        assert!(!layer.has_parallax());
+        assert_eq!(layer.parallax_offset(100.0, 50.0), (0.0, 0.0));
+        assert_eq!(layer.tint_rgba(), [1.0, 1.0, 1.0, 1.0]);
+
+        layer.parallax_x = 0.5;
+        layer.parallax_y = 0.0;
+        layer.offset_x = 8.0;
+        assert!(layer.has_parallax());
+        assert_eq!(layer.parallax_offset(100.0, 50.0), (58.0, 50.0));
+
+        layer.tint = Some("#FF000080".to_string());
+        let [r, g, b, a] = layer.tint_rgba();
+        assert_eq!((r, g, b), (1.0, 0.0, 0.0));
+        assert!((a - 128.0 / 255.0).abs() < 1e-6);
+
+        layer.tint = Some("nonsense".to_string());
+        assert_eq!(layer.tint_rgba(), [1.0, 1.0, 1.0, 1.0]);
+
+        // Older files without the new fields load with neutral values
+        let json = r#"{"name":"Old","visible":true,"data":{"Objects":{"entities":[]}}}"#;
+        let layer: Layer = serde_json::from_str(json).unwrap();
+        assert_eq!((layer.parallax_x, layer.parallax_y), (1.0, 1.0));
+        assert_eq!((layer.offset_x, layer.offset_y), (0.0, 0.0));
+        assert!(layer.tint.is_none());
+    }
 }
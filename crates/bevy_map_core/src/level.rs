@@ -1,7 +1,10 @@
 //! Level/map containing tiles and entities
 
-use crate::{EntityInstance, Layer, LayerData};
+use crate::{
+    EffectiveLayer, EntityInstance, Layer, LayerData, MapOrientation, Value, OCCUPIED_CELL,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 /// A level/map containing tiles and entities
@@ -22,6 +25,12 @@ pub struct Level {
     /// Background color for world view (hex format, e.g., "#3C3C50")
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bg_color: Option<String>,
+    /// How the level's cells are laid out
+    #[serde(default, skip_serializing_if = "MapOrientation::is_orthogonal")]
+    pub orientation: MapOrientation,
+    /// Custom properties defined by the schema's `level_properties`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub properties: HashMap<String, Value>,
 }
 
 impl Level {
@@ -37,6 +46,8 @@ impl Level {
             world_x: 0,
             world_y: 0,
             bg_color: None,
+            orientation: MapOrientation::default(),
+            properties: HashMap::new(),
         }
     }
 
@@ -52,6 +63,8 @@ impl Level {
             world_x,
             world_y,
             bg_color: None,
+            orientation: MapOrientation::default(),
+            properties: HashMap::new(),
         }
     }
 
@@ -123,33 +136,410 @@ impl Level {
         }
     }
 
+    /// Place a tile covering `grid_size` cells, with its base cell at `(x, y)`
+    ///
+    /// Multi-cell tiles mark the cells they cover with [`OCCUPIED_CELL`].
+    /// Any tile overlapping the covered cells is erased first. Returns `false`
+    /// without changing anything if the tile doesn't fit in the level or the
+    /// layer is not a tile layer.
+    pub fn place_tile(
+        &mut self,
+        layer_index: usize,
+        x: u32,
+        y: u32,
+        tile: u32,
+        grid_size: (u32, u32),
+    ) -> bool {
+        let (grid_width, grid_height) = (grid_size.0.max(1), grid_size.1.max(1));
+        if x + grid_width > self.width || y + grid_height > self.height {
+            return false;
+        }
+        if !matches!(
+            self.layers.get(layer_index).map(|l| &l.data),
+            Some(LayerData::Tiles { .. })
+        ) {
+            return false;
+        }
+
+        for cy in y..y + grid_height {
+            for cx in x..x + grid_width {
+                self.erase_tile(layer_index, cx, cy);
+            }
+        }
+
+        let width = self.width;
+        let base = (y * width + x) as usize;
+        if let Some(LayerData::Tiles {
+            tiles,
+            occupied_cells,
+            ..
+        }) = self.layers.get_mut(layer_index).map(|l| &mut l.data)
+        {
+            for cy in y..y + grid_height {
+                for cx in x..x + grid_width {
+                    let cell = (cy * width + cx) as usize;
+                    let Some(slot) = tiles.get_mut(cell) else {
+                        continue;
+                    };
+                    if cell == base {
+                        *slot = Some(tile);
+                    } else {
+                        *slot = Some(OCCUPIED_CELL);
+                        occupied_cells.insert(cell, base);
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// Erase the tile covering `(x, y)`
+    ///
+    /// For a multi-cell tile this clears its base cell and every cell it
+    /// covers, whichever of them `(x, y)` is.
+    pub fn erase_tile(&mut self, layer_index: usize, x: u32, y: u32) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        let cell = (y * self.width + x) as usize;
+        if let Some(LayerData::Tiles {
+            tiles,
+            occupied_cells,
+            ..
+        }) = self.layers.get_mut(layer_index).map(|l| &mut l.data)
+        {
+            let base = occupied_cells.get(&cell).copied().unwrap_or(cell);
+            occupied_cells.retain(|&covered, &mut covered_base| {
+                if covered_base == base {
+                    if let Some(tile) = tiles.get_mut(covered) {
+                        *tile = None;
+                    }
+                    false
+                } else {
+                    true
+                }
+            });
+            if let Some(tile) = tiles.get_mut(base) {
+                *tile = None;
+            }
+            if let Some(tile) = tiles.get_mut(cell) {
+                *tile = None;
+            }
+        }
+    }
+
+    /// Get the value of an IntGrid cell (0 for empty cells and other layer types)
+    pub fn get_int_grid_value(&self, layer_index: usize, x: u32, y: u32) -> u32 {
+        if x >= self.width || y >= self.height {
+            return 0;
+        }
+        if let Some(layer) = self.layers.get(layer_index) {
+            if let LayerData::IntGrid { values, .. } = &layer.data {
+                let index = (y * self.width + x) as usize;
+                return values.get(index).copied().unwrap_or(0);
+            }
+        }
+        0
+    }
+
+    /// Set the value of an IntGrid cell (0 clears it)
+    pub fn set_int_grid_value(&mut self, layer_index: usize, x: u32, y: u32, value: u32) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        if let Some(layer) = self.layers.get_mut(layer_index) {
+            if let LayerData::IntGrid { values, .. } = &mut layer.data {
+                let index = (y * self.width + x) as usize;
+                if index < values.len() {
+                    values[index] = value;
+                }
+            }
+        }
+    }
+
+    /// Resize the level, keeping the content at `anchor` in place
+    ///
+    /// Tile and IntGrid layers grow with empty cells or are cropped. Entities
+    /// and the world position are shifted so nothing moves relative to the
+    /// anchored edge. `tile_size` is the width and height of a grid cell in
+    /// pixels.
+    pub fn resize(&mut self, width: u32, height: u32, anchor: ResizeAnchor, tile_size: (f32, f32)) {
+        let (offset_x, offset_y) = anchor.offset(self.width, self.height, width, height);
+        self.resize_with_offset(width, height, offset_x, offset_y, tile_size);
+    }
+
+    /// Resize the level and move its content by a cell offset
+    ///
+    /// Cell `(x, y)` moves to `(x + offset_x, y + offset_y)`; cells that end up
+    /// outside the new size are dropped. With an unchanged size this shifts the
+    /// level's origin.
+    pub fn resize_with_offset(
+        &mut self,
+        width: u32,
+        height: u32,
+        offset_x: i32,
+        offset_y: i32,
+        tile_size: (f32, f32),
+    ) {
+        let (old_width, old_height) = (self.width, self.height);
+        let remap = |x: u32, y: u32| -> Option<usize> {
+            let nx = x as i64 + offset_x as i64;
+            let ny = y as i64 + offset_y as i64;
+            (nx >= 0 && ny >= 0 && nx < width as i64 && ny < height as i64)
+                .then_some((ny * width as i64 + nx) as usize)
+        };
+        let remap_index = |index: usize| -> Option<usize> {
+            let index = index as u32;
+            if old_width == 0 || index >= old_width * old_height {
+                return None;
+            }
+            remap(index % old_width, index / old_width)
+        };
+        let cell_count = (width * height) as usize;
+
+        for layer in &mut self.layers {
+            match &mut layer.data {
+                LayerData::Tiles {
+                    tiles,
+                    occupied_cells,
+                    ..
+                } => {
+                    let mut new_tiles = vec![None; cell_count];
+                    for (index, tile) in tiles.iter().enumerate() {
+                        if let Some(new_index) = remap_index(index) {
+                            new_tiles[new_index] = *tile;
+                        }
+                    }
+
+                    // Multi-cell tiles whose base cell was cropped leave nothing behind
+                    let mut new_occupied = HashMap::new();
+                    for (&cell, &base) in occupied_cells.iter() {
+                        match (remap_index(cell), remap_index(base)) {
+                            (Some(new_cell), Some(new_base)) => {
+                                new_occupied.insert(new_cell, new_base);
+                            }
+                            (Some(new_cell), None)
+                                if new_tiles[new_cell] == Some(OCCUPIED_CELL) =>
+                            {
+                                new_tiles[new_cell] = None;
+                            }
+                            _ => {}
+                        }
+                    }
+
+                    *tiles = new_tiles;
+                    *occupied_cells = new_occupied;
+                }
+                LayerData::IntGrid { values, .. } => {
+                    let mut new_values = vec![0; cell_count];
+                    for (index, value) in values.iter().enumerate() {
+                        if let Some(new_index) = remap_index(index) {
+                            new_values[new_index] = *value;
+                        }
+                    }
+                    *values = new_values;
+                }
+                _ => {}
+            }
+        }
+
+        let (tile_width, tile_height) = tile_size;
+        let shift_x = offset_x as f32 * tile_width;
+        let shift_y = offset_y as f32 * tile_height;
+        for entity in &mut self.entities {
+            entity.position[0] += shift_x;
+            entity.position[1] += shift_y;
+        }
+
+        // World positions are measured from the level's top-left corner, y down
+        let top_shift = height as i32 - offset_y - old_height as i32;
+        self.world_x -= shift_x as i32;
+        self.world_y -= (top_shift as f32 * tile_height) as i32;
+
+        self.width = width;
+        self.height = height;
+    }
+
     /// Remove a layer by index
+    ///
+    /// Children of a removed group move up to the group's own parent.
     pub fn remove_layer(&mut self, index: usize) -> Option<Layer> {
-        if index < self.layers.len() {
-            Some(self.layers.remove(index))
-        } else {
-            None
+        if index >= self.layers.len() {
+            return None;
+        }
+        let layer = self.layers.remove(index);
+        if layer.is_group() {
+            for child in &mut self.layers {
+                if child.parent_id == Some(layer.id) {
+                    child.parent_id = layer.parent_id;
+                }
+            }
+        }
+        Some(layer)
+    }
+
+    /// Get the index of a layer by ID
+    pub fn layer_index_by_id(&self, id: Uuid) -> Option<usize> {
+        self.layers.iter().position(|layer| layer.id == id)
+    }
+
+    /// Get the index of the group a layer is nested in
+    pub fn parent_layer_index(&self, index: usize) -> Option<usize> {
+        let parent_id = self.layers.get(index)?.parent_id?;
+        self.layer_index_by_id(parent_id)
+            .filter(|&parent| parent != index && self.layers[parent].is_group())
+    }
+
+    /// Get the indices of a layer's parent groups, innermost first
+    pub fn layer_ancestors(&self, index: usize) -> Vec<usize> {
+        let mut ancestors = Vec::new();
+        let mut current = index;
+        while let Some(parent) = self.parent_layer_index(current) {
+            // Guard against cycles in hand-edited files
+            if parent == index || ancestors.contains(&parent) {
+                break;
+            }
+            ancestors.push(parent);
+            current = parent;
+        }
+        ancestors
+    }
+
+    /// Get the number of groups a layer is nested in
+    pub fn layer_depth(&self, index: usize) -> usize {
+        self.layer_ancestors(index).len()
+    }
+
+    /// Check if a layer is nested (directly or indirectly) in a group
+    pub fn is_layer_in_group(&self, index: usize, group_index: usize) -> bool {
+        self.layer_ancestors(index).contains(&group_index)
+    }
+
+    /// Get the display state of a layer combined with all of its parent groups
+    pub fn effective_layer(&self, index: usize) -> Option<EffectiveLayer> {
+        let layer = self.layers.get(index)?;
+        let mut effective = EffectiveLayer {
+            visible: layer.visible,
+            opacity: layer.opacity,
+            offset_x: layer.offset_x,
+            offset_y: layer.offset_y,
+        };
+        for ancestor in self.layer_ancestors(index) {
+            let group = &self.layers[ancestor];
+            effective.visible &= group.visible;
+            effective.opacity *= group.opacity;
+            effective.offset_x += group.offset_x;
+            effective.offset_y += group.offset_y;
+        }
+        Some(effective)
+    }
+
+    /// Get the index just past a layer and the children that directly follow it
+    pub fn layer_subtree_end(&self, index: usize) -> usize {
+        let mut end = (index + 1).min(self.layers.len());
+        while end < self.layers.len() && self.is_layer_in_group(end, index) {
+            end += 1;
+        }
+        end
+    }
+
+    /// Move a layer (and its children, for groups) into a group or to the top level
+    ///
+    /// The layer is placed after the group's last child, or at the end of the
+    /// layer list when `parent_id` is `None`. Returns the layer's new index, or
+    /// `None` if the parent is not a group or is the layer itself or one of its
+    /// children.
+    pub fn set_layer_parent(&mut self, index: usize, parent_id: Option<Uuid>) -> Option<usize> {
+        if index >= self.layers.len() {
+            return None;
+        }
+        if let Some(parent_id) = parent_id {
+            let parent = self.layer_index_by_id(parent_id)?;
+            if !self.layers[parent].is_group()
+                || parent == index
+                || self.is_layer_in_group(parent, index)
+            {
+                return None;
+            }
+        }
+
+        let end = self.layer_subtree_end(index);
+        let mut moved: Vec<Layer> = self.layers.drain(index..end).collect();
+        moved[0].parent_id = parent_id;
+
+        let insert_at = match parent_id.and_then(|id| self.layer_index_by_id(id)) {
+            Some(parent) => self.layer_subtree_end(parent),
+            None => self.layers.len(),
+        };
+        self.layers.splice(insert_at..insert_at, moved);
+        Some(insert_at)
+    }
+
+    /// Copy a layer (and its children, for groups) right after the original
+    ///
+    /// Every copied layer gets a new ID, and copied children belong to the
+    /// copied group. Returns the index of the copy.
+    pub fn duplicate_layer(&mut self, index: usize) -> Option<usize> {
+        if index >= self.layers.len() {
+            return None;
         }
+        let end = self.layer_subtree_end(index);
+        let mut copies = self.layers[index..end].to_vec();
+        let new_ids: HashMap<Uuid, Uuid> = copies
+            .iter()
+            .map(|layer| (layer.id, Uuid::new_v4()))
+            .collect();
+        for copy in &mut copies {
+            copy.id = new_ids[&copy.id];
+            if let Some(parent_id) = copy.parent_id.and_then(|id| new_ids.get(&id)) {
+                copy.parent_id = Some(*parent_id);
+            }
+        }
+        self.layers.splice(end..end, copies);
+        Some(end)
     }
 
     /// Move a layer up (toward index 0)
+    ///
+    /// The layer swaps places with the previous layer in the same group (or
+    /// at the top level), moving together with its children, so a layer never
+    /// leaves its group this way.
     pub fn move_layer_up(&mut self, index: usize) -> bool {
-        if index > 0 && index < self.layers.len() {
-            self.layers.swap(index, index - 1);
-            true
-        } else {
-            false
-        }
+        let Some(previous) = self.previous_sibling(index) else {
+            return false;
+        };
+        let end = self.layer_subtree_end(index);
+        self.layers[previous..end].rotate_left(index - previous);
+        true
     }
 
     /// Move a layer down (toward higher index)
+    ///
+    /// The layer swaps places with the next layer in the same group (or at the
+    /// top level), moving together with its children.
     pub fn move_layer_down(&mut self, index: usize) -> bool {
-        if index < self.layers.len().saturating_sub(1) {
-            self.layers.swap(index, index + 1);
-            true
-        } else {
-            false
+        if index >= self.layers.len() {
+            return false;
         }
+        let next = self.layer_subtree_end(index);
+        if next >= self.layers.len()
+            || self.parent_layer_index(next) != self.parent_layer_index(index)
+        {
+            return false;
+        }
+        self.move_layer_up(next)
+    }
+
+    /// Get the index of the previous layer with the same parent
+    fn previous_sibling(&self, index: usize) -> Option<usize> {
+        if index >= self.layers.len() {
+            return None;
+        }
+        let parent = self.parent_layer_index(index);
+        (0..index)
+            .rev()
+            .take_while(|&i| Some(i) != parent)
+            .find(|&i| self.parent_layer_index(i) == parent)
     }
 
     /// Toggle layer visibility
@@ -197,10 +587,178 @@ impl Level {
     }
 }
 
+/// The part of a level that stays in place when it is resized
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResizeAnchor {
+    #[default]
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl ResizeAnchor {
+    /// All anchors in reading order, for a 3x3 picker
+    pub const ALL: [ResizeAnchor; 9] = [
+        ResizeAnchor::TopLeft,
+        ResizeAnchor::Top,
+        ResizeAnchor::TopRight,
+        ResizeAnchor::Left,
+        ResizeAnchor::Center,
+        ResizeAnchor::Right,
+        ResizeAnchor::BottomLeft,
+        ResizeAnchor::Bottom,
+        ResizeAnchor::BottomRight,
+    ];
+
+    /// Cell offset applied to existing content when resizing between two sizes
+    ///
+    /// Rows count up from the bottom, so anchoring to the top moves content up
+    /// when the level grows.
+    pub fn offset(self, old_width: u32, old_height: u32, width: u32, height: u32) -> (i32, i32) {
+        let grow_x = width as i32 - old_width as i32;
+        let grow_y = height as i32 - old_height as i32;
+        let offset_x = match self {
+            ResizeAnchor::TopLeft | ResizeAnchor::Left | ResizeAnchor::BottomLeft => 0,
+            ResizeAnchor::Top | ResizeAnchor::Center | ResizeAnchor::Bottom => grow_x / 2,
+            ResizeAnchor::TopRight | ResizeAnchor::Right | ResizeAnchor::BottomRight => grow_x,
+        };
+        let offset_y = match self {
+            ResizeAnchor::BottomLeft | ResizeAnchor::Bottom | ResizeAnchor::BottomRight => 0,
+            ResizeAnchor::Left | ResizeAnchor::Center | ResizeAnchor::Right => grow_y / 2,
+            ResizeAnchor::TopLeft | ResizeAnchor::Top | ResizeAnchor::TopRight => grow_y,
+        };
+        (offset_x, offset_y)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_layer_groups() {
+        let mut level = Level::new("Groups".to_string(), 4, 4);
+        let mut group = Layer::new_group_layer("Background".to_string());
+        group.opacity = 0.5;
+        group.offset_x = 10.0;
+        let group_id = group.id;
+        level.add_layer(Layer::new_image_layer(
+            "Sky".to_string(),
+            "sky.png".to_string(),
+        ));
+        level.add_layer(group);
+        level.add_layer(Layer::new_object_layer("Entities".to_string()));
+
+        // Moving a layer into a group places it right after the group
+        assert_eq!(level.set_layer_parent(0, Some(group_id)), Some(1));
+        assert_eq!(level.layers[0].name, "Background");
+        assert_eq!(level.layers[1].name, "Sky");
+        assert_eq!(level.layer_depth(1), 1);
+        assert_eq!(level.layer_subtree_end(0), 2);
+
+        // Group state passes down to children
+        level.layers[1].opacity = 0.5;
+        level.layers[1].offset_x = 1.0;
+        let effective = level.effective_layer(1).unwrap();
+        assert!(effective.visible);
+        assert_eq!(effective.opacity, 0.25);
+        assert_eq!(effective.offset_x, 11.0);
+        level.layers[0].visible = false;
+        assert!(!level.effective_layer(1).unwrap().visible);
+
+        // A group can't be moved into itself or its own children
+        assert_eq!(level.set_layer_parent(0, Some(group_id)), None);
+        assert_eq!(level.set_layer_parent(0, Some(level.layers[1].id)), None);
+
+        // Moving a group moves its children along
+        assert_eq!(level.set_layer_parent(0, None), Some(1));
+        assert_eq!(level.layers[0].name, "Entities");
+        assert_eq!(level.layers[2].name, "Sky");
+
+        // Removing a group keeps its children at the group's level
+        level.remove_layer(1);
+        assert_eq!(level.layers[1].parent_id, None);
+        assert_eq!(level.layer_depth(1), 0);
+    }
+
+    #[test]
+    fn test_move_layers_with_groups() {
+        let mut level = Level::new("Groups".to_string(), 4, 4);
+        level.add_layer(Layer::new_object_layer("A".to_string()));
+        let group = Layer::new_group_layer("Group".to_string());
+        let group_id = group.id;
+        level.add_layer(group);
+        for name in ["Child 1", "Child 2"] {
+            let mut child = Layer::new_object_layer(name.to_string());
+            child.parent_id = Some(group_id);
+            level.add_layer(child);
+        }
+        level.add_layer(Layer::new_object_layer("B".to_string()));
+        let names = |level: &Level| {
+            level
+                .layers
+                .iter()
+                .map(|layer| layer.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        // A top-level layer skips over the whole group instead of entering it
+        assert!(level.move_layer_down(0));
+        assert_eq!(names(&level), ["Group", "Child 1", "Child 2", "A", "B"]);
+        assert_eq!(level.layers[3].parent_id, None);
+
+        // Children only move within their group
+        assert!(!level.move_layer_up(1));
+        assert!(level.move_layer_down(1));
+        assert_eq!(names(&level), ["Group", "Child 2", "Child 1", "A", "B"]);
+        assert!(!level.move_layer_down(2));
+
+        // Groups move together with their children
+        assert!(!level.move_layer_up(0));
+        assert!(level.move_layer_up(3));
+        assert!(level.move_layer_down(1));
+        assert_eq!(names(&level), ["A", "B", "Group", "Child 2", "Child 1"]);
+        assert_eq!(level.layer_subtree_end(2), 5);
+        assert!(level.layers[3..]
+            .iter()
+            .all(|layer| layer.parent_id == Some(group_id)));
+        assert!(!level.move_layer_down(2));
+    }
+
+    #[test]
+    fn test_duplicate_group_layer() {
+        let mut level = Level::new("Groups".to_string(), 4, 4);
+        let group = Layer::new_group_layer("Group".to_string());
+        let group_id = group.id;
+        level.add_layer(group);
+        let mut child = Layer::new_object_layer("Child".to_string());
+        child.parent_id = Some(group_id);
+        let child_id = child.id;
+        level.add_layer(child);
+        level.add_layer(Layer::new_object_layer("After".to_string()));
+
+        assert_eq!(level.duplicate_layer(0), Some(2));
+        assert_eq!(level.layers.len(), 5);
+        assert_eq!(level.layers[4].name, "After");
+
+        let (copy, copied_child) = (&level.layers[2], &level.layers[3]);
+        assert_ne!(copy.id, group_id);
+        assert_eq!(copy.parent_id, None);
+        assert_ne!(copied_child.id, child_id);
+        assert_eq!(copied_child.parent_id, Some(copy.id));
+        assert_eq!(level.layer_subtree_end(2), 4);
+
+        // The original group keeps its own child
+        assert_eq!(level.layers[1].parent_id, Some(group_id));
+        assert_eq!(level.layer_subtree_end(0), 2);
+    }
+
     #[test]
     fn test_new_level() {
         let level = Level::new("Test Level".to_string(), 10, 10);
@@ -234,6 +792,95 @@ mod tests {
         assert_eq!(level.get_tile(0, 5, 5), None);
     }
 
+    #[test]
+    fn test_int_grid_operations() {
+        let mut level = Level::new("Test".to_string(), 10, 10);
+        level.add_layer(Layer::new_int_grid_layer("Gameplay".to_string(), 10, 10));
+
+        assert_eq!(level.get_int_grid_value(0, 3, 4), 0);
+        level.set_int_grid_value(0, 3, 4, 2);
+        assert_eq!(level.get_int_grid_value(0, 3, 4), 2);
+        // Tile accessors don't see IntGrid values
+        assert_eq!(level.get_tile(0, 3, 4), None);
+        // Out of bounds writes are ignored
+        level.set_int_grid_value(0, 10, 0, 1);
+        assert_eq!(level.get_int_grid_value(0, 10, 0), 0);
+    }
+
+    #[test]
+    fn test_resize_level() {
+        let mut level = Level::new("Test".to_string(), 4, 4);
+        level.add_layer(Layer::new_tile_layer(
+            "Ground".to_string(),
+            Uuid::new_v4(),
+            4,
+            4,
+        ));
+        level.add_layer(Layer::new_int_grid_layer("Gameplay".to_string(), 4, 4));
+        level.set_tile(0, 0, 0, Some(1));
+        level.set_tile(0, 3, 3, Some(2));
+        level.set_int_grid_value(1, 3, 3, 5);
+        level.add_entity(EntityInstance::new("NPC".to_string(), [8.0, 8.0]));
+
+        // Growing from the top-right keeps the top-right corner in place
+        level.resize(6, 5, ResizeAnchor::TopRight, (16.0, 16.0));
+        assert_eq!((level.width, level.height), (6, 5));
+        assert_eq!(level.get_tile(0, 2, 1), Some(1));
+        assert_eq!(level.get_tile(0, 5, 4), Some(2));
+        assert_eq!(level.get_int_grid_value(1, 5, 4), 5);
+        assert_eq!(level.entities[0].position, [40.0, 24.0]);
+        assert_eq!(level.world_position(), (-32, 0));
+
+        // Cropping from the bottom-left drops the far corner
+        level.resize(3, 3, ResizeAnchor::BottomLeft, (16.0, 16.0));
+        assert_eq!(level.get_tile(0, 2, 1), Some(1));
+        assert_eq!(level.get_int_grid_value(1, 2, 2), 0);
+        if let LayerData::Tiles { tiles, .. } = &level.layers[0].data {
+            assert_eq!(tiles.len(), 9);
+            assert_eq!(tiles.iter().flatten().count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_resize_anchor_offset() {
+        assert_eq!(ResizeAnchor::BottomLeft.offset(4, 4, 8, 8), (0, 0));
+        assert_eq!(ResizeAnchor::Center.offset(4, 4, 8, 6), (2, 1));
+        assert_eq!(ResizeAnchor::TopRight.offset(4, 4, 2, 2), (-2, -2));
+    }
+
+    #[test]
+    fn test_place_and_erase_multi_cell_tile() {
+        let mut level = Level::new("Test".to_string(), 4, 4);
+        level.add_layer(Layer::new_tile_layer(
+            "Tiles".to_string(),
+            Uuid::new_v4(),
+            4,
+            4,
+        ));
+
+        level.place_tile(0, 0, 0, 7, (1, 1));
+        assert!(level.place_tile(0, 1, 1, 3, (2, 2)));
+        assert_eq!(level.get_tile(0, 1, 1), Some(3));
+        assert_eq!(level.get_tile(0, 2, 2), Some(OCCUPIED_CELL));
+        assert!(!level.place_tile(0, 3, 3, 3, (2, 2)));
+
+        // Placing over a covered cell removes the whole multi-cell tile
+        assert!(level.place_tile(0, 2, 2, 5, (1, 1)));
+        assert_eq!(level.get_tile(0, 1, 1), None);
+        assert_eq!(level.get_tile(0, 2, 1), None);
+        assert_eq!(level.get_tile(0, 2, 2), Some(5));
+
+        // Erasing any covered cell clears the base too
+        level.place_tile(0, 0, 2, 3, (2, 2));
+        level.erase_tile(0, 1, 3);
+        assert_eq!(level.get_tile(0, 0, 2), None);
+        assert_eq!(level.get_tile(0, 0, 3), None);
+        assert_eq!(level.get_tile(0, 0, 0), Some(7));
+        if let LayerData::Tiles { occupied_cells, .. } = &level.layers[0].data {
+            assert!(occupied_cells.is_empty());
+        }
+    }
+
     #[test]
     fn test_entity_operations() {
         let mut level = Level::new("Test".to_string(), 10, 10);
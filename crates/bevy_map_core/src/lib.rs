@@ -5,20 +5,32 @@
 //! - `Layer` - A single layer (tiles or objects)
 //! - `Tileset` - Tile atlas configuration with multi-image support
 //! - `EntityInstance` - Placed entities with properties
+//! - `EntityTemplate` - Named entity presets that instances inherit from
+//! - `RegionShape` - Rectangle or polygon area of a region entity
 //! - `Value` - Generic property value type
 //! - `MapProject` - Self-contained format bundling level and tilesets
+//! - `MapOrientation` - Orthogonal, isometric or hexagonal cell layout
 //! - `EntityTypeConfig` - Type-level component configurations (physics, input, sprite)
+//! - `to_binary` / `from_binary` - Compact binary encoding of map files
 
+mod binary;
 mod collision;
 mod entity;
 mod entity_type_config;
 mod layer;
 mod level;
+mod orientation;
 mod project;
+mod region;
+mod template;
 mod tileset;
 mod value;
 mod world;
 
+pub use binary::{
+    binary_to_json, from_binary, is_binary, to_binary, BinaryError, BINARY_FORMAT_VERSION,
+    BINARY_MAGIC,
+};
 pub use collision::{CollisionData, CollisionShape, OneWayDirection, PhysicsBody};
 pub use entity::{
     ComponentOverrides, EntityInstance, InputOverrides, PhysicsOverrides, SpriteOverrides,
@@ -28,11 +40,15 @@ pub use entity_type_config::{
     SpriteConfig,
 };
 pub use layer::{
-    tile_flip_x, tile_flip_y, tile_index, tile_with_flips, toggle_flip_x, toggle_flip_y, Layer,
-    LayerData, LayerType, OCCUPIED_CELL, TILE_FLIP_MASK, TILE_FLIP_X, TILE_FLIP_Y, TILE_INDEX_MASK,
+    is_occupied_cell, tile_flip_diagonal, tile_flip_x, tile_flip_y, tile_index, tile_with_flips,
+    toggle_flip_x, toggle_flip_y, EffectiveLayer, IntGridValue, Layer, LayerData, LayerType,
+    OCCUPIED_CELL, TILE_FLIP_DIAGONAL, TILE_FLIP_MASK, TILE_FLIP_X, TILE_FLIP_Y, TILE_INDEX_MASK,
 };
-pub use level::Level;
-pub use project::{EditorProject, MapProject, MapProjectBuilder};
+pub use level::{Level, ResizeAnchor};
+pub use orientation::MapOrientation;
+pub use project::{EditorProject, LevelSelector, MapProject, MapProjectBuilder, WorldProject};
+pub use region::RegionShape;
+pub use template::EntityTemplate;
 pub use tileset::{TileProperties, Tileset, TilesetImage};
 pub use value::Value;
 pub use world::{ConnectionDirection, LevelConnection, WorldConfig, WorldLayout};
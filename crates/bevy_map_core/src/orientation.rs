@@ -0,0 +1,266 @@
+//! Grid orientation of a level
+//!
+//! Levels store tiles in a `width * height` grid no matter how it is drawn.
+//! The orientation decides where each cell ends up on screen, matching the
+//! isometric and hexagonal layouts of bevy_ecs_tilemap:
+//!
+//! - `Orthogonal` - square grid, rows stacked bottom to top
+//! - `Isometric` - diamond grid, +x runs down-right and +y up-right
+//! - `IsometricStaggered` - diamond cells in zig-zag rows, odd rows shifted right
+//! - `HexRow` - pointy-top hexagons, odd rows shifted right
+//! - `HexColumn` - flat-top hexagons, odd columns shifted up
+//!
+//! Positions are in pixels relative to the center of cell `(0, 0)`, with +y up.
+
+use serde::{Deserialize, Serialize};
+
+/// How the cells of a level are laid out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum MapOrientation {
+    /// Square cells in rows and columns
+    #[default]
+    Orthogonal,
+    /// Diamond cells, the whole level forms a diamond
+    Isometric,
+    /// Diamond cells in zig-zag rows, the whole level forms a rectangle
+    IsometricStaggered,
+    /// Pointy-top hexagons in rows
+    HexRow,
+    /// Flat-top hexagons in columns
+    HexColumn,
+}
+
+impl MapOrientation {
+    /// Get display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            MapOrientation::Orthogonal => "Orthogonal",
+            MapOrientation::Isometric => "Isometric (Diamond)",
+            MapOrientation::IsometricStaggered => "Isometric (Staggered)",
+            MapOrientation::HexRow => "Hexagonal (Rows)",
+            MapOrientation::HexColumn => "Hexagonal (Columns)",
+        }
+    }
+
+    /// Returns all orientations for UI enumeration
+    pub fn all() -> &'static [MapOrientation] {
+        &[
+            MapOrientation::Orthogonal,
+            MapOrientation::Isometric,
+            MapOrientation::IsometricStaggered,
+            MapOrientation::HexRow,
+            MapOrientation::HexColumn,
+        ]
+    }
+
+    /// Check if this is the default square grid
+    pub fn is_orthogonal(&self) -> bool {
+        *self == MapOrientation::Orthogonal
+    }
+
+    /// Center of a cell, relative to the center of cell `(0, 0)`
+    pub fn cell_center(&self, x: i32, y: i32, tile_width: f32, tile_height: f32) -> [f32; 2] {
+        let (fx, fy) = (x as f32, y as f32);
+        let odd = |n: i32| (n & 1) as f32;
+        match self {
+            MapOrientation::Orthogonal => [fx * tile_width, fy * tile_height],
+            MapOrientation::Isometric => {
+                [(fx + fy) * tile_width / 2.0, (fy - fx) * tile_height / 2.0]
+            }
+            MapOrientation::IsometricStaggered => {
+                [(fx + odd(y) / 2.0) * tile_width, fy * tile_height / 2.0]
+            }
+            MapOrientation::HexRow => [(fx + odd(y) / 2.0) * tile_width, fy * tile_height * 0.75],
+            MapOrientation::HexColumn => {
+                [fx * tile_width * 0.75, (fy + odd(x) / 2.0) * tile_height]
+            }
+        }
+    }
+
+    /// Cell containing a point, relative to the center of cell `(0, 0)`
+    ///
+    /// The grid is unbounded, so the cell may lie outside the level.
+    pub fn cell_at(&self, point: [f32; 2], tile_width: f32, tile_height: f32) -> [i32; 2] {
+        let [gx, gy] = self.grid_position(point, tile_width, tile_height);
+        [gx.floor() as i32, gy.floor() as i32]
+    }
+
+    /// Convert a point to continuous grid coordinates
+    ///
+    /// Cell `(x, y)` covers `x..x + 1` and `y..y + 1`, so tools built for a
+    /// square grid (rectangles, lines, terrain corners) work on any
+    /// orientation. Orthogonal and isometric grids map exactly; staggered and
+    /// hex grids map each cell's bounding box onto its unit square.
+    pub fn grid_position(&self, point: [f32; 2], tile_width: f32, tile_height: f32) -> [f32; 2] {
+        let [px, py] = [point[0] / tile_width, point[1] / tile_height];
+        match self {
+            MapOrientation::Orthogonal => [px + 0.5, py + 0.5],
+            MapOrientation::Isometric => [px - py + 0.5, px + py + 0.5],
+            _ => {
+                let [x, y] = self.nearest_cell(px, py);
+                let [cx, cy] = self.cell_center(x, y, 1.0, 1.0);
+                [
+                    x as f32 + (px - cx + 0.5).clamp(0.0, 0.999),
+                    y as f32 + (py - cy + 0.5).clamp(0.0, 0.999),
+                ]
+            }
+        }
+    }
+
+    /// Find the cell whose shape contains a point given in tile units
+    fn nearest_cell(&self, px: f32, py: f32) -> [i32; 2] {
+        // Distance from a cell center, at most 1 inside the cell's shape
+        let distance = |dx: f32, dy: f32| match self {
+            // Diamonds: |dx| / half width + |dy| / half height
+            MapOrientation::IsometricStaggered => 2.0 * (dx.abs() + dy.abs()),
+            // Hexagons are nearest-center cells once scaled to be regular
+            MapOrientation::HexRow => ((dx * 3f32.sqrt()).powi(2) + (dy * 2.0).powi(2)).sqrt(),
+            _ => ((dx * 2.0).powi(2) + (dy * 3f32.sqrt()).powi(2)).sqrt(),
+        };
+        let estimate = match self {
+            MapOrientation::IsometricStaggered => [px.round() as i32, (py * 2.0).round() as i32],
+            MapOrientation::HexRow => [px.round() as i32, (py / 0.75).round() as i32],
+            _ => [(px / 0.75).round() as i32, py.round() as i32],
+        };
+
+        let mut best = (f32::INFINITY, estimate);
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                let cell = [estimate[0] + dx, estimate[1] + dy];
+                let [cx, cy] = self.cell_center(cell[0], cell[1], 1.0, 1.0);
+                let d = distance(px - cx, py - cy);
+                if d < best.0 {
+                    best = (d, cell);
+                }
+            }
+        }
+        best.1
+    }
+
+    /// Corners of a cell's shape, relative to its center, counter-clockwise
+    pub fn cell_outline(&self, tile_width: f32, tile_height: f32) -> Vec<[f32; 2]> {
+        let (hw, hh) = (tile_width / 2.0, tile_height / 2.0);
+        match self {
+            MapOrientation::Orthogonal => vec![[-hw, -hh], [hw, -hh], [hw, hh], [-hw, hh]],
+            MapOrientation::Isometric | MapOrientation::IsometricStaggered => {
+                vec![[0.0, -hh], [hw, 0.0], [0.0, hh], [-hw, 0.0]]
+            }
+            MapOrientation::HexRow => vec![
+                [0.0, -hh],
+                [hw, -hh / 2.0],
+                [hw, hh / 2.0],
+                [0.0, hh],
+                [-hw, hh / 2.0],
+                [-hw, -hh / 2.0],
+            ],
+            MapOrientation::HexColumn => vec![
+                [-hw / 2.0, -hh],
+                [hw / 2.0, -hh],
+                [hw, 0.0],
+                [hw / 2.0, hh],
+                [-hw / 2.0, hh],
+                [-hw, 0.0],
+            ],
+        }
+    }
+
+    /// Bounding box of a whole level as `(min, max)`, relative to the center
+    /// of cell `(0, 0)`
+    pub fn level_bounds(
+        &self,
+        width: u32,
+        height: u32,
+        tile_width: f32,
+        tile_height: f32,
+    ) -> ([f32; 2], [f32; 2]) {
+        let (hw, hh) = (tile_width / 2.0, tile_height / 2.0);
+        if width == 0 || height == 0 {
+            return ([-hw, -hh], [hw, hh]);
+        }
+        let (w, h) = (width as i32, height as i32);
+
+        // The outermost cells all lie on the level's edge
+        let edge = (0..w)
+            .flat_map(|x| [(x, 0), (x, h - 1)])
+            .chain((0..h).flat_map(|y| [(0, y), (w - 1, y)]));
+        let mut min = [f32::INFINITY; 2];
+        let mut max = [f32::NEG_INFINITY; 2];
+        for (x, y) in edge {
+            let [cx, cy] = self.cell_center(x, y, tile_width, tile_height);
+            min = [min[0].min(cx - hw), min[1].min(cy - hh)];
+            max = [max[0].max(cx + hw), max[1].max(cy + hh)];
+        }
+        (min, max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cell_at_inverts_cell_center() {
+        for orientation in MapOrientation::all() {
+            for y in -3..6 {
+                for x in -3..6 {
+                    let [cx, cy] = orientation.cell_center(x, y, 32.0, 16.0);
+                    assert_eq!(
+                        orientation.cell_at([cx, cy], 32.0, 16.0),
+                        [x, y],
+                        "{:?} cell ({}, {})",
+                        orientation,
+                        x,
+                        y
+                    );
+
+                    // Points just inside the outline belong to the same cell
+                    for [ox, oy] in orientation.cell_outline(32.0, 16.0) {
+                        let point = [cx + ox * 0.9, cy + oy * 0.9];
+                        assert_eq!(orientation.cell_at(point, 32.0, 16.0), [x, y]);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_orientation_layouts() {
+        let center = |o: MapOrientation, x, y| o.cell_center(x, y, 32.0, 16.0);
+        assert_eq!(center(MapOrientation::Orthogonal, 2, 1), [64.0, 16.0]);
+        assert_eq!(center(MapOrientation::Isometric, 1, 0), [16.0, -8.0]);
+        assert_eq!(center(MapOrientation::Isometric, 0, 1), [16.0, 8.0]);
+        assert_eq!(
+            center(MapOrientation::IsometricStaggered, 0, 1),
+            [16.0, 8.0]
+        );
+        assert_eq!(
+            center(MapOrientation::IsometricStaggered, 0, 2),
+            [0.0, 16.0]
+        );
+        assert_eq!(center(MapOrientation::HexRow, 0, 1), [16.0, 12.0]);
+        assert_eq!(center(MapOrientation::HexColumn, 1, 0), [24.0, 8.0]);
+
+        // Orthogonal grid positions are plain pixel divisions
+        assert_eq!(
+            MapOrientation::Orthogonal.grid_position([-16.0, 24.0], 32.0, 16.0),
+            [0.0, 2.0]
+        );
+
+        let (min, max) = MapOrientation::Orthogonal.level_bounds(4, 3, 16.0, 16.0);
+        assert_eq!((min, max), ([-8.0, -8.0], [56.0, 40.0]));
+        let (min, max) = MapOrientation::Isometric.level_bounds(4, 4, 32.0, 16.0);
+        assert_eq!((min, max), ([-16.0, -32.0], [112.0, 32.0]));
+    }
+
+    #[test]
+    fn test_orthogonal_is_not_serialized() {
+        let mut level = crate::Level::new("Test".to_string(), 4, 4);
+        let json = serde_json::to_value(&level).unwrap();
+        assert!(json.get("orientation").is_none());
+
+        level.orientation = MapOrientation::HexRow;
+        let json = serde_json::to_string(&level).unwrap();
+        let loaded: crate::Level = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.orientation, MapOrientation::HexRow);
+    }
+}
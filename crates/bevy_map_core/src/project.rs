@@ -7,12 +7,17 @@
 //! Two formats are supported:
 //! - `MapProject`: Simple format with HashMap collections (for hand-crafted JSON)
 //! - `EditorProject`: Full editor format with array collections (exported by the editor)
+//!
+//! `WorldProject` keeps every level of a project (plus the world config) for games
+//! that need more than the first level.
 
-use crate::{EntityTypeConfig, Level, Tileset};
+use crate::{
+    EntityTemplate, EntityTypeConfig, Level, LevelConnection, RegionShape, Tileset, WorldConfig,
+};
 use bevy_map_animation::SpriteData;
 use bevy_map_dialogue::DialogueTree;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 /// Editor project format - matches what the editor exports
@@ -47,6 +52,12 @@ pub struct EditorProject {
     /// Entity type component configurations (physics, input, sprite per type)
     #[serde(default)]
     pub entity_type_configs: HashMap<String, EntityTypeConfig>,
+    /// World layout and level connections
+    #[serde(default)]
+    pub world_config: WorldConfig,
+    /// Entity templates (prefabs) referenced by placed instances
+    #[serde(default)]
+    pub entity_templates: Vec<EntityTemplate>,
 }
 
 impl EditorProject {
@@ -55,6 +66,16 @@ impl EditorProject {
         self.levels.first()
     }
 
+    /// Get a level by name
+    pub fn level_by_name(&self, name: &str) -> Option<&Level> {
+        self.levels.iter().find(|l| l.name == name)
+    }
+
+    /// Get a level by ID
+    pub fn level_by_id(&self, id: Uuid) -> Option<&Level> {
+        self.levels.iter().find(|l| l.id == id)
+    }
+
     /// Get the first sprite sheet
     pub fn first_sprite_sheet(&self) -> Option<&SpriteData> {
         self.sprite_sheets.first()
@@ -80,31 +101,144 @@ impl EditorProject {
         self.tilesets.iter().find(|t| t.id == id)
     }
 
+    /// Get an entity template by ID
+    pub fn template_by_id(&self, id: Uuid) -> Option<&EntityTemplate> {
+        self.entity_templates.iter().find(|t| t.id == id)
+    }
+
     /// Convert to MapProject (uses first level)
     pub fn to_map_project(&self) -> Option<MapProject> {
-        let level = self.first_level()?.clone();
-        let tilesets = self.tilesets.iter().map(|t| (t.id, t.clone())).collect();
-        let sprite_sheets = self
-            .sprite_sheets
-            .iter()
-            .map(|s| (s.id, s.clone()))
-            .collect();
-        let dialogues = self
-            .dialogues
-            .iter()
-            .map(|d| (d.id.to_string(), d.clone()))
-            .collect();
+        let level = self.first_level()?.id;
+        self.to_map_project_for_level(&LevelSelector::Id(level))
+    }
+
+    /// Convert to MapProject for a specific level
+    pub fn to_map_project_for_level(&self, selector: &LevelSelector) -> Option<MapProject> {
+        let level = self.resolve_level(self.levels.iter().find(|l| selector.matches(l))?);
 
         Some(MapProject {
             version: self.version,
             level,
-            tilesets,
-            sprite_sheets,
-            dialogues,
+            tilesets: self.tileset_map(),
+            sprite_sheets: self.sprite_sheet_map(),
+            dialogues: self.dialogue_map(),
             entity_type_configs: self.entity_type_configs.clone(),
+            autotile_config: self.autotile_config.clone(),
+            ref_properties: self.ref_properties(),
         })
     }
 
+    /// Convert to WorldProject (keeps every level and the world config)
+    pub fn to_world_project(&self) -> WorldProject {
+        WorldProject {
+            version: self.version,
+            levels: self
+                .levels
+                .iter()
+                .map(|level| self.resolve_level(level))
+                .collect(),
+            tilesets: self.tileset_map(),
+            sprite_sheets: self.sprite_sheet_map(),
+            dialogues: self.dialogue_map(),
+            entity_type_configs: self.entity_type_configs.clone(),
+            world_config: self.world_config.clone(),
+            autotile_config: self.autotile_config.clone(),
+            ref_properties: self.ref_properties(),
+        }
+    }
+
+    /// Copy a level with template values baked into its entities
+    ///
+    /// Runtime formats don't carry templates or the schema, so instances are
+    /// resolved here and instances of region types always get a shape.
+    fn resolve_level(&self, level: &Level) -> Level {
+        let region_types = self.region_types();
+        let mut level = level.clone();
+        for entity in &mut level.entities {
+            if let Some(template) = entity.template_id.and_then(|id| self.template_by_id(id)) {
+                *entity = entity.resolve(template);
+            }
+            if entity.region.is_none() && region_types.contains(&entity.type_name) {
+                entity.region = Some(RegionShape::Rectangle);
+            }
+        }
+        level
+    }
+
+    /// Get the names of the entity types whose kind is `region` in the schema
+    pub fn region_types(&self) -> HashSet<String> {
+        let Some(types) = self
+            .schema
+            .as_ref()
+            .and_then(|schema| schema.get("data_types"))
+            .and_then(|types| types.as_object())
+        else {
+            return HashSet::new();
+        };
+
+        types
+            .iter()
+            .filter(|(_, type_def)| type_def.get("kind").and_then(|k| k.as_str()) == Some("region"))
+            .map(|(type_name, _)| type_name.clone())
+            .collect()
+    }
+
+    fn tileset_map(&self) -> HashMap<Uuid, Tileset> {
+        self.tilesets.iter().map(|t| (t.id, t.clone())).collect()
+    }
+
+    fn sprite_sheet_map(&self) -> HashMap<Uuid, SpriteData> {
+        self.sprite_sheets
+            .iter()
+            .map(|s| (s.id, s.clone()))
+            .collect()
+    }
+
+    /// Get the reference properties of each entity type from the schema
+    ///
+    /// Covers `ref` properties and arrays whose item type is a data type.
+    pub fn ref_properties(&self) -> HashMap<String, Vec<String>> {
+        let Some(types) = self
+            .schema
+            .as_ref()
+            .and_then(|schema| schema.get("data_types"))
+            .and_then(|types| types.as_object())
+        else {
+            return HashMap::new();
+        };
+
+        let mut ref_properties = HashMap::new();
+        for (type_name, type_def) in types {
+            let Some(properties) = type_def.get("properties").and_then(|p| p.as_array()) else {
+                continue;
+            };
+            let names: Vec<String> = properties
+                .iter()
+                .filter(|prop| match prop.get("type").and_then(|t| t.as_str()) {
+                    Some("ref") => true,
+                    Some("array") => prop
+                        .get("itemType")
+                        .and_then(|t| t.as_str())
+                        .is_some_and(|item_type| types.contains_key(item_type)),
+                    _ => false,
+                })
+                .filter_map(|prop| prop.get("name").and_then(|n| n.as_str()))
+                .map(|name| name.to_string())
+                .collect();
+            if !names.is_empty() {
+                ref_properties.insert(type_name.clone(), names);
+            }
+        }
+        ref_properties
+    }
+
+    fn dialogue_map(&self) -> HashMap<String, DialogueTree> {
+        self.dialogues
+            .iter()
+            .map(|d| (d.id.to_string(), d.clone()))
+            .collect()
+    }
+
     /// Get entity type config by type name
     pub fn get_entity_type_config(&self, type_name: &str) -> Option<&EntityTypeConfig> {
         self.entity_type_configs.get(type_name)
@@ -142,6 +276,14 @@ pub struct MapProject {
     /// Entity type component configurations (physics, input, sprite per type)
     #[serde(default)]
     pub entity_type_configs: HashMap<String, EntityTypeConfig>,
+    /// Autotile terrain configuration, for painting terrain at runtime
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub autotile_config: Option<serde_json::Value>,
+    /// Reference properties of each entity type, keyed by type name
+    ///
+    /// Their values are `EntityInstance` IDs, or arrays of them.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub ref_properties: HashMap<String, Vec<String>>,
 }
 
 impl MapProject {
@@ -155,6 +297,8 @@ impl MapProject {
             sprite_sheets: HashMap::new(),
             dialogues: HashMap::new(),
             entity_type_configs: HashMap::new(),
+            autotile_config: None,
+            ref_properties: HashMap::new(),
         }
     }
 
@@ -173,6 +317,8 @@ impl MapProject {
             sprite_sheets: sprite_sheet_map,
             dialogues: HashMap::new(),
             entity_type_configs: HashMap::new(),
+            autotile_config: None,
+            ref_properties: HashMap::new(),
         }
     }
 
@@ -312,10 +458,199 @@ impl MapProjectBuilder {
     }
 }
 
+/// Identifies a level within a multi-level project, either by name or by UUID
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LevelSelector {
+    /// Match the level with this name
+    Name(String),
+    /// Match the level with this ID
+    Id(Uuid),
+}
+
+impl LevelSelector {
+    /// Check whether the given level matches this selector
+    pub fn matches(&self, level: &Level) -> bool {
+        match self {
+            LevelSelector::Name(name) => level.name == *name,
+            LevelSelector::Id(id) => level.id == *id,
+        }
+    }
+}
+
+impl From<&str> for LevelSelector {
+    fn from(name: &str) -> Self {
+        LevelSelector::Name(name.to_string())
+    }
+}
+
+impl From<String> for LevelSelector {
+    fn from(name: String) -> Self {
+        LevelSelector::Name(name)
+    }
+}
+
+impl From<Uuid> for LevelSelector {
+    fn from(id: Uuid) -> Self {
+        LevelSelector::Id(id)
+    }
+}
+
+impl std::fmt::Display for LevelSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LevelSelector::Name(name) => write!(f, "'{}'", name),
+            LevelSelector::Id(id) => write!(f, "{}", id),
+        }
+    }
+}
+
+/// A whole project with every level, for games that use more than one level
+///
+/// Where `MapProject` holds a single level, this keeps all levels of an editor
+/// project together with the world layout and level connections. Individual
+/// levels can be extracted as a `MapProject` for spawning.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "bevy", derive(bevy::asset::Asset, bevy::reflect::TypePath))]
+pub struct WorldProject {
+    /// Format version for future compatibility
+    pub version: u32,
+    /// All levels in the project
+    pub levels: Vec<Level>,
+    /// Tilesets shared by all levels, keyed by their UUID
+    pub tilesets: HashMap<Uuid, Tileset>,
+    /// Sprite sheets, keyed by their UUID
+    #[serde(default)]
+    pub sprite_sheets: HashMap<Uuid, SpriteData>,
+    /// Dialogue trees, keyed by their ID
+    #[serde(default)]
+    pub dialogues: HashMap<String, DialogueTree>,
+    /// Entity type component configurations (physics, input, sprite per type)
+    #[serde(default)]
+    pub entity_type_configs: HashMap<String, EntityTypeConfig>,
+    /// World layout and level connections
+    #[serde(default)]
+    pub world_config: WorldConfig,
+    /// Autotile terrain configuration, for painting terrain at runtime
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub autotile_config: Option<serde_json::Value>,
+    /// Reference properties of each entity type, keyed by type name
+    ///
+    /// Their values are `EntityInstance` IDs, or arrays of them.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub ref_properties: HashMap<String, Vec<String>>,
+}
+
+impl WorldProject {
+    /// Get a level by selector
+    pub fn level(&self, selector: &LevelSelector) -> Option<&Level> {
+        self.levels.iter().find(|l| selector.matches(l))
+    }
+
+    /// Get a level by name
+    pub fn level_by_name(&self, name: &str) -> Option<&Level> {
+        self.levels.iter().find(|l| l.name == name)
+    }
+
+    /// Get a level by ID
+    pub fn level_by_id(&self, id: Uuid) -> Option<&Level> {
+        self.levels.iter().find(|l| l.id == id)
+    }
+
+    /// Get the names of all levels, in project order
+    pub fn level_names(&self) -> impl Iterator<Item = &str> {
+        self.levels.iter().map(|l| l.name.as_str())
+    }
+
+    /// Get a tileset by ID
+    pub fn get_tileset(&self, id: Uuid) -> Option<&Tileset> {
+        self.tilesets.get(&id)
+    }
+
+    /// Get connections leaving the given level
+    pub fn connections_from(&self, level_id: Uuid) -> Vec<&LevelConnection> {
+        self.world_config.connections_from(level_id)
+    }
+
+    /// Get the tile size used to lay out a level in the world
+    ///
+    /// Uses the tileset of the level's first tile layer, falling back to the
+    /// project's tileset with the lowest ID, then to 32.
+    pub fn level_tile_size(&self, level: &Level) -> u32 {
+        self.level_tile_dimensions(level).0
+    }
+
+    /// Get the tile width and height used to lay out a level in the world
+    ///
+    /// Picks the tileset the same way as [`Self::level_tile_size`].
+    pub fn level_tile_dimensions(&self, level: &Level) -> (u32, u32) {
+        level
+            .layers
+            .iter()
+            .filter_map(|layer| layer.tileset_id())
+            .find_map(|id| self.tilesets.get(&id))
+            // Tilesets are in a map, so pick one that doesn't change between runs
+            .or_else(|| self.tilesets.values().min_by_key(|t| t.id))
+            .map(|t| t.tile_dimensions())
+            .unwrap_or((32, 32))
+    }
+
+    /// Extract a single level as a MapProject
+    ///
+    /// The returned project shares tilesets, sprite sheets, dialogues and
+    /// entity type configs with the whole project.
+    pub fn to_map_project(&self, selector: &LevelSelector) -> Option<MapProject> {
+        let level = self.level(selector)?.clone();
+        Some(MapProject {
+            version: self.version,
+            level,
+            tilesets: self.tilesets.clone(),
+            sprite_sheets: self.sprite_sheets.clone(),
+            dialogues: self.dialogues.clone(),
+            entity_type_configs: self.entity_type_configs.clone(),
+            autotile_config: self.autotile_config.clone(),
+            ref_properties: self.ref_properties.clone(),
+        })
+    }
+
+    /// Validate that all tileset references in every level are satisfied
+    pub fn validate(&self) -> Result<(), String> {
+        use crate::LayerData;
+        for level in &self.levels {
+            for (layer_idx, layer) in level.layers.iter().enumerate() {
+                if let LayerData::Tiles { tileset_id, .. } = &layer.data {
+                    if !self.tilesets.contains_key(tileset_id) {
+                        return Err(format!(
+                            "Level '{}' layer {} references missing tileset {}",
+                            level.name, layer_idx, tileset_id
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<MapProject> for WorldProject {
+    fn from(project: MapProject) -> Self {
+        Self {
+            version: project.version,
+            levels: vec![project.level],
+            tilesets: project.tilesets,
+            sprite_sheets: project.sprite_sheets,
+            dialogues: project.dialogues,
+            entity_type_configs: project.entity_type_configs,
+            world_config: WorldConfig::default(),
+            autotile_config: project.autotile_config,
+            ref_properties: project.ref_properties,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Layer;
+    use crate::{EntityInstance, Layer, Value};
 
     #[test]
     fn test_map_project_creation() {
@@ -420,4 +755,232 @@ mod tests {
         assert_eq!(project.level.name, "Test");
         assert_eq!(project.tilesets.len(), 1);
     }
+    fn two_level_editor_project() -> EditorProject {
+        let tileset = Tileset::new("Ground".to_string(), "tiles.png".to_string(), 32, 10, 10);
+        let mut first = Level::new("First".to_string(), 10, 10);
+        first.add_layer(Layer::new_tile_layer(
+            "Ground".to_string(),
+            tileset.id,
+            10,
+            10,
+        ));
+        let second = Level::new_at("Second".to_string(), 8, 8, 320, 0);
+
+        let mut world_config = WorldConfig::default();
+        world_config.add_connection(LevelConnection::auto_direction(
+            first.id,
+            crate::ConnectionDirection::East,
+            second.id,
+        ));
+
+        EditorProject {
+            version: 1,
+            schema: None,
+            tilesets: vec![tileset],
+            data: None,
+            levels: vec![first, second],
+            autotile_config: None,
+            sprite_sheets: Vec::new(),
+            dialogues: Vec::new(),
+            entity_type_configs: HashMap::new(),
+            world_config,
+            entity_templates: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_editor_project_to_world_project() {
+        let editor = two_level_editor_project();
+        let world = editor.to_world_project();
+
+        assert_eq!(world.levels.len(), 2);
+        assert_eq!(world.world_config.connections.len(), 1);
+        assert!(world.validate().is_ok());
+        assert_eq!(
+            world.level_names().collect::<Vec<_>>(),
+            vec!["First", "Second"]
+        );
+    }
+
+    #[test]
+    fn test_world_project_level_lookup() {
+        let editor = two_level_editor_project();
+        let world = editor.to_world_project();
+        let second_id = editor.levels[1].id;
+
+        assert_eq!(
+            world.level(&LevelSelector::from("Second")).unwrap().id,
+            second_id
+        );
+        assert_eq!(
+            world.level(&LevelSelector::from(second_id)).unwrap().name,
+            "Second"
+        );
+        assert!(world.level(&LevelSelector::from("Missing")).is_none());
+
+        let project = world.to_map_project(&"Second".into()).unwrap();
+        assert_eq!(project.level.name, "Second");
+        assert_eq!(project.level.world_position(), (320, 0));
+        assert_eq!(project.tilesets.len(), 1);
+    }
+
+    #[test]
+    fn test_editor_project_to_map_project_for_level() {
+        let editor = two_level_editor_project();
+
+        let first = editor.to_map_project().unwrap();
+        assert_eq!(first.level.name, "First");
+
+        let second = editor
+            .to_map_project_for_level(&LevelSelector::Name("Second".to_string()))
+            .unwrap();
+        assert_eq!(second.level.name, "Second");
+        assert!(editor
+            .to_map_project_for_level(&LevelSelector::Id(Uuid::new_v4()))
+            .is_none());
+    }
+
+    #[test]
+    fn test_editor_project_resolves_templates() {
+        let mut editor = two_level_editor_project();
+        let mut template = crate::EntityTemplate::new("Guard".to_string(), "NPC".to_string());
+        template
+            .properties
+            .insert("health".to_string(), Value::Int(50));
+        template
+            .properties
+            .insert("hostile".to_string(), Value::Bool(false));
+
+        let mut entity = template.instantiate([8.0, 8.0]);
+        entity.set_bool("hostile", true);
+        editor.levels[0].add_entity(entity);
+        editor.entity_templates.push(template);
+
+        let entity = &editor.to_map_project().unwrap().level.entities[0];
+        assert_eq!(entity.get_int("health"), Some(50));
+        assert_eq!(entity.get_bool("hostile"), Some(true));
+
+        let world = editor.to_world_project();
+        assert_eq!(world.levels[0].entities[0].get_int("health"), Some(50));
+
+        // The editor project itself still stores only the override
+        assert_eq!(editor.levels[0].entities[0].properties.len(), 1);
+    }
+
+    #[test]
+    fn test_editor_project_exports_region_shapes() {
+        let mut editor = two_level_editor_project();
+        editor.schema = Some(serde_json::json!({
+            "data_types": {
+                "LevelExit": { "placeable": true, "kind": "region" },
+                "Chest": { "placeable": true }
+            }
+        }));
+        let polygon = RegionShape::polygon_from_size([32.0, 32.0]);
+        editor.levels[0].add_entity(
+            EntityInstance::new("LevelExit".to_string(), [0.0, 0.0]).with_size(64.0, 32.0),
+        );
+        editor.levels[0].add_entity(
+            EntityInstance::new("LevelExit".to_string(), [0.0, 0.0]).with_region(polygon.clone()),
+        );
+        editor.levels[0].add_entity(EntityInstance::new("Chest".to_string(), [0.0, 0.0]));
+
+        let entities = editor.to_map_project().unwrap().level.entities;
+        assert_eq!(entities[0].region, Some(RegionShape::Rectangle));
+        assert_eq!(entities[1].region, Some(polygon));
+        assert_eq!(entities[2].region, None);
+        assert!(entities[0].region_contains([30.0, 0.0]));
+
+        // The editor project itself is left alone
+        assert_eq!(editor.levels[0].entities[0].region, None);
+    }
+
+    #[test]
+    fn test_editor_project_world_config_defaults() {
+        let json = r#"{ "version": 1, "levels": [] }"#;
+        let editor: EditorProject = serde_json::from_str(json).unwrap();
+        assert!(editor.world_config.connections.is_empty());
+        assert!(editor.to_map_project().is_none());
+        assert!(editor.to_world_project().levels.is_empty());
+    }
+
+    #[test]
+    fn test_world_project_validation_fails_missing_tileset() {
+        let mut level = Level::new("Test".to_string(), 10, 10);
+        level.add_layer(Layer::new_tile_layer(
+            "Ground".to_string(),
+            Uuid::new_v4(),
+            10,
+            10,
+        ));
+
+        let world = WorldProject::from(MapProject::new(level, vec![]));
+        assert!(world.validate().is_err());
+    }
+
+    #[test]
+    fn test_world_project_level_tile_size() {
+        let small = Tileset::new("Small".to_string(), "small.png".to_string(), 16, 4, 4);
+        let mut level = Level::new("Test".to_string(), 4, 4);
+        level.add_layer(Layer::new_object_layer("Objects".to_string()));
+        level.add_layer(Layer::new_tile_layer("Ground".to_string(), small.id, 4, 4));
+
+        let world = WorldProject::from(MapProject::new(level, vec![small]));
+        assert_eq!(world.level_tile_size(&world.levels[0]), 16);
+
+        let empty = WorldProject::from(MapProject::new(
+            Level::new("Empty".to_string(), 4, 4),
+            vec![],
+        ));
+        assert_eq!(empty.level_tile_size(&empty.levels[0]), 32);
+
+        // Levels without tile layers use the tileset with the lowest ID
+        let large = Tileset::new("Large".to_string(), "large.png".to_string(), 48, 4, 4);
+        let small = Tileset::new("Small".to_string(), "small.png".to_string(), 16, 4, 4);
+        let expected = if large.id < small.id { 48 } else { 16 };
+        for tilesets in [vec![large.clone(), small.clone()], vec![small, large]] {
+            let world = WorldProject::from(MapProject::new(
+                Level::new("Objects".to_string(), 4, 4),
+                tilesets,
+            ));
+            assert_eq!(world.level_tile_size(&world.levels[0]), expected);
+        }
+    }
+
+    #[test]
+    fn test_editor_project_ref_properties() {
+        let mut editor = two_level_editor_project();
+        editor.schema = Some(serde_json::json!({
+            "data_types": {
+                "Switch": {
+                    "placeable": true,
+                    "properties": [
+                        { "name": "target", "type": "ref", "refType": "Door" },
+                        { "name": "label", "type": "string" }
+                    ]
+                },
+                "Guard": {
+                    "placeable": true,
+                    "properties": [
+                        { "name": "waypoints", "type": "array", "itemType": "Waypoint" },
+                        { "name": "tags", "type": "array", "itemType": "String" }
+                    ]
+                },
+                "Door": { "placeable": true, "properties": [] },
+                "Waypoint": { "placeable": true }
+            }
+        }));
+
+        let refs = editor.ref_properties();
+        assert_eq!(refs.len(), 2);
+        assert_eq!(refs["Switch"], vec!["target".to_string()]);
+        assert_eq!(refs["Guard"], vec!["waypoints".to_string()]);
+
+        let project = editor.to_map_project().unwrap();
+        assert_eq!(project.ref_properties, refs);
+        assert_eq!(editor.to_world_project().ref_properties, refs);
+
+        editor.schema = None;
+        assert!(editor.ref_properties().is_empty());
+    }
 }
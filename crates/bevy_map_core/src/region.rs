@@ -0,0 +1,131 @@
+//! Region shapes for area entities
+//!
+//! Region entities cover an area instead of a point. The shape is stored on
+//! each placed instance in the entity's local space, before its rotation and
+//! scale.
+
+use serde::{Deserialize, Serialize};
+
+/// Shape of the area covered by a region entity
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RegionShape {
+    /// Rectangle centered on the entity, covering its `size`
+    #[default]
+    Rectangle,
+    /// Polygon with points relative to the entity's position
+    Polygon { points: Vec<[f32; 2]> },
+}
+
+impl RegionShape {
+    /// Create a polygon from the corners of a rectangle of the given size
+    pub fn polygon_from_size(size: [f32; 2]) -> Self {
+        Self::Polygon {
+            points: rectangle_points(size),
+        }
+    }
+
+    /// Check if this is a polygon
+    pub fn is_polygon(&self) -> bool {
+        matches!(self, Self::Polygon { .. })
+    }
+
+    /// Get the outline points in local space
+    ///
+    /// `size` is the entity's width and height, used by rectangles.
+    pub fn outline(&self, size: [f32; 2]) -> Vec<[f32; 2]> {
+        match self {
+            Self::Rectangle => rectangle_points(size),
+            Self::Polygon { points } => points.clone(),
+        }
+    }
+
+    /// Get the local bounding box as `(min, max)`
+    pub fn bounds(&self, size: [f32; 2]) -> ([f32; 2], [f32; 2]) {
+        let outline = self.outline(size);
+        if outline.is_empty() {
+            return ([0.0, 0.0], [0.0, 0.0]);
+        }
+        outline.iter().fold(
+            ([f32::MAX, f32::MAX], [f32::MIN, f32::MIN]),
+            |(min, max), p| {
+                (
+                    [min[0].min(p[0]), min[1].min(p[1])],
+                    [max[0].max(p[0]), max[1].max(p[1])],
+                )
+            },
+        )
+    }
+
+    /// Check whether a local-space point is inside the shape
+    ///
+    /// Polygons use the even-odd rule, so concave shapes work.
+    pub fn contains(&self, size: [f32; 2], point: [f32; 2]) -> bool {
+        match self {
+            Self::Rectangle => point[0].abs() <= size[0] / 2.0 && point[1].abs() <= size[1] / 2.0,
+            Self::Polygon { points } => {
+                let mut inside = false;
+                let mut j = points.len().wrapping_sub(1);
+                for (i, a) in points.iter().enumerate() {
+                    let b = points[j];
+                    if (a[1] > point[1]) != (b[1] > point[1])
+                        && point[0] < (b[0] - a[0]) * (point[1] - a[1]) / (b[1] - a[1]) + a[0]
+                    {
+                        inside = !inside;
+                    }
+                    j = i;
+                }
+                inside
+            }
+        }
+    }
+}
+
+/// Corners of a rectangle centered on the origin, counter-clockwise
+fn rectangle_points(size: [f32; 2]) -> Vec<[f32; 2]> {
+    let [hw, hh] = [size[0] / 2.0, size[1] / 2.0];
+    vec![[-hw, -hh], [hw, -hh], [hw, hh], [-hw, hh]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rectangle_region() {
+        let shape = RegionShape::Rectangle;
+        assert_eq!(shape.bounds([64.0, 32.0]), ([-32.0, -16.0], [32.0, 16.0]));
+        assert!(shape.contains([64.0, 32.0], [30.0, -10.0]));
+        assert!(!shape.contains([64.0, 32.0], [0.0, 20.0]));
+    }
+
+    #[test]
+    fn test_concave_polygon_region() {
+        // L-shaped region
+        let shape = RegionShape::Polygon {
+            points: vec![
+                [0.0, 0.0],
+                [20.0, 0.0],
+                [20.0, 10.0],
+                [10.0, 10.0],
+                [10.0, 20.0],
+                [0.0, 20.0],
+            ],
+        };
+        assert_eq!(shape.bounds([0.0, 0.0]), ([0.0, 0.0], [20.0, 20.0]));
+        assert!(shape.contains([0.0, 0.0], [5.0, 15.0]));
+        assert!(shape.contains([0.0, 0.0], [15.0, 5.0]));
+        assert!(!shape.contains([0.0, 0.0], [15.0, 15.0]));
+    }
+
+    #[test]
+    fn test_region_shape_serialization() {
+        let json = serde_json::to_string(&RegionShape::polygon_from_size([2.0, 2.0])).unwrap();
+        assert!(json.contains(r#""type":"polygon""#));
+        let shape: RegionShape = serde_json::from_str(&json).unwrap();
+        assert!(shape.is_polygon());
+
+        let shape: RegionShape = serde_json::from_str(r#"{"type":"rectangle"}"#).unwrap();
+        assert_eq!(shape, RegionShape::Rectangle);
+    }
+}
@@ -0,0 +1,183 @@
+//! Entity templates (prefabs) shared by placed instances
+//!
+//! A template is a named preset of an entity type, its properties and its
+//! component overrides. Instances created from a template keep a
+//! `template_id` and store only the values they override; everything else is
+//! read from the template when the instance is resolved, so editing a
+//! template updates every instance that doesn't override that value.
+
+use crate::{ComponentOverrides, EntityInstance, Value};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// A named entity preset stored at the project level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityTemplate {
+    /// Unique identifier referenced by `EntityInstance::template_id`
+    pub id: Uuid,
+    /// Display name (e.g., "Goblin Archer")
+    pub name: String,
+    /// Entity type name from the schema
+    pub type_name: String,
+    /// Property values inherited by instances
+    #[serde(default)]
+    pub properties: HashMap<String, Value>,
+    /// Component overrides inherited by instances (physics, input, sprite)
+    #[serde(default, skip_serializing_if = "ComponentOverrides::is_empty")]
+    pub component_overrides: ComponentOverrides,
+}
+
+impl EntityTemplate {
+    /// Create an empty template for an entity type
+    pub fn new(name: String, type_name: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            type_name,
+            properties: HashMap::new(),
+            component_overrides: ComponentOverrides::default(),
+        }
+    }
+
+    /// Create a template from the resolved values of an existing instance
+    pub fn from_instance(
+        name: String,
+        instance: &EntityInstance,
+        template: Option<&EntityTemplate>,
+    ) -> Self {
+        let resolved = match template {
+            Some(template) => instance.resolve(template),
+            None => instance.clone(),
+        };
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            type_name: resolved.type_name,
+            properties: resolved.properties,
+            component_overrides: resolved.component_overrides,
+        }
+    }
+
+    /// Create an instance of this template that overrides nothing
+    pub fn instantiate(&self, position: [f32; 2]) -> EntityInstance {
+        EntityInstance::from_template(self.id, self.type_name.clone(), position)
+    }
+
+    /// Move an instance's overrides into this template
+    ///
+    /// The instance is left inheriting everything, so the new values also
+    /// reach every other instance that doesn't override them.
+    pub fn apply_overrides(&mut self, instance: &mut EntityInstance) {
+        self.properties.extend(instance.properties.drain());
+        self.component_overrides = std::mem::take(&mut instance.component_overrides)
+            .merged_over(&self.component_overrides);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SpriteOverrides;
+
+    fn goblin_template() -> EntityTemplate {
+        let mut template = EntityTemplate::new("Goblin".to_string(), "Enemy".to_string());
+        template
+            .properties
+            .insert("health".to_string(), Value::Int(30));
+        template
+            .properties
+            .insert("name".to_string(), Value::String("Goblin".to_string()));
+        template.component_overrides.sprite = Some(SpriteOverrides {
+            scale: Some(2.0),
+            ..Default::default()
+        });
+        template
+    }
+
+    #[test]
+    fn test_instance_inherits_template_values() {
+        let mut template = goblin_template();
+        let instance = template.instantiate([10.0, 20.0]);
+
+        assert_eq!(instance.template_id, Some(template.id));
+        assert!(instance.properties.is_empty());
+        assert_eq!(
+            instance.property_value("health", Some(&template)),
+            Some(&Value::Int(30))
+        );
+
+        // Editing the template reaches instances that don't override the value
+        template
+            .properties
+            .insert("health".to_string(), Value::Int(45));
+        assert_eq!(instance.resolve(&template).get_int("health"), Some(45));
+    }
+
+    #[test]
+    fn test_overrides_win_and_revert() {
+        let template = goblin_template();
+        let mut instance = template.instantiate([0.0, 0.0]);
+        instance.set_int("health", 99);
+
+        assert!(instance.is_overridden("health"));
+        assert!(!instance.is_overridden("name"));
+
+        let resolved = instance.resolve(&template);
+        assert_eq!(resolved.get_int("health"), Some(99));
+        assert_eq!(resolved.get_string("name"), Some("Goblin"));
+
+        assert_eq!(instance.revert_property("health"), Some(Value::Int(99)));
+        assert_eq!(instance.resolve(&template).get_int("health"), Some(30));
+    }
+
+    #[test]
+    fn test_component_overrides_merge_field_by_field() {
+        let template = goblin_template();
+        let mut instance = template.instantiate([0.0, 0.0]);
+        instance.component_overrides.sprite = Some(SpriteOverrides {
+            default_animation: Some("attack".to_string()),
+            ..Default::default()
+        });
+
+        let sprite = instance
+            .resolve(&template)
+            .component_overrides
+            .sprite
+            .unwrap();
+        assert_eq!(sprite.scale, Some(2.0));
+        assert_eq!(sprite.default_animation.as_deref(), Some("attack"));
+    }
+
+    #[test]
+    fn test_apply_overrides_to_template() {
+        let mut template = goblin_template();
+        let mut instance = template.instantiate([0.0, 0.0]);
+        instance.set_int("health", 50);
+        instance.component_overrides.sprite = Some(SpriteOverrides {
+            scale: Some(3.0),
+            ..Default::default()
+        });
+
+        template.apply_overrides(&mut instance);
+
+        assert!(instance.properties.is_empty());
+        assert!(instance.component_overrides.is_empty());
+        assert_eq!(template.properties.get("health"), Some(&Value::Int(50)));
+        assert_eq!(
+            template.component_overrides.sprite.as_ref().unwrap().scale,
+            Some(3.0)
+        );
+    }
+
+    #[test]
+    fn test_template_from_instance() {
+        let mut instance = EntityInstance::new("Chest".to_string(), [0.0, 0.0]);
+        instance.set_bool("locked", true);
+
+        let template = EntityTemplate::from_instance("Locked Chest".to_string(), &instance, None);
+        assert_eq!(template.type_name, "Chest");
+        assert_eq!(template.properties.get("locked"), Some(&Value::Bool(true)));
+        assert_ne!(template.id, instance.id);
+    }
+}
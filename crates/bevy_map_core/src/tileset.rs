@@ -1,6 +1,7 @@
 //! Tileset configuration with multi-image support
 
 use crate::collision::{CollisionData, CollisionShape, OneWayDirection};
+use crate::Value;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
@@ -44,6 +45,11 @@ fn is_one(val: &u32) -> bool {
     *val == 1
 }
 
+/// Check if value equals 0 (for skipping serialization)
+fn is_zero(val: &u32) -> bool {
+    *val == 0
+}
+
 /// Deserialize collision data with backward compatibility for old bool format
 fn deserialize_collision<'de, D>(deserializer: D) -> Result<CollisionData, D::Error>
 where
@@ -186,6 +192,12 @@ pub struct TilesetImage {
     pub path: String,
     pub columns: u32,
     pub rows: u32,
+    /// Pixels between the image edge and the first tile
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub margin: u32,
+    /// Pixels between neighbouring tiles
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub spacing: u32,
 }
 
 impl TilesetImage {
@@ -197,13 +209,96 @@ impl TilesetImage {
             path,
             columns,
             rows,
+            margin: 0,
+            spacing: 0,
         }
     }
 
+    /// Set the margin and spacing of the tile grid
+    pub fn with_margin_spacing(mut self, margin: u32, spacing: u32) -> Self {
+        self.margin = margin;
+        self.spacing = spacing;
+        self
+    }
+
     /// Total number of tiles in this image
     pub fn tile_count(&self) -> u32 {
         self.columns * self.rows
     }
+
+    /// Count the columns and rows of tiles that fit in an image
+    ///
+    /// The margin is only required before the first tile, so images cropped
+    /// right after the last tile slice the same as padded ones.
+    pub fn grid_for_size(
+        image_width: u32,
+        image_height: u32,
+        tile_width: u32,
+        tile_height: u32,
+        margin: u32,
+        spacing: u32,
+    ) -> (u32, u32) {
+        let count = |size: u32, tile: u32| {
+            if tile == 0 {
+                return 0;
+            }
+            (size.saturating_sub(margin) + spacing) / (tile + spacing)
+        };
+        (
+            count(image_width, tile_width),
+            count(image_height, tile_height),
+        )
+    }
+
+    /// Check if tiles sit edge to edge from the image's top-left corner
+    pub fn is_packed(&self) -> bool {
+        self.margin == 0 && self.spacing == 0
+    }
+
+    /// Pixel position of a tile's top-left corner in the image
+    pub fn tile_origin(&self, col: u32, row: u32, tile_width: u32, tile_height: u32) -> (u32, u32) {
+        (
+            self.margin + col * (tile_width + self.spacing),
+            self.margin + row * (tile_height + self.spacing),
+        )
+    }
+
+    /// Copy the tiles of an image into a grid without margin or spacing
+    ///
+    /// `data` holds rows of `image_width` pixels, `pixel_size` bytes each.
+    /// The result is `columns * tile_width` by `rows * tile_height` pixels,
+    /// for renderers that expect tiles packed edge to edge. Pixels that fall
+    /// outside the source image are left zeroed.
+    pub fn pack_pixels(
+        &self,
+        data: &[u8],
+        image_width: u32,
+        pixel_size: usize,
+        tile_width: u32,
+        tile_height: u32,
+    ) -> Vec<u8> {
+        let src_stride = image_width as usize * pixel_size;
+        let dst_stride = (self.columns * tile_width) as usize * pixel_size;
+        let mut packed = vec![0; dst_stride * (self.rows * tile_height) as usize];
+
+        for row in 0..self.rows {
+            for col in 0..self.columns {
+                let (src_x, src_y) = self.tile_origin(col, row, tile_width, tile_height);
+                // Clip tiles that run past the right edge of the image
+                let width = tile_width.min(image_width.saturating_sub(src_x)) as usize * pixel_size;
+                for line in 0..tile_height {
+                    let src = (src_y + line) as usize * src_stride + src_x as usize * pixel_size;
+                    let Some(src_line) = data.get(src..src + width) else {
+                        break;
+                    };
+                    let dst = (row * tile_height + line) as usize * dst_stride
+                        + (col * tile_width) as usize * pixel_size;
+                    packed[dst..dst + width].copy_from_slice(src_line);
+                }
+            }
+        }
+        packed
+    }
 }
 
 /// Tileset configuration - can contain multiple images (Godot-style)
@@ -211,8 +306,11 @@ impl TilesetImage {
 pub struct Tileset {
     pub id: Uuid,
     pub name: String,
-    /// Tile size in pixels (assumes square tiles)
+    /// Tile width in pixels, also the height unless `tile_height` is set
     pub tile_size: u32,
+    /// Tile height in pixels, for tiles that are not square
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tile_height: Option<u32>,
     /// Multiple image sources
     #[serde(default)]
     pub images: Vec<TilesetImage>,
@@ -228,6 +326,9 @@ pub struct Tileset {
     /// Legacy rows (for backward compatibility)
     #[serde(default)]
     pub rows: u32,
+    /// Custom properties defined by the schema's `tileset_properties`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub properties: HashMap<String, Value>,
 }
 
 impl Tileset {
@@ -238,11 +339,13 @@ impl Tileset {
             id: Uuid::new_v4(),
             name,
             tile_size,
+            tile_height: None,
             images: vec![image],
             tile_properties: HashMap::new(),
             path: Some(path),
             columns,
             rows,
+            properties: HashMap::new(),
         }
     }
 
@@ -252,14 +355,27 @@ impl Tileset {
             id: Uuid::new_v4(),
             name,
             tile_size,
+            tile_height: None,
             images: Vec::new(),
             tile_properties: HashMap::new(),
             path: None,
             columns: 0,
             rows: 0,
+            properties: HashMap::new(),
         }
     }
 
+    /// Get the tile width and height in pixels
+    pub fn tile_dimensions(&self) -> (u32, u32) {
+        (self.tile_size, self.tile_height.unwrap_or(self.tile_size))
+    }
+
+    /// Set the tile width and height in pixels
+    pub fn set_tile_dimensions(&mut self, width: u32, height: u32) {
+        self.tile_size = width;
+        self.tile_height = (height != width).then_some(height);
+    }
+
     /// Get properties for a tile (returns default if not set)
     pub fn get_tile_properties(&self, tile_index: u32) -> Option<&TileProperties> {
         self.tile_properties.get(&tile_index)
@@ -479,6 +595,14 @@ impl Tileset {
         Some((col, row))
     }
 
+    /// Convert local tile index to the pixel position of the tile's top-left
+    /// corner within its image, accounting for margin and spacing
+    pub fn local_to_pixel(&self, image_index: usize, local_index: u32) -> Option<(u32, u32)> {
+        let (col, row) = self.local_to_grid(image_index, local_index)?;
+        let (tile_width, tile_height) = self.tile_dimensions();
+        Some(self.images[image_index].tile_origin(col, row, tile_width, tile_height))
+    }
+
     /// Get the grid size for a tile (width, height in cells)
     /// Returns (1, 1) for tiles without multi-cell properties
     pub fn get_tile_grid_size(&self, tile_index: u32) -> (u32, u32) {
@@ -488,6 +612,33 @@ impl Tileset {
             .unwrap_or((1, 1))
     }
 
+    /// Get a tile's animation frames as local indices within a single image
+    ///
+    /// Returns the image index of the first frame and the local index of every
+    /// frame in that image. Frames in other images or out of range are dropped,
+    /// since a tile can only sample one texture. Returns `None` for tiles without
+    /// an animation of at least two usable frames.
+    pub fn animation_frames_local(&self, tile_index: u32) -> Option<(usize, Vec<u32>)> {
+        let props = self.tile_properties.get(&tile_index)?;
+        if !props.has_animation() {
+            return None;
+        }
+        let frames = props.animation_frames.as_ref()?;
+        let mut local_frames = frames
+            .iter()
+            .filter_map(|&frame| self.virtual_to_local(frame));
+        let (image_index, first) = local_frames.next()?;
+
+        let mut locals = vec![first];
+        locals.extend(
+            local_frames
+                .filter(|(image, _)| *image == image_index)
+                .map(|(_, local)| local),
+        );
+
+        (locals.len() > 1).then_some((image_index, locals))
+    }
+
     /// Check if a tile is multi-cell (spans more than 1x1 grid cells)
     pub fn is_multi_cell_tile(&self, tile_index: u32) -> bool {
         self.tile_properties
@@ -553,4 +704,76 @@ mod tests {
         assert_eq!(tileset.local_to_virtual(1, 0), Some(16));
         assert_eq!(tileset.local_to_virtual(1, 3), Some(19));
     }
+
+    #[test]
+    fn test_animation_frames_local() {
+        let mut tileset = Tileset::new_empty("Test".to_string(), 32);
+        tileset.add_image("First".to_string(), "first.png".to_string(), 4, 4);
+        tileset.add_image("Second".to_string(), "second.png".to_string(), 2, 2);
+
+        // Frames in the second image resolve to local indices
+        tileset.set_tile_properties(
+            16,
+            TileProperties::new().with_animation(vec![16, 17, 18], 8.0),
+        );
+        assert_eq!(tileset.animation_frames_local(16), Some((1, vec![0, 1, 2])));
+
+        // Frames from another image than the first frame are dropped
+        tileset.set_tile_properties(0, TileProperties::new().with_animation(vec![0, 17, 1], 8.0));
+        assert_eq!(tileset.animation_frames_local(0), Some((0, vec![0, 1])));
+
+        // A single usable frame is not an animation
+        tileset.set_tile_properties(2, TileProperties::new().with_animation(vec![2, 99], 8.0));
+        assert_eq!(tileset.animation_frames_local(2), None);
+        assert_eq!(tileset.animation_frames_local(3), None);
+    }
+
+    #[test]
+    fn test_non_square_tiles_with_margin_and_spacing() {
+        // 16x24 tiles, 2px margin, 1px spacing: 2 + 3 * 17 - 1 = 52 pixels wide
+        assert_eq!(TilesetImage::grid_for_size(52, 77, 16, 24, 2, 1), (3, 3));
+        // A trailing margin doesn't add a column
+        assert_eq!(TilesetImage::grid_for_size(54, 79, 16, 24, 2, 1), (3, 3));
+        assert_eq!(TilesetImage::grid_for_size(48, 72, 16, 24, 0, 0), (3, 3));
+
+        let mut tileset = Tileset::new_empty("Test".to_string(), 16);
+        tileset.set_tile_dimensions(16, 24);
+        tileset.images.push(
+            TilesetImage::new("Main".to_string(), "main.png".to_string(), 3, 3)
+                .with_margin_spacing(2, 1),
+        );
+        assert_eq!(tileset.tile_dimensions(), (16, 24));
+        assert_eq!(tileset.local_to_grid(0, 4), Some((1, 1)));
+        assert_eq!(tileset.local_to_pixel(0, 0), Some((2, 2)));
+        assert_eq!(tileset.local_to_pixel(0, 4), Some((19, 27)));
+        assert_eq!(tileset.local_to_pixel(0, 9), None);
+
+        // Square tiles and packed images keep the old file format
+        let json = serde_json::to_value(&tileset).unwrap();
+        assert_eq!(json["tile_height"], 24);
+        assert_eq!(json["images"][0]["spacing"], 1);
+        tileset.set_tile_dimensions(16, 16);
+        tileset.images[0].margin = 0;
+        tileset.images[0].spacing = 0;
+        let json = serde_json::to_value(&tileset).unwrap();
+        assert!(json.get("tile_height").is_none());
+        assert!(json["images"][0].get("margin").is_none());
+    }
+
+    #[test]
+    fn test_pack_pixels() {
+        // 2x1 tiles of 2x2 pixels, 1px margin and spacing, one byte per pixel
+        let image = TilesetImage::new("Main".to_string(), "main.png".to_string(), 2, 1)
+            .with_margin_spacing(1, 1);
+        #[rustfmt::skip]
+        let data = [
+            0, 0, 0, 0, 0, 0,
+            0, 1, 2, 0, 5, 6,
+            0, 3, 4, 0, 7, 8,
+        ];
+        assert_eq!(
+            image.pack_pixels(&data, 6, 1, 2, 2),
+            vec![1, 2, 5, 6, 3, 4, 7, 8]
+        );
+    }
 }
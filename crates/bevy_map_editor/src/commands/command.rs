@@ -1,7 +1,7 @@
 //! Command pattern for undo/redo
 
 use bevy::prelude::*;
-use bevy_map_core::LayerData;
+use bevy_map_core::{EntityInstance, LayerData, RegionShape, ResizeAnchor};
 use std::collections::HashMap;
 use uuid::Uuid;
 
@@ -66,13 +66,9 @@ impl Command for BatchTileCommand {
     fn execute(&self, project: &mut Project, render_state: &mut RenderState) {
         if let Some(level) = project.get_level_mut(self.level_id) {
             if let Some(layer) = level.layers.get_mut(self.layer_idx) {
-                if let LayerData::Tiles { tiles, .. } = &mut layer.data {
-                    for ((x, y), (_, new_tile)) in &self.changes {
-                        let idx = (*y * level.width + *x) as usize;
-                        if idx < tiles.len() {
-                            tiles[idx] = *new_tile;
-                        }
-                    }
+                for ((x, y), (_, new_tile)) in &self.changes {
+                    let idx = (*y * level.width + *x) as usize;
+                    write_cell(&mut layer.data, idx, *new_tile);
                 }
             }
         }
@@ -82,13 +78,9 @@ impl Command for BatchTileCommand {
     fn undo(&self, project: &mut Project, render_state: &mut RenderState) {
         if let Some(level) = project.get_level_mut(self.level_id) {
             if let Some(layer) = level.layers.get_mut(self.layer_idx) {
-                if let LayerData::Tiles { tiles, .. } = &mut layer.data {
-                    for ((x, y), (old_tile, _)) in &self.changes {
-                        let idx = (*y * level.width + *x) as usize;
-                        if idx < tiles.len() {
-                            tiles[idx] = *old_tile;
-                        }
-                    }
+                for ((x, y), (old_tile, _)) in &self.changes {
+                    let idx = (*y * level.width + *x) as usize;
+                    write_cell(&mut layer.data, idx, *old_tile);
                 }
             }
         }
@@ -100,7 +92,37 @@ impl Command for BatchTileCommand {
     }
 }
 
+/// Write one cell of a tile or IntGrid layer
+///
+/// IntGrid layers store `None` as the empty value 0.
+fn write_cell(data: &mut LayerData, idx: usize, value: Option<u32>) {
+    match data {
+        LayerData::Tiles { tiles, .. } => {
+            if let Some(cell) = tiles.get_mut(idx) {
+                *cell = value;
+            }
+        }
+        LayerData::IntGrid { values, .. } => {
+            if let Some(cell) = values.get_mut(idx) {
+                *cell = value.unwrap_or(0);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Read one cell of a tile or IntGrid layer, mapping IntGrid 0 to `None`
+fn read_cell(data: &LayerData, idx: usize) -> Option<u32> {
+    match data {
+        LayerData::Tiles { tiles, .. } => tiles.get(idx).copied().flatten(),
+        LayerData::IntGrid { values, .. } => values.get(idx).copied().filter(|v| *v != 0),
+        _ => None,
+    }
+}
+
 /// Collect tiles in a rectangular region for undo tracking
+///
+/// Works for IntGrid layers too, with empty cells collected as `None`.
 pub fn collect_tiles_in_region(
     project: &Project,
     level_id: Uuid,
@@ -114,16 +136,15 @@ pub fn collect_tiles_in_region(
 
     if let Some(level) = project.levels.iter().find(|l| l.id == level_id) {
         if let Some(layer) = level.layers.get(layer_idx) {
-            if let LayerData::Tiles {
-                tiles: tile_data, ..
-            } = &layer.data
-            {
+            if matches!(
+                layer.data,
+                LayerData::Tiles { .. } | LayerData::IntGrid { .. }
+            ) {
                 for y in min_y..=max_y {
                     for x in min_x..=max_x {
                         if x >= 0 && y >= 0 && x < level.width as i32 && y < level.height as i32 {
                             let idx = (y as u32 * level.width + x as u32) as usize;
-                            let tile = tile_data.get(idx).copied().flatten();
-                            tiles.insert((x as u32, y as u32), tile);
+                            tiles.insert((x as u32, y as u32), read_cell(&layer.data, idx));
                         }
                     }
                 }
@@ -270,6 +291,164 @@ impl Command for MoveEntityCommand {
     }
 }
 
+/// Position, rotation, scale, size and region shape of a placed entity
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityTransform {
+    pub position: [f32; 2],
+    pub rotation: f32,
+    pub scale: [f32; 2],
+    pub size: Option<[f32; 2]>,
+    pub region: Option<RegionShape>,
+}
+
+impl EntityTransform {
+    /// Capture the transform of an entity
+    pub fn of(entity: &EntityInstance) -> Self {
+        Self {
+            position: entity.position,
+            rotation: entity.rotation,
+            scale: entity.scale,
+            size: entity.size,
+            region: entity.region.clone(),
+        }
+    }
+
+    /// Write this transform back to an entity
+    pub fn apply(&self, entity: &mut EntityInstance) {
+        entity.position = self.position;
+        entity.rotation = self.rotation;
+        entity.scale = self.scale;
+        entity.size = self.size;
+        entity.region = self.region.clone();
+    }
+}
+
+/// Command for rotating, scaling or resizing an entity
+pub struct TransformEntityCommand {
+    pub level_id: Uuid,
+    pub entity_id: Uuid,
+    pub old_transform: EntityTransform,
+    pub new_transform: EntityTransform,
+}
+
+impl TransformEntityCommand {
+    pub fn new(
+        level_id: Uuid,
+        entity_id: Uuid,
+        old_transform: EntityTransform,
+        new_transform: EntityTransform,
+    ) -> Self {
+        Self {
+            level_id,
+            entity_id,
+            old_transform,
+            new_transform,
+        }
+    }
+}
+
+impl Command for TransformEntityCommand {
+    fn execute(&self, project: &mut Project, _render_state: &mut RenderState) {
+        if let Some(level) = project.get_level_mut(self.level_id) {
+            if let Some(entity) = level.entities.iter_mut().find(|e| e.id == self.entity_id) {
+                self.new_transform.apply(entity);
+            }
+        }
+    }
+
+    fn undo(&self, project: &mut Project, _render_state: &mut RenderState) {
+        if let Some(level) = project.get_level_mut(self.level_id) {
+            if let Some(entity) = level.entities.iter_mut().find(|e| e.id == self.entity_id) {
+                self.old_transform.apply(entity);
+            }
+        }
+    }
+
+    fn description(&self) -> &str {
+        "Transform Entity"
+    }
+}
+
+/// Command for resizing a level around an anchor
+///
+/// Undo restores the previous size, the data of every layer, entity
+/// positions and the level's world position. Layers and entities are matched
+/// by ID, so anything added after the resize is left alone.
+pub struct ResizeLevelCommand {
+    pub level_id: Uuid,
+    pub width: u32,
+    pub height: u32,
+    pub anchor: ResizeAnchor,
+    /// Grid cell width and height in pixels, used to shift entities
+    pub tile_size: (f32, f32),
+    old_size: (u32, u32),
+    old_world_position: (i32, i32),
+    old_layer_data: HashMap<Uuid, LayerData>,
+    old_entity_positions: HashMap<Uuid, [f32; 2]>,
+}
+
+impl ResizeLevelCommand {
+    /// Create a resize command, capturing the level's current state for undo
+    pub fn new(
+        level: &bevy_map_core::Level,
+        width: u32,
+        height: u32,
+        anchor: ResizeAnchor,
+        tile_size: (f32, f32),
+    ) -> Self {
+        Self {
+            level_id: level.id,
+            width,
+            height,
+            anchor,
+            tile_size,
+            old_size: (level.width, level.height),
+            old_world_position: level.world_position(),
+            old_layer_data: level
+                .layers
+                .iter()
+                .map(|layer| (layer.id, layer.data.clone()))
+                .collect(),
+            old_entity_positions: level
+                .entities
+                .iter()
+                .map(|entity| (entity.id, entity.position))
+                .collect(),
+        }
+    }
+}
+
+impl Command for ResizeLevelCommand {
+    fn execute(&self, project: &mut Project, render_state: &mut RenderState) {
+        if let Some(level) = project.get_level_mut(self.level_id) {
+            level.resize(self.width, self.height, self.anchor, self.tile_size);
+        }
+        render_state.needs_rebuild = true;
+    }
+
+    fn undo(&self, project: &mut Project, render_state: &mut RenderState) {
+        if let Some(level) = project.get_level_mut(self.level_id) {
+            (level.width, level.height) = self.old_size;
+            level.set_world_position(self.old_world_position.0, self.old_world_position.1);
+            for layer in &mut level.layers {
+                if let Some(data) = self.old_layer_data.get(&layer.id) {
+                    layer.data = data.clone();
+                }
+            }
+            for entity in &mut level.entities {
+                if let Some(position) = self.old_entity_positions.get(&entity.id) {
+                    entity.position = *position;
+                }
+            }
+        }
+        render_state.needs_rebuild = true;
+    }
+
+    fn description(&self) -> &str {
+        "Resize Level"
+    }
+}
+
 /// Stores command history for undo/redo
 #[derive(Resource, Default)]
 pub struct CommandHistory {
@@ -7,6 +7,6 @@ mod shortcuts;
 pub use clipboard::TileClipboard;
 pub use command::{
     collect_tiles_in_region, AutomapCommand, BatchTileCommand, Command, CommandHistory,
-    MoveEntityCommand,
+    EntityTransform, MoveEntityCommand, ResizeLevelCommand, TransformEntityCommand,
 };
 pub use shortcuts::handle_keyboard_shortcuts;
@@ -526,12 +526,15 @@ pub struct EditorState {
     pub selected_tileset: Option<uuid::Uuid>,
     pub selected_tile: Option<u32>,
     pub selected_level: Option<uuid::Uuid>,
+    /// Value painted on IntGrid layers
+    pub selected_int_grid_value: u32,
 
     // Tools
     pub current_tool: EditorTool,
     pub tool_mode: ToolMode,
     pub show_grid: bool,
     pub show_collisions: bool,
+    pub preview_parallax: bool,
     pub snap_to_grid: bool,
     pub zoom: f32,
     pub camera_offset: bevy::math::Vec2,
@@ -562,11 +565,19 @@ pub struct EditorState {
     pub new_level_name: String,
     pub new_level_width: u32,
     pub new_level_height: u32,
+    pub new_level_orientation: bevy_map_core::MapOrientation,
+
+    // Resize level dialog state (open while a level is set)
+    pub resize_level_id: Option<uuid::Uuid>,
+    pub resize_level_width: u32,
+    pub resize_level_height: u32,
+    pub resize_level_anchor: bevy_map_core::ResizeAnchor,
 
     // New tileset dialog state
     pub new_tileset_name: String,
     pub new_tileset_path: String,
     pub new_tileset_tile_size: u32,
+    pub new_tileset_tile_height: u32,
 
     // Add image to tileset dialog state
     pub show_add_tileset_image_dialog: bool,
@@ -636,6 +647,8 @@ pub struct EditorState {
     // Entity placement
     pub entity_paint_state: EntityPaintState,
     pub selected_entity_type: Option<String>,
+    /// Template to place instead of a bare entity of `selected_entity_type`
+    pub selected_entity_template: Option<uuid::Uuid>,
 
     // Tile selection (for copy/paste/delete)
     pub tile_selection: TileSelection,
@@ -668,6 +681,10 @@ pub struct EditorState {
     pub move_drag_start: Option<bevy::math::Vec2>,
     /// Entity's original position before drag (for undo/cancel)
     pub entity_original_position: Option<[f32; 2]>,
+    /// Gizmo handle being dragged on the selected entity (rotate/resize)
+    pub entity_gizmo_drag: Option<tools::EntityGizmoHandle>,
+    /// Entity's original transform before a gizmo drag (for undo/cancel)
+    pub entity_original_transform: Option<commands::EntityTransform>,
     /// Original tiles being moved: (x, y) -> (layer_idx, tile_index)
     pub tile_move_original: Option<std::collections::HashMap<(u32, u32), (usize, Option<u32>)>>,
     /// Current drag offset in tile coordinates
@@ -730,11 +747,13 @@ impl Default for EditorState {
             selected_tileset: None,
             selected_tile: None,
             selected_level: None,
+            selected_int_grid_value: 1,
 
             current_tool: EditorTool::Select,
             tool_mode: ToolMode::Point,
             show_grid: true,
             show_collisions: false,
+            preview_parallax: true,
             snap_to_grid: true,
             zoom: 1.0,
             camera_offset: bevy::math::Vec2::ZERO,
@@ -760,10 +779,17 @@ impl Default for EditorState {
             new_level_name: "New Level".to_string(),
             new_level_width: 50,
             new_level_height: 50,
+            new_level_orientation: bevy_map_core::MapOrientation::default(),
+
+            resize_level_id: None,
+            resize_level_width: 50,
+            resize_level_height: 50,
+            resize_level_anchor: bevy_map_core::ResizeAnchor::default(),
 
             new_tileset_name: "New Tileset".to_string(),
             new_tileset_path: String::new(),
             new_tileset_tile_size: 32,
+            new_tileset_tile_height: 32,
 
             show_add_tileset_image_dialog: false,
             add_image_name: String::new(),
@@ -814,6 +840,7 @@ impl Default for EditorState {
 
             entity_paint_state: EntityPaintState::new(),
             selected_entity_type: None,
+            selected_entity_template: None,
 
             tile_selection: TileSelection::default(),
             is_pasting: false,
@@ -832,6 +859,8 @@ impl Default for EditorState {
             is_moving: false,
             move_drag_start: None,
             entity_original_position: None,
+            entity_gizmo_drag: None,
+            entity_original_transform: None,
             tile_move_original: None,
             tile_move_offset: None,
             pending_cancel_move: false,
@@ -89,6 +89,17 @@ impl Project {
         Ok(())
     }
 
+    /// Export the project in the compact binary map format
+    ///
+    /// Writes a `.map.bin` file the runtime loads like the JSON project.
+    /// Unlike `save`, this leaves the project path and dirty flag alone.
+    pub fn export_binary(&self, path: &Path) -> Result<(), ProjectError> {
+        let content = bevy_map_core::to_binary(self)
+            .map_err(|e| ProjectError::SerializeError(e.to_string()))?;
+
+        std::fs::write(path, content).map_err(|e| ProjectError::IoError(e.to_string()))
+    }
+
     /// Save to current path if set
     pub fn save_current(&mut self) -> Result<(), ProjectError> {
         if let Some(path) = self.path.clone() {
@@ -10,7 +10,9 @@ use bevy::prelude::Resource;
 use bevy_map_animation::SpriteData;
 use bevy_map_autotile::AutotileConfig;
 use bevy_map_automap::AutomapConfig;
-use bevy_map_core::{EntityTypeConfig, Level, Tileset, WorldConfig};
+use bevy_map_core::{
+    EntityInstance, EntityTemplate, EntityTypeConfig, Level, Tileset, WorldConfig,
+};
 use bevy_map_dialogue::DialogueTree;
 use bevy_map_schema::Schema;
 use serde::{Deserialize, Serialize};
@@ -140,6 +142,9 @@ pub struct Project {
     /// Entity type component configurations (physics, input, sprite per type)
     #[serde(default)]
     pub entity_type_configs: HashMap<String, EntityTypeConfig>,
+    /// Entity templates (prefabs) that placed instances inherit from
+    #[serde(default)]
+    pub entity_templates: Vec<EntityTemplate>,
     #[serde(skip)]
     pub dirty: bool,
 
@@ -172,6 +177,7 @@ impl Default for Project {
             stamps: Vec::new(),
             game_config: GameProjectConfig::default(),
             entity_type_configs: HashMap::new(),
+            entity_templates: Vec::new(),
             dirty: false,
             level_index: HashMap::new(),
             tileset_index: HashMap::new(),
@@ -199,6 +205,7 @@ impl Project {
             stamps: Vec::new(),
             game_config: GameProjectConfig::default(),
             entity_type_configs: HashMap::new(),
+            entity_templates: Vec::new(),
             dirty: false,
             level_index: HashMap::new(),
             tileset_index: HashMap::new(),
@@ -241,6 +248,60 @@ impl Project {
         self.entity_type_configs.get_mut(type_name).unwrap()
     }
 
+    // Entity template methods
+
+    /// Get an entity template by ID
+    pub fn get_entity_template(&self, id: Uuid) -> Option<&EntityTemplate> {
+        self.entity_templates.iter().find(|t| t.id == id)
+    }
+
+    /// Get mutable entity template by ID
+    pub fn get_entity_template_mut(&mut self, id: Uuid) -> Option<&mut EntityTemplate> {
+        self.dirty = true;
+        self.entity_templates.iter_mut().find(|t| t.id == id)
+    }
+
+    /// Add a new entity template
+    pub fn add_entity_template(&mut self, template: EntityTemplate) {
+        self.entity_templates.push(template);
+        self.dirty = true;
+    }
+
+    /// Remove an entity template by ID
+    ///
+    /// Instances of the template keep their current values: the template is
+    /// baked into them before they are detached.
+    pub fn remove_entity_template(&mut self, id: Uuid) -> Option<EntityTemplate> {
+        let idx = self.entity_templates.iter().position(|t| t.id == id)?;
+        let removed = self.entity_templates.remove(idx);
+        for level in &mut self.levels {
+            for entity in &mut level.entities {
+                if entity.template_id == Some(id) {
+                    *entity = entity.resolve(&removed);
+                    entity.template_id = None;
+                }
+            }
+        }
+        self.dirty = true;
+        Some(removed)
+    }
+
+    /// Get the template an entity instance inherits from, if any
+    pub fn template_for(&self, entity: &EntityInstance) -> Option<&EntityTemplate> {
+        entity
+            .template_id
+            .and_then(|id| self.get_entity_template(id))
+    }
+
+    /// Get an entity's component config with template and instance overrides applied
+    pub fn resolved_entity_type_config(&self, entity: &EntityInstance) -> EntityTypeConfig {
+        let overrides = entity.effective_overrides(self.template_for(entity));
+        self.get_entity_type_config(&entity.type_name)
+            .cloned()
+            .unwrap_or_default()
+            .with_overrides(&overrides)
+    }
+
     /// Rebuild all lookup indices. Call after loading or bulk modifications.
     pub fn rebuild_indices(&mut self) {
         self.level_index.clear();
@@ -723,3 +784,45 @@ impl DataStore {
         self.instances.values().flatten()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_map_core::Value;
+
+    #[test]
+    fn test_remove_entity_template_bakes_values() {
+        let mut project = Project::default();
+        let mut template = EntityTemplate::new("Goblin".to_string(), "Enemy".to_string());
+        template
+            .properties
+            .insert("health".to_string(), Value::Int(30));
+        template
+            .properties
+            .insert("name".to_string(), Value::String("Goblin".to_string()));
+        let template_id = template.id;
+
+        let mut level = Level::new("Test".to_string(), 4, 4);
+        let inheriting = template.instantiate([0.0, 0.0]);
+        let mut overriding = template.instantiate([16.0, 0.0]);
+        overriding.set_int("health", 50);
+        level.add_entity(inheriting);
+        level.add_entity(overriding);
+        project.add_level(level);
+        project.add_entity_template(template);
+        project.dirty = false;
+
+        assert!(project.remove_entity_template(template_id).is_some());
+        assert!(project.get_entity_template(template_id).is_none());
+        assert!(project.is_dirty());
+
+        let entities = &project.levels[0].entities;
+        assert!(entities.iter().all(|e| e.template_id.is_none()));
+        assert_eq!(entities[0].get_int("health"), Some(30));
+        assert_eq!(entities[0].get_string("name"), Some("Goblin"));
+        assert_eq!(entities[1].get_int("health"), Some(50));
+        assert_eq!(entities[1].get_string("name"), Some("Goblin"));
+
+        assert!(project.remove_entity_template(template_id).is_none());
+    }
+}
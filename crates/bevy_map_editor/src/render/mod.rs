@@ -4,14 +4,19 @@
 //! matching the approach used in bevy_map_runtime for consistent rendering
 //! between editor and game.
 
+use bevy::asset::RenderAssetUsages;
+use bevy::image::ImageSampler;
 use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
 use bevy_ecs_tilemap::prelude::*;
-use bevy_map_core::{LayerData, OCCUPIED_CELL};
+use bevy_map_core::{
+    EntityInstance, IntGridValue, LayerData, MapOrientation, RegionShape, OCCUPIED_CELL,
+};
 use std::collections::HashMap;
 use uuid::Uuid;
 
 use crate::project::Project;
-use crate::tools::{bresenham_line, ViewportInputState};
+use crate::tools::{bresenham_line, entity_gizmo_handles, EntityGizmoHandle, ViewportInputState};
 use crate::ui::{EditorTool, EntityTextureCache, Selection, TilesetTextureCache, ToolMode};
 use crate::EditorState;
 use bevy_map_schema::ViewportDisplayMode;
@@ -37,6 +42,10 @@ impl Plugin for MapRenderPlugin {
             .add_systems(Update, sync_terrain_preview)
             .add_systems(Update, sync_brush_preview)
             .add_systems(Update, sync_entity_rendering)
+            .add_systems(Update, sync_entity_gizmos)
+            .add_systems(Update, fit_image_layer_sprites)
+            .add_systems(Update, sync_int_grid_overlays)
+            .add_systems(PostUpdate, sync_layer_offsets)
             .add_systems(
                 PostUpdate,
                 sync_layer_dimming.before(update_camera_from_editor_state),
@@ -62,15 +71,57 @@ pub struct RenderState {
     pub layer_visibility: HashMap<(Uuid, usize), bool>,
     /// Last known grid visibility state
     pub last_grid_visible: bool,
-    /// Last rendered level dimensions for grid
-    pub last_grid_dimensions: Option<(u32, u32, u32)>, // (width, height, tile_size)
+    /// Last rendered level dimensions for grid: (width, height, tile_size, orientation)
+    pub last_grid_dimensions: Option<(u32, u32, (u32, u32), MapOrientation)>,
     /// Multi-cell tile sprites: (level_id, layer_index, x, y) -> sprite entity
     /// These are rendered as separate Sprites instead of TileBundle to span multiple cells
     pub multi_cell_sprites: HashMap<(Uuid, usize, u32, u32), Entity>,
+    /// Image layer and IntGrid overlay sprites: (level_id, layer_index) -> sprite entity
+    pub image_layer_sprites: HashMap<(Uuid, usize), Entity>,
+    /// Layer order and image layer settings of the rendered level, for change detection
+    pub last_layer_layout: Vec<LayerLayout>,
     /// Last known per-layer opacities for change detection
     pub last_layer_opacities: HashMap<usize, f32>,
+    /// Last known per-layer tints (RGBA) for change detection
+    pub last_layer_tints: HashMap<usize, [f32; 4]>,
     /// Last known selected layer for dimming change detection
     pub last_selected_layer: Option<Option<usize>>,
+    /// Orientation of the rendered level, for change detection
+    pub last_orientation: MapOrientation,
+}
+
+/// What the viewport needs to know about a layer's place in the level
+///
+/// Rendered entities are keyed by layer index and image layers are spawned
+/// from their path, so a change here requires a rebuild.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayerLayout {
+    pub layer_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    /// Image path and repeat flags, for image layers
+    pub image: Option<(String, bool, bool)>,
+}
+
+impl LayerLayout {
+    /// Get the layout of all layers in a level
+    pub fn of_level(level: &bevy_map_core::Level) -> Vec<Self> {
+        level
+            .layers
+            .iter()
+            .map(|layer| Self {
+                layer_id: layer.id,
+                parent_id: layer.parent_id,
+                image: match &layer.data {
+                    LayerData::Image {
+                        path,
+                        repeat_x,
+                        repeat_y,
+                    } => Some((path.clone(), *repeat_x, *repeat_y)),
+                    _ => None,
+                },
+            })
+            .collect()
+    }
 }
 
 impl RenderState {
@@ -94,6 +145,8 @@ pub struct EditorTilemap {
     pub level_id: Uuid,
     pub layer_index: usize,
     pub image_index: usize,
+    /// Position without layer offset or parallax
+    pub base: Vec2,
 }
 
 /// Marker component for the grid overlay
@@ -115,8 +168,38 @@ pub struct MultiCellTileSprite {
     pub layer_index: usize,
     pub x: u32,
     pub y: u32,
+    /// Position without layer offset or parallax
+    pub base: Vec2,
 }
 
+/// Marker component for sprites covering a whole layer
+///
+/// Used by image layers and by IntGrid overlays, so both follow layer
+/// visibility, opacity and offsets the same way.
+#[derive(Component)]
+pub struct EditorImageLayer {
+    pub level_id: Uuid,
+    pub layer_index: usize,
+    pub repeat_x: bool,
+    pub repeat_y: bool,
+    /// Level size in pixels, covered by repeated axes
+    pub level_size: Vec2,
+    /// Position of the image's top-left corner without layer offset or parallax
+    pub base: Vec2,
+}
+
+/// IntGrid overlay sprite, with the data its image was last built from
+#[derive(Component)]
+pub struct EditorIntGridOverlay {
+    pub level_id: Uuid,
+    pub layer_index: usize,
+    pub values: Vec<u32>,
+    pub palette: Vec<IntGridValue>,
+}
+
+/// Alpha applied on top of palette colors so tiles below stay readable
+const INT_GRID_OVERLAY_ALPHA: f32 = 0.5;
+
 /// Cache for collision overlay entities (for efficient updates)
 #[derive(Resource, Default)]
 pub struct CollisionOverlayCache {
@@ -137,6 +220,7 @@ fn sync_level_rendering(
     tileset_cache: Res<TilesetTextureCache>,
     tilemap_query: Query<Entity, With<EditorTilemap>>,
     asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
 ) {
     let current_level_id = editor_state.selected_level;
 
@@ -156,11 +240,17 @@ fn sync_level_rendering(
         for entity in render_state.multi_cell_sprites.values() {
             let _ = commands.get_entity(*entity).map(|mut e| e.despawn());
         }
+        // Despawn image layer sprites (safe - entity may not exist)
+        for entity in render_state.image_layer_sprites.values() {
+            let _ = commands.get_entity(*entity).map(|mut e| e.despawn());
+        }
         render_state.tilemap_entities.clear();
         render_state.tile_storages.clear();
         render_state.multi_cell_sprites.clear();
+        render_state.image_layer_sprites.clear();
         render_state.layer_visibility.clear();
         render_state.last_layer_opacities.clear();
+        render_state.last_layer_tints.clear();
         render_state.last_selected_layer = None;
         render_state.rendered_level = current_level_id;
         render_state.needs_rebuild = true;
@@ -176,6 +266,17 @@ fn sync_level_rendering(
         return;
     };
 
+    // Layers were added, removed, reordered or an image layer changed
+    let layout = LayerLayout::of_level(level);
+    if render_state.last_layer_layout != layout {
+        render_state.last_layer_layout = layout;
+        render_state.needs_rebuild = true;
+    }
+    if render_state.last_orientation != level.orientation {
+        render_state.last_orientation = level.orientation;
+        render_state.needs_rebuild = true;
+    }
+
     // Rebuild if needed
     if render_state.needs_rebuild {
         // Despawn all tile entities from storages first (safe - entity may not exist)
@@ -192,10 +293,16 @@ fn sync_level_rendering(
         for entity in render_state.multi_cell_sprites.values() {
             let _ = commands.get_entity(*entity).map(|mut e| e.despawn());
         }
+        // Despawn image layer sprites (safe - entity may not exist)
+        for entity in render_state.image_layer_sprites.values() {
+            let _ = commands.get_entity(*entity).map(|mut e| e.despawn());
+        }
         render_state.tilemap_entities.clear();
         render_state.tile_storages.clear();
         render_state.multi_cell_sprites.clear();
+        render_state.image_layer_sprites.clear();
         render_state.last_layer_opacities.clear();
+        render_state.last_layer_tints.clear();
         render_state.last_selected_layer = None;
 
         spawn_level_tilemaps(
@@ -205,6 +312,7 @@ fn sync_level_rendering(
             &project,
             &tileset_cache,
             &asset_server,
+            &mut images,
         );
         render_state.needs_rebuild = false;
     }
@@ -216,7 +324,14 @@ fn sync_layer_visibility(
     project: Res<Project>,
     mut render_state: ResMut<RenderState>,
     mut tilemap_query: Query<(&EditorTilemap, &mut Visibility), Without<MultiCellTileSprite>>,
-    mut multi_cell_query: Query<(&MultiCellTileSprite, &mut Visibility), Without<EditorTilemap>>,
+    mut multi_cell_query: Query<
+        (&MultiCellTileSprite, &mut Visibility),
+        (Without<EditorTilemap>, Without<EditorImageLayer>),
+    >,
+    mut image_layer_query: Query<
+        (&EditorImageLayer, &mut Visibility),
+        (Without<EditorTilemap>, Without<MultiCellTileSprite>),
+    >,
 ) {
     let Some(level_id) = editor_state.selected_level else {
         return;
@@ -227,15 +342,19 @@ fn sync_layer_visibility(
         return;
     };
 
-    // Check for layer visibility changes and update tilemaps
-    for (layer_index, layer) in level.layers.iter().enumerate() {
+    // Check for layer visibility changes and update tilemaps.
+    // A layer is only shown if all of its parent groups are visible too.
+    for layer_index in 0..level.layers.len() {
+        let visible = level
+            .effective_layer(layer_index)
+            .is_some_and(|effective| effective.visible);
         let key = (level_id, layer_index);
         let old_vis = render_state.layer_visibility.get(&key).copied();
 
-        if old_vis != Some(layer.visible) {
-            render_state.layer_visibility.insert(key, layer.visible);
+        if old_vis != Some(visible) {
+            render_state.layer_visibility.insert(key, visible);
 
-            let new_visibility = if layer.visible {
+            let new_visibility = if visible {
                 Visibility::Inherited
             } else {
                 Visibility::Hidden
@@ -257,6 +376,13 @@ fn sync_layer_visibility(
                     *visibility = new_visibility;
                 }
             }
+
+            // Update visibility of the image layer sprite
+            for (image_layer, mut visibility) in image_layer_query.iter_mut() {
+                if image_layer.level_id == level_id && image_layer.layer_index == layer_index {
+                    *visibility = new_visibility;
+                }
+            }
         }
     }
 }
@@ -269,8 +395,114 @@ fn spawn_level_tilemaps(
     project: &Project,
     tileset_cache: &TilesetTextureCache,
     asset_server: &AssetServer,
+    images: &mut Assets<Image>,
 ) {
     for (layer_index, layer) in level.layers.iter().enumerate() {
+        let visible = level
+            .effective_layer(layer_index)
+            .is_some_and(|effective| effective.visible);
+        let layer_visibility = if visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        render_state
+            .layer_visibility
+            .insert((level.id, layer_index), visible);
+
+        if let LayerData::Image {
+            path,
+            repeat_x,
+            repeat_y,
+        } = &layer.data
+        {
+            if path.is_empty() {
+                continue;
+            }
+
+            // The image's top-left corner sits on the level's top-left corner
+            let bounds = level_rect(level, level_tile_dimensions(level, project));
+            let level_size = bounds.size();
+            let base = Vec2::new(bounds.min.x, bounds.max.y);
+            let layer_z = layer_index as f32 * 0.1;
+
+            let sprite_entity = commands
+                .spawn((
+                    Sprite {
+                        image: asset_server.load(crate::to_asset_path(path)),
+                        ..default()
+                    },
+                    bevy::sprite::Anchor::TOP_LEFT,
+                    Transform::from_xyz(base.x, base.y, layer_z),
+                    layer_visibility,
+                    EditorImageLayer {
+                        level_id: level.id,
+                        layer_index,
+                        repeat_x: *repeat_x,
+                        repeat_y: *repeat_y,
+                        level_size,
+                        base,
+                    },
+                ))
+                .id();
+            render_state
+                .image_layer_sprites
+                .insert((level.id, layer_index), sprite_entity);
+            continue;
+        }
+
+        if let LayerData::IntGrid { values, palette } = &layer.data {
+            // One pixel per cell, stretched over the level
+            let tile_size = level_tile_dimensions(level, project);
+            let level_size = Vec2::new(level.width as f32, level.height as f32) * tile_size;
+            let base = Vec2::new(0.0, level_size.y);
+            let layer_z = layer_index as f32 * 0.1;
+
+            let mut image = Image::new(
+                Extent3d {
+                    width: level.width.max(1),
+                    height: level.height.max(1),
+                    depth_or_array_layers: 1,
+                },
+                TextureDimension::D2,
+                int_grid_overlay_pixels(level.width, level.height, values, palette),
+                TextureFormat::Rgba8UnormSrgb,
+                RenderAssetUsages::default(),
+            );
+            image.sampler = ImageSampler::nearest();
+
+            let sprite_entity = commands
+                .spawn((
+                    Sprite {
+                        image: images.add(image),
+                        custom_size: Some(level_size),
+                        ..default()
+                    },
+                    bevy::sprite::Anchor::TOP_LEFT,
+                    Transform::from_xyz(base.x, base.y, layer_z),
+                    layer_visibility,
+                    EditorImageLayer {
+                        level_id: level.id,
+                        layer_index,
+                        repeat_x: false,
+                        repeat_y: false,
+                        level_size,
+                        base,
+                    },
+                    EditorIntGridOverlay {
+                        level_id: level.id,
+                        layer_index,
+                        values: values.clone(),
+                        palette: palette.clone(),
+                    },
+                ))
+                .id();
+            render_state
+                .image_layer_sprites
+                .insert((level.id, layer_index), sprite_entity);
+            continue;
+        }
+
         // Skip non-tile layers
         let LayerData::Tiles {
             tileset_id, tiles, ..
@@ -284,8 +516,9 @@ fn spawn_level_tilemaps(
             continue;
         };
 
-        let tile_size = tileset.tile_size;
-        let tile_size_f32 = tile_size as f32;
+        let (tile_width, tile_height) = tileset.tile_dimensions();
+        let tile_size = tile_dimensions(tileset);
+        let (map_type, anchor, base) = tilemap_layout(level.orientation, tile_size);
 
         // Group tiles by image (for multi-image tilesets)
         // bevy_ecs_tilemap uses a single texture per tilemap, so we need separate tilemaps per image
@@ -348,8 +581,8 @@ fn spawn_level_tilemaps(
         for (image_index, image_tiles) in tiles_by_image {
             // Get texture handle for this image
             let texture_handle = if let Some(image) = tileset.images.get(image_index) {
-                if let Some((handle, _, _, _)) = tileset_cache.loaded.get(&image.id) {
-                    handle.clone()
+                if let Some(handle) = tileset_cache.tilemap_handle(&image.id) {
+                    handle
                 } else {
                     asset_server.load(crate::to_asset_path(&image.path))
                 }
@@ -365,8 +598,8 @@ fn spawn_level_tilemaps(
             };
 
             let tilemap_tile_size = TilemapTileSize {
-                x: tile_size_f32,
-                y: tile_size_f32,
+                x: tile_size.x,
+                y: tile_size.y,
             };
 
             let grid_size: TilemapGridSize = tilemap_tile_size.into();
@@ -397,28 +630,25 @@ fn spawn_level_tilemaps(
             let layer_z = layer_index as f32 * 0.1 + image_index as f32 * 0.01;
 
             // Insert TilemapBundle first (which includes Visibility internally)
-            // Use BottomLeft anchor so tiles at (0,0) start at world origin
+            // Tile (0,0) starts at world origin, see `tilemap_layout`
             commands.entity(tilemap_entity).insert((
                 TilemapBundle {
                     grid_size,
-                    map_type: TilemapType::Square,
+                    map_type,
                     size: map_size,
                     storage: tile_storage.clone(),
                     texture: TilemapTexture::Single(texture_handle),
                     tile_size: tilemap_tile_size,
-                    transform: Transform::from_xyz(0.0, 0.0, layer_z),
-                    anchor: TilemapAnchor::BottomLeft,
-                    visibility: if layer.visible {
-                        Visibility::Inherited
-                    } else {
-                        Visibility::Hidden
-                    },
+                    transform: Transform::from_translation(base.extend(layer_z)),
+                    anchor,
+                    visibility: layer_visibility,
                     ..default()
                 },
                 EditorTilemap {
                     level_id: level.id,
                     layer_index,
                     image_index,
+                    base,
                 },
             ));
 
@@ -437,13 +667,10 @@ fn spawn_level_tilemaps(
                 continue;
             };
 
-            let texture_handle =
-                if let Some((handle, _, _, _)) = tileset_cache.loaded.get(&image.id) {
-                    handle.clone()
-                } else {
-                    // Image not loaded yet, skip for now (will be rendered on rebuild)
-                    continue;
-                };
+            let Some(texture_handle) = tileset_cache.tilemap_handle(&image.id) else {
+                // Image not loaded yet, skip for now (will be rendered on rebuild)
+                continue;
+            };
 
             // Calculate local tile position in the tileset image
             let (_, local_tile_index) = tileset.virtual_to_local(virtual_tile_index).unwrap();
@@ -452,10 +679,10 @@ fn spawn_level_tilemaps(
 
             // Source rect in texture coordinates (pixels)
             // Note: In Bevy textures, Y=0 is at top, but we need to flip for correct sampling
-            let src_x = (tile_col * tile_size) as f32;
-            let src_y = (tile_row * tile_size) as f32;
-            let src_width = (grid_width * tile_size) as f32;
-            let src_height = (grid_height * tile_size) as f32;
+            let src_x = (tile_col * tile_width) as f32;
+            let src_y = (tile_row * tile_height) as f32;
+            let src_width = (grid_width * tile_width) as f32;
+            let src_height = (grid_height * tile_height) as f32;
 
             // Create a rect for the source region
             let rect = bevy::math::Rect::new(src_x, src_y, src_x + src_width, src_y + src_height);
@@ -470,8 +697,9 @@ fn spawn_level_tilemaps(
             // World position: place sprite so origin aligns with grid cell corner
             // For center origin (size/2): sprite center at grid + size/2 (standard behavior)
             // For top-left origin (0): sprite center at grid + 0 (tile shifts left/down)
-            let world_x = x as f32 * tile_size_f32 + origin_x as f32;
-            let world_y = y as f32 * tile_size_f32 + origin_y as f32;
+            let corner = cell_corner(level.orientation, x as i32, y as i32, tile_size);
+            let world_x = corner.x + origin_x as f32;
+            let world_y = corner.y + origin_y as f32;
 
             // Z-offset slightly above regular tiles in same layer
             let layer_z = layer_index as f32 * 0.1 + image_index as f32 * 0.01 + 0.001;
@@ -487,12 +715,13 @@ fn spawn_level_tilemaps(
                         ..default()
                     },
                     Transform::from_xyz(world_x, world_y, layer_z),
-                    Visibility::Inherited,
+                    layer_visibility,
                     MultiCellTileSprite {
                         level_id: level.id,
                         layer_index,
                         x,
                         y,
+                        base: Vec2::new(world_x, world_y),
                     },
                 ))
                 .id();
@@ -502,11 +731,312 @@ fn spawn_level_tilemaps(
                 .multi_cell_sprites
                 .insert((level.id, layer_index, x, y), sprite_entity);
         }
+    }
+}
 
-        // Store layer visibility
-        render_state
-            .layer_visibility
-            .insert((level.id, layer_index), layer.visible);
+/// Get the pixel width and height of a level's grid cells
+///
+/// Uses the tileset of the first tile layer, falling back to the project's
+/// first tileset.
+pub(crate) fn level_tile_dimensions(level: &bevy_map_core::Level, project: &Project) -> Vec2 {
+    level
+        .layers
+        .iter()
+        .find_map(|layer| layer.tileset_id())
+        .or_else(|| project.tilesets.first().map(|t| t.id))
+        .and_then(|id| project.get_tileset(id))
+        .map(tile_dimensions)
+        .unwrap_or(Vec2::splat(32.0))
+}
+
+/// Get a tileset's tile width and height in pixels
+pub(crate) fn tile_dimensions(tileset: &bevy_map_core::Tileset) -> Vec2 {
+    let (width, height) = tileset.tile_dimensions();
+    Vec2::new(width as f32, height as f32)
+}
+
+/// Get the orientation of the selected level
+pub(crate) fn selected_orientation(
+    editor_state: &EditorState,
+    project: &Project,
+) -> MapOrientation {
+    editor_state
+        .selected_level
+        .and_then(|id| project.get_level(id))
+        .map(|level| level.orientation)
+        .unwrap_or_default()
+}
+
+/// Get the tilemap type, anchor and position for a level orientation
+///
+/// Cell (0, 0) always spans `0..tile_size`, so orthogonal levels cover
+/// (0, 0) to (width, height) in pixels. Unanchored tilemaps center cell
+/// (0, 0) on their position.
+fn tilemap_layout(
+    orientation: MapOrientation,
+    tile_size: Vec2,
+) -> (TilemapType, TilemapAnchor, Vec2) {
+    let map_type = match orientation {
+        MapOrientation::Orthogonal => {
+            return (TilemapType::Square, TilemapAnchor::BottomLeft, Vec2::ZERO)
+        }
+        MapOrientation::Isometric => TilemapType::Isometric(IsoCoordSystem::Diamond),
+        MapOrientation::IsometricStaggered => TilemapType::Isometric(IsoCoordSystem::Staggered),
+        MapOrientation::HexRow => TilemapType::Hexagon(HexCoordSystem::RowOdd),
+        MapOrientation::HexColumn => TilemapType::Hexagon(HexCoordSystem::ColumnOdd),
+    };
+    (map_type, TilemapAnchor::None, tile_size / 2.0)
+}
+
+/// Bottom-left corner of a cell's bounding box in level space
+pub(crate) fn cell_corner(orientation: MapOrientation, x: i32, y: i32, tile_size: Vec2) -> Vec2 {
+    Vec2::from(orientation.cell_center(x, y, tile_size.x, tile_size.y))
+}
+
+/// Corners of a cell's outline in level space, closed for drawing
+fn cell_outline(orientation: MapOrientation, x: i32, y: i32, tile_size: Vec2) -> Vec<Vec2> {
+    let center = cell_corner(orientation, x, y, tile_size) + tile_size / 2.0;
+    let mut points: Vec<Vec2> = orientation
+        .cell_outline(tile_size.x, tile_size.y)
+        .into_iter()
+        .map(|corner| center + Vec2::from(corner))
+        .collect();
+    points.push(points[0]);
+    points
+}
+
+/// Convert a level-space position to grid space, where cell (x, y) covers
+/// `x * tile_width..(x + 1) * tile_width` on both axes
+///
+/// Positions on orthogonal levels with square tiles are already in grid
+/// space. Rectangle, line and terrain tools work in grid space, so they
+/// follow any orientation and tile shape.
+pub(crate) fn grid_position(orientation: MapOrientation, world_pos: Vec2, tile_size: Vec2) -> Vec2 {
+    if orientation.is_orthogonal() {
+        return Vec2::new(world_pos.x, world_pos.y * tile_size.x / tile_size.y);
+    }
+    let half = tile_size / 2.0;
+    let grid = orientation.grid_position(
+        [world_pos.x - half.x, world_pos.y - half.y],
+        tile_size.x,
+        tile_size.y,
+    );
+    Vec2::from(grid) * tile_size.x
+}
+
+/// Get the cell under a level-space position
+pub(crate) fn world_to_tile(
+    orientation: MapOrientation,
+    world_pos: Vec2,
+    tile_size: Vec2,
+) -> (i32, i32) {
+    let grid = (grid_position(orientation, world_pos, tile_size) / tile_size.x).floor();
+    (grid.x as i32, grid.y as i32)
+}
+
+/// Bounding box of a level in level space
+pub(crate) fn level_rect(level: &bevy_map_core::Level, tile_size: Vec2) -> Rect {
+    let (min, max) =
+        level
+            .orientation
+            .level_bounds(level.width, level.height, tile_size.x, tile_size.y);
+    let half = tile_size / 2.0;
+    Rect::from_corners(Vec2::from(min) + half, Vec2::from(max) + half)
+}
+
+/// Unscaled half width and height of an entity: its size if set, else its marker
+///
+/// Polygon regions use the box around their points.
+pub(crate) fn entity_half_extents(entity: &EntityInstance, project: &Project) -> Vec2 {
+    if let Some(shape @ RegionShape::Polygon { .. }) = &entity.region {
+        let (min, max) = shape.bounds([0.0, 0.0]);
+        return Vec2::from(min).abs().max(Vec2::from(max).abs());
+    }
+    match entity.size {
+        Some(size) => Vec2::from(size) / 2.0,
+        None => {
+            let marker_size = project
+                .schema
+                .get_type(&entity.type_name)
+                .and_then(|td| td.marker_size)
+                .unwrap_or(16) as f32;
+            Vec2::splat(marker_size / 2.0)
+        }
+    }
+}
+
+/// Viewport transform of an entity (position, rotation and scale) at the given depth
+pub(crate) fn entity_transform(entity: &EntityInstance, z: f32) -> Transform {
+    Transform::from_xyz(entity.position[0], entity.position[1], z)
+        .with_rotation(Quat::from_rotation_z(entity.rotation_radians()))
+        .with_scale(Vec3::new(entity.scale[0], entity.scale[1], 1.0))
+}
+
+/// Convert a level-space position into an entity's unrotated, unscaled space
+pub(crate) fn entity_local_point(entity: &EntityInstance, world_pos: Vec2) -> Vec2 {
+    let offset = world_pos - Vec2::from(entity.position);
+    let unrotated = Vec2::from_angle(-entity.rotation_radians()).rotate(offset);
+    let scale = Vec2::from(entity.scale);
+    // Guard against a zero scale collapsing the entity
+    let scale = Vec2::select(
+        scale.abs().cmpgt(Vec2::splat(f32::EPSILON)),
+        scale,
+        Vec2::ONE,
+    );
+    unrotated / scale
+}
+
+/// Convert a point in an entity's local space back into level space
+pub(crate) fn entity_world_point(entity: &EntityInstance, local: Vec2) -> Vec2 {
+    Vec2::from(entity.position)
+        + Vec2::from_angle(entity.rotation_radians()).rotate(local * Vec2::from(entity.scale))
+}
+
+/// Check whether a level-space position is inside an entity's rotated bounds
+///
+/// Polygon regions are hit inside their outline.
+pub(crate) fn entity_contains(entity: &EntityInstance, project: &Project, world_pos: Vec2) -> bool {
+    if entity
+        .region
+        .as_ref()
+        .is_some_and(|region| region.is_polygon())
+    {
+        return entity.region_contains(entity_local_point(entity, world_pos).into());
+    }
+    let local = entity_local_point(entity, world_pos).abs();
+    let half = entity_half_extents(entity, project);
+    local.x <= half.x && local.y <= half.y
+}
+
+/// Closed outline of an entity in level space: its region shape, else its bounds
+pub(crate) fn entity_outline(entity: &EntityInstance, project: &Project) -> Vec<Vec2> {
+    let local: Vec<Vec2> = match &entity.region {
+        Some(shape @ RegionShape::Polygon { .. }) => shape
+            .outline([0.0, 0.0])
+            .into_iter()
+            .map(Vec2::from)
+            .collect(),
+        _ => {
+            let half = entity_half_extents(entity, project);
+            [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+                .map(|(x, y)| half * Vec2::new(x, y))
+                .to_vec()
+        }
+    };
+    let mut outline: Vec<Vec2> = local
+        .into_iter()
+        .map(|point| entity_world_point(entity, point))
+        .collect();
+    if let Some(&first) = outline.first() {
+        outline.push(first);
+    }
+    outline
+}
+
+/// Build the RGBA pixels of an IntGrid overlay image
+///
+/// Image rows run top to bottom while level rows run bottom to top. Values
+/// missing from the palette are drawn grey.
+fn int_grid_overlay_pixels(
+    width: u32,
+    height: u32,
+    values: &[u32],
+    palette: &[IntGridValue],
+) -> Vec<u8> {
+    let mut pixels = vec![0u8; (width.max(1) * height.max(1) * 4) as usize];
+    for y in 0..height {
+        for x in 0..width {
+            let value = values.get((y * width + x) as usize).copied().unwrap_or(0);
+            if value == 0 {
+                continue;
+            }
+            let [r, g, b, a] = palette
+                .iter()
+                .find(|def| def.value == value)
+                .map(|def| def.color_rgba())
+                .unwrap_or([0.5, 0.5, 0.5, 1.0]);
+            let row = height - 1 - y;
+            let offset = ((row * width + x) * 4) as usize;
+            pixels[offset..offset + 4].copy_from_slice(&[
+                (r * 255.0) as u8,
+                (g * 255.0) as u8,
+                (b * 255.0) as u8,
+                (a * INT_GRID_OVERLAY_ALPHA * 255.0) as u8,
+            ]);
+        }
+    }
+    pixels
+}
+
+/// System that rebuilds IntGrid overlay images when values or palettes change
+fn sync_int_grid_overlays(
+    project: Res<Project>,
+    mut images: ResMut<Assets<Image>>,
+    mut query: Query<(&mut EditorIntGridOverlay, &Sprite)>,
+) {
+    for (mut overlay, sprite) in query.iter_mut() {
+        let Some(level) = project.get_level(overlay.level_id) else {
+            continue;
+        };
+        let Some(LayerData::IntGrid { values, palette }) = level
+            .layers
+            .get(overlay.layer_index)
+            .map(|layer| &layer.data)
+        else {
+            continue;
+        };
+        if overlay.values == *values && overlay.palette == *palette {
+            continue;
+        }
+
+        if let Some(image) = images.get_mut(&sprite.image) {
+            image.data = Some(int_grid_overlay_pixels(
+                level.width,
+                level.height,
+                values,
+                palette,
+            ));
+        }
+        overlay.values = values.clone();
+        overlay.palette = palette.clone();
+    }
+}
+
+/// System that sizes image layer sprites once their images are loaded
+///
+/// Repeated axes cover the whole level, the others keep the image's own size.
+fn fit_image_layer_sprites(
+    images: Res<Assets<Image>>,
+    mut query: Query<(&EditorImageLayer, &mut Sprite)>,
+) {
+    for (image_layer, mut sprite) in query.iter_mut() {
+        if sprite.custom_size.is_some() {
+            continue;
+        }
+        let Some(image) = images.get(&sprite.image) else {
+            continue;
+        };
+
+        let image_size = image.size_f32();
+        sprite.custom_size = Some(Vec2::new(
+            if image_layer.repeat_x {
+                image_layer.level_size.x
+            } else {
+                image_size.x
+            },
+            if image_layer.repeat_y {
+                image_layer.level_size.y
+            } else {
+                image_size.y
+            },
+        ));
+        if image_layer.repeat_x || image_layer.repeat_y {
+            sprite.image_mode = SpriteImageMode::Tiled {
+                tile_x: image_layer.repeat_x,
+                tile_y: image_layer.repeat_y,
+                stretch_value: 1.0,
+            };
+        }
     }
 }
 
@@ -542,8 +1072,8 @@ pub fn update_tile(
         return;
     };
 
-    let tile_size = tileset.tile_size;
-    let tile_size_f32 = tile_size as f32;
+    let (tile_width, tile_height) = tileset.tile_dimensions();
+    let tile_size = tile_dimensions(tileset);
     let tile_pos = TilePos { x, y };
 
     // Skip rendering OCCUPIED_CELL sentinel values (used for multi-cell tiles)
@@ -595,16 +1125,16 @@ pub fn update_tile(
 
                 // Get texture handle
                 if let Some(image) = tileset.images.get(image_index) {
-                    if let Some((texture_handle, _, _, _)) = tileset_cache.loaded.get(&image.id) {
+                    if let Some(texture_handle) = tileset_cache.tilemap_handle(&image.id) {
                         // Calculate tile position in tileset image
                         let tile_col = local_idx % image.columns;
                         let tile_row = local_idx / image.columns;
 
                         // Source rect
-                        let src_x = (tile_col * tile_size) as f32;
-                        let src_y = (tile_row * tile_size) as f32;
-                        let src_width = (grid_width * tile_size) as f32;
-                        let src_height = (grid_height * tile_size) as f32;
+                        let src_x = (tile_col * tile_width) as f32;
+                        let src_y = (tile_row * tile_height) as f32;
+                        let src_width = (grid_width * tile_width) as f32;
+                        let src_height = (grid_height * tile_height) as f32;
 
                         let rect = bevy::math::Rect::new(
                             src_x,
@@ -622,8 +1152,9 @@ pub fn update_tile(
                             props.get_origin(src_width as u32, src_height as u32);
 
                         // World position: place sprite so origin aligns with grid cell corner
-                        let world_x = x as f32 * tile_size_f32 + origin_x as f32;
-                        let world_y = y as f32 * tile_size_f32 + origin_y as f32;
+                        let corner = cell_corner(level.orientation, x as i32, y as i32, tile_size);
+                        let world_x = corner.x + origin_x as f32;
+                        let world_y = corner.y + origin_y as f32;
                         let layer_z = layer_index as f32 * 0.1 + image_index as f32 * 0.01 + 0.001;
 
                         let sprite_entity = commands
@@ -643,6 +1174,7 @@ pub fn update_tile(
                                     layer_index,
                                     x,
                                     y,
+                                    base: Vec2::new(world_x, world_y),
                                 },
                             ))
                             .id();
@@ -672,12 +1204,10 @@ pub fn update_tile(
                 // Create tilemap on-demand if it doesn't exist
                 if !render_state.tile_storages.contains_key(&key) {
                     // Get texture handle from cache
-                    let texture_handle = tileset.images.get(image_index).and_then(|image| {
-                        tileset_cache
-                            .loaded
-                            .get(&image.id)
-                            .map(|(handle, _, _, _)| handle.clone())
-                    });
+                    let texture_handle = tileset
+                        .images
+                        .get(image_index)
+                        .and_then(|image| tileset_cache.tilemap_handle(&image.id));
 
                     if let Some(texture_handle) = texture_handle {
                         let map_size = TilemapSize {
@@ -686,8 +1216,8 @@ pub fn update_tile(
                         };
 
                         let tilemap_tile_size = TilemapTileSize {
-                            x: tile_size_f32,
-                            y: tile_size_f32,
+                            x: tile_size.x,
+                            y: tile_size.y,
                         };
 
                         let grid_size: TilemapGridSize = tilemap_tile_size.into();
@@ -696,17 +1226,18 @@ pub fn update_tile(
 
                         let layer_z = layer_index as f32 * 0.1 + image_index as f32 * 0.01;
                         let layer_visible = layer.visible;
+                        let (map_type, anchor, base) = tilemap_layout(level.orientation, tile_size);
 
                         commands.entity(tilemap_entity).insert((
                             TilemapBundle {
                                 grid_size,
-                                map_type: TilemapType::Square,
+                                map_type,
                                 size: map_size,
                                 storage: tile_storage.clone(),
                                 texture: TilemapTexture::Single(texture_handle),
                                 tile_size: tilemap_tile_size,
-                                transform: Transform::from_xyz(0.0, 0.0, layer_z),
-                                anchor: TilemapAnchor::BottomLeft,
+                                transform: Transform::from_translation(base.extend(layer_z)),
+                                anchor,
                                 visibility: if layer_visible {
                                     Visibility::Inherited
                                 } else {
@@ -718,6 +1249,7 @@ pub fn update_tile(
                                 level_id,
                                 layer_index,
                                 image_index,
+                                base,
                             },
                         ));
 
@@ -772,6 +1304,7 @@ fn sync_grid_rendering(
     mut render_state: ResMut<RenderState>,
     editor_state: Res<EditorState>,
     project: Res<Project>,
+    mut gizmo_assets: ResMut<Assets<GizmoAsset>>,
 ) {
     let show_grid = editor_state.show_grid;
 
@@ -792,13 +1325,13 @@ fn sync_grid_rendering(
                                 .tilesets
                                 .iter()
                                 .find(|t| t.id == *tileset_id)
-                                .map(|t| t.tile_size)
+                                .map(|t| t.tile_dimensions())
                         } else {
                             None
                         }
                     })
-                    .unwrap_or(32);
-                (level.width, level.height, tile_size)
+                    .unwrap_or((32, 32));
+                (level.width, level.height, tile_size, level.orientation)
             })
     });
 
@@ -822,19 +1355,42 @@ fn sync_grid_rendering(
         return;
     }
 
-    let Some((width, height, tile_size)) = level_info else {
+    let Some((width, height, tile_size, orientation)) = level_info else {
         return;
     };
 
-    let tile_size_f32 = tile_size as f32;
+    let tile_size = Vec2::new(tile_size.0 as f32, tile_size.1 as f32);
     let grid_color = Color::srgba(0.5, 0.5, 0.5, 0.5);
+
+    // Other orientations have no straight grid lines, so outline every cell
+    if !orientation.is_orthogonal() {
+        let mut gizmo = GizmoAsset::default();
+        for y in 0..height as i32 {
+            for x in 0..width as i32 {
+                gizmo.linestrip_2d(cell_outline(orientation, x, y, tile_size), grid_color);
+            }
+        }
+        let entity = commands
+            .spawn((
+                Gizmo {
+                    handle: gizmo_assets.add(gizmo),
+                    ..default()
+                },
+                Transform::from_xyz(0.0, 0.0, 100.0),
+                GridLine,
+            ))
+            .id();
+        render_state.grid_entities.push(entity);
+        return;
+    }
+
     let line_thickness = 1.0;
-    let grid_width = width as f32 * tile_size_f32;
-    let grid_height = height as f32 * tile_size_f32;
+    let grid_width = width as f32 * tile_size.x;
+    let grid_height = height as f32 * tile_size.y;
 
     // Spawn vertical lines
     for x in 0..=width {
-        let world_x = x as f32 * tile_size_f32;
+        let world_x = x as f32 * tile_size.x;
         let center_y = grid_height / 2.0;
         let entity = commands
             .spawn((
@@ -852,7 +1408,7 @@ fn sync_grid_rendering(
 
     // Spawn horizontal lines
     for y in 0..=height {
-        let world_y = y as f32 * tile_size_f32;
+        let world_y = y as f32 * tile_size.y;
         let center_x = grid_width / 2.0;
         let entity = commands
             .spawn((
@@ -924,7 +1480,7 @@ fn sync_collision_rendering(
                 continue;
             };
 
-            let tile_size = tileset.tile_size as f32;
+            let tile_size = tile_dimensions(tileset);
 
             // Iterate through tiles
             for y in 0..level.height {
@@ -939,8 +1495,7 @@ fn sync_collision_rendering(
                                     &mut commands,
                                     &mut cache,
                                     &props.collision.shape,
-                                    x,
-                                    y,
+                                    cell_corner(level.orientation, x as i32, y as i32, tile_size),
                                     tile_size,
                                     layer_idx,
                                     collision_color,
@@ -959,15 +1514,13 @@ fn spawn_collision_overlay(
     commands: &mut Commands,
     cache: &mut CollisionOverlayCache,
     shape: &bevy_map_core::CollisionShape,
-    tile_x: u32,
-    tile_y: u32,
-    tile_size: f32,
+    corner: Vec2,
+    tile_size: Vec2,
     layer_idx: usize,
     color: Color,
 ) {
-    // Calculate world position (tiles are positioned from bottom-left)
-    let base_x = tile_x as f32 * tile_size;
-    let base_y = tile_y as f32 * tile_size;
+    // Shapes are laid out from the bottom-left corner of the tile's cell
+    let (base_x, base_y) = (corner.x, corner.y);
     let z = 101.0 + layer_idx as f32 * 0.01; // Just above grid (100.0)
 
     match shape {
@@ -978,10 +1531,10 @@ fn spawn_collision_overlay(
                 .spawn((
                     Sprite {
                         color,
-                        custom_size: Some(Vec2::new(tile_size, tile_size)),
+                        custom_size: Some(tile_size),
                         ..default()
                     },
-                    Transform::from_xyz(base_x + tile_size / 2.0, base_y + tile_size / 2.0, z),
+                    Transform::from_xyz(base_x + tile_size.x / 2.0, base_y + tile_size.y / 2.0, z),
                     CollisionOverlay,
                 ))
                 .id();
@@ -990,10 +1543,10 @@ fn spawn_collision_overlay(
         bevy_map_core::CollisionShape::Rectangle { offset, size } => {
             // Rectangle at offset with size (both normalized 0-1)
             // Flip Y: editor uses Y-down (top=0), Bevy uses Y-up (bottom=0)
-            let width = size[0] * tile_size;
-            let height = size[1] * tile_size;
-            let center_x = base_x + (offset[0] + size[0] / 2.0) * tile_size;
-            let center_y = base_y + (1.0 - offset[1] - size[1] / 2.0) * tile_size;
+            let width = size[0] * tile_size.x;
+            let height = size[1] * tile_size.y;
+            let center_x = base_x + (offset[0] + size[0] / 2.0) * tile_size.x;
+            let center_y = base_y + (1.0 - offset[1] - size[1] / 2.0) * tile_size.y;
 
             let entity = commands
                 .spawn((
@@ -1012,9 +1565,9 @@ fn spawn_collision_overlay(
             // Circle - approximate with a square sprite for now
             // Could use a circle texture or shader in the future
             // Flip Y: editor uses Y-down (top=0), Bevy uses Y-up (bottom=0)
-            let diameter = radius * 2.0 * tile_size;
-            let center_x = base_x + offset[0] * tile_size;
-            let center_y = base_y + (1.0 - offset[1]) * tile_size;
+            let diameter = radius * 2.0 * tile_size.min_element();
+            let center_x = base_x + offset[0] * tile_size.x;
+            let center_y = base_y + (1.0 - offset[1]) * tile_size.y;
 
             let entity = commands
                 .spawn((
@@ -1044,10 +1597,10 @@ fn spawn_collision_overlay(
 
                 // Convert normalized coords to world coords
                 // Flip Y: editor uses Y-down (top=0), Bevy uses Y-up (bottom=0)
-                let x1 = base_x + p1[0] * tile_size;
-                let y1 = base_y + (1.0 - p1[1]) * tile_size;
-                let x2 = base_x + p2[0] * tile_size;
-                let y2 = base_y + (1.0 - p2[1]) * tile_size;
+                let x1 = base_x + p1[0] * tile_size.x;
+                let y1 = base_y + (1.0 - p1[1]) * tile_size.y;
+                let x2 = base_x + p2[0] * tile_size.x;
+                let y2 = base_y + (1.0 - p2[1]) * tile_size.y;
 
                 // Calculate line center, length, and angle
                 let center_x = (x1 + x2) / 2.0;
@@ -1102,6 +1655,7 @@ fn sync_selection_preview(
     input_state: Option<Res<ViewportInputState>>,
     project: Res<Project>,
     existing_preview: Query<Entity, With<SelectionPreview>>,
+    mut gizmos: Gizmos,
 ) {
     // Always despawn existing preview first
     for entity in existing_preview.iter() {
@@ -1133,10 +1687,10 @@ fn sync_selection_preview(
 
     // Get tile size
     let tile_size = get_tile_size(&editor_state, &project);
+    let orientation = selected_orientation(&editor_state, &project);
 
     // Calculate end tile position
-    let end_x = (current_pos.x / tile_size).floor() as i32;
-    let end_y = (current_pos.y / tile_size).floor() as i32;
+    let (end_x, end_y) = world_to_tile(orientation, current_pos, tile_size);
 
     // Choose color based on whether we're filling or erasing
     let color = if editor_state.selected_tile.is_some() {
@@ -1145,16 +1699,34 @@ fn sync_selection_preview(
         Color::srgba(0.8, 0.2, 0.2, 0.4) // Red for erase
     };
 
+    // Outline the affected cells on isometric and hexagonal levels
+    if !orientation.is_orthogonal() {
+        let cells = if is_line_mode {
+            bresenham_line(start_x, start_y, end_x, end_y)
+        } else {
+            (start_y.min(end_y)..=start_y.max(end_y))
+                .flat_map(|y| (start_x.min(end_x)..=start_x.max(end_x)).map(move |x| (x, y)))
+                .collect()
+        };
+        for (x, y) in cells {
+            gizmos.linestrip_2d(
+                cell_outline(orientation, x, y, tile_size),
+                color.with_alpha(1.0),
+            );
+        }
+        return;
+    }
+
     if is_line_mode {
         // Spawn a preview sprite for each tile along the line
         let line_points = bresenham_line(start_x, start_y, end_x, end_y);
         for (tx, ty) in line_points {
-            let center_x = (tx as f32 + 0.5) * tile_size;
-            let center_y = (ty as f32 + 0.5) * tile_size;
+            let center_x = (tx as f32 + 0.5) * tile_size.x;
+            let center_y = (ty as f32 + 0.5) * tile_size.y;
             commands.spawn((
                 Sprite {
                     color,
-                    custom_size: Some(Vec2::new(tile_size, tile_size)),
+                    custom_size: Some(tile_size),
                     ..default()
                 },
                 Transform::from_xyz(center_x, center_y, 200.0),
@@ -1168,10 +1740,10 @@ fn sync_selection_preview(
         let min_y = start_y.min(end_y);
         let max_y = start_y.max(end_y);
 
-        let world_min_x = min_x as f32 * tile_size;
-        let world_max_x = (max_x + 1) as f32 * tile_size;
-        let world_min_y = min_y as f32 * tile_size;
-        let world_max_y = (max_y + 1) as f32 * tile_size;
+        let world_min_x = min_x as f32 * tile_size.x;
+        let world_max_x = (max_x + 1) as f32 * tile_size.x;
+        let world_min_y = min_y as f32 * tile_size.y;
+        let world_max_y = (max_y + 1) as f32 * tile_size.y;
 
         let width = world_max_x - world_min_x;
         let height = world_max_y - world_min_y;
@@ -1190,13 +1762,25 @@ fn sync_selection_preview(
     }
 }
 
-/// Get the tile size for the current level/layer/tileset (for preview rendering)
-fn get_tile_size(editor_state: &EditorState, project: &Project) -> f32 {
+/// Get the tile width and height for the current level/layer/tileset (for preview rendering)
+fn get_tile_size(editor_state: &EditorState, project: &Project) -> Vec2 {
     let level_id = editor_state.selected_level;
     let layer_idx = editor_state.selected_layer;
 
     // Use O(1) lookup for level
     let level = level_id.and_then(|id| project.get_level(id));
+
+    // IntGrid layers share the level's grid rather than a tileset's
+    if let Some(level) = level {
+        let selected_layer = layer_idx.and_then(|idx| level.layers.get(idx));
+        if matches!(
+            selected_layer.map(|layer| &layer.data),
+            Some(LayerData::IntGrid { .. })
+        ) {
+            return level_tile_dimensions(level, project);
+        }
+    }
+
     let layer_tileset_id = level.and_then(|l| {
         layer_idx
             .and_then(|idx| l.layers.get(idx))
@@ -1213,8 +1797,8 @@ fn get_tile_size(editor_state: &EditorState, project: &Project) -> f32 {
     layer_tileset_id
         .or(editor_state.selected_tileset)
         .and_then(|id| project.get_tileset(id))
-        .map(|t| t.tile_size as f32)
-        .unwrap_or(32.0)
+        .map(tile_dimensions)
+        .unwrap_or(Vec2::splat(32.0))
 }
 
 /// Resource tracking the current selection highlight state for change detection
@@ -1272,6 +1856,7 @@ fn sync_tile_selection_highlights(
     mut selection_state: ResMut<SelectionRenderState>,
     time: Res<Time>,
     mut sprite_query: Query<(&mut Sprite, &mut Transform), With<TileSelectionHighlight>>,
+    mut gizmos: Gizmos,
 ) {
     let current_selection = &editor_state.tile_selection.tiles;
 
@@ -1308,6 +1893,20 @@ fn sync_tile_selection_highlights(
     let alpha = 0.85 + 0.15 * (elapsed_time * std::f32::consts::PI).sin();
     let color = Color::srgba(0.0, 0.8, 1.0, alpha);
 
+    // A bounding box is not a rectangle on isometric and hexagonal levels,
+    // so outline each selected cell instead
+    let orientation = selected_orientation(&editor_state, &project);
+    if !orientation.is_orthogonal() {
+        clear_highlights(&mut commands, &mut selection_state);
+        for (level_id, _, x, y) in current_selection.iter() {
+            if Some(*level_id) == editor_state.selected_level {
+                let (x, y) = (*x as i32 + offset_x, *y as i32 + offset_y);
+                gizmos.linestrip_2d(cell_outline(orientation, x, y, tile_size), color);
+            }
+        }
+        return;
+    }
+
     // Calculate bounding box of all selected tiles
     let mut min_x = i32::MAX;
     let mut max_x = i32::MIN;
@@ -1343,10 +1942,10 @@ fn sync_tile_selection_highlights(
     // If bounds or offset changed, we need to update or recreate the border sprites
     if bounds_changed || offset_changed {
         // Calculate world coordinates for bounding box (apply move offset)
-        let world_min_x = (min_x + offset_x) as f32 * tile_size;
-        let world_max_x = ((max_x + 1) + offset_x) as f32 * tile_size;
-        let world_min_y = (min_y + offset_y) as f32 * tile_size;
-        let world_max_y = ((max_y + 1) + offset_y) as f32 * tile_size;
+        let world_min_x = (min_x + offset_x) as f32 * tile_size.x;
+        let world_max_x = ((max_x + 1) + offset_x) as f32 * tile_size.x;
+        let world_min_y = (min_y + offset_y) as f32 * tile_size.y;
+        let world_max_y = ((max_y + 1) + offset_y) as f32 * tile_size.y;
 
         let width = world_max_x - world_min_x;
         let height = world_max_y - world_min_y;
@@ -1534,7 +2133,8 @@ fn sync_terrain_preview(
         return;
     };
 
-    let tile_size = tileset.tile_size as f32;
+    let tile_size = tile_dimensions(tileset);
+    let orientation = selected_orientation(&editor_state, &project);
     let preview_tile_color = Color::srgba(1.0, 1.0, 1.0, 0.6);
     let highlight_color = Color::srgba(0.2, 0.5, 1.0, 0.2);
     let border_color = Color::srgba(0.2, 0.5, 1.0, 0.8);
@@ -1549,26 +2149,22 @@ fn sync_terrain_preview(
             }
         }
 
-        let world_x = x as f32 * tile_size + tile_size / 2.0;
-        let world_y = y as f32 * tile_size + tile_size / 2.0;
+        let center = cell_corner(orientation, x, y, tile_size) + tile_size / 2.0;
+        let (world_x, world_y) = (center.x, center.y);
         let mut entities = Vec::new();
 
         // Spawn tile sprite
         if let Some((image_index, local_tile_index)) = tileset.virtual_to_local(tile_id) {
             if let Some(image) = tileset.images.get(image_index) {
-                if let Some((texture_handle, _, img_width, img_height)) =
-                    tileset_cache.loaded.get(&image.id)
-                {
-                    let columns = (*img_width as u32) / tileset.tile_size;
-                    let rows = (*img_height as u32) / tileset.tile_size;
-
-                    if columns > 0 && rows > 0 {
+                if let Some((texture_handle, _, _, _)) = tileset_cache.loaded.get(&image.id) {
+                    if image.columns > 0 && image.rows > 0 {
+                        let (tile_width, tile_height) = tileset.tile_dimensions();
                         let layout = TextureAtlasLayout::from_grid(
-                            UVec2::new(tileset.tile_size, tileset.tile_size),
-                            columns,
-                            rows,
-                            None,
-                            None,
+                            UVec2::new(tile_width, tile_height),
+                            image.columns,
+                            image.rows,
+                            Some(UVec2::splat(image.spacing)),
+                            Some(UVec2::splat(image.margin)),
                         );
                         let atlas_layout_handle = texture_atlas_layouts.add(layout);
 
@@ -1598,7 +2194,7 @@ fn sync_terrain_preview(
             .spawn((
                 Sprite {
                     color: highlight_color,
-                    custom_size: Some(Vec2::new(tile_size, tile_size)),
+                    custom_size: Some(tile_size),
                     ..default()
                 },
                 Transform::from_xyz(world_x, world_y, 180.0),
@@ -1612,12 +2208,12 @@ fn sync_terrain_preview(
             .spawn((
                 Sprite {
                     color: border_color,
-                    custom_size: Some(Vec2::new(tile_size, border_thickness)),
+                    custom_size: Some(Vec2::new(tile_size.x, border_thickness)),
                     ..default()
                 },
                 Transform::from_xyz(
                     world_x,
-                    world_y + tile_size / 2.0 - border_thickness / 2.0,
+                    world_y + tile_size.y / 2.0 - border_thickness / 2.0,
                     181.0,
                 ),
                 TerrainPreviewHighlight,
@@ -1630,12 +2226,12 @@ fn sync_terrain_preview(
             .spawn((
                 Sprite {
                     color: border_color,
-                    custom_size: Some(Vec2::new(tile_size, border_thickness)),
+                    custom_size: Some(Vec2::new(tile_size.x, border_thickness)),
                     ..default()
                 },
                 Transform::from_xyz(
                     world_x,
-                    world_y - tile_size / 2.0 + border_thickness / 2.0,
+                    world_y - tile_size.y / 2.0 + border_thickness / 2.0,
                     181.0,
                 ),
                 TerrainPreviewHighlight,
@@ -1648,11 +2244,11 @@ fn sync_terrain_preview(
             .spawn((
                 Sprite {
                     color: border_color,
-                    custom_size: Some(Vec2::new(border_thickness, tile_size)),
+                    custom_size: Some(Vec2::new(border_thickness, tile_size.y)),
                     ..default()
                 },
                 Transform::from_xyz(
-                    world_x - tile_size / 2.0 + border_thickness / 2.0,
+                    world_x - tile_size.x / 2.0 + border_thickness / 2.0,
                     world_y,
                     181.0,
                 ),
@@ -1666,11 +2262,11 @@ fn sync_terrain_preview(
             .spawn((
                 Sprite {
                     color: border_color,
-                    custom_size: Some(Vec2::new(border_thickness, tile_size)),
+                    custom_size: Some(Vec2::new(border_thickness, tile_size.y)),
                     ..default()
                 },
                 Transform::from_xyz(
-                    world_x + tile_size / 2.0 - border_thickness / 2.0,
+                    world_x + tile_size.x / 2.0 - border_thickness / 2.0,
                     world_y,
                     181.0,
                 ),
@@ -1743,39 +2339,43 @@ fn sync_brush_preview(
         return;
     };
 
-    let tile_size = tileset.tile_size as f32;
+    let (tile_width, tile_height) = tileset.tile_dimensions();
+    let tile_size = tile_dimensions(tileset);
     let (grid_width, grid_height) = tileset.get_tile_grid_size(tile_id);
     let preview_color = Color::srgba(1.0, 1.0, 1.0, 0.6);
     let border_color = Color::srgba(0.2, 0.8, 0.2, 0.8); // Green for brush
 
     // Calculate world position using origin (consistent with tile placement)
-    let total_width = grid_width as f32 * tile_size;
-    let total_height = grid_height as f32 * tile_size;
+    let total_width = grid_width as f32 * tile_size.x;
+    let total_height = grid_height as f32 * tile_size.y;
     let props = tileset
         .get_tile_properties(tile_id)
         .cloned()
         .unwrap_or_default();
     let (origin_x, origin_y) = props.get_origin(total_width as u32, total_height as u32);
-    let world_x = position.0 as f32 * tile_size + origin_x as f32;
-    let world_y = position.1 as f32 * tile_size + origin_y as f32;
+    let corner = cell_corner(
+        selected_orientation(&editor_state, &project),
+        position.0,
+        position.1,
+        tile_size,
+    );
+    let world_x = corner.x + origin_x as f32;
+    let world_y = corner.y + origin_y as f32;
 
     // Spawn tile sprite (try to use texture, fall back to colored rectangle)
     let mut sprite_created = false;
     if let Some((image_index, local_tile_index)) = tileset.virtual_to_local(tile_id) {
         if let Some(image) = tileset.images.get(image_index) {
-            if let Some((texture_handle, _, img_width, img_height)) =
-                tileset_cache.loaded.get(&image.id)
-            {
-                let columns = (*img_width as u32) / tileset.tile_size;
-                let rows = (*img_height as u32) / tileset.tile_size;
+            if let Some(texture_handle) = tileset_cache.tilemap_handle(&image.id) {
+                let (columns, rows) = (image.columns, image.rows);
 
                 if columns > 0 && rows > 0 {
                     if grid_width > 1 || grid_height > 1 {
                         // Multi-cell tile: use Sprite with rect for the full region
                         let tile_col = local_tile_index % image.columns;
                         let tile_row = local_tile_index / image.columns;
-                        let src_x = (tile_col * tileset.tile_size) as f32;
-                        let src_y = (tile_row * tileset.tile_size) as f32;
+                        let src_x = (tile_col * tile_width) as f32;
+                        let src_y = (tile_row * tile_height) as f32;
                         let src_width = total_width;
                         let src_height = total_height;
 
@@ -1805,7 +2405,7 @@ fn sync_brush_preview(
                     } else {
                         // Single tile: use TextureAtlas
                         let layout = TextureAtlasLayout::from_grid(
-                            UVec2::new(tileset.tile_size, tileset.tile_size),
+                            UVec2::new(tile_width, tile_height),
                             columns,
                             rows,
                             None,
@@ -2028,8 +2628,7 @@ fn sync_entity_rendering(
     // Toggle visibility based on whether entity is in current layer
     for entity in &level.entities {
         let key = (level_id, entity.id);
-        let x = entity.position[0];
-        let y = entity.position[1];
+        let transform = entity_transform(entity, 50.0);
 
         // Determine visibility: only visible if entity is in current layer
         let is_visible = layer_entity_ids.contains(&entity.id);
@@ -2048,7 +2647,7 @@ fn sync_entity_rendering(
         let viewport_display = type_def.map(|td| td.viewport_display).unwrap_or_default();
 
         // Determine sprite configuration based on viewport display mode
-        let sprite = match viewport_display {
+        let mut sprite = match viewport_display {
             ViewportDisplayMode::Icon => {
                 // Try to use icon texture
                 if let Some(icon_path) = type_def.and_then(|td| td.icon.as_ref()) {
@@ -2077,85 +2676,74 @@ fn sync_entity_rendering(
                 }
             }
             ViewportDisplayMode::Sprite => {
-                // Try to use first frame of sprite animation
-                if let Some(entity_type_config) = project.entity_type_configs.get(&entity.type_name)
-                {
-                    if let Some(sprite_config) = &entity_type_config.sprite {
-                        if let Some(sprite_sheet_id) = sprite_config.sprite_sheet_id {
-                            // Find the sprite sheet
-                            if let Some(sprite_sheet) = project
-                                .sprite_sheets
-                                .iter()
-                                .find(|ss| ss.id == sprite_sheet_id)
+                // Try to use first frame of sprite animation (template and instance
+                // overrides can pick a different sheet or animation)
+                if let Some(sprite_config) = &project.resolved_entity_type_config(entity).sprite {
+                    if let Some(sprite_sheet_id) = sprite_config.sprite_sheet_id {
+                        // Find the sprite sheet
+                        if let Some(sprite_sheet) = project
+                            .sprite_sheets
+                            .iter()
+                            .find(|ss| ss.id == sprite_sheet_id)
+                        {
+                            // Get texture from cache
+                            if let Some((handle, img_width, _img_height)) =
+                                entity_texture_cache.sprite_sheets.get(&sprite_sheet_id)
                             {
-                                // Get texture from cache
-                                if let Some((handle, img_width, _img_height)) =
-                                    entity_texture_cache.sprite_sheets.get(&sprite_sheet_id)
-                                {
-                                    // Get the default animation to find the first frame
-                                    let frame_rect = sprite_config
-                                        .default_animation
-                                        .as_ref()
-                                        .and_then(|anim_name| {
-                                            sprite_sheet.animations.get(anim_name)
-                                        })
-                                        .and_then(|anim| anim.frames.first())
-                                        .map(|&frame_index| {
-                                            // Calculate source rect from frame index
-                                            let frame_w = sprite_sheet.frame_width;
-                                            let frame_h = sprite_sheet.frame_height;
-                                            let cols = (*img_width) / frame_w.max(1);
-                                            let col = (frame_index as u32) % cols.max(1);
-                                            let row = (frame_index as u32) / cols.max(1);
-                                            let src_x = col * frame_w;
-                                            let src_y = row * frame_h;
-                                            bevy::math::Rect::new(
-                                                src_x as f32,
-                                                src_y as f32,
-                                                (src_x + frame_w) as f32,
-                                                (src_y + frame_h) as f32,
-                                            )
-                                        });
-
-                                    if let Some(rect) = frame_rect {
-                                        Sprite {
-                                            image: handle.clone(),
-                                            rect: Some(rect),
-                                            custom_size: Some(Vec2::new(
-                                                sprite_sheet.frame_width as f32,
-                                                sprite_sheet.frame_height as f32,
-                                            )),
-                                            ..default()
-                                        }
-                                    } else {
-                                        // No valid frame, use first frame as fallback
+                                // Get the default animation to find the first frame
+                                let frame_rect = sprite_config
+                                    .default_animation
+                                    .as_ref()
+                                    .and_then(|anim_name| sprite_sheet.animations.get(anim_name))
+                                    .and_then(|anim| anim.frames.first())
+                                    .map(|&frame_index| {
+                                        // Calculate source rect from frame index
                                         let frame_w = sprite_sheet.frame_width;
                                         let frame_h = sprite_sheet.frame_height;
-                                        Sprite {
-                                            image: handle.clone(),
-                                            rect: Some(bevy::math::Rect::new(
-                                                0.0,
-                                                0.0,
-                                                frame_w as f32,
-                                                frame_h as f32,
-                                            )),
-                                            custom_size: Some(Vec2::new(
-                                                frame_w as f32,
-                                                frame_h as f32,
-                                            )),
-                                            ..default()
-                                        }
+                                        let cols = (*img_width) / frame_w.max(1);
+                                        let col = (frame_index as u32) % cols.max(1);
+                                        let row = (frame_index as u32) / cols.max(1);
+                                        let src_x = col * frame_w;
+                                        let src_y = row * frame_h;
+                                        bevy::math::Rect::new(
+                                            src_x as f32,
+                                            src_y as f32,
+                                            (src_x + frame_w) as f32,
+                                            (src_y + frame_h) as f32,
+                                        )
+                                    });
+
+                                if let Some(rect) = frame_rect {
+                                    Sprite {
+                                        image: handle.clone(),
+                                        rect: Some(rect),
+                                        custom_size: Some(Vec2::new(
+                                            sprite_sheet.frame_width as f32,
+                                            sprite_sheet.frame_height as f32,
+                                        )),
+                                        ..default()
                                     }
                                 } else {
-                                    // Texture not loaded yet, fallback to colored square
+                                    // No valid frame, use first frame as fallback
+                                    let frame_w = sprite_sheet.frame_width;
+                                    let frame_h = sprite_sheet.frame_height;
                                     Sprite {
-                                        color,
-                                        custom_size: Some(Vec2::new(entity_size, entity_size)),
+                                        image: handle.clone(),
+                                        rect: Some(bevy::math::Rect::new(
+                                            0.0,
+                                            0.0,
+                                            frame_w as f32,
+                                            frame_h as f32,
+                                        )),
+                                        custom_size: Some(Vec2::new(
+                                            frame_w as f32,
+                                            frame_h as f32,
+                                        )),
                                         ..default()
                                     }
                                 }
                             } else {
-                                // Sprite sheet not found, fallback
+                                // Texture not loaded yet, fallback to colored square
                                 Sprite {
                                     color,
                                     custom_size: Some(Vec2::new(entity_size, entity_size)),
@@ -2163,7 +2751,7 @@ fn sync_entity_rendering(
                                 }
                             }
                         } else {
-                            // No sprite sheet configured, fallback
+                            // Sprite sheet not found, fallback
                             Sprite {
                                 color,
                                 custom_size: Some(Vec2::new(entity_size, entity_size)),
@@ -2171,7 +2759,7 @@ fn sync_entity_rendering(
                             }
                         }
                     } else {
-                        // No sprite config, fallback
+                        // No sprite sheet configured, fallback
                         Sprite {
                             color,
                             custom_size: Some(Vec2::new(entity_size, entity_size)),
@@ -2179,7 +2767,7 @@ fn sync_entity_rendering(
                         }
                     }
                 } else {
-                    // No entity type config, fallback
+                    // No sprite config, fallback
                     Sprite {
                         color,
                         custom_size: Some(Vec2::new(entity_size, entity_size)),
@@ -2197,17 +2785,28 @@ fn sync_entity_rendering(
             }
         };
 
+        // Sized entities stretch their sprite to `size` and regions are
+        // see-through; polygon regions keep their marker since their outline
+        // is drawn with gizmos
+        let is_polygon = entity.region.as_ref().is_some_and(|r| r.is_polygon());
+        if let Some(size) = entity.size.filter(|_| !is_polygon) {
+            sprite.custom_size = Some(Vec2::from(size));
+            if entity.is_region() {
+                sprite.color = sprite.color.with_alpha(0.35);
+            }
+        }
+
         if let Some(&sprite_entity) = entity_render_state.entity_sprites.get(&key) {
             // Update position and visibility of existing sprite
             if let Ok(mut entity_commands) = commands.get_entity(sprite_entity) {
-                entity_commands.insert((Transform::from_xyz(x, y, 50.0), sprite, visibility));
+                entity_commands.insert((transform, sprite, visibility));
             }
         } else {
             // Spawn new sprite with visibility
             let sprite_entity = commands
                 .spawn((
                     sprite,
-                    Transform::from_xyz(x, y, 50.0),
+                    transform,
                     visibility,
                     EditorEntitySprite {
                         level_id,
@@ -2232,24 +2831,17 @@ fn sync_entity_rendering(
     if let Selection::Entity(sel_level_id, sel_entity_id) = &editor_state.selection {
         if *sel_level_id == level_id {
             if let Some(entity) = level.entities.iter().find(|e| e.id == *sel_entity_id) {
-                // Get marker size from schema for the selected entity
-                let sel_entity_size = project
-                    .schema
-                    .get_type(&entity.type_name)
-                    .and_then(|td| td.marker_size)
-                    .unwrap_or(16) as f32;
+                // Highlight follows the entity's size, rotation and scale
+                let sel_half_extents = entity_half_extents(entity, &project);
 
                 let highlight_entity = commands
                     .spawn((
                         Sprite {
                             color: Color::srgba(1.0, 1.0, 0.0, 0.5), // Yellow highlight
-                            custom_size: Some(Vec2::new(
-                                sel_entity_size + 8.0,
-                                sel_entity_size + 8.0,
-                            )),
+                            custom_size: Some(sel_half_extents * 2.0 + Vec2::splat(8.0)),
                             ..default()
                         },
-                        Transform::from_xyz(entity.position[0], entity.position[1], 49.0),
+                        entity_transform(entity, 49.0),
                     ))
                     .id();
                 entity_render_state.selection_highlight = Some(highlight_entity);
@@ -2258,6 +2850,62 @@ fn sync_entity_rendering(
     }
 }
 
+/// System to draw region outlines and the move/rotate/resize gizmo of the selected entity
+fn sync_entity_gizmos(editor_state: Res<EditorState>, project: Res<Project>, mut gizmos: Gizmos) {
+    let Some(level) = editor_state
+        .selected_level
+        .and_then(|level_id| project.get_level(level_id))
+    else {
+        return;
+    };
+
+    // Outline regions on the selected Object layer in their type's color
+    if let Some(LayerData::Objects { entities }) = editor_state
+        .selected_layer
+        .and_then(|idx| level.layers.get(idx))
+        .map(|layer| &layer.data)
+    {
+        for entity in level
+            .entities
+            .iter()
+            .filter(|e| e.is_region() && entities.contains(&e.id))
+        {
+            let color = project
+                .schema
+                .get_type(&entity.type_name)
+                .map(|td| parse_hex_color(&td.color))
+                .unwrap_or(Color::srgba(0.4, 0.8, 0.4, 0.8));
+            gizmos.linestrip_2d(entity_outline(entity, &project), color.with_alpha(1.0));
+        }
+    }
+
+    if editor_state.current_tool != EditorTool::Select {
+        return;
+    }
+    let Selection::Entity(level_id, entity_id) = &editor_state.selection else {
+        return;
+    };
+    if level.id != *level_id {
+        return;
+    }
+    let Some(entity) = level.entities.iter().find(|e| e.id == *entity_id) else {
+        return;
+    };
+
+    // Oriented outline
+    let color = Color::srgb(1.0, 0.85, 0.0);
+    gizmos.linestrip_2d(entity_outline(entity, &project), color);
+
+    // Rotation, resize and polygon point handles
+    let handle_radius = crate::tools::GIZMO_HANDLE_RADIUS / editor_state.zoom;
+    for (handle, position) in entity_gizmo_handles(entity, &project, editor_state.zoom) {
+        if handle == EntityGizmoHandle::Rotate {
+            gizmos.line_2d(Vec2::from(entity.position), position, color);
+        }
+        gizmos.circle_2d(position, handle_radius, color);
+    }
+}
+
 /// Parse a hex color string like "#FF0000" or "FF0000" into Color
 fn parse_hex_color(color_str: &str) -> Color {
     let hex = color_str.trim_start_matches('#');
@@ -2277,7 +2925,8 @@ fn parse_hex_color(color_str: &str) -> Color {
 }
 
 /// System to dim non-selected tile layers for visual clarity.
-/// The selected layer renders at full opacity while other layers are dimmed to 40%.
+/// The selected layer (and the children of a selected group) renders at full
+/// opacity while other layers are dimmed to 40%.
 fn sync_layer_dimming(
     mut render_state: ResMut<RenderState>,
     project: Res<Project>,
@@ -2285,30 +2934,54 @@ fn sync_layer_dimming(
     mut commands: Commands,
     tilemap_query: Query<(Entity, &EditorTilemap), Without<MultiCellTileSprite>>,
     tile_query: Query<(Entity, &TilemapId), With<TilePos>>,
-    mut multi_cell_query: Query<(&MultiCellTileSprite, &mut Sprite)>,
+    mut multi_cell_query: Query<(&MultiCellTileSprite, &mut Sprite), Without<EditorImageLayer>>,
+    mut image_layer_query: Query<(&EditorImageLayer, &mut Sprite), Without<MultiCellTileSprite>>,
 ) {
-    // Build current opacity map from project data
+    // Build current opacity and tint maps from project data.
+    // Opacities include the opacity of parent groups.
     let mut current_opacities: HashMap<usize, f32> = HashMap::new();
+    let mut current_tints: HashMap<usize, [f32; 4]> = HashMap::new();
+    let mut highlighted: HashMap<usize, bool> = HashMap::new();
     if let Some(level_id) = editor_state.selected_level {
         if let Some(level) = project.get_level(level_id) {
             for (i, layer) in level.layers.iter().enumerate() {
-                current_opacities.insert(i, layer.opacity);
+                let opacity = level
+                    .effective_layer(i)
+                    .map_or(layer.opacity, |effective| effective.opacity);
+                current_opacities.insert(i, opacity);
+                current_tints.insert(i, layer.tint_rgba());
+                let is_highlighted = match editor_state.selected_layer {
+                    Some(sel) => sel == i || level.is_layer_in_group(i, sel),
+                    None => true,
+                };
+                highlighted.insert(i, is_highlighted);
             }
         }
     }
 
     let current_selected = Some(editor_state.selected_layer);
 
-    // Only update when opacities or selected layer change
+    // Only update when opacities, tints or selected layer change
     if render_state.last_layer_opacities == current_opacities
+        && render_state.last_layer_tints == current_tints
         && render_state.last_selected_layer == current_selected
     {
         return;
     }
     render_state.last_layer_opacities = current_opacities.clone();
+    render_state.last_layer_tints = current_tints.clone();
     render_state.last_selected_layer = current_selected;
 
-    let selected_layer = editor_state.selected_layer;
+    let layer_color = |layer_index: usize| {
+        let base_opacity = current_opacities.get(&layer_index).copied().unwrap_or(1.0);
+        let opacity = if highlighted.get(&layer_index).copied().unwrap_or(true) {
+            base_opacity
+        } else {
+            base_opacity * 0.4
+        };
+        let [r, g, b, a] = current_tints.get(&layer_index).copied().unwrap_or([1.0; 4]);
+        Color::srgba(r, g, b, a * opacity)
+    };
 
     // Build a map of tilemap entity -> layer_index for quick lookup
     let mut tilemap_layers: HashMap<Entity, usize> = HashMap::new();
@@ -2321,26 +2994,88 @@ fn sync_layer_dimming(
         let Some(&layer_index) = tilemap_layers.get(&tilemap_id.0) else {
             continue;
         };
-        let base_opacity = current_opacities.get(&layer_index).copied().unwrap_or(1.0);
-        let effective = match selected_layer {
-            Some(sel) if sel != layer_index => base_opacity * 0.4,
-            _ => base_opacity,
-        };
         commands
             .entity(tile_entity)
-            .insert(TileColor(Color::srgba(1.0, 1.0, 1.0, effective)));
+            .insert(TileColor(layer_color(layer_index)));
     }
 
     // Update multi-cell tile sprites
     for (multi_cell, mut sprite) in multi_cell_query.iter_mut() {
-        let base_opacity = current_opacities
-            .get(&multi_cell.layer_index)
-            .copied()
-            .unwrap_or(1.0);
-        let effective = match selected_layer {
-            Some(sel) if sel != multi_cell.layer_index => base_opacity * 0.4,
-            _ => base_opacity,
+        sprite.color = layer_color(multi_cell.layer_index);
+    }
+
+    // Update image layer sprites
+    for (image_layer, mut sprite) in image_layer_query.iter_mut() {
+        sprite.color = layer_color(image_layer.layer_index);
+    }
+}
+
+/// System to apply layer pixel offsets and preview parallax scrolling.
+///
+/// Offsets include the offsets of parent groups. Parallax is measured from the
+/// level origin, so panning the camera moves layers with a factor other than
+/// 1.0 the same way the runtime does.
+fn sync_layer_offsets(
+    project: Res<Project>,
+    editor_state: Res<EditorState>,
+    mut tilemap_query: Query<(&EditorTilemap, &mut Transform), Without<MultiCellTileSprite>>,
+    mut multi_cell_query: Query<
+        (&MultiCellTileSprite, &mut Transform),
+        (Without<EditorTilemap>, Without<EditorImageLayer>),
+    >,
+    mut image_layer_query: Query<
+        (&EditorImageLayer, &mut Transform),
+        (Without<EditorTilemap>, Without<MultiCellTileSprite>),
+    >,
+) {
+    let Some(level) = editor_state
+        .selected_level
+        .and_then(|level_id| project.get_level(level_id))
+    else {
+        return;
+    };
+
+    let camera = editor_state.camera_offset;
+    let layer_shift = |layer_index: usize| {
+        let (Some(layer), Some(effective)) = (
+            level.layers.get(layer_index),
+            level.effective_layer(layer_index),
+        ) else {
+            return Vec2::ZERO;
         };
-        sprite.color = Color::srgba(1.0, 1.0, 1.0, effective);
+        let offset = Vec2::new(effective.offset_x, effective.offset_y);
+        if editor_state.preview_parallax {
+            offset
+                + Vec2::new(
+                    camera.x * (1.0 - layer.parallax_x),
+                    camera.y * (1.0 - layer.parallax_y),
+                )
+        } else {
+            offset
+        }
+    };
+
+    for (editor_tilemap, mut transform) in tilemap_query.iter_mut() {
+        let position = editor_tilemap.base + layer_shift(editor_tilemap.layer_index);
+        if transform.translation.truncate() != position {
+            transform.translation.x = position.x;
+            transform.translation.y = position.y;
+        }
+    }
+
+    for (multi_cell, mut transform) in multi_cell_query.iter_mut() {
+        let position = multi_cell.base + layer_shift(multi_cell.layer_index);
+        if transform.translation.truncate() != position {
+            transform.translation.x = position.x;
+            transform.translation.y = position.y;
+        }
+    }
+
+    for (image_layer, mut transform) in image_layer_query.iter_mut() {
+        let position = image_layer.base + layer_shift(image_layer.layer_index);
+        if transform.translation.truncate() != position {
+            transform.translation.x = position.x;
+            transform.translation.y = position.y;
+        }
     }
 }
@@ -7,11 +7,12 @@ use bevy::input::mouse::MouseWheel;
 use bevy::prelude::*;
 use bevy_egui::EguiContexts;
 use bevy_map_autotile;
-use bevy_map_core::{EntityInstance, LayerData, OCCUPIED_CELL};
+use bevy_map_core::{EntityInstance, LayerData, RegionShape, OCCUPIED_CELL};
 use std::collections::HashMap;
 
 use crate::commands::{
-    collect_tiles_in_region, BatchTileCommand, CommandHistory, MoveEntityCommand,
+    collect_tiles_in_region, BatchTileCommand, CommandHistory, EntityTransform, MoveEntityCommand,
+    TransformEntityCommand,
 };
 use crate::preferences::EditorPreferences;
 use crate::project::Project;
@@ -62,6 +63,30 @@ pub struct ViewportInputState {
     pub last_preview_full_tile_mode: bool,
 }
 
+/// Screen-space radius of the entity gizmo handles, in pixels
+pub const GIZMO_HANDLE_RADIUS: f32 = 5.0;
+
+/// Screen-space distance of the rotation handle above an entity, in pixels
+const ROTATE_HANDLE_OFFSET: f32 = 20.0;
+
+/// Rotation step used while snap-to-grid is enabled, in degrees
+const ROTATION_SNAP_DEGREES: f32 = 15.0;
+
+/// Smallest width or height an entity can be resized to, in pixels
+const MIN_ENTITY_SIZE: f32 = 1.0;
+
+/// A draggable handle on the selected entity's canvas gizmo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityGizmoHandle {
+    /// Rotates the entity around its position
+    Rotate,
+    /// Resizes the entity from a corner; the signs pick the corner
+    /// (`-1` left/bottom, `1` right/top) and the opposite corner stays put
+    Resize(i8, i8),
+    /// Moves a point of a polygon region
+    Vertex(usize),
+}
+
 /// Tracks tile changes during a painting stroke for undo support
 #[derive(Resource, Default)]
 pub struct PaintStrokeTracker {
@@ -289,7 +314,15 @@ fn handle_viewport_input(
     }
 
     // Get tile size for coordinate conversion
-    let tile_size = get_tile_size(&editor_state, &project);
+    let tile_dimensions = get_tile_dimensions(&editor_state, &project);
+    let tile_size = tile_dimensions.x;
+
+    // Tile tools work in grid space so they follow the level's orientation
+    let grid_pos = crate::render::grid_position(
+        crate::render::selected_orientation(&editor_state, &project),
+        world_pos,
+        tile_dimensions,
+    );
 
     // Check if pointer is over any UI panel (tree view, inspector, asset browser, modal editors)
     // This properly tracks panel hover state using egui's response system
@@ -316,11 +349,23 @@ fn handle_viewport_input(
                     &mut editor_state,
                     &mut project,
                     &mut render_state,
-                    world_pos,
+                    &mut history,
+                    grid_pos,
                 );
             }
             // Select tool - check for move operations first, then entity click, then marquee selection
             EditorTool::Select => {
+                // Gizmo handles of the selected entity → start rotate/resize
+                if let Some((handle, original)) =
+                    find_gizmo_handle_at(world_pos, &editor_state, &project)
+                {
+                    editor_state.is_moving = true;
+                    editor_state.move_drag_start = Some(world_pos);
+                    editor_state.entity_gizmo_drag = Some(handle);
+                    editor_state.entity_original_transform = Some(original);
+                    return;
+                }
+
                 // FIRST: Check if clicking on already-selected entity → start entity move
                 if is_click_on_selected_entity(world_pos, &editor_state, &project) {
                     if let Selection::Entity(level_id, entity_id) = &editor_state.selection {
@@ -338,9 +383,9 @@ fn handle_viewport_input(
                 }
 
                 // SECOND: Check if clicking on tile selection → start tile move
-                if is_click_on_tile_selection(world_pos, &editor_state, tile_size) {
+                if is_click_on_tile_selection(grid_pos, &editor_state, tile_size) {
                     editor_state.is_moving = true;
-                    editor_state.move_drag_start = Some(world_pos);
+                    editor_state.move_drag_start = Some(grid_pos);
                     editor_state.tile_move_offset = Some((0, 0));
                     capture_tile_selection_for_move(&mut editor_state, &project);
                     return;
@@ -366,8 +411,8 @@ fn handle_viewport_input(
                 // FOURTH: No entity hit - start marquee selection for tiles
                 // Clear entity selection when starting tile selection
                 editor_state.selection = Selection::None;
-                let tile_x = (world_pos.x / tile_size).floor() as i32;
-                let tile_y = (world_pos.y / tile_size).floor() as i32;
+                let tile_x = (grid_pos.x / tile_size).floor() as i32;
+                let tile_y = (grid_pos.y / tile_size).floor() as i32;
                 input_state.rect_start_tile = Some((tile_x, tile_y));
                 input_state.is_drawing_rect = true;
                 editor_state.tile_selection.is_selecting = true;
@@ -377,8 +422,8 @@ fn handle_viewport_input(
             EditorTool::Paint | EditorTool::Erase | EditorTool::Terrain
                 if is_rectangle_mode || is_line_mode =>
             {
-                let tile_x = (world_pos.x / tile_size).floor() as i32;
-                let tile_y = (world_pos.y / tile_size).floor() as i32;
+                let tile_x = (grid_pos.x / tile_size).floor() as i32;
+                let tile_y = (grid_pos.y / tile_size).floor() as i32;
                 input_state.rect_start_tile = Some((tile_x, tile_y));
                 input_state.is_drawing_rect = true;
             }
@@ -389,8 +434,8 @@ fn handle_viewport_input(
     // Handle rectangle mode release
     if mouse_buttons.just_released(MouseButton::Left) && input_state.is_drawing_rect {
         if let Some((start_x, start_y)) = input_state.rect_start_tile {
-            let end_x = (world_pos.x / tile_size).floor() as i32;
-            let end_y = (world_pos.y / tile_size).floor() as i32;
+            let end_x = (grid_pos.x / tile_size).floor() as i32;
+            let end_y = (grid_pos.y / tile_size).floor() as i32;
 
             // Fill based on the current tool
             match editor_state.current_tool {
@@ -460,8 +505,12 @@ fn handle_viewport_input(
 
     // Handle move operation release (finalize move)
     if mouse_buttons.just_released(MouseButton::Left) && editor_state.is_moving {
+        // Finalize entity rotate/resize
+        if editor_state.entity_gizmo_drag.is_some() {
+            finalize_entity_transform(&mut editor_state, &mut project, &mut history);
+        }
         // Finalize entity move
-        if editor_state.entity_original_position.is_some() {
+        else if editor_state.entity_original_position.is_some() {
             finalize_entity_move(&mut editor_state, &mut project, &mut history);
         }
         // Finalize tile move
@@ -478,6 +527,8 @@ fn handle_viewport_input(
         editor_state.is_moving = false;
         editor_state.move_drag_start = None;
         editor_state.entity_original_position = None;
+        editor_state.entity_gizmo_drag = None;
+        editor_state.entity_original_transform = None;
         editor_state.tile_move_original = None;
         editor_state.tile_move_offset = None;
     }
@@ -488,8 +539,25 @@ fn handle_viewport_input(
         if let Some(start_pos) = editor_state.move_drag_start {
             let delta = world_pos - start_pos;
 
+            // Entity rotate/resize - update transform live
+            if let (Some(handle), Some(original)) = (
+                editor_state.entity_gizmo_drag,
+                &editor_state.entity_original_transform,
+            ) {
+                if let Selection::Entity(level_id, entity_id) = editor_state.selection {
+                    drag_entity_gizmo(
+                        &mut project,
+                        level_id,
+                        entity_id,
+                        handle,
+                        original,
+                        world_pos,
+                        editor_state.snap_to_grid.then_some(tile_dimensions),
+                    );
+                }
+            }
             // Entity move - update position live
-            if editor_state.entity_original_position.is_some() {
+            else if editor_state.entity_original_position.is_some() {
                 if let Selection::Entity(level_id, entity_id) = &editor_state.selection {
                     let level_id = *level_id;
                     let entity_id = *entity_id;
@@ -499,19 +567,18 @@ fn handle_viewport_input(
 
                         // Apply snap-to-grid if enabled
                         if editor_state.snap_to_grid {
-                            let snap_unit = tile_size / 2.0;
-                            new_pos[0] = (new_pos[0] / snap_unit).round() * snap_unit;
-                            new_pos[1] = (new_pos[1] / snap_unit).round() * snap_unit;
+                            let snap_unit = tile_dimensions / 2.0;
+                            new_pos[0] = (new_pos[0] / snap_unit.x).round() * snap_unit.x;
+                            new_pos[1] = (new_pos[1] / snap_unit.y).round() * snap_unit.y;
                         }
 
                         // Bounds check
                         if let Some(level) = project.get_level_mut(level_id) {
-                            let level_width_px = level.width as f32 * tile_size;
-                            let level_height_px = level.height as f32 * tile_size;
+                            let bounds = crate::render::level_rect(level, tile_dimensions);
 
                             // Clamp to level bounds
-                            new_pos[0] = new_pos[0].clamp(0.0, level_width_px);
-                            new_pos[1] = new_pos[1].clamp(0.0, level_height_px);
+                            new_pos[0] = new_pos[0].clamp(bounds.min.x, bounds.max.x);
+                            new_pos[1] = new_pos[1].clamp(bounds.min.y, bounds.max.y);
 
                             // Update entity position
                             if let Some(entity) =
@@ -525,7 +592,8 @@ fn handle_viewport_input(
             }
             // Tile move - update offset (tiles aren't moved until release)
             else if editor_state.tile_move_original.is_some() {
-                // Calculate tile offset from delta
+                // Calculate tile offset from the grid-space delta
+                let delta = grid_pos - start_pos;
                 let offset_x = (delta.x / tile_size).round() as i32;
                 let offset_y = (delta.y / tile_size).round() as i32;
                 editor_state.tile_move_offset = Some((offset_x, offset_y));
@@ -557,8 +625,8 @@ fn handle_viewport_input(
                     .unwrap_or(32.0);
 
                 let paint_target = bevy_map_autotile::get_paint_target(
-                    world_pos.x,
-                    world_pos.y,
+                    grid_pos.x,
+                    grid_pos.y,
                     tile_size,
                     terrain_set.set_type,
                 );
@@ -571,7 +639,7 @@ fn handle_viewport_input(
                     calculate_terrain_preview(
                         &mut editor_state,
                         &project,
-                        world_pos,
+                        grid_pos,
                         tile_size,
                         full_tile_mode,
                     );
@@ -594,12 +662,13 @@ fn handle_viewport_input(
     if editor_state.current_tool == EditorTool::Paint
         && !editor_state.terrain_paint_state.is_terrain_mode
         && editor_state.selected_tile.is_some()
+        && !selected_layer_is_int_grid(&editor_state, &project)
         && !input_state.is_drawing_rect
         && !pointer_over_ui_panel
     {
         // Simple floor division to get tile under cursor
-        let tile_x = (world_pos.x / tile_size).floor() as i32;
-        let tile_y = (world_pos.y / tile_size).floor() as i32;
+        let tile_x = (grid_pos.x / tile_size).floor() as i32;
+        let tile_y = (grid_pos.y / tile_size).floor() as i32;
         editor_state.brush_preview.position = Some((tile_x, tile_y));
         editor_state.brush_preview.active = true;
     } else {
@@ -614,6 +683,22 @@ fn handle_viewport_input(
         && !is_line_mode
     {
         match editor_state.current_tool {
+            EditorTool::Paint | EditorTool::Erase
+                if selected_layer_is_int_grid(&editor_state, &project) =>
+            {
+                let value = if editor_state.current_tool == EditorTool::Erase {
+                    0
+                } else {
+                    editor_state.selected_int_grid_value
+                };
+                paint_int_grid_cell(
+                    &mut editor_state,
+                    &mut project,
+                    &mut stroke_tracker,
+                    grid_pos,
+                    value,
+                );
+            }
             EditorTool::Paint => {
                 paint_tile(
                     &mut commands,
@@ -622,7 +707,7 @@ fn handle_viewport_input(
                     &mut render_state,
                     &mut stroke_tracker,
                     &tileset_cache,
-                    world_pos,
+                    grid_pos,
                 );
             }
             EditorTool::Terrain => {
@@ -637,7 +722,7 @@ fn handle_viewport_input(
                     &mut input_state,
                     &mut stroke_tracker,
                     &tileset_cache,
-                    world_pos,
+                    grid_pos,
                     full_tile_mode,
                 );
             }
@@ -649,7 +734,7 @@ fn handle_viewport_input(
                     &mut render_state,
                     &mut stroke_tracker,
                     &tileset_cache,
-                    world_pos,
+                    grid_pos,
                 );
             }
             _ => {}
@@ -663,12 +748,29 @@ fn handle_viewport_input(
     }
 }
 
-/// Get the tile size for the current level/layer/tileset
+/// Get the grid-space cell size, the tile width, for the current level/layer/tileset
 fn get_tile_size(editor_state: &EditorState, project: &Project) -> f32 {
+    get_tile_dimensions(editor_state, project).x
+}
+
+/// Get the tile width and height for the current level/layer/tileset
+fn get_tile_dimensions(editor_state: &EditorState, project: &Project) -> Vec2 {
     let level_id = editor_state.selected_level;
     let layer_idx = editor_state.selected_layer;
 
     let level = level_id.and_then(|id| project.levels.iter().find(|l| l.id == id));
+
+    // IntGrid layers share the level's grid rather than a tileset's
+    if let Some(level) = level {
+        let selected_layer = layer_idx.and_then(|idx| level.layers.get(idx));
+        if matches!(
+            selected_layer.map(|layer| &layer.data),
+            Some(LayerData::IntGrid { .. })
+        ) {
+            return crate::render::level_tile_dimensions(level, project);
+        }
+    }
+
     let layer_tileset_id = level.and_then(|l| {
         layer_idx
             .and_then(|idx| l.layers.get(idx))
@@ -684,8 +786,8 @@ fn get_tile_size(editor_state: &EditorState, project: &Project) -> f32 {
     layer_tileset_id
         .or(editor_state.selected_tileset)
         .and_then(|id| project.tilesets.iter().find(|t| t.id == id))
-        .map(|t| t.tile_size as f32)
-        .unwrap_or(32.0)
+        .map(crate::render::tile_dimensions)
+        .unwrap_or(Vec2::splat(32.0))
 }
 
 /// Clamp camera offset so it doesn't pan too far beyond the level boundaries.
@@ -696,18 +798,16 @@ fn clamp_camera_to_level(editor_state: &mut EditorState, project: &Project, wind
         .and_then(|id| project.levels.iter().find(|l| l.id == id));
     let Some(level) = level else { return };
 
-    let tile_size = get_tile_size(editor_state, project);
-    let level_pixel_w = level.width as f32 * tile_size;
-    let level_pixel_h = level.height as f32 * tile_size;
+    let bounds = crate::render::level_rect(level, get_tile_dimensions(editor_state, project));
 
     // Allow panning one viewport-width/height beyond the level in each direction
     let margin_x = window_size.x / editor_state.zoom;
     let margin_y = window_size.y / editor_state.zoom;
 
-    let min_x = -margin_x;
-    let max_x = level_pixel_w + margin_x;
-    let min_y = -margin_y;
-    let max_y = level_pixel_h + margin_y;
+    let min_x = bounds.min.x - margin_x;
+    let max_x = bounds.max.x + margin_x;
+    let min_y = bounds.min.y - margin_y;
+    let max_y = bounds.max.y + margin_y;
 
     editor_state.camera_offset.x = editor_state.camera_offset.x.clamp(min_x, max_x);
     editor_state.camera_offset.y = editor_state.camera_offset.y.clamp(min_y, max_y);
@@ -819,6 +919,23 @@ fn is_tile_layer(project: &Project, level_id: uuid::Uuid, layer_idx: usize) -> b
         .unwrap_or(false)
 }
 
+/// Check if the selected layer is an IntGrid layer
+fn is_int_grid_layer(project: &Project, level_id: uuid::Uuid, layer_idx: usize) -> bool {
+    project
+        .get_level(level_id)
+        .and_then(|level| level.layers.get(layer_idx))
+        .map(|layer| matches!(&layer.data, LayerData::IntGrid { .. }))
+        .unwrap_or(false)
+}
+
+/// Check if the editor's selected layer is an IntGrid layer
+fn selected_layer_is_int_grid(editor_state: &EditorState, project: &Project) -> bool {
+    match (editor_state.selected_level, editor_state.selected_layer) {
+        (Some(level_id), Some(layer_idx)) => is_int_grid_layer(project, level_id, layer_idx),
+        _ => false,
+    }
+}
+
 /// Find an entity at the given world position
 /// Returns the entity ID if found, None otherwise
 /// Only checks entities on the selected Object layer
@@ -851,25 +968,8 @@ fn find_entity_at_position(
             continue;
         }
 
-        // Get marker size from schema, default to 16
-        let marker_size = project
-            .schema
-            .get_type(&entity.type_name)
-            .and_then(|td| td.marker_size)
-            .unwrap_or(16) as f32;
-
-        let half_size = marker_size / 2.0;
-        let entity_pos = Vec2::new(entity.position[0], entity.position[1]);
-
-        // Check if click is within entity bounds
-        let min = entity_pos - Vec2::splat(half_size);
-        let max = entity_pos + Vec2::splat(half_size);
-
-        if world_pos.x >= min.x
-            && world_pos.x <= max.x
-            && world_pos.y >= min.y
-            && world_pos.y <= max.y
-        {
+        // Check if click is within the entity's rotated, scaled bounds
+        if crate::render::entity_contains(entity, project, world_pos) {
             return Some(entity.id);
         }
     }
@@ -886,28 +986,151 @@ fn is_click_on_selected_entity(
     if let Selection::Entity(level_id, entity_id) = &editor_state.selection {
         if let Some(level) = project.levels.iter().find(|l| l.id == *level_id) {
             if let Some(entity) = level.entities.iter().find(|e| e.id == *entity_id) {
-                // Get marker size from schema, default to 16
-                let marker_size = project
-                    .schema
-                    .get_type(&entity.type_name)
-                    .and_then(|td| td.marker_size)
-                    .unwrap_or(16) as f32;
+                return crate::render::entity_contains(entity, project, world_pos);
+            }
+        }
+    }
+    false
+}
+
+/// Positions of the gizmo handles of an entity
+///
+/// The rotation handle sits above the entity. Polygon regions get a handle on
+/// each point; corner resize handles are offered for types marked resizable
+/// or region in the schema, and for entities that already have a size.
+pub(crate) fn entity_gizmo_handles(
+    entity: &EntityInstance,
+    project: &Project,
+    zoom: f32,
+) -> Vec<(EntityGizmoHandle, Vec2)> {
+    let half = crate::render::entity_half_extents(entity, project);
+    let mut handles = Vec::with_capacity(5);
+
+    let above = half.y * entity.scale[1].abs() + ROTATE_HANDLE_OFFSET / zoom;
+    let rotate_pos = Vec2::from(entity.position)
+        + Vec2::from_angle(entity.rotation_radians()).rotate(Vec2::new(0.0, above));
+    handles.push((EntityGizmoHandle::Rotate, rotate_pos));
 
-                let half_size = marker_size / 2.0;
-                let entity_pos = Vec2::new(entity.position[0], entity.position[1]);
+    if let Some(RegionShape::Polygon { points }) = &entity.region {
+        for (index, point) in points.iter().enumerate() {
+            handles.push((
+                EntityGizmoHandle::Vertex(index),
+                crate::render::entity_world_point(entity, Vec2::from(*point)),
+            ));
+        }
+        return handles;
+    }
+
+    let resizable = entity.size.is_some()
+        || project
+            .schema
+            .get_type(&entity.type_name)
+            .is_some_and(|td| td.is_sized());
+    if resizable {
+        for (x, y) in [(-1, -1), (1, -1), (1, 1), (-1, 1)] {
+            let corner = half * Vec2::new(x as f32, y as f32);
+            handles.push((
+                EntityGizmoHandle::Resize(x, y),
+                crate::render::entity_world_point(entity, corner),
+            ));
+        }
+    }
+
+    handles
+}
 
-                // Check if click is within entity bounds
-                let min = entity_pos - Vec2::splat(half_size);
-                let max = entity_pos + Vec2::splat(half_size);
+/// Find the gizmo handle of the selected entity under the cursor
+/// Returns the handle and the entity's current transform
+fn find_gizmo_handle_at(
+    world_pos: Vec2,
+    editor_state: &EditorState,
+    project: &Project,
+) -> Option<(EntityGizmoHandle, EntityTransform)> {
+    let Selection::Entity(level_id, entity_id) = &editor_state.selection else {
+        return None;
+    };
+    if editor_state.selected_level != Some(*level_id) {
+        return None;
+    }
+    let level = project.get_level(*level_id)?;
+    let entity = level.entities.iter().find(|e| e.id == *entity_id)?;
+
+    let radius = GIZMO_HANDLE_RADIUS / editor_state.zoom;
+    entity_gizmo_handles(entity, project, editor_state.zoom)
+        .into_iter()
+        .find(|(_, position)| position.distance(world_pos) <= radius)
+        .map(|(handle, _)| (handle, EntityTransform::of(entity)))
+}
+
+/// Rotate, resize or reshape an entity from its transform before the drag
+/// `snap` is the tile size when snap-to-grid is enabled
+fn drag_entity_gizmo(
+    project: &mut Project,
+    level_id: uuid::Uuid,
+    entity_id: uuid::Uuid,
+    handle: EntityGizmoHandle,
+    original: &EntityTransform,
+    world_pos: Vec2,
+    snap: Option<Vec2>,
+) {
+    // Half extents fall back to the marker size, so look them up before borrowing mutably
+    let half = {
+        let Some(entity) = project
+            .get_level(level_id)
+            .and_then(|level| level.entities.iter().find(|e| e.id == entity_id))
+        else {
+            return;
+        };
+        let mut entity = entity.clone();
+        original.apply(&mut entity);
+        crate::render::entity_half_extents(&entity, project)
+    };
+
+    let Some(entity) = project
+        .get_level_mut(level_id)
+        .and_then(|level| level.entities.iter_mut().find(|e| e.id == entity_id))
+    else {
+        return;
+    };
+    original.apply(entity);
 
-                return world_pos.x >= min.x
-                    && world_pos.x <= max.x
-                    && world_pos.y >= min.y
-                    && world_pos.y <= max.y;
+    match handle {
+        EntityGizmoHandle::Rotate => {
+            // The handle sits on the entity's local +Y axis
+            let offset = world_pos - Vec2::from(entity.position);
+            let mut degrees = (offset.to_angle() - std::f32::consts::FRAC_PI_2).to_degrees();
+            if snap.is_some() {
+                degrees = (degrees / ROTATION_SNAP_DEGREES).round() * ROTATION_SNAP_DEGREES;
+            }
+            entity.rotation = degrees.rem_euclid(360.0);
+        }
+        EntityGizmoHandle::Resize(x, y) => {
+            // Keep the opposite corner in place and stretch towards the cursor
+            let corner = Vec2::new(x as f32, y as f32);
+            let anchor = -corner * half;
+            let cursor = crate::render::entity_local_point(entity, world_pos);
+            let mut size = ((cursor - anchor) * corner).max(Vec2::splat(MIN_ENTITY_SIZE));
+            if let Some(tile_size) = snap {
+                let snap_unit = tile_size / 2.0;
+                size = ((size / snap_unit).round() * snap_unit).max(snap_unit);
+            }
+            let center = anchor + corner * size / 2.0;
+            entity.position = crate::render::entity_world_point(entity, center).into();
+            entity.size = Some(size.into());
+        }
+        EntityGizmoHandle::Vertex(index) => {
+            let mut cursor = crate::render::entity_local_point(entity, world_pos);
+            if let Some(tile_size) = snap {
+                let snap_unit = tile_size / 2.0;
+                cursor = (cursor / snap_unit).round() * snap_unit;
+            }
+            if let Some(RegionShape::Polygon { points }) = &mut entity.region {
+                if let Some(point) = points.get_mut(index) {
+                    *point = cursor.into();
+                }
             }
         }
     }
-    false
 }
 
 /// Check if click is within current tile selection
@@ -1005,6 +1228,37 @@ fn finalize_entity_move(
     }
 }
 
+/// Finalize entity rotate/resize operation and create undo command
+fn finalize_entity_transform(
+    editor_state: &mut EditorState,
+    project: &mut Project,
+    history: &mut CommandHistory,
+) {
+    let Some(original) = editor_state.entity_original_transform.clone() else {
+        return;
+    };
+    let Selection::Entity(level_id, entity_id) = editor_state.selection else {
+        return;
+    };
+
+    let Some(entity) = project
+        .get_level(level_id)
+        .and_then(|level| level.entities.iter().find(|e| e.id == entity_id))
+    else {
+        return;
+    };
+    let new_transform = EntityTransform::of(entity);
+
+    // Skip if no change
+    if original == new_transform {
+        return;
+    }
+
+    let command = TransformEntityCommand::new(level_id, entity_id, original, new_transform);
+    history.push_undo(Box::new(command));
+    project.mark_dirty();
+}
+
 /// Finalize tile move operation and create undo command
 fn finalize_tile_move(
     editor_state: &mut EditorState,
@@ -1107,10 +1361,23 @@ fn cancel_move_operation(editor_state: &mut EditorState, project: &mut Project)
         }
     }
 
+    // Restore entity transform if a rotate/resize was in progress
+    if let Some(original) = &editor_state.entity_original_transform {
+        if let Selection::Entity(level_id, entity_id) = &editor_state.selection {
+            if let Some(level) = project.get_level_mut(*level_id) {
+                if let Some(entity) = level.entities.iter_mut().find(|e| e.id == *entity_id) {
+                    original.apply(entity);
+                }
+            }
+        }
+    }
+
     // Reset all move state
     editor_state.is_moving = false;
     editor_state.move_drag_start = None;
     editor_state.entity_original_position = None;
+    editor_state.entity_gizmo_drag = None;
+    editor_state.entity_original_transform = None;
     editor_state.tile_move_original = None;
     editor_state.tile_move_offset = None;
 }
@@ -1582,15 +1849,15 @@ fn place_entity(editor_state: &mut EditorState, project: &mut Project, world_pos
     }
 
     // Get tile size for bounds check and snapping
-    let tile_size = get_tile_size(editor_state, project);
+    let tile_size = get_tile_dimensions(editor_state, project);
 
     // Apply snap-to-grid if enabled (snap to nearest tile center)
     let final_pos = if editor_state.snap_to_grid {
         // Snap to nearest tile center: round to nearest half-tile unit
         // This snaps to positions 0.5*size, 1.5*size, 2.5*size, etc.
         let snap_unit = tile_size / 2.0;
-        let snapped_x = (world_pos.x / snap_unit).round() * snap_unit;
-        let snapped_y = (world_pos.y / snap_unit).round() * snap_unit;
+        let snapped_x = (world_pos.x / snap_unit.x).round() * snap_unit.x;
+        let snapped_y = (world_pos.y / snap_unit.y).round() * snap_unit.y;
         Vec2::new(snapped_x, snapped_y)
     } else {
         world_pos
@@ -1601,13 +1868,10 @@ fn place_entity(editor_state: &mut EditorState, project: &mut Project, world_pos
         let Some(level) = project.get_level(level_id) else {
             return;
         };
-        let level_width_px = level.width as f32 * tile_size;
-        let level_height_px = level.height as f32 * tile_size;
+        let (tile_x, tile_y) =
+            crate::render::world_to_tile(level.orientation, final_pos, tile_size);
 
-        if final_pos.x < 0.0
-            || final_pos.y < 0.0
-            || final_pos.x >= level_width_px
-            || final_pos.y >= level_height_px
+        if tile_x < 0 || tile_y < 0 || tile_x >= level.width as i32 || tile_y >= level.height as i32
         {
             return; // Can't place outside level bounds
         }
@@ -1615,10 +1879,34 @@ fn place_entity(editor_state: &mut EditorState, project: &mut Project, world_pos
 
     let position = [final_pos.x, final_pos.y];
 
-    let mut entity = EntityInstance::new(type_name.clone(), position);
+    // Template instances start out overriding nothing and inherit the rest
+    let template = editor_state
+        .selected_entity_template
+        .and_then(|id| project.get_entity_template(id))
+        .filter(|template| template.type_name == type_name);
+    let mut entity = match template {
+        Some(template) => template.instantiate(position),
+        None => EntityInstance::new(type_name.clone(), position),
+    };
+
+    // Resizable and region types start out covering one tile
+    if let Some(type_def) = project
+        .schema
+        .get_type(&type_name)
+        .filter(|td| td.is_sized())
+    {
+        entity.size = Some(tile_size.into());
+        if type_def.is_region() {
+            entity.region = Some(RegionShape::Rectangle);
+        }
+    }
 
     // Initialize properties from schema defaults if the type exists
-    if let Some(type_def) = project.schema.get_type(&type_name) {
+    if let Some(type_def) = project
+        .schema
+        .get_type(&type_name)
+        .filter(|_| entity.template_id.is_none())
+    {
         for prop in &type_def.properties {
             if let Some(default_val) = &prop.default {
                 entity.properties.insert(
@@ -1666,6 +1954,14 @@ fn fill_rectangle(
         return;
     };
 
+    if is_int_grid_layer(project, level_id, layer_idx) {
+        let cells: Vec<(i32, i32)> = (start_y.min(end_y)..=start_y.max(end_y))
+            .flat_map(|y| (start_x.min(end_x)..=start_x.max(end_x)).map(move |x| (x, y)))
+            .collect();
+        fill_int_grid_cells(editor_state, project, history, &cells, "Rectangle");
+        return;
+    }
+
     // Can only fill tiles on Tile layers
     if !is_tile_layer(project, level_id, layer_idx) {
         return;
@@ -1767,6 +2063,13 @@ fn fill_line(
         return;
     };
 
+    let points = bresenham_line(start_x, start_y, end_x, end_y);
+
+    if is_int_grid_layer(project, level_id, layer_idx) {
+        fill_int_grid_cells(editor_state, project, history, &points, "Line");
+        return;
+    }
+
     if !is_tile_layer(project, level_id, layer_idx) {
         return;
     }
@@ -1775,8 +2078,6 @@ fn fill_line(
     let selected_tileset = editor_state.selected_tileset;
     let is_erase = editor_state.current_tool == EditorTool::Erase;
 
-    let points = bresenham_line(start_x, start_y, end_x, end_y);
-
     // Collect before-state for undo
     let mut before = HashMap::new();
     if let Some(level) = project.levels.iter().find(|l| l.id == level_id) {
@@ -1868,6 +2169,7 @@ fn fill_area(
     editor_state: &mut EditorState,
     project: &mut Project,
     render_state: &mut RenderState,
+    history: &mut CommandHistory,
     world_pos: Vec2,
 ) {
     let Some(level_id) = editor_state.selected_level else {
@@ -1876,6 +2178,21 @@ fn fill_area(
     let Some(layer_idx) = editor_state.selected_layer else {
         return;
     };
+
+    if is_int_grid_layer(project, level_id, layer_idx) {
+        let tile_size = get_tile_size(editor_state, project);
+        let start_x = (world_pos.x / tile_size).floor() as i32;
+        let start_y = (world_pos.y / tile_size).floor() as i32;
+        let value = editor_state.selected_int_grid_value;
+        let cells = project
+            .get_level(level_id)
+            .map(|level| int_grid_flood_region(level, layer_idx, start_x, start_y, value))
+            .unwrap_or_default();
+        // Recorded as one undoable batch, like rectangle and line fills
+        fill_int_grid_cells(editor_state, project, history, &cells, "Bucket");
+        return;
+    }
+
     let Some(tile_index) = editor_state.selected_tile else {
         return;
     };
@@ -1962,6 +2279,178 @@ fn fill_area(
     render_state.needs_rebuild = true;
 }
 
+/// Paint or erase one IntGrid cell at the given world position
+///
+/// Changes are recorded in the stroke tracker like tile painting, with empty
+/// cells stored as `None`, so a stroke undoes as one `BatchTileCommand`.
+fn paint_int_grid_cell(
+    editor_state: &mut EditorState,
+    project: &mut Project,
+    stroke_tracker: &mut PaintStrokeTracker,
+    world_pos: Vec2,
+    value: u32,
+) {
+    let Some(level_id) = editor_state.selected_level else {
+        return;
+    };
+    let Some(layer_idx) = editor_state.selected_layer else {
+        return;
+    };
+
+    let tile_size = get_tile_size(editor_state, project);
+    let tile_x = (world_pos.x / tile_size).floor() as i32;
+    let tile_y = (world_pos.y / tile_size).floor() as i32;
+
+    let Some(level) = project.get_level_mut(level_id) else {
+        return;
+    };
+    if tile_x < 0 || tile_y < 0 || tile_x >= level.width as i32 || tile_y >= level.height as i32 {
+        return;
+    }
+    let (tile_x, tile_y) = (tile_x as u32, tile_y as u32);
+
+    if editor_state.last_painted_tile == Some((tile_x, tile_y)) {
+        return;
+    }
+
+    let old_value = level.get_int_grid_value(layer_idx, tile_x, tile_y);
+    if old_value != value {
+        if !stroke_tracker.active {
+            stroke_tracker.active = true;
+            stroke_tracker.level_id = Some(level_id);
+            stroke_tracker.layer_idx = Some(layer_idx);
+            stroke_tracker.changes.clear();
+            stroke_tracker.description = if value == 0 {
+                "Erase IntGrid".to_string()
+            } else {
+                "Paint IntGrid".to_string()
+            };
+        }
+
+        level.set_int_grid_value(layer_idx, tile_x, tile_y, value);
+        let new_cell = (value != 0).then_some(value);
+        stroke_tracker
+            .changes
+            .entry((tile_x, tile_y))
+            .and_modify(|change| change.1 = new_cell)
+            .or_insert(((old_value != 0).then_some(old_value), new_cell));
+        project.mark_dirty();
+    }
+
+    editor_state.is_painting = true;
+    editor_state.last_painted_tile = Some((tile_x, tile_y));
+}
+
+/// Set a list of IntGrid cells to the selected value (or clear them when
+/// erasing) as one undoable operation
+///
+/// `shape` names the operation in the undo history, e.g. "Rectangle".
+fn fill_int_grid_cells(
+    editor_state: &EditorState,
+    project: &mut Project,
+    history: &mut CommandHistory,
+    cells: &[(i32, i32)],
+    shape: &str,
+) {
+    let (Some(level_id), Some(layer_idx)) =
+        (editor_state.selected_level, editor_state.selected_layer)
+    else {
+        return;
+    };
+    let is_erase = editor_state.current_tool == EditorTool::Erase;
+    let value = if is_erase {
+        0
+    } else {
+        editor_state.selected_int_grid_value
+    };
+
+    let Some(level) = project.get_level_mut(level_id) else {
+        return;
+    };
+
+    let mut changes = HashMap::new();
+    for &(x, y) in cells {
+        if x < 0 || y < 0 || x >= level.width as i32 || y >= level.height as i32 {
+            continue;
+        }
+        let (x, y) = (x as u32, y as u32);
+        let old_value = level.get_int_grid_value(layer_idx, x, y);
+        if old_value != value {
+            level.set_int_grid_value(layer_idx, x, y, value);
+            changes.insert(
+                (x, y),
+                (
+                    (old_value != 0).then_some(old_value),
+                    (value != 0).then_some(value),
+                ),
+            );
+        }
+    }
+
+    if changes.is_empty() {
+        return;
+    }
+
+    project.mark_dirty();
+
+    let description = if is_erase {
+        format!("{} erase", shape)
+    } else {
+        format!("{} fill", shape)
+    };
+    history.push_undo(Box::new(BatchTileCommand::new(
+        level_id,
+        layer_idx,
+        changes,
+        description,
+    )));
+}
+
+/// Get the cells of the region of equal IntGrid values containing a cell
+///
+/// Returns no cells if the region already holds `value`.
+fn int_grid_flood_region(
+    level: &bevy_map_core::Level,
+    layer_idx: usize,
+    start_x: i32,
+    start_y: i32,
+    value: u32,
+) -> Vec<(i32, i32)> {
+    if start_x < 0 || start_y < 0 || start_x >= level.width as i32 || start_y >= level.height as i32
+    {
+        return Vec::new();
+    }
+
+    let target = level.get_int_grid_value(layer_idx, start_x as u32, start_y as u32);
+    if target == value {
+        return Vec::new();
+    }
+
+    let mut cells = Vec::new();
+    let mut visited = std::collections::HashSet::new();
+    let mut stack = vec![(start_x as u32, start_y as u32)];
+    while let Some((x, y)) = stack.pop() {
+        if !visited.insert((x, y)) || level.get_int_grid_value(layer_idx, x, y) != target {
+            continue;
+        }
+        cells.push((x as i32, y as i32));
+
+        if x > 0 {
+            stack.push((x - 1, y));
+        }
+        if x < level.width - 1 {
+            stack.push((x + 1, y));
+        }
+        if y > 0 {
+            stack.push((x, y - 1));
+        }
+        if y < level.height - 1 {
+            stack.push((x, y + 1));
+        }
+    }
+    cells
+}
+
 /// Paint a terrain tile with autotiling at the given world position
 /// If full_tile_mode is true (Ctrl held), paints all 8 positions of the tile
 fn paint_terrain_tile(
@@ -36,6 +36,7 @@ impl Default for DialogBinds {
                 DialogType::Open.into(),
                 DialogType::OpenSpritesheet.into(),
                 DialogType::SaveAs.into(),
+                DialogType::ExportBinary.into(),
                 DialogType::NewTilesetImage.into(),
                 DialogType::AddImageToTileset.into(),
                 DialogType::ParentDirectory.into(),
@@ -152,6 +153,7 @@ pub enum DialogType {
     Open,
     OpenSpritesheet,
     SaveAs,
+    ExportBinary,
     NewTilesetImage,
     AddImageToTileset,
     ParentDirectory,
@@ -163,6 +165,7 @@ pub enum DialogType {
 type FilterPair = (&'static str, &'static [&'static str]);
 
 static MAP_PROJECT: &[FilterPair] = &[("Map Project", &["map.json", "json"])];
+static BINARY_MAP: &[FilterPair] = &[("Binary Map", &["map.bin"])];
 static IMAGE: &[FilterPair] = &[("Images", &["png", "jpg", "jpeg"])];
 static SPRITESHEET: &[FilterPair] = &[("Images", &["png", "jpg", "jpeg", "webp", "gif", "bmp"])];
 static EXECUTABLE: &[FilterPair] = &[("Executable", &["exe"])];
@@ -176,6 +179,7 @@ impl DialogType {
         match self {
             DialogType::Open => MAP_PROJECT,
             DialogType::SaveAs => MAP_PROJECT,
+            DialogType::ExportBinary => BINARY_MAP,
             DialogType::NewTilesetImage => IMAGE,
             DialogType::AddImageToTileset => IMAGE,
             DialogType::OpenSpritesheet => SPRITESHEET,
@@ -190,6 +194,7 @@ impl DialogType {
         match self {
             DialogType::Open => file_dialog.pick_file().await,
             DialogType::SaveAs => file_dialog.save_file().await,
+            DialogType::ExportBinary => file_dialog.save_file().await,
             DialogType::NewTilesetImage => file_dialog.pick_file().await,
             DialogType::AddImageToTileset => file_dialog.pick_file().await,
             DialogType::OpenSpritesheet => file_dialog.pick_file().await,
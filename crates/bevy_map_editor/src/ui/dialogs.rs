@@ -6,6 +6,7 @@ use crate::ui::dialog_box::{DialogBinds, DialogStatus, DialogType};
 use crate::EditorState;
 use crate::{AssetsBasePath, CopyFileCallback};
 use bevy_egui::egui;
+use bevy_map_core::ResizeAnchor;
 use uuid::Uuid;
 
 /// Actions that can be triggered from menus
@@ -15,6 +16,8 @@ pub enum PendingAction {
     Open,
     Save,
     SaveAs,
+    /// Export the project as a compact binary `.map.bin` file
+    ExportBinary,
     Exit,
     Undo,
     Redo,
@@ -46,6 +49,16 @@ pub enum PendingAction {
     /// the `Ctrl+Shift+R` shortcut (once wired). The action is handled in
     /// `process_edit_actions` in `ui/mod.rs`.
     RunAutomapRules,
+    /// Resize a level, keeping the content at the anchor in place.
+    ///
+    /// Dispatched from the Resize Level dialog and handled in
+    /// `process_edit_actions` in `ui/mod.rs` so it can be undone.
+    ResizeLevel {
+        level_id: Uuid,
+        width: u32,
+        height: u32,
+        anchor: ResizeAnchor,
+    },
     /// Prompt the user to confirm deleting a layer that has automap rule references.
     ///
     /// Shown when a layer is about to be deleted and
@@ -80,6 +93,7 @@ pub fn render_dialogs(
     preferences: &mut EditorPreferences,
 ) {
     render_new_level_dialog(ctx, editor_state, project);
+    render_resize_level_dialog(ctx, editor_state, project);
     render_new_tileset_dialog(ctx, editor_state, project, dialog_binds, assets_base_path);
     render_add_tileset_image_dialog(ctx, editor_state, project, dialog_binds, assets_base_path);
     render_copy_file_dialog(ctx, editor_state, project, assets_base_path);
@@ -163,6 +177,31 @@ pub fn render_dialogs(
                     }
                 }
             }
+            PendingAction::ExportBinary => {
+                #[cfg(feature = "native")]
+                {
+                    if let Some(file_name) = project
+                        .path
+                        .as_ref()
+                        .and_then(|path| path.file_name())
+                        .and_then(|name| name.to_str())
+                    {
+                        let stem = file_name
+                            .trim_end_matches(".json")
+                            .trim_end_matches(".map");
+                        dialog_binds.set_file_name(format!("{stem}.map.bin"));
+                    }
+                    let status = dialog_binds.spawn_and_poll(DialogType::ExportBinary);
+                    if let DialogStatus::Success(path) = status {
+                        if let Err(e) = project.export_binary(&path) {
+                            editor_state.error_message =
+                                Some(format!("Failed to export binary map: {}", e));
+                        }
+                    } else if let DialogStatus::Pending = status {
+                        editor_state.pending_action = Some(action);
+                    }
+                }
+            }
             _ => {
                 // Put other actions back
                 editor_state.pending_action = Some(action);
@@ -247,7 +286,7 @@ fn render_automap_orphan_warning_dialog(
 
         if let Some(level) = project.get_level_mut(level_id) {
             if layer_idx < level.layers.len() {
-                level.layers.remove(layer_idx);
+                level.remove_layer(layer_idx);
                 // Adjust selected layer downward if it pointed past the end.
                 if let Some(selected) = editor_state.selected_layer {
                     if selected >= level.layers.len() {
@@ -293,15 +332,31 @@ fn render_new_level_dialog(
                 ui.add(egui::DragValue::new(&mut editor_state.new_level_height).range(1..=1000));
             });
 
+            ui.horizontal(|ui| {
+                ui.label("Orientation:");
+                egui::ComboBox::from_id_salt("new_level_orientation")
+                    .selected_text(editor_state.new_level_orientation.display_name())
+                    .show_ui(ui, |ui| {
+                        for orientation in bevy_map_core::MapOrientation::all() {
+                            ui.selectable_value(
+                                &mut editor_state.new_level_orientation,
+                                *orientation,
+                                orientation.display_name(),
+                            );
+                        }
+                    });
+            });
+
             ui.separator();
 
             ui.horizontal(|ui| {
                 if ui.button("Create").clicked() {
-                    let level = bevy_map_core::Level::new(
+                    let mut level = bevy_map_core::Level::new(
                         editor_state.new_level_name.clone(),
                         editor_state.new_level_width,
                         editor_state.new_level_height,
                     );
+                    level.orientation = editor_state.new_level_orientation;
                     let level_id = level.id;
                     project.add_level(level);
                     editor_state.selected_level = Some(level_id);
@@ -319,6 +374,80 @@ fn render_new_level_dialog(
         });
 }
 
+fn render_resize_level_dialog(
+    ctx: &egui::Context,
+    editor_state: &mut EditorState,
+    project: &Project,
+) {
+    let Some(level_id) = editor_state.resize_level_id else {
+        return;
+    };
+    let Some(level) = project.get_level(level_id) else {
+        editor_state.resize_level_id = None;
+        return;
+    };
+    let (old_width, old_height) = (level.width, level.height);
+
+    egui::Window::new(format!("Resize Level - {}", level.name))
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            ui.label(format!("Current size: {}x{}", old_width, old_height));
+
+            ui.horizontal(|ui| {
+                ui.label("Width:");
+                ui.add(egui::DragValue::new(&mut editor_state.resize_level_width).range(1..=1000));
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Height:");
+                ui.add(egui::DragValue::new(&mut editor_state.resize_level_height).range(1..=1000));
+            });
+
+            ui.label("Anchor:");
+            egui::Grid::new("resize_level_anchor").show(ui, |ui| {
+                for (i, anchor) in ResizeAnchor::ALL.iter().enumerate() {
+                    let selected = editor_state.resize_level_anchor == *anchor;
+                    let label = if selected { "■" } else { "□" };
+                    if ui.selectable_label(selected, label).clicked() {
+                        editor_state.resize_level_anchor = *anchor;
+                    }
+                    if i % 3 == 2 {
+                        ui.end_row();
+                    }
+                }
+            });
+
+            let (offset_x, offset_y) = editor_state.resize_level_anchor.offset(
+                old_width,
+                old_height,
+                editor_state.resize_level_width,
+                editor_state.resize_level_height,
+            );
+            ui.label(format!(
+                "Content moves by ({}, {}) tiles",
+                offset_x, offset_y
+            ));
+
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                if ui.button("Resize").clicked() {
+                    editor_state.pending_action = Some(PendingAction::ResizeLevel {
+                        level_id,
+                        width: editor_state.resize_level_width,
+                        height: editor_state.resize_level_height,
+                        anchor: editor_state.resize_level_anchor,
+                    });
+                    editor_state.resize_level_id = None;
+                }
+                if ui.button("Cancel").clicked() {
+                    editor_state.resize_level_id = None;
+                }
+            });
+        });
+}
+
 fn render_new_tileset_dialog(
     ctx: &egui::Context,
     editor_state: &mut EditorState,
@@ -356,6 +485,11 @@ fn render_new_tileset_dialog(
                 ui.add(
                     egui::DragValue::new(&mut editor_state.new_tileset_tile_size).range(1..=256),
                 );
+                ui.label("x");
+                ui.add(
+                    egui::DragValue::new(&mut editor_state.new_tileset_tile_height)
+                        .range(1..=256),
+                );
             });
 
             ui.horizontal(|ui| {
@@ -416,13 +550,17 @@ fn create_tileset_from_path(
 ) {
     let path_str = path.to_string_lossy().to_string();
 
-    let tileset = bevy_map_core::Tileset::new(
+    let mut tileset = bevy_map_core::Tileset::new(
         editor_state.new_tileset_name.clone(),
         path_str,
         editor_state.new_tileset_tile_size,
         0, // columns - will be determined when texture loads
         0, // rows
     );
+    tileset.set_tile_dimensions(
+        editor_state.new_tileset_tile_size,
+        editor_state.new_tileset_tile_height,
+    );
     let tileset_id = tileset.id;
     project.add_tileset(tileset);
     editor_state.selected_tileset = Some(tileset_id);
@@ -432,6 +570,7 @@ fn create_tileset_from_path(
     editor_state.new_tileset_name = "New Tileset".to_string();
     editor_state.new_tileset_path = String::new();
     editor_state.new_tileset_tile_size = 32;
+    editor_state.new_tileset_tile_height = 32;
 }
 
 /// Render the copy file confirmation dialog
@@ -24,7 +24,11 @@ impl EntityPaintState {
 }
 
 /// Render the entity palette showing placeable types from the schema
-pub fn render_entity_palette(ui: &mut egui::Ui, editor_state: &mut EditorState, project: &Project) {
+pub fn render_entity_palette(
+    ui: &mut egui::Ui,
+    editor_state: &mut EditorState,
+    project: &mut Project,
+) {
     // Get all placeable types from schema
     let placeable_types = project.schema.placeable_type_names();
 
@@ -39,13 +43,17 @@ pub fn render_entity_palette(ui: &mut egui::Ui, editor_state: &mut EditorState,
         return;
     }
 
-    ui.label("Click to select an entity type, then place on canvas with the Entity tool.");
+    ui.label(
+        "Click to select an entity type or template, then place on canvas with the Entity tool.",
+    );
     ui.separator();
 
     // List all placeable types
+    let mut delete_template = None;
     for type_name in placeable_types {
         if let Some(type_def) = project.schema.get_type(type_name) {
-            let selected = editor_state.selected_entity_type.as_deref() == Some(type_name);
+            let selected = editor_state.selected_entity_type.as_deref() == Some(type_name)
+                && editor_state.selected_entity_template.is_none();
 
             ui.horizontal(|ui| {
                 // Color swatch from type's color field
@@ -62,9 +70,44 @@ pub fn render_entity_palette(ui: &mut egui::Ui, editor_state: &mut EditorState,
                 // Selectable label with type name
                 if ui.selectable_label(selected, type_name).clicked() {
                     editor_state.selected_entity_type = Some(type_name.to_string());
+                    editor_state.selected_entity_template = None;
                     // Don't automatically switch tools - let users manually select Entity tool
                 }
             });
+
+            // Templates of this type, indented under it
+            for template in project
+                .entity_templates
+                .iter()
+                .filter(|t| t.type_name == type_name)
+            {
+                let selected = editor_state.selected_entity_template == Some(template.id);
+                ui.horizontal(|ui| {
+                    ui.add_space(28.0);
+                    let response = ui.selectable_label(selected, &template.name);
+                    if response.clicked() {
+                        editor_state.selected_entity_type = Some(type_name.to_string());
+                        editor_state.selected_entity_template = Some(template.id);
+                    }
+                    response.context_menu(|ui| {
+                        if ui
+                            .button("Delete")
+                            .on_hover_text("Placed instances keep the template's values")
+                            .clicked()
+                        {
+                            delete_template = Some(template.id);
+                            ui.close();
+                        }
+                    });
+                });
+            }
+        }
+    }
+
+    if let Some(template_id) = delete_template {
+        project.remove_entity_template(template_id);
+        if editor_state.selected_entity_template == Some(template_id) {
+            editor_state.selected_entity_template = None;
         }
     }
 
@@ -76,6 +119,13 @@ pub fn render_entity_palette(ui: &mut egui::Ui, editor_state: &mut EditorState,
             ui.heading("Selected Type");
             ui.label(format!("Name: {}", type_name));
 
+            if let Some(template) = editor_state
+                .selected_entity_template
+                .and_then(|id| project.get_entity_template(id))
+            {
+                ui.label(format!("Template: {}", template.name));
+            }
+
             if let Some(icon) = &type_def.icon {
                 ui.label(format!("Icon: {}", icon));
             }
@@ -3,8 +3,9 @@
 use bevy_egui::egui;
 use bevy_map_animation::SpriteData;
 use bevy_map_core::{
-    ComponentOverrides, EntityTypeConfig, InputConfig, InputOverrides, PhysicsConfig,
-    PhysicsOverrides, SpriteConfig, SpriteOverrides,
+    ComponentOverrides, EntityTemplate, EntityTypeConfig, InputConfig, InputOverrides,
+    IntGridValue, LayerData, PhysicsConfig, PhysicsOverrides, RegionShape, SpriteConfig,
+    SpriteOverrides,
 };
 use uuid::Uuid;
 
@@ -44,6 +45,10 @@ pub struct InspectorResult {
     /// Create a new data instance and add its ID to an array property
     /// (type_name, target_instance_id, property_name)
     pub create_instance_for_array: Option<(String, Uuid, String)>,
+    /// Move a layer into a group, or out of all groups (level_id, layer_index, group_id)
+    pub set_layer_parent: Option<(Uuid, usize, Option<Uuid>)>,
+    /// Open the Resize Level dialog
+    pub resize_level: Option<Uuid>,
 }
 
 /// Render the property inspector
@@ -63,10 +68,17 @@ pub fn render_inspector(
             ui.label("Nothing selected");
         }
         Selection::Level(level_id) => {
-            render_level_inspector(ui, *level_id, project);
+            render_level_inspector(ui, *level_id, project, &mut result);
         }
         Selection::Layer(level_id, layer_idx) => {
-            render_layer_inspector(ui, *level_id, *layer_idx, project);
+            render_layer_inspector(
+                ui,
+                *level_id,
+                *layer_idx,
+                project,
+                &mut editor_state.selected_int_grid_value,
+                &mut result,
+            );
         }
         Selection::Entity(level_id, entity_id) => {
             if render_entity_inspector(ui, *level_id, *entity_id, project, integration_registry) {
@@ -103,7 +115,19 @@ pub fn render_inspector(
     result
 }
 
-fn render_level_inspector(ui: &mut egui::Ui, level_id: Uuid, project: &mut Project) {
+fn render_level_inspector(
+    ui: &mut egui::Ui,
+    level_id: Uuid,
+    project: &mut Project,
+    result: &mut InspectorResult,
+) {
+    // Extract schema data before the mutable borrow
+    let defs = project.schema.level_properties.clone();
+    let (enums, dialogue_options, ref_options) = collect_property_options(project);
+    let validation_error = project
+        .get_level(level_id)
+        .and_then(|level| bevy_map_schema::validate_level_properties(&project.schema, level).err());
+
     let Some(level) = project.get_level_mut(level_id) else {
         ui.label("Level not found");
         return;
@@ -120,10 +144,50 @@ fn render_level_inspector(ui: &mut egui::Ui, level_id: Uuid, project: &mut Proje
     ui.horizontal(|ui| {
         ui.label("Size:");
         ui.label(format!("{}x{}", level.width, level.height));
+        if ui.small_button("Resize...").clicked() {
+            result.resize_level = Some(level_id);
+        }
+    });
+
+    let mut orientation_changed = false;
+    ui.horizontal(|ui| {
+        ui.label("Orientation:");
+        egui::ComboBox::from_id_salt("level_orientation")
+            .selected_text(level.orientation.display_name())
+            .show_ui(ui, |ui| {
+                for orientation in bevy_map_core::MapOrientation::all() {
+                    if ui
+                        .selectable_label(
+                            level.orientation == *orientation,
+                            orientation.display_name(),
+                        )
+                        .clicked()
+                        && level.orientation != *orientation
+                    {
+                        level.orientation = *orientation;
+                        orientation_changed = true;
+                    }
+                }
+            });
     });
 
     ui.label(format!("Layers: {}", level.layers.len()));
     ui.label(format!("Entities: {}", level.entities.len()));
+
+    let properties_changed = render_custom_properties(
+        ui,
+        &format!("level_{}", level_id),
+        &defs,
+        &mut level.properties,
+        &enums,
+        &dialogue_options,
+        &ref_options,
+        validation_error,
+    );
+
+    if orientation_changed || properties_changed {
+        project.mark_dirty();
+    }
 }
 
 fn render_layer_inspector(
@@ -131,12 +195,47 @@ fn render_layer_inspector(
     level_id: Uuid,
     layer_idx: usize,
     project: &mut Project,
+    selected_int_grid_value: &mut u32,
+    result: &mut InspectorResult,
 ) {
+    // Extract schema data before the mutable borrow
+    let defs = project.schema.layer_properties.clone();
+    let (enums, dialogue_options, ref_options) = collect_property_options(project);
+    let validation_error = project
+        .get_level(level_id)
+        .and_then(|level| level.layers.get(layer_idx))
+        .and_then(|layer| {
+            bevy_map_schema::validate_properties(
+                &project.schema,
+                &format!("layer '{}'", layer.name),
+                &defs,
+                &properties_to_json(&layer.properties),
+            )
+            .err()
+        });
+
     let Some(level) = project.get_level_mut(level_id) else {
         ui.label("Level not found");
         return;
     };
 
+    // Groups this layer can be moved into: not itself or one of its children
+    let group_options: Vec<(Uuid, String)> = level
+        .layers
+        .iter()
+        .enumerate()
+        .filter(|(idx, l)| {
+            l.is_group() && *idx != layer_idx && !level.is_layer_in_group(*idx, layer_idx)
+        })
+        .map(|(_, l)| (l.id, l.name.clone()))
+        .collect();
+    let layer_id = level.layers.get(layer_idx).map(|l| l.id);
+    let child_count = level
+        .layers
+        .iter()
+        .filter(|l| l.parent_id.is_some() && l.parent_id == layer_id)
+        .count();
+
     let Some(layer) = level.layers.get_mut(layer_idx) else {
         ui.label("Layer not found");
         return;
@@ -159,6 +258,190 @@ fn render_layer_inspector(
         ui.label("Opacity:");
         ui.add(egui::Slider::new(&mut layer.opacity, 0.0..=1.0));
     });
+
+    ui.horizontal(|ui| {
+        ui.label("Tint:");
+        let mut rgb = layer
+            .tint
+            .as_deref()
+            .map(parse_hex_color)
+            .unwrap_or([1.0, 1.0, 1.0]);
+        if ui.color_edit_button_rgb(&mut rgb).changed() {
+            layer.tint = Some(format!(
+                "#{:02x}{:02x}{:02x}",
+                (rgb[0] * 255.0) as u8,
+                (rgb[1] * 255.0) as u8,
+                (rgb[2] * 255.0) as u8
+            ));
+        }
+        if layer.tint.is_some() && ui.small_button("Clear").clicked() {
+            layer.tint = None;
+        }
+    });
+
+    ui.separator();
+
+    ui.horizontal(|ui| {
+        ui.label("Offset:");
+        ui.add(
+            egui::DragValue::new(&mut layer.offset_x)
+                .speed(1.0)
+                .prefix("X: "),
+        );
+        ui.add(
+            egui::DragValue::new(&mut layer.offset_y)
+                .speed(1.0)
+                .prefix("Y: "),
+        );
+    });
+
+    ui.horizontal(|ui| {
+        ui.label("Parallax:");
+        ui.add(
+            egui::DragValue::new(&mut layer.parallax_x)
+                .speed(0.01)
+                .range(0.0..=2.0)
+                .prefix("X: "),
+        )
+        .on_hover_text("1.0 scrolls with the map, 0.0 stays fixed to the camera");
+        ui.add(
+            egui::DragValue::new(&mut layer.parallax_y)
+                .speed(0.01)
+                .range(0.0..=2.0)
+                .prefix("Y: "),
+        )
+        .on_hover_text("1.0 scrolls with the map, 0.0 stays fixed to the camera");
+    });
+
+    ui.separator();
+
+    ui.horizontal(|ui| {
+        ui.label("Group:");
+        let current_name = layer
+            .parent_id
+            .and_then(|id| group_options.iter().find(|(gid, _)| *gid == id))
+            .map(|(_, name)| name.as_str())
+            .unwrap_or("(none)");
+        egui::ComboBox::from_id_salt("layer_parent_group")
+            .selected_text(current_name)
+            .show_ui(ui, |ui| {
+                if ui
+                    .selectable_label(layer.parent_id.is_none(), "(none)")
+                    .clicked()
+                    && layer.parent_id.is_some()
+                {
+                    result.set_layer_parent = Some((level_id, layer_idx, None));
+                }
+                for (group_id, group_name) in &group_options {
+                    let is_parent = layer.parent_id == Some(*group_id);
+                    if ui.selectable_label(is_parent, group_name).clicked() && !is_parent {
+                        result.set_layer_parent = Some((level_id, layer_idx, Some(*group_id)));
+                    }
+                }
+            });
+    });
+
+    match &mut layer.data {
+        LayerData::Image {
+            path,
+            repeat_x,
+            repeat_y,
+        } => {
+            ui.horizontal(|ui| {
+                ui.label("Image:");
+                ui.text_edit_singleline(path);
+                #[cfg(feature = "native")]
+                if ui.button("Browse...").clicked() {
+                    if let Some(file) = rfd::FileDialog::new()
+                        .add_filter("Images", &["png", "jpg", "jpeg", "bmp", "gif", "webp"])
+                        .pick_file()
+                    {
+                        *path = file.to_string_lossy().to_string();
+                    }
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label("Repeat:");
+                ui.checkbox(repeat_x, "X");
+                ui.checkbox(repeat_y, "Y");
+            });
+        }
+        LayerData::Group {} => {
+            ui.label(format!("Children: {}", child_count));
+            ui.label("Visibility, opacity and offset apply to all children");
+        }
+        LayerData::IntGrid { palette, .. } => {
+            ui.label("Values:");
+            let mut remove_value = None;
+            for def in palette.iter_mut() {
+                ui.horizontal(|ui| {
+                    let selected = *selected_int_grid_value == def.value;
+                    if ui
+                        .selectable_label(selected, def.value.to_string())
+                        .on_hover_text("Paint with this value")
+                        .clicked()
+                    {
+                        *selected_int_grid_value = def.value;
+                    }
+                    let mut rgb = parse_hex_color(&def.color);
+                    if ui.color_edit_button_rgb(&mut rgb).changed() {
+                        def.color = format!(
+                            "#{:02x}{:02x}{:02x}",
+                            (rgb[0] * 255.0) as u8,
+                            (rgb[1] * 255.0) as u8,
+                            (rgb[2] * 255.0) as u8
+                        );
+                    }
+                    ui.text_edit_singleline(&mut def.name);
+                    if ui.small_button("x").clicked() {
+                        remove_value = Some(def.value);
+                    }
+                });
+            }
+            if let Some(value) = remove_value {
+                palette.retain(|def| def.value != value);
+            }
+            if ui.button("Add Value").clicked() {
+                let value = palette.iter().map(|def| def.value).max().unwrap_or(0) + 1;
+                palette.push(IntGridValue::new(
+                    value,
+                    format!("Value {}", value),
+                    "#FFFFFF".to_string(),
+                ));
+                *selected_int_grid_value = value;
+            }
+            ui.label("Paint with the Paint, Fill and Erase tools");
+        }
+        _ => {}
+    }
+
+    if render_custom_properties(
+        ui,
+        &format!("layer_{}", layer.id),
+        &defs,
+        &mut layer.properties,
+        &enums,
+        &dialogue_options,
+        &ref_options,
+        validation_error,
+    ) {
+        project.mark_dirty();
+    }
+}
+
+/// Template changes requested from the entity inspector
+///
+/// These touch both the entity and the project's templates, so they are
+/// applied once the entity is no longer borrowed.
+enum TemplateAction {
+    /// Create a template from the entity and link the entity to it
+    SaveAs,
+    /// Move the entity's overrides into its template
+    Apply,
+    /// Bake the template into the entity and unlink it
+    Detach,
+    /// Rename the entity's template
+    Rename(String),
 }
 
 fn render_entity_inspector(
@@ -174,6 +457,7 @@ fn render_entity_inspector(
     let (
         type_name,
         type_def,
+        template,
         entity_type_config,
         enums,
         sprite_sheets,
@@ -192,7 +476,15 @@ fn render_entity_inspector(
 
         let type_name = entity.type_name.clone();
         let type_def = project.schema.get_type(&type_name).cloned();
-        let entity_type_config = project.get_entity_type_config(&type_name).cloned();
+        let template = project.template_for(entity).cloned();
+        // Template instances fall back to the template's component values
+        let template_overrides = template
+            .as_ref()
+            .map(|t| t.component_overrides.clone())
+            .unwrap_or_default();
+        let entity_type_config = project
+            .get_entity_type_config(&type_name)
+            .map(|config| config.with_overrides(&template_overrides));
         let enums = project.schema.enums.clone();
 
         // Collect sprite sheet data (full SpriteData for embedding)
@@ -228,9 +520,9 @@ fn render_entity_inspector(
             .collect();
 
         // Collect animation names from the entity's sprite sheet (if any)
-        let animation_names: Vec<String> = entity_type_config
-            .as_ref()
-            .and_then(|cfg| cfg.sprite.as_ref())
+        let animation_names: Vec<String> = project
+            .resolved_entity_type_config(entity)
+            .sprite
             .and_then(|sprite_cfg| sprite_cfg.sprite_sheet_id)
             .and_then(|sheet_id| project.get_sprite_sheet(sheet_id))
             .map(|sheet| sheet.animations.keys().cloned().collect())
@@ -239,6 +531,7 @@ fn render_entity_inspector(
         (
             type_name,
             type_def,
+            template,
             entity_type_config,
             enums,
             sprite_sheets,
@@ -260,6 +553,42 @@ fn render_entity_inspector(
 
     // Header
     ui.label(format!("Entity: {}", type_name));
+
+    // Template link
+    let mut template_action = None;
+    if let Some(template) = &template {
+        ui.horizontal(|ui| {
+            ui.label("Template:");
+            let mut name = template.name.clone();
+            if ui.text_edit_singleline(&mut name).changed() {
+                template_action = Some(TemplateAction::Rename(name));
+            }
+        });
+        ui.horizontal(|ui| {
+            let has_overrides =
+                !entity.properties.is_empty() || !entity.component_overrides.is_empty();
+            if ui
+                .add_enabled(has_overrides, egui::Button::new("Apply to Template"))
+                .on_hover_text("Move this entity's overrides into the template")
+                .clicked()
+            {
+                template_action = Some(TemplateAction::Apply);
+            }
+            if ui
+                .button("Detach")
+                .on_hover_text("Keep the current values and stop following the template")
+                .clicked()
+            {
+                template_action = Some(TemplateAction::Detach);
+            }
+        });
+    } else if ui
+        .button("Save as Template")
+        .on_hover_text("Create a template from this entity and link it")
+        .clicked()
+    {
+        template_action = Some(TemplateAction::SaveAs);
+    }
     ui.separator();
 
     // Position editor
@@ -277,46 +606,213 @@ fn render_entity_inspector(
         );
     });
 
+    // Rotation and scale editor
+    ui.horizontal(|ui| {
+        ui.label("Rotation:");
+        ui.add(
+            egui::DragValue::new(&mut entity.rotation)
+                .speed(1.0)
+                .suffix("°"),
+        );
+    });
+    ui.horizontal(|ui| {
+        ui.label("Scale:");
+        ui.add(
+            egui::DragValue::new(&mut entity.scale[0])
+                .speed(0.05)
+                .prefix("X: "),
+        );
+        ui.add(
+            egui::DragValue::new(&mut entity.scale[1])
+                .speed(0.05)
+                .prefix("Y: "),
+        );
+    });
+
+    // Region shape editor
+    let is_region_type = type_def.as_ref().is_some_and(|td| td.is_region());
+    if is_region_type && entity.region.is_none() {
+        entity.region = Some(RegionShape::Rectangle);
+    }
+    if let Some(region) = entity.region.clone() {
+        let mut is_polygon = region.is_polygon();
+        ui.horizontal(|ui| {
+            ui.label("Shape:");
+            ui.selectable_value(&mut is_polygon, false, "Rectangle");
+            ui.selectable_value(&mut is_polygon, true, "Polygon");
+        });
+        let size = entity.size.unwrap_or([32.0, 32.0]);
+        if is_polygon != region.is_polygon() {
+            // Convert between shapes, keeping the width and height
+            if is_polygon {
+                entity.region = Some(RegionShape::polygon_from_size(size));
+            } else {
+                let (min, max) = region.bounds(size);
+                entity.size = Some([max[0] - min[0], max[1] - min[1]]);
+                entity.region = Some(RegionShape::Rectangle);
+            }
+        }
+
+        if let Some(RegionShape::Polygon { points }) = &mut entity.region {
+            let mut remove = None;
+            for (index, point) in points.iter_mut().enumerate() {
+                ui.horizontal(|ui| {
+                    ui.label(format!("Point {}:", index + 1));
+                    ui.add(egui::DragValue::new(&mut point[0]).speed(1.0).prefix("X: "));
+                    ui.add(egui::DragValue::new(&mut point[1]).speed(1.0).prefix("Y: "));
+                    if ui.small_button("x").clicked() {
+                        remove = Some(index);
+                    }
+                });
+            }
+            // A polygon needs at least three points
+            if let Some(index) = remove.filter(|_| points.len() > 3) {
+                points.remove(index);
+            }
+            if ui.button("+ Add Point").clicked() {
+                // Insert halfway along the closing edge
+                let point = match (points.first(), points.last()) {
+                    (Some(first), Some(last)) => {
+                        [(first[0] + last[0]) / 2.0, (first[1] + last[1]) / 2.0]
+                    }
+                    _ => [0.0, 0.0],
+                };
+                points.push(point);
+            }
+        }
+    }
+
+    // Size editor (resizable and region types, or entities that already have a size)
+    let sized = type_def.as_ref().is_some_and(|td| td.is_sized());
+    let is_polygon = entity.region.as_ref().is_some_and(|r| r.is_polygon());
+    if (sized || entity.size.is_some()) && !is_polygon {
+        let marker_size = type_def
+            .as_ref()
+            .and_then(|td| td.marker_size)
+            .unwrap_or(16) as f32;
+        let mut size = entity.size.unwrap_or([marker_size, marker_size]);
+        ui.horizontal(|ui| {
+            ui.label("Size:");
+            let width_changed = ui
+                .add(
+                    egui::DragValue::new(&mut size[0])
+                        .speed(1.0)
+                        .range(1.0..=f32::MAX)
+                        .prefix("W: "),
+                )
+                .changed();
+            let height_changed = ui
+                .add(
+                    egui::DragValue::new(&mut size[1])
+                        .speed(1.0)
+                        .range(1.0..=f32::MAX)
+                        .prefix("H: "),
+                )
+                .changed();
+            if width_changed || height_changed {
+                entity.size = Some(size);
+            }
+            if entity.size.is_some()
+                && !entity.is_region()
+                && ui
+                    .small_button("x")
+                    .on_hover_text("Clear size (use the marker size)")
+                    .clicked()
+            {
+                entity.size = None;
+            }
+        });
+    }
+
     // Properties section
     if let Some(type_def) = type_def {
         ui.separator();
         ui.label("Properties");
 
+        // Conditions see inherited values as well as overridden ones
+        let mut effective_properties = template
+            .as_ref()
+            .map(|t| t.properties.clone())
+            .unwrap_or_default();
+        effective_properties.extend(
+            entity
+                .properties
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+
         for prop_def in &type_def.properties {
             // Check show_if condition
-            if !should_show_property(prop_def, &entity.properties) {
+            if !should_show_property(prop_def, &effective_properties) {
                 continue;
             }
 
-            // Ensure property exists with default
-            if !entity.properties.contains_key(&prop_def.name) {
+            // Ensure property exists with default (template instances inherit instead)
+            if template.is_none() && !entity.properties.contains_key(&prop_def.name) {
                 entity
                     .properties
                     .insert(prop_def.name.clone(), get_default_value(prop_def));
             }
 
-            let value = entity.properties.get_mut(&prop_def.name).unwrap();
             let id_salt = format!("entity_{}_{}", entity_id, prop_def.name);
+            let inherited = template.is_some() && !entity.is_overridden(&prop_def.name);
+            let mut revert = false;
 
-            // Label with required indicator
+            // Label with required indicator and override state
             ui.horizontal(|ui| {
                 ui.label(&prop_def.name);
                 if prop_def.required {
                     ui.colored_label(egui::Color32::RED, "*");
                 }
+                if inherited {
+                    ui.label(egui::RichText::new("(inherited)").weak().small());
+                } else if template.is_some() {
+                    ui.colored_label(egui::Color32::YELLOW, "*");
+                    revert = ui
+                        .small_button("↺")
+                        .on_hover_text("Revert to template")
+                        .clicked();
+                }
             });
 
             // Render editor based on prop_type
-            render_property_value_editor(
-                ui,
-                prop_def,
-                value,
-                &id_salt,
-                &enums,
-                &sprite_sheets,
-                &dialogue_options,
-                &ref_options,
-            );
+            if inherited {
+                // Editing an inherited value turns it into an override
+                let mut value = entity
+                    .property_value(&prop_def.name, template.as_ref())
+                    .cloned()
+                    .unwrap_or_else(|| get_default_value(prop_def));
+                let before = value.clone();
+                render_property_value_editor(
+                    ui,
+                    prop_def,
+                    &mut value,
+                    &id_salt,
+                    &enums,
+                    &sprite_sheets,
+                    &dialogue_options,
+                    &ref_options,
+                );
+                if value != before {
+                    entity.properties.insert(prop_def.name.clone(), value);
+                }
+            } else {
+                let value = entity.properties.get_mut(&prop_def.name).unwrap();
+                render_property_value_editor(
+                    ui,
+                    prop_def,
+                    value,
+                    &id_salt,
+                    &enums,
+                    &sprite_sheets,
+                    &dialogue_options,
+                    &ref_options,
+                );
+            }
+
+            if revert {
+                entity.revert_property(&prop_def.name);
+            }
         }
     }
 
@@ -327,6 +823,8 @@ fn render_entity_inspector(
             entity_id,
             &mut entity.component_overrides,
             type_config,
+            template.is_some(),
+            &sprite_sheets,
             &animation_names,
         );
     }
@@ -335,13 +833,36 @@ fn render_entity_inspector(
     if let Some(registry) = integration_registry {
         let plugin_props = registry.properties_for_entity(&type_name);
         if !plugin_props.is_empty() {
-            render_plugin_properties(
-                ui,
-                entity_id,
-                &mut entity.properties,
-                &plugin_props,
-                registry,
-            );
+            if let Some(template) = &template {
+                // Edit the inherited values and keep only what actually changed
+                let mut properties = template.properties.clone();
+                properties.extend(
+                    entity
+                        .properties
+                        .iter()
+                        .map(|(key, value)| (key.clone(), value.clone())),
+                );
+                for (_, prop_def) in &plugin_props {
+                    properties
+                        .entry(prop_def.name.clone())
+                        .or_insert_with(|| plugin_property_default(prop_def));
+                }
+                let before = properties.clone();
+                render_plugin_properties(ui, entity_id, &mut properties, &plugin_props, registry);
+                for (key, value) in properties {
+                    if before.get(&key) != Some(&value) {
+                        entity.properties.insert(key, value);
+                    }
+                }
+            } else {
+                render_plugin_properties(
+                    ui,
+                    entity_id,
+                    &mut entity.properties,
+                    &plugin_props,
+                    registry,
+                );
+            }
         }
 
         // InspectorSection contributions from Rust companion crates
@@ -376,10 +897,84 @@ fn render_entity_inspector(
         should_delete = true;
     }
 
+    if let Some(action) = template_action {
+        apply_template_action(project, level_id, entity_id, template.as_ref(), action);
+    }
+
     should_delete
 }
 
+/// Apply a template change requested from the entity inspector
+fn apply_template_action(
+    project: &mut Project,
+    level_id: Uuid,
+    entity_id: Uuid,
+    template: Option<&EntityTemplate>,
+    action: TemplateAction,
+) {
+    let Some(mut entity) = project
+        .get_level(level_id)
+        .and_then(|level| level.get_entity(entity_id))
+        .cloned()
+    else {
+        return;
+    };
+
+    match action {
+        TemplateAction::SaveAs => {
+            let name = entity
+                .get_string("name")
+                .unwrap_or(entity.type_name.as_str())
+                .to_string();
+            let new_template = EntityTemplate::from_instance(name, &entity, None);
+            entity.template_id = Some(new_template.id);
+            entity.properties.clear();
+            entity.component_overrides.clear();
+            project.add_entity_template(new_template);
+        }
+        TemplateAction::Apply => {
+            let Some(template) = template.and_then(|t| project.get_entity_template_mut(t.id))
+            else {
+                return;
+            };
+            template.apply_overrides(&mut entity);
+        }
+        TemplateAction::Detach => {
+            let Some(template) = template else {
+                return;
+            };
+            entity = entity.resolve(template);
+            entity.template_id = None;
+        }
+        TemplateAction::Rename(name) => {
+            if let Some(template) = template.and_then(|t| project.get_entity_template_mut(t.id)) {
+                template.name = name;
+            }
+            return;
+        }
+    }
+
+    if let Some(slot) = project
+        .get_level_mut(level_id)
+        .and_then(|level| level.get_entity_mut(entity_id))
+    {
+        *slot = entity;
+    }
+    project.mark_dirty();
+}
+
 fn render_tileset_inspector(ui: &mut egui::Ui, tileset_id: Uuid, project: &mut Project) {
+    // Extract schema data before the mutable borrow
+    let defs = project.schema.tileset_properties.clone();
+    let (enums, dialogue_options, ref_options) = collect_property_options(project);
+    let validation_error = project
+        .tilesets
+        .iter()
+        .find(|t| t.id == tileset_id)
+        .and_then(|tileset| {
+            bevy_map_schema::validate_tileset_properties(&project.schema, tileset).err()
+        });
+
     let Some(tileset) = project.tilesets.iter_mut().find(|t| t.id == tileset_id) else {
         ui.label("Tileset not found");
         return;
@@ -395,11 +990,34 @@ fn render_tileset_inspector(ui: &mut egui::Ui, tileset_id: Uuid, project: &mut P
 
     ui.horizontal(|ui| {
         ui.label("Tile Size:");
-        ui.add(egui::DragValue::new(&mut tileset.tile_size).range(1..=256));
+        let (mut width, mut height) = tileset.tile_dimensions();
+        let mut changed = ui
+            .add(egui::DragValue::new(&mut width).range(1..=256))
+            .changed();
+        ui.label("x");
+        changed |= ui
+            .add(egui::DragValue::new(&mut height).range(1..=256))
+            .changed();
+        if changed {
+            tileset.set_tile_dimensions(width, height);
+        }
     });
 
     ui.label(format!("Images: {}", tileset.images.len()));
     ui.label(format!("Total Tiles: {}", tileset.total_tile_count()));
+
+    if render_custom_properties(
+        ui,
+        &format!("tileset_{}", tileset_id),
+        &defs,
+        &mut tileset.properties,
+        &enums,
+        &dialogue_options,
+        &ref_options,
+        validation_error,
+    ) {
+        project.mark_dirty();
+    }
 }
 
 fn render_data_type_inspector(ui: &mut egui::Ui, type_name: &str, project: &mut Project) {
@@ -751,6 +1369,127 @@ pub fn get_default_value(prop_def: &bevy_map_schema::PropertyDef) -> bevy_map_co
     }
 }
 
+/// Collect the enums, dialogue options and ref options the property editors need
+fn collect_property_options(
+    project: &Project,
+) -> (
+    std::collections::HashMap<String, Vec<String>>,
+    Vec<(String, String)>,
+    std::collections::HashMap<String, Vec<(String, String)>>,
+) {
+    let enums = project.schema.enums.clone();
+
+    let dialogue_options: Vec<(String, String)> = project
+        .dialogues
+        .iter()
+        .map(|d| (d.id.clone(), d.name.clone()))
+        .collect();
+
+    let ref_options: std::collections::HashMap<String, Vec<(String, String)>> = project
+        .data
+        .instances
+        .iter()
+        .map(|(type_name, instances)| {
+            let opts: Vec<(String, String)> = instances
+                .iter()
+                .map(|inst| {
+                    let name = inst
+                        .properties
+                        .get("name")
+                        .and_then(|v| v.as_string())
+                        .unwrap_or(&inst.id.to_string())
+                        .to_string();
+                    (inst.id.to_string(), name)
+                })
+                .collect();
+            (type_name.clone(), opts)
+        })
+        .collect();
+
+    (enums, dialogue_options, ref_options)
+}
+
+/// Convert property values to JSON for schema validation
+fn properties_to_json(
+    properties: &std::collections::HashMap<String, bevy_map_core::Value>,
+) -> std::collections::HashMap<String, serde_json::Value> {
+    properties
+        .iter()
+        .map(|(name, value)| (name.clone(), value.to_json()))
+        .collect()
+}
+
+/// Render the schema-defined custom properties of a level, layer or tileset
+///
+/// Missing properties are shown with their defaults but only stored once
+/// the user edits them. Nothing is shown when the schema defines no
+/// properties for the object. Returns true if a property was changed.
+fn render_custom_properties(
+    ui: &mut egui::Ui,
+    id_prefix: &str,
+    defs: &[bevy_map_schema::PropertyDef],
+    properties: &mut std::collections::HashMap<String, bevy_map_core::Value>,
+    enums: &std::collections::HashMap<String, Vec<String>>,
+    dialogue_options: &[(String, String)],
+    ref_options: &std::collections::HashMap<String, Vec<(String, String)>>,
+    validation_error: Option<bevy_map_schema::SchemaError>,
+) -> bool {
+    if defs.is_empty() {
+        return false;
+    }
+
+    ui.separator();
+    ui.label("Properties");
+
+    // Conditions are checked against the values as displayed, defaults included
+    let mut displayed = properties.clone();
+    for prop_def in defs {
+        displayed
+            .entry(prop_def.name.clone())
+            .or_insert_with(|| get_default_value(prop_def));
+    }
+
+    let mut changed = false;
+    for prop_def in defs {
+        if !should_show_property(prop_def, &displayed) {
+            continue;
+        }
+
+        let original = displayed[&prop_def.name].clone();
+        let mut value = original.clone();
+        let id_salt = format!("{}_{}", id_prefix, prop_def.name);
+
+        ui.horizontal(|ui| {
+            ui.label(&prop_def.name);
+            if prop_def.required {
+                ui.colored_label(egui::Color32::RED, "*");
+            }
+        });
+
+        render_property_value_editor(
+            ui,
+            prop_def,
+            &mut value,
+            &id_salt,
+            enums,
+            &[],
+            dialogue_options,
+            ref_options,
+        );
+
+        if value != original {
+            properties.insert(prop_def.name.clone(), value);
+            changed = true;
+        }
+    }
+
+    if let Some(error) = validation_error {
+        ui.colored_label(egui::Color32::RED, error.to_string());
+    }
+
+    changed
+}
+
 /// Check if a property should be shown based on its show_if condition
 fn should_show_property(
     prop_def: &bevy_map_schema::PropertyDef,
@@ -1198,6 +1937,8 @@ fn render_component_overrides_section(
     entity_id: Uuid,
     overrides: &mut ComponentOverrides,
     type_config: &EntityTypeConfig,
+    has_template: bool,
+    sprite_sheets: &[SpriteData],
     animation_names: &[String],
 ) {
     let has_physics = type_config.physics.is_some();
@@ -1228,13 +1969,25 @@ fn render_component_overrides_section(
 
             // Sprite overrides
             if let Some(ref sprite_config) = type_config.sprite {
-                render_sprite_overrides(ui, entity_id, overrides, sprite_config, animation_names);
+                render_sprite_overrides(
+                    ui,
+                    entity_id,
+                    overrides,
+                    sprite_config,
+                    sprite_sheets,
+                    animation_names,
+                );
             }
 
             // Reset button
             ui.add_space(8.0);
             if !overrides.is_empty() {
-                if ui.button("Reset to Type Defaults").clicked() {
+                let label = if has_template {
+                    "Reset to Template"
+                } else {
+                    "Reset to Type Defaults"
+                };
+                if ui.button(label).clicked() {
                     overrides.clear();
                 }
             }
@@ -1387,6 +2140,7 @@ fn render_sprite_overrides(
     entity_id: Uuid,
     overrides: &mut ComponentOverrides,
     sprite_config: &SpriteConfig,
+    sprite_sheets: &[SpriteData],
     animation_names: &[String],
 ) {
     let sprite_id = format!("sprite_{}", entity_id);
@@ -1399,6 +2153,46 @@ fn render_sprite_overrides(
                 .sprite
                 .get_or_insert_with(SpriteOverrides::default);
 
+            // Sprite sheet (dropdown)
+            let sheet_name = |id: Option<Uuid>| {
+                id.and_then(|id| sprite_sheets.iter().find(|s| s.id == id))
+                    .map(|s| s.name.clone())
+                    .unwrap_or_else(|| "(none)".to_string())
+            };
+            let default_sheet = sheet_name(sprite_config.sprite_sheet_id);
+
+            ui.horizontal(|ui| {
+                ui.label("Sheet:");
+
+                let is_overridden = sprite.sprite_sheet_id.is_some();
+                if is_overridden {
+                    ui.colored_label(egui::Color32::YELLOW, "*");
+                }
+
+                egui::ComboBox::from_id_salt(format!("{}_sheet", sprite_id))
+                    .selected_text(sheet_name(
+                        sprite.sprite_sheet_id.or(sprite_config.sprite_sheet_id),
+                    ))
+                    .show_ui(ui, |ui| {
+                        if ui
+                            .selectable_label(
+                                !is_overridden,
+                                format!("(default: {})", default_sheet),
+                            )
+                            .clicked()
+                        {
+                            sprite.sprite_sheet_id = None;
+                        }
+
+                        for sheet in sprite_sheets {
+                            let selected = sprite.sprite_sheet_id == Some(sheet.id);
+                            if ui.selectable_label(selected, &sheet.name).clicked() {
+                                sprite.sprite_sheet_id = Some(sheet.id);
+                            }
+                        }
+                    });
+            });
+
             // Scale
             let default_scale = sprite_config.scale.unwrap_or(1.0);
             render_override_field_f32_opt(
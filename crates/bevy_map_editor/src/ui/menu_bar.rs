@@ -65,6 +65,10 @@ pub fn render_menu_bar(
                     editor_state.pending_action = Some(PendingAction::SaveAs);
                     ui.close();
                 }
+                if ui.button("Export Binary...").clicked() {
+                    editor_state.pending_action = Some(PendingAction::ExportBinary);
+                    ui.close();
+                }
                 ui.separator();
                 if ui.button("Settings...").clicked() {
                     editor_state.show_settings_dialog = true;
@@ -153,6 +157,12 @@ pub fn render_menu_bar(
                 {
                     ui.close();
                 }
+                if ui
+                    .checkbox(&mut editor_state.preview_parallax, "Preview Parallax")
+                    .clicked()
+                {
+                    ui.close();
+                }
                 // Snapping submenu (Tiled-style)
                 ui.menu_button("Snapping", |ui| {
                     if ui
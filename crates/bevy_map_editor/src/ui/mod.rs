@@ -44,6 +44,7 @@ pub use terrain_palette::{render_terrain_palette, TerrainPaintState};
 pub use theme::EditorTheme;
 pub use tileset::{
     find_base_tile_for_position, render_tileset_palette, render_tileset_palette_with_cache,
+    tile_display_size, tile_uv_rect,
 };
 pub use tileset_editor::{render_tileset_editor, TilesetEditorState};
 pub use toolbar::{render_toolbar, EditorTool, ToolMode};
@@ -54,6 +55,7 @@ use bevy::prelude::*;
 use bevy_egui::{
     egui, EguiContextSettings, EguiContexts, EguiPrimaryContextPass, EguiTextureHandle,
 };
+use bevy_map_core::TilesetImage;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use uuid::Uuid;
@@ -97,6 +99,10 @@ pub struct TilesetTextureCache {
     pub tileset_primary_image: HashMap<Uuid, Uuid>,
     /// Load state for each image (for UI feedback)
     pub load_states: HashMap<Uuid, ImageLoadState>,
+    /// Copies of images with a margin or spacing, with the tiles packed edge to edge
+    pub packed: HashMap<Uuid, Handle<Image>>,
+    /// Tile size, margin and spacing each loaded image was sliced with
+    pub slicing: HashMap<Uuid, ((u32, u32), u32, u32)>,
 }
 
 impl TilesetTextureCache {
@@ -112,6 +118,23 @@ impl TilesetTextureCache {
             ImageLoadState::Pending
         }
     }
+
+    /// Get the texture to use for tilemaps and sprites, which expect the
+    /// tiles packed edge to edge
+    pub fn tilemap_handle(&self, image_id: &Uuid) -> Option<Handle<Image>> {
+        self.packed
+            .get(image_id)
+            .or_else(|| self.loaded.get(image_id).map(|(handle, _, _, _)| handle))
+            .cloned()
+    }
+
+    /// Drop an image's textures so it is reloaded and sliced again
+    pub fn invalidate(&mut self, image_id: &Uuid) {
+        self.loaded.remove(image_id);
+        self.packed.remove(image_id);
+        self.slicing.remove(image_id);
+        self.load_states.remove(image_id);
+    }
 }
 
 /// Cache for entity icon/sprite textures used in viewport rendering
@@ -209,10 +232,27 @@ fn load_tileset_textures(
     mut cache: ResMut<TilesetTextureCache>,
     mut contexts: EguiContexts,
     asset_server: Res<AssetServer>,
-    images: Res<Assets<Image>>,
+    mut images: ResMut<Assets<Image>>,
+    mut render_state: ResMut<RenderState>,
 ) {
     use bevy::asset::LoadState;
 
+    // Reslice images whose tile size, margin or spacing changed
+    for tileset in project.tilesets.iter() {
+        let tile_size = tileset.tile_dimensions();
+        for image in &tileset.images {
+            let slicing = (tile_size, image.margin, image.spacing);
+            if cache
+                .slicing
+                .get(&image.id)
+                .is_some_and(|sliced| *sliced != slicing)
+            {
+                cache.invalidate(&image.id);
+                render_state.mark_dirty();
+            }
+        }
+    }
+
     // Early exit: if no pending loads and all images loaded/failed, skip entirely
     if cache.pending.is_empty() {
         // Check if we have any unprocessed images
@@ -244,10 +284,10 @@ fn load_tileset_textures(
 
     // Process images directly without collecting into Vec
     // First gather what we need to process (without cloning paths yet)
-    let mut images_to_process: Vec<(uuid::Uuid, usize, uuid::Uuid, u32)> = Vec::new();
+    let mut images_to_process: Vec<(uuid::Uuid, usize, uuid::Uuid, (u32, u32))> = Vec::new();
     for tileset in project.tilesets.iter() {
         let tileset_id = tileset.id;
-        let tile_size = tileset.tile_size;
+        let tile_size = tileset.tile_dimensions();
         for (img_idx, image) in tileset.images.iter().enumerate() {
             let img_id = image.id;
             if !cache.loaded.contains_key(&img_id)
@@ -306,13 +346,38 @@ fn load_tileset_textures(
                             if let Some(tileset_image) =
                                 tileset.images.iter_mut().find(|i| i.id == image_id)
                             {
-                                tileset_image.columns = (width as u32) / tile_size.max(1);
-                                tileset_image.rows = (height as u32) / tile_size.max(1);
+                                let (columns, rows) = TilesetImage::grid_for_size(
+                                    width as u32,
+                                    height as u32,
+                                    tile_size.0,
+                                    tile_size.1,
+                                    tileset_image.margin,
+                                    tileset_image.spacing,
+                                );
+                                tileset_image.columns = columns;
+                                tileset_image.rows = rows;
+                                cache.slicing.insert(
+                                    image_id,
+                                    (tile_size, tileset_image.margin, tileset_image.spacing),
+                                );
+
+                                // Tilemaps slice textures into a plain grid
+                                if tileset_image.is_packed() {
+                                    let packed = images.get(&handle).and_then(|image| {
+                                        pack_tileset_image(image, tileset_image, tile_size)
+                                    });
+                                    if let Some(packed) = packed {
+                                        cache.packed.insert(image_id, images.add(packed));
+                                        render_state.mark_dirty();
+                                    }
+                                }
                             }
                             // Also update legacy columns/rows if this is first image
                             if img_idx == 0 {
-                                tileset.columns = (width as u32) / tile_size.max(1);
-                                tileset.rows = (height as u32) / tile_size.max(1);
+                                if let Some(first) = tileset.images.first() {
+                                    tileset.columns = first.columns;
+                                    tileset.rows = first.rows;
+                                }
                             }
                         }
                     }
@@ -356,6 +421,24 @@ fn load_tileset_textures(
     }
 }
 
+/// Copy a tileset image with its tiles packed edge to edge
+fn pack_tileset_image(
+    image: &Image,
+    tileset_image: &TilesetImage,
+    tile_size: (u32, u32),
+) -> Option<Image> {
+    let pixel_size = image.texture_descriptor.format.pixel_size().ok()?;
+    let data = image.data.as_ref()?;
+    let (tile_width, tile_height) = tile_size;
+
+    let mut packed = image.clone();
+    packed.data =
+        Some(tileset_image.pack_pixels(data, image.width(), pixel_size, tile_width, tile_height));
+    packed.texture_descriptor.size.width = tileset_image.columns * tile_width;
+    packed.texture_descriptor.size.height = tileset_image.rows * tile_height;
+    Some(packed)
+}
+
 /// System to load spritesheet textures for both SpriteSheet Editor and Animation Editor
 fn load_spritesheet_textures(
     mut editor_state: ResMut<EditorState>,
@@ -524,6 +607,7 @@ fn load_entity_textures(
     }
 
     // Discover textures needed from entity types based on their viewport_display mode
+    let mut sprite_sheet_ids = Vec::new();
     for (type_name, type_def) in &project.schema.data_types {
         match type_def.viewport_display {
             ViewportDisplayMode::Icon => {
@@ -543,22 +627,7 @@ fn load_entity_textures(
                 // Load sprite sheet texture if entity type has a SpriteConfig
                 if let Some(entity_type_config) = project.entity_type_configs.get(type_name) {
                     if let Some(sprite_config) = &entity_type_config.sprite {
-                        if let Some(sprite_sheet_id) = sprite_config.sprite_sheet_id {
-                            if !cache.sprite_sheets.contains_key(&sprite_sheet_id)
-                                && !cache.pending_sprite_sheets.contains_key(&sprite_sheet_id)
-                            {
-                                // Find the sprite sheet and load its texture
-                                if let Some(sprite_sheet) = project
-                                    .sprite_sheets
-                                    .iter()
-                                    .find(|ss| ss.id == sprite_sheet_id)
-                                {
-                                    let asset_path = crate::to_asset_path(&sprite_sheet.sheet_path);
-                                    let handle: Handle<Image> = asset_server.load(&asset_path);
-                                    cache.pending_sprite_sheets.insert(sprite_sheet_id, handle);
-                                }
-                            }
-                        }
+                        sprite_sheet_ids.extend(sprite_config.sprite_sheet_id);
                     }
                 }
             }
@@ -567,6 +636,43 @@ fn load_entity_textures(
             }
         }
     }
+
+    // Templates and instances can override the sprite sheet of their type
+    let is_sprite_type = |type_name: &str| {
+        project
+            .schema
+            .get_type(type_name)
+            .is_some_and(|td| matches!(td.viewport_display, ViewportDisplayMode::Sprite))
+    };
+    let template_overrides = project
+        .entity_templates
+        .iter()
+        .map(|t| (t.type_name.as_str(), &t.component_overrides));
+    let instance_overrides = project.levels.iter().flat_map(|level| {
+        level
+            .entities
+            .iter()
+            .map(|e| (e.type_name.as_str(), &e.component_overrides))
+    });
+    for (type_name, overrides) in template_overrides.chain(instance_overrides) {
+        if is_sprite_type(type_name) {
+            sprite_sheet_ids.extend(overrides.sprite.as_ref().and_then(|s| s.sprite_sheet_id));
+        }
+    }
+
+    for sprite_sheet_id in sprite_sheet_ids {
+        if cache.sprite_sheets.contains_key(&sprite_sheet_id)
+            || cache.pending_sprite_sheets.contains_key(&sprite_sheet_id)
+        {
+            continue;
+        }
+        // Find the sprite sheet and load its texture
+        if let Some(sprite_sheet) = project.get_sprite_sheet(sprite_sheet_id) {
+            let asset_path = crate::to_asset_path(&sprite_sheet.sheet_path);
+            let handle: Handle<Image> = asset_server.load(&asset_path);
+            cache.pending_sprite_sheets.insert(sprite_sheet_id, handle);
+        }
+    }
 }
 
 /// Main UI rendering system
@@ -694,7 +800,7 @@ fn render_ui(
                     if matches!(editor_state.current_tool, EditorTool::Entity) || is_object_layer {
                         ui.heading("Entity Types");
                         ui.separator();
-                        render_entity_palette(ui, &mut editor_state, &project);
+                        render_entity_palette(ui, &mut editor_state, &mut project);
                     } else {
                         ui.heading("Terrain & Tiles");
                         ui.separator();
@@ -743,6 +849,16 @@ fn render_ui(
             editor_state.selection = Selection::Level(new_id);
         }
     }
+    if let Some(id) = tree_view_result
+        .resize_level
+        .or(inspector_result.resize_level)
+    {
+        if let Some(level) = project.get_level(id) {
+            editor_state.resize_level_id = Some(id);
+            editor_state.resize_level_width = level.width;
+            editor_state.resize_level_height = level.height;
+        }
+    }
     if let Some(id) = tree_view_result.delete_level {
         project.remove_level(id);
         editor_state.selection = Selection::None;
@@ -1101,6 +1217,62 @@ fn render_ui(
         }
     }
 
+    if let Some(level_id) = tree_view_result.add_image_layer {
+        if let Some(level) = project.get_level_mut(level_id) {
+            let layer = bevy_map_core::Layer::new_image_layer(
+                format!("Image Layer {}", level.layers.len() + 1),
+                String::new(),
+            );
+            level.layers.push(layer);
+            editor_state.selected_layer = Some(level.layers.len() - 1);
+        }
+    }
+
+    if let Some(level_id) = tree_view_result.add_group_layer {
+        if let Some(level) = project.get_level_mut(level_id) {
+            let layer = bevy_map_core::Layer::new_group_layer(format!(
+                "Group {}",
+                level.layers.len() + 1
+            ));
+            level.layers.push(layer);
+            editor_state.selected_layer = Some(level.layers.len() - 1);
+        }
+    }
+
+    if let Some(level_id) = tree_view_result.add_int_grid_layer {
+        if let Some(level) = project.get_level_mut(level_id) {
+            let mut layer = bevy_map_core::Layer::new_int_grid_layer(
+                format!("IntGrid Layer {}", level.layers.len() + 1),
+                level.width,
+                level.height,
+            );
+            if let bevy_map_core::LayerData::IntGrid { palette, .. } = &mut layer.data {
+                palette.push(bevy_map_core::IntGridValue::new(
+                    1,
+                    "Solid".to_string(),
+                    "#FF8040".to_string(),
+                ));
+            }
+            level.layers.push(layer);
+            editor_state.selected_layer = Some(level.layers.len() - 1);
+            editor_state.selected_int_grid_value = 1;
+        }
+    }
+
+    // Handle moving layers into or out of groups
+    if let Some((level_id, layer_idx, group_id)) = tree_view_result
+        .set_layer_parent
+        .or(inspector_result.set_layer_parent)
+    {
+        if let Some(level) = project.get_level_mut(level_id) {
+            if let Some(new_idx) = level.set_layer_parent(layer_idx, group_id) {
+                editor_state.selected_level = Some(level_id);
+                editor_state.selected_layer = Some(new_idx);
+                editor_state.selection = Selection::Layer(level_id, new_idx);
+            }
+        }
+    }
+
     // Handle layer deletion
     if let Some((level_id, layer_idx)) = tree_view_result.delete_layer {
         // Look up the layer's stable UUID before any mutable borrow.
@@ -1135,7 +1307,7 @@ fn render_ui(
                 // No automap references, or the warning is suppressed — delete directly.
                 if let Some(level) = project.get_level_mut(level_id) {
                     if layer_idx < level.layers.len() {
-                        level.layers.remove(layer_idx);
+                        level.remove_layer(layer_idx);
                         // Adjust selected layer if it now points past the end.
                         if let Some(selected) = editor_state.selected_layer {
                             if selected >= level.layers.len() {
@@ -1153,28 +1325,26 @@ fn render_ui(
         }
     }
 
-    // Handle layer reordering
-    if let Some((level_id, layer_idx)) = tree_view_result.move_layer_up {
+    // Handle layer reordering (groups move together with their children)
+    let layer_move = tree_view_result
+        .move_layer_up
+        .map(|target| (target, true))
+        .or(tree_view_result.move_layer_down.map(|target| (target, false)));
+    if let Some(((level_id, layer_idx), up)) = layer_move {
         if let Some(level) = project.get_level_mut(level_id) {
-            if layer_idx > 0 && layer_idx < level.layers.len() {
-                level.layers.swap(layer_idx, layer_idx - 1);
-                if editor_state.selected_layer == Some(layer_idx) {
-                    editor_state.selected_layer = Some(layer_idx - 1);
-                } else if editor_state.selected_layer == Some(layer_idx - 1) {
-                    editor_state.selected_layer = Some(layer_idx);
-                }
-            }
-        }
-    }
-
-    if let Some((level_id, layer_idx)) = tree_view_result.move_layer_down {
-        if let Some(level) = project.get_level_mut(level_id) {
-            if layer_idx + 1 < level.layers.len() {
-                level.layers.swap(layer_idx, layer_idx + 1);
-                if editor_state.selected_layer == Some(layer_idx) {
-                    editor_state.selected_layer = Some(layer_idx + 1);
-                } else if editor_state.selected_layer == Some(layer_idx + 1) {
-                    editor_state.selected_layer = Some(layer_idx);
+            // Indices shift by whole subtrees, so follow the selection by ID
+            let selected_id = editor_state
+                .selected_layer
+                .and_then(|index| level.layers.get(index))
+                .map(|layer| layer.id);
+            let moved = if up {
+                level.move_layer_up(layer_idx)
+            } else {
+                level.move_layer_down(layer_idx)
+            };
+            if moved {
+                if let Some(id) = selected_id {
+                    editor_state.selected_layer = level.layer_index_by_id(id);
                 }
             }
         }
@@ -1192,11 +1362,11 @@ fn render_ui(
     // Handle layer duplication
     if let Some((level_id, layer_idx)) = tree_view_result.duplicate_layer {
         if let Some(level) = project.get_level_mut(level_id) {
-            if let Some(layer) = level.layers.get(layer_idx).cloned() {
-                let mut duplicate = layer;
+            // Groups are copied together with their children
+            if let Some(copy_idx) = level.duplicate_layer(layer_idx) {
+                let duplicate = &mut level.layers[copy_idx];
                 duplicate.name = format!("{} (Copy)", duplicate.name);
-                level.layers.insert(layer_idx + 1, duplicate);
-                editor_state.selected_layer = Some(layer_idx + 1);
+                editor_state.selected_layer = Some(copy_idx);
             }
         }
     }
@@ -1204,6 +1374,7 @@ fn render_ui(
     // Handle entity type selection from tree view
     if let Some(type_name) = tree_view_result.select_entity_type_for_placement {
         editor_state.selected_entity_type = Some(type_name);
+        editor_state.selected_entity_template = None;
         // Don't automatically switch tools - let users manually select Entity tool
     }
 
@@ -1763,6 +1934,21 @@ fn process_edit_actions(
                     &mut render_state,
                 );
             }
+            PendingAction::ResizeLevel {
+                level_id,
+                width,
+                height,
+                anchor,
+            } => {
+                if let Some(level) = project.get_level(level_id) {
+                    let tile_size = crate::render::level_tile_dimensions(level, &project).into();
+                    let command = crate::commands::ResizeLevelCommand::new(
+                        level, width, height, anchor, tile_size,
+                    );
+                    history.execute(Box::new(command), &mut project, &mut render_state);
+                    editor_state.tile_selection.clear();
+                }
+            }
             // File operations and modal dialogs are handled in dialogs.rs
             _ => {
                 // Put the action back so dialogs.rs can handle it
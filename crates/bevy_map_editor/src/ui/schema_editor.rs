@@ -11,7 +11,7 @@ use bevy_egui::egui;
 use bevy_map_core::{
     ColliderConfig, InputConfig, InputProfile, PhysicsBodyType, PhysicsConfig, SpriteConfig,
 };
-use bevy_map_schema::{PropType, PropertyDef, TypeDef, ViewportDisplayMode};
+use bevy_map_schema::{EntityKind, PropType, PropertyDef, TypeDef, ViewportDisplayMode};
 
 /// State for the schema editor
 #[derive(Default)]
@@ -454,6 +454,8 @@ fn render_type_editor(
         current_icon,
         current_marker_size,
         current_viewport_display,
+        current_resizable,
+        current_kind,
     ) = {
         let type_def = project.schema.data_types.get(type_name).unwrap();
         (
@@ -462,6 +464,8 @@ fn render_type_editor(
             type_def.icon.clone(),
             type_def.marker_size,
             type_def.viewport_display,
+            type_def.resizable,
+            type_def.kind,
         )
     };
 
@@ -471,6 +475,8 @@ fn render_type_editor(
     let mut new_icon = current_icon.clone().unwrap_or_default();
     let mut new_marker_size = current_marker_size.unwrap_or(16) as i32;
     let mut new_viewport_display = current_viewport_display;
+    let mut new_resizable = current_resizable;
+    let mut new_kind = current_kind;
     let mut settings_changed = false;
 
     egui::CollapsingHeader::new("Settings")
@@ -506,6 +512,42 @@ fn render_type_editor(
                         }
                         ui.end_row();
 
+                        // Kind: point or region
+                        ui.label("Kind:");
+                        egui::ComboBox::from_id_salt(format!("entity_kind_{}", type_name))
+                            .selected_text(match new_kind {
+                                EntityKind::Point => "Point",
+                                EntityKind::Region => "Region",
+                            })
+                            .show_ui(ui, |ui| {
+                                if ui
+                                    .selectable_value(&mut new_kind, EntityKind::Point, "Point")
+                                    .changed()
+                                {
+                                    settings_changed = true;
+                                }
+                                if ui
+                                    .selectable_value(&mut new_kind, EntityKind::Region, "Region")
+                                    .on_hover_text("Area that fires enter/exit events at runtime")
+                                    .changed()
+                                {
+                                    settings_changed = true;
+                                }
+                            });
+                        ui.end_row();
+
+                        // Resizable; regions always have a size
+                        if new_kind == EntityKind::Point {
+                            ui.label("Resizable:");
+                            if ui
+                                .checkbox(&mut new_resizable, "Instances have a width and height")
+                                .changed()
+                            {
+                                settings_changed = true;
+                            }
+                            ui.end_row();
+                        }
+
                         // Viewport Display Mode
                         ui.label("Viewport Display:");
                         egui::ComboBox::from_id_salt(format!("viewport_display_{}", type_name))
@@ -608,6 +650,12 @@ fn render_type_editor(
             } else {
                 ViewportDisplayMode::ColoredSquare
             };
+            type_def.resizable = new_placeable && new_resizable;
+            type_def.kind = if new_placeable {
+                new_kind
+            } else {
+                EntityKind::Point
+            };
             type_def.color = format!(
                 "#{:02x}{:02x}{:02x}",
                 (new_color[0] * 255.0) as u8,
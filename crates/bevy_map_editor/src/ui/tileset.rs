@@ -1,7 +1,7 @@
 //! Tileset palette display
 
 use bevy_egui::egui;
-use bevy_map_core::Tileset;
+use bevy_map_core::{Tileset, TilesetImage};
 
 use super::{EditorTheme, ImageLoadState, TilesetTextureCache};
 use crate::project::Project;
@@ -43,6 +43,37 @@ pub fn find_base_tile_for_position(
     clicked_idx // Not in any merged region, return original
 }
 
+/// UV rectangle of a block of tiles within a tileset image
+///
+/// Covers `span` (columns, rows) tiles starting at `col`, `row`, skipping the
+/// image's margin. `tile_size` is the tileset's tile width and height and
+/// `image_size` is the size of the loaded texture in pixels.
+pub fn tile_uv_rect(
+    tile_size: (u32, u32),
+    image: &TilesetImage,
+    col: u32,
+    row: u32,
+    span: (u32, u32),
+    image_size: egui::Vec2,
+) -> egui::Rect {
+    let (tile_width, tile_height) = tile_size;
+    let (x, y) = image.tile_origin(col, row, tile_width, tile_height);
+    let (span_cols, span_rows) = (span.0.max(1), span.1.max(1));
+    let width = span_cols * tile_width + (span_cols - 1) * image.spacing;
+    let height = span_rows * tile_height + (span_rows - 1) * image.spacing;
+    let image_size = image_size.max(egui::vec2(1.0, 1.0));
+    egui::Rect::from_min_size(
+        egui::pos2(x as f32 / image_size.x, y as f32 / image_size.y),
+        egui::vec2(width as f32 / image_size.x, height as f32 / image_size.y),
+    )
+}
+
+/// Size to draw a tile at in tile grids, keeping the tile's aspect ratio
+pub fn tile_display_size(tileset: &Tileset, width: f32) -> egui::Vec2 {
+    let (tile_width, tile_height) = tileset.tile_dimensions();
+    egui::vec2(width, width * tile_height as f32 / tile_width.max(1) as f32)
+}
+
 pub fn render_tileset_palette(
     ui: &mut egui::Ui,
     editor_state: &mut EditorState,
@@ -109,12 +140,14 @@ pub fn render_tileset_palette_with_cache(
             // Show tileset summary info
             let total_tiles = tileset.total_tile_count();
             let image_count = tileset.images.len();
+            let (tile_width, tile_height) = tileset.tile_dimensions();
             ui.label(format!(
-                "{} tiles across {} image{}, {}px each",
+                "{} tiles across {} image{}, {}x{}px each",
                 total_tiles,
                 image_count,
                 if image_count == 1 { "" } else { "s" },
-                tileset.tile_size
+                tile_width,
+                tile_height
             ));
 
             egui::ScrollArea::both()
@@ -143,7 +176,7 @@ fn render_multi_image_tileset(
     tileset: &Tileset,
     tileset_cache: Option<&TilesetTextureCache>,
 ) {
-    let display_size = egui::vec2(32.0, 32.0);
+    let display_size = tile_display_size(tileset, 32.0);
     let mut virtual_offset = 0u32;
 
     for (img_idx, image) in tileset.images.iter().enumerate() {
@@ -196,11 +229,13 @@ fn render_multi_image_tileset(
                     }
                     ImageLoadState::Loaded => {
                         // Get texture for this image
-                        let texture_id = tileset_cache
+                        let texture = tileset_cache
                             .and_then(|cache| cache.loaded.get(&image.id))
-                            .map(|(_, tex_id, _, _)| *tex_id);
+                            .map(|(_, tex_id, width, height)| {
+                                (*tex_id, egui::vec2(*width, *height))
+                            });
 
-                        if let Some(tex_id) = texture_id {
+                        if let Some((tex_id, image_size)) = texture {
                             // Check if we have valid dimensions
                             if image.columns == 0 || image.rows == 0 {
                                 ui.colored_label(
@@ -209,10 +244,6 @@ fn render_multi_image_tileset(
                                 );
                             }
 
-                            // Render tiles with texture
-                            let uv_tile_width = 1.0 / image.columns.max(1) as f32;
-                            let uv_tile_height = 1.0 / image.rows.max(1) as f32;
-
                             // Collect tile rects for drawing combined multi-cell borders
                             let mut tile_rects: Vec<(u32, u32, egui::Rect, u32)> = Vec::new();
 
@@ -233,13 +264,13 @@ fn render_multi_image_tileset(
                                             tileset.get_tile_grid_size(virtual_index);
                                         let is_multi_cell = grid_width > 1 || grid_height > 1;
 
-                                        let uv_min = egui::pos2(
-                                            col as f32 * uv_tile_width,
-                                            row as f32 * uv_tile_height,
-                                        );
-                                        let uv_max = egui::pos2(
-                                            (col + 1) as f32 * uv_tile_width,
-                                            (row + 1) as f32 * uv_tile_height,
+                                        let uv = tile_uv_rect(
+                                            tileset.tile_dimensions(),
+                                            image,
+                                            col,
+                                            row,
+                                            (1, 1),
+                                            image_size,
                                         );
 
                                         let response = ui.add(
@@ -248,7 +279,7 @@ fn render_multi_image_tileset(
                                                     tex_id,
                                                     display_size,
                                                 ))
-                                                .uv(egui::Rect::from_min_max(uv_min, uv_max)),
+                                                .uv(uv),
                                             )
                                             .frame(false) // Remove button padding
                                             .corner_radius(0.0),
@@ -367,20 +398,19 @@ fn render_multi_image_tileset(
 
                                             if let Some(rect) = combined_rect {
                                                 // Draw the merged tile image as overlay (covering gaps)
-                                                let uv_min = egui::pos2(
-                                                    col as f32 * uv_tile_width,
-                                                    row as f32 * uv_tile_height,
-                                                );
-                                                let uv_max = egui::pos2(
-                                                    (col + props.grid_width) as f32 * uv_tile_width,
-                                                    (row + props.grid_height) as f32
-                                                        * uv_tile_height,
+                                                let uv = tile_uv_rect(
+                                                    tileset.tile_dimensions(),
+                                                    image,
+                                                    col,
+                                                    row,
+                                                    props.grid_size(),
+                                                    image_size,
                                                 );
 
                                                 ui.painter().image(
                                                     tex_id,
                                                     rect,
-                                                    egui::Rect::from_min_max(uv_min, uv_max),
+                                                    uv,
                                                     egui::Color32::WHITE,
                                                 );
 
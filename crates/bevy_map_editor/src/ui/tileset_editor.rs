@@ -13,7 +13,7 @@ use bevy_map_autotile::terrain::Color as TerrainColor;
 use bevy_map_autotile::TerrainSetType;
 use std::f32::consts::PI;
 
-use super::{find_base_tile_for_position, EditorTheme, TilesetTextureCache};
+use super::{find_base_tile_for_position, tile_uv_rect, EditorTheme, TilesetTextureCache};
 use crate::project::Project;
 use crate::EditorState;
 
@@ -719,6 +719,30 @@ fn render_images_tab(
 
     ui.separator();
 
+    // Pixels around and between the tiles of the selected image
+    let mut slicing_changed = false;
+    if let Some(image) = editor_state
+        .tileset_editor_state
+        .selected_image_idx
+        .and_then(|idx| tileset.images.get_mut(idx))
+    {
+        ui.label(format!("Slicing: {}", image.name));
+        ui.horizontal(|ui| {
+            ui.label("Margin:");
+            slicing_changed |= ui
+                .add(egui::DragValue::new(&mut image.margin).range(0..=256))
+                .changed();
+            ui.label("Spacing:");
+            slicing_changed |= ui
+                .add(egui::DragValue::new(&mut image.spacing).range(0..=256))
+                .changed();
+        });
+        ui.separator();
+    }
+    if slicing_changed {
+        project.mark_dirty();
+    }
+
     if ui.button("Add Image...").clicked() {
         editor_state.show_add_tileset_image_dialog = true;
     }
@@ -740,7 +764,7 @@ fn render_terrain_sets_tab(
         .tilesets
         .iter()
         .find(|t| t.id == tileset_id)
-        .map(|t| (t.tile_size, t.images.clone(), !t.images.is_empty()));
+        .map(|t| (t.tile_dimensions(), t.images.clone(), !t.images.is_empty()));
 
     // Split into left panel (terrain list) and right panel (tileset preview)
     // Using resizable SidePanel for better UX
@@ -911,7 +935,7 @@ fn render_tileset_with_terrain_overlay(
     editor_state: &mut EditorState,
     project: &mut Project,
     tileset_id: uuid::Uuid,
-    tile_size: u32,
+    tile_size: (u32, u32),
     images: &[bevy_map_core::TilesetImage],
     cache: Option<&TilesetTextureCache>,
 ) {
@@ -940,9 +964,9 @@ fn render_tileset_with_terrain_overlay(
 
     for image in images {
         // Get texture for this image
-        let texture_id = cache
+        let texture = cache
             .and_then(|c| c.loaded.get(&image.id))
-            .map(|(_, tex_id, _, _)| *tex_id);
+            .map(|(_, tex_id, width, height)| (*tex_id, egui::vec2(*width, *height)));
 
         let image_virtual_offset = virtual_offset;
 
@@ -952,9 +976,6 @@ fn render_tileset_with_terrain_overlay(
                 return;
             }
 
-            let uv_tile_width = 1.0 / image.columns.max(1) as f32;
-            let uv_tile_height = 1.0 / image.rows.max(1) as f32;
-
             // Calculate full grid size (with spacing)
             let spacing = 2.0f32;
             let grid_width =
@@ -982,21 +1003,12 @@ fn render_tileset_with_terrain_overlay(
                     let rect = egui::Rect::from_min_size(egui::pos2(tile_x, tile_y), display_size);
 
                     // Draw tile texture
-                    if let Some(tex_id) = texture_id {
-                        let uv_min =
-                            egui::pos2(col as f32 * uv_tile_width, row as f32 * uv_tile_height);
-                        let uv_max = egui::pos2(
-                            (col + 1) as f32 * uv_tile_width,
-                            (row + 1) as f32 * uv_tile_height,
-                        );
+                    if let Some((tex_id, image_size)) = texture {
+                        let uv = tile_uv_rect(tile_size, image, col, row, (1, 1), image_size);
 
                         // Draw texture using mesh
                         let mut mesh = egui::Mesh::with_texture(tex_id);
-                        mesh.add_rect_with_uv(
-                            rect,
-                            egui::Rect::from_min_max(uv_min, uv_max),
-                            Color32::WHITE,
-                        );
+                        mesh.add_rect_with_uv(rect, uv, Color32::WHITE);
                         ui.painter().add(Shape::mesh(mesh));
                     } else {
                         // Fallback: draw placeholder
@@ -1039,13 +1051,14 @@ fn render_tileset_with_terrain_overlay(
                     if let Some(tileset) = project.tilesets.iter().find(|t| t.id == tileset_id) {
                         if let Some(props) = tileset.get_tile_properties(virtual_index) {
                             if props.origin_x.is_some() || props.origin_y.is_some() {
-                                let tile_pixel_size = tile_size as f32;
-                                let (ox, oy) = props.get_origin(tile_size, tile_size);
+                                let (tile_width, tile_height) = tile_size;
+                                let (ox, oy) = props.get_origin(tile_width, tile_height);
 
                                 // Scale origin to display size
-                                let scale = tile_display_size / tile_pixel_size;
-                                let origin_screen_x = rect.left() + ox as f32 * scale;
-                                let origin_screen_y = rect.top() + oy as f32 * scale;
+                                let origin_screen_x = rect.left()
+                                    + ox as f32 * tile_display_size / tile_width.max(1) as f32;
+                                let origin_screen_y = rect.top()
+                                    + oy as f32 * tile_display_size / tile_height.max(1) as f32;
 
                                 // Draw small red dot
                                 let dot_radius = 2.0 * (tile_display_size / 32.0).max(1.0);
@@ -1209,7 +1222,7 @@ fn render_tile_properties_tab(
         .tilesets
         .iter()
         .find(|t| t.id == tileset_id)
-        .map(|t| (t.tile_size, t.images.clone(), !t.images.is_empty()));
+        .map(|t| (t.tile_dimensions(), t.images.clone(), !t.images.is_empty()));
 
     // Left panel: Tile selector (resizable)
     egui::SidePanel::left("tile_properties_selector")
@@ -1430,11 +1443,11 @@ fn render_tile_properties_tab(
 
                     // Get tileset info for rendering
                     if let Some(tileset) = project.tilesets.iter().find(|t| t.id == tileset_id) {
-                        let tile_size = tileset.tile_size;
+                        let (tile_width, tile_height) = tileset.tile_dimensions();
 
                         // Calculate tile dimensions in pixels
-                        let tile_pixel_width = current_props.grid_width * tile_size;
-                        let tile_pixel_height = current_props.grid_height * tile_size;
+                        let tile_pixel_width = current_props.grid_width * tile_width;
+                        let tile_pixel_height = current_props.grid_height * tile_height;
 
                         // Current origin (default to center)
                         let origin_x = current_props.origin_x.unwrap_or(tile_pixel_width / 2);
@@ -1456,34 +1469,25 @@ fn render_tile_properties_tab(
                         // Try to draw tile texture preview
                         if let Some((image_index, local_idx)) = tileset.virtual_to_local(tile_idx) {
                             if let Some(image) = tileset.images.get(image_index) {
-                                if let Some(tex_id) = cache
+                                if let Some((tex_id, image_size)) = cache
                                     .and_then(|c| c.loaded.get(&image.id))
-                                    .map(|(_, tex_id, _, _)| *tex_id)
+                                    .map(|(_, tex_id, width, height)| {
+                                        (*tex_id, egui::vec2(*width, *height))
+                                    })
                                 {
                                     // Calculate UV coordinates for this tile
-                                    let tile_col = local_idx % image.columns;
-                                    let tile_row = local_idx / image.columns;
-                                    let uv_tile_width = 1.0 / image.columns as f32;
-                                    let uv_tile_height = 1.0 / image.rows as f32;
-
-                                    let uv_min = egui::pos2(
-                                        tile_col as f32 * uv_tile_width,
-                                        tile_row as f32 * uv_tile_height,
-                                    );
-                                    let uv_max = egui::pos2(
-                                        (tile_col + current_props.grid_width) as f32
-                                            * uv_tile_width,
-                                        (tile_row + current_props.grid_height) as f32
-                                            * uv_tile_height,
+                                    let uv = tile_uv_rect(
+                                        (tile_width, tile_height),
+                                        image,
+                                        local_idx % image.columns,
+                                        local_idx / image.columns,
+                                        current_props.grid_size(),
+                                        image_size,
                                     );
 
                                     // Draw tile texture
                                     let mut mesh = egui::Mesh::with_texture(tex_id);
-                                    mesh.add_rect_with_uv(
-                                        rect,
-                                        egui::Rect::from_min_max(uv_min, uv_max),
-                                        Color32::WHITE,
-                                    );
+                                    mesh.add_rect_with_uv(rect, uv, Color32::WHITE);
                                     ui.painter().add(egui::Shape::mesh(mesh));
                                 }
                             }
@@ -1642,11 +1646,11 @@ fn render_tile_selector_for_properties(
     editor_state: &mut EditorState,
     project: &mut Project,
     tileset_id: uuid::Uuid,
-    _tile_size: u32,
+    tile_size: (u32, u32),
     images: &[bevy_map_core::TilesetImage],
     cache: Option<&TilesetTextureCache>,
 ) {
-    let display_size = egui::vec2(32.0, 32.0);
+    let display_size = egui::vec2(32.0, 32.0 * tile_size.1 as f32 / tile_size.0.max(1) as f32);
     let spacing = 1.0;
     let mut virtual_offset = 0u32;
 
@@ -1663,9 +1667,9 @@ fn render_tile_selector_for_properties(
     }
 
     for (image_idx, image) in images.iter().enumerate() {
-        let texture_id = cache
+        let texture = cache
             .and_then(|c| c.loaded.get(&image.id))
-            .map(|(_, tex_id, _, _)| *tex_id);
+            .map(|(_, tex_id, width, height)| (*tex_id, egui::vec2(*width, *height)));
 
         ui.collapsing(&image.name, |ui| {
             if image.columns == 0 || image.rows == 0 {
@@ -1673,9 +1677,6 @@ fn render_tile_selector_for_properties(
                 return;
             }
 
-            let uv_tile_width = 1.0 / image.columns.max(1) as f32;
-            let uv_tile_height = 1.0 / image.rows.max(1) as f32;
-
             // Store tile rects for shift+drag interaction
             let mut tile_rects: Vec<(u32, u32, egui::Rect, u32)> = Vec::new();
 
@@ -1691,13 +1692,8 @@ fn render_tile_selector_for_properties(
                             .selected_tile_for_properties
                             == Some(virtual_index);
 
-                        let response = if let Some(tex_id) = texture_id {
-                            let uv_min =
-                                egui::pos2(col as f32 * uv_tile_width, row as f32 * uv_tile_height);
-                            let uv_max = egui::pos2(
-                                (col + 1) as f32 * uv_tile_width,
-                                (row + 1) as f32 * uv_tile_height,
-                            );
+                        let response = if let Some((tex_id, image_size)) = texture {
+                            let uv = tile_uv_rect(tile_size, image, col, row, (1, 1), image_size);
 
                             ui.add(
                                 egui::Button::image(
@@ -1705,7 +1701,7 @@ fn render_tile_selector_for_properties(
                                         tex_id,
                                         display_size,
                                     ))
-                                    .uv(egui::Rect::from_min_max(uv_min, uv_max)),
+                                    .uv(uv),
                                 )
                                 .frame(false) // Remove button padding
                                 .corner_radius(0.0),
@@ -1943,12 +1939,13 @@ fn render_collision_tab(
         .tilesets
         .iter()
         .find(|t| t.id == tileset_id)
-        .map(|t| (t.tile_size, t.images.clone()));
+        .map(|t| (t.tile_dimensions(), t.images.clone()));
 
     let Some((tile_size, images)) = tileset_data else {
         ui.label("Tileset not found");
         return;
     };
+    let tile_size = egui::vec2(tile_size.0 as f32, tile_size.1 as f32);
 
     if images.is_empty() {
         ui.label("No images in tileset");
@@ -1984,7 +1981,7 @@ fn render_collision_tab(
                         ui,
                         editor_state,
                         project,
-                        tile_size as f32,
+                        tile_size,
                         &images,
                         cache,
                     );
@@ -2098,7 +2095,7 @@ fn render_collision_tab(
         ui.separator();
 
         // Render the canvas with collision shape
-        render_collision_canvas(ui, editor_state, project, tile_size as f32, &images, cache);
+        render_collision_canvas(ui, editor_state, project, tile_size, &images, cache);
     });
 }
 
@@ -2107,7 +2104,7 @@ fn render_collision_tile_selector(
     ui: &mut egui::Ui,
     editor_state: &mut EditorState,
     project: &Project,
-    tile_size: f32,
+    tile_size: egui::Vec2,
     images: &[bevy_map_core::TilesetImage],
     cache: Option<&TilesetTextureCache>,
 ) {
@@ -2115,13 +2112,14 @@ fn render_collision_tile_selector(
     let tileset = project.tilesets.iter().find(|t| t.id == tileset_id);
 
     let zoom = editor_state.tileset_editor_state.collision_editor.grid_zoom;
-    let display_size = egui::vec2(tile_size * zoom, tile_size * zoom);
+    let display_size = tile_size * zoom;
+    let tile_dimensions = (tile_size.x as u32, tile_size.y as u32);
     let mut virtual_offset = 0u32;
 
     for image in images {
-        let texture_id = cache
+        let texture = cache
             .and_then(|c| c.loaded.get(&image.id))
-            .map(|(_, tex_id, _, _)| *tex_id);
+            .map(|(_, tex_id, width, height)| (*tex_id, egui::vec2(*width, *height)));
 
         ui.collapsing(&image.name, |ui| {
             if image.columns == 0 || image.rows == 0 {
@@ -2129,9 +2127,6 @@ fn render_collision_tile_selector(
                 return;
             }
 
-            let uv_tile_width = 1.0 / image.columns.max(1) as f32;
-            let uv_tile_height = 1.0 / image.rows.max(1) as f32;
-
             for row in 0..image.rows {
                 ui.horizontal(|ui| {
                     ui.spacing_mut().item_spacing = egui::vec2(1.0, 1.0);
@@ -2154,20 +2149,12 @@ fn render_collision_tile_selector(
                             ui.allocate_exact_size(display_size, egui::Sense::click());
 
                         // Draw tile texture
-                        if let Some(tex_id) = texture_id {
-                            let uv_min =
-                                egui::pos2(col as f32 * uv_tile_width, row as f32 * uv_tile_height);
-                            let uv_max = egui::pos2(
-                                (col + 1) as f32 * uv_tile_width,
-                                (row + 1) as f32 * uv_tile_height,
-                            );
+                        if let Some((tex_id, image_size)) = texture {
+                            let uv =
+                                tile_uv_rect(tile_dimensions, image, col, row, (1, 1), image_size);
 
                             let mut mesh = egui::Mesh::with_texture(tex_id);
-                            mesh.add_rect_with_uv(
-                                rect,
-                                egui::Rect::from_min_max(uv_min, uv_max),
-                                Color32::WHITE,
-                            );
+                            mesh.add_rect_with_uv(rect, uv, Color32::WHITE);
                             ui.painter().add(Shape::mesh(mesh));
                         } else {
                             ui.painter().rect_filled(rect, 0.0, Color32::from_gray(60));
@@ -2235,7 +2222,7 @@ fn render_collision_canvas(
     ui: &mut egui::Ui,
     editor_state: &mut EditorState,
     project: &mut Project,
-    tile_size: f32,
+    tile_size: egui::Vec2,
     images: &[bevy_map_core::TilesetImage],
     cache: Option<&TilesetTextureCache>,
 ) {
@@ -2248,7 +2235,7 @@ fn render_collision_canvas(
     };
 
     let zoom = collision_state.preview_zoom;
-    let canvas_size = egui::vec2(tile_size * zoom, tile_size * zoom);
+    let canvas_size = tile_size * zoom;
 
     // Find which image and local index this tile belongs to
     let mut virtual_offset = 0u32;
@@ -2273,30 +2260,25 @@ fn render_collision_canvas(
         ui.allocate_exact_size(canvas_size, egui::Sense::click_and_drag());
 
     // 1. Draw tile texture as background
-    let texture_id = cache
+    let texture = cache
         .and_then(|c| c.loaded.get(&image.id))
-        .map(|(_, tex_id, _, _)| *tex_id);
+        .map(|(_, tex_id, width, height)| (*tex_id, egui::vec2(*width, *height)));
 
-    if let Some(tex_id) = texture_id {
+    if let Some((tex_id, image_size)) = texture {
         if image.columns > 0 && image.rows > 0 {
-            let uv_tile_width = 1.0 / image.columns as f32;
-            let uv_tile_height = 1.0 / image.rows as f32;
-
             let col = local_index % image.columns;
             let row = local_index / image.columns;
-
-            let uv_min = egui::pos2(col as f32 * uv_tile_width, row as f32 * uv_tile_height);
-            let uv_max = egui::pos2(
-                (col + 1) as f32 * uv_tile_width,
-                (row + 1) as f32 * uv_tile_height,
+            let uv = tile_uv_rect(
+                (tile_size.x as u32, tile_size.y as u32),
+                image,
+                col,
+                row,
+                (1, 1),
+                image_size,
             );
 
             let mut mesh = egui::Mesh::with_texture(tex_id);
-            mesh.add_rect_with_uv(
-                canvas_rect,
-                egui::Rect::from_min_max(uv_min, uv_max),
-                Color32::WHITE,
-            );
+            mesh.add_rect_with_uv(canvas_rect, uv, Color32::WHITE);
             ui.painter().add(Shape::mesh(mesh));
         }
     } else {
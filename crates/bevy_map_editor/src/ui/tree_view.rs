@@ -16,13 +16,20 @@ pub struct TreeViewResult {
     pub delete_data: Option<Uuid>,
     pub duplicate_level: Option<Uuid>,
     pub delete_level: Option<Uuid>,
+    /// Open the Resize Level dialog
+    pub resize_level: Option<Uuid>,
     pub delete_entity: Option<(Uuid, Uuid)>,
     pub add_tile_layer: Option<Uuid>,
     pub add_object_layer: Option<Uuid>,
+    pub add_image_layer: Option<Uuid>,
+    pub add_group_layer: Option<Uuid>,
+    pub add_int_grid_layer: Option<Uuid>,
     pub delete_layer: Option<(Uuid, usize)>,
     pub move_layer_up: Option<(Uuid, usize)>,
     pub move_layer_down: Option<(Uuid, usize)>,
     pub toggle_layer_visibility: Option<(Uuid, usize)>,
+    /// Move a layer into a group, or out of all groups (level_id, layer_index, group_id)
+    pub set_layer_parent: Option<(Uuid, usize, Option<Uuid>)>,
     /// Select entity type for placement (switches to Entity tool)
     pub select_entity_type_for_placement: Option<String>,
     // Sprite sheet actions
@@ -545,37 +552,41 @@ fn render_levels_section(
                         let layer_selected = editor_state.selected_level == Some(level_id)
                             && editor_state.selected_layer == Some(*layer_idx);
 
-                        if *is_object_layer {
-                            // Object layer: use CollapsingHeader with nested entities
-                            render_object_layer(
-                                ui,
-                                editor_state,
-                                result,
-                                level_id,
-                                *layer_idx,
-                                layer_name,
-                                *visible,
-                                layer_selected,
-                                entity_ids,
-                                &level_entities,
-                                &placeable_types,
-                                project,
-                                integration_registry,
-                            );
-                        } else {
-                            // Tile layer: simple horizontal layout
-                            render_tile_layer(
-                                ui,
-                                editor_state,
-                                result,
-                                level_id,
-                                *layer_idx,
-                                layer_name,
-                                *visible,
-                                layer_selected,
-                                integration_registry,
-                            );
-                        }
+                        // Nest layers under their groups
+                        let depth = level.layer_depth(*layer_idx);
+                        indented(ui, depth, egui::Id::new((level_id, *layer_idx)), |ui| {
+                            if *is_object_layer {
+                                // Object layer: use CollapsingHeader with nested entities
+                                render_object_layer(
+                                    ui,
+                                    editor_state,
+                                    result,
+                                    level,
+                                    *layer_idx,
+                                    layer_name,
+                                    *visible,
+                                    layer_selected,
+                                    entity_ids,
+                                    &level_entities,
+                                    &placeable_types,
+                                    project,
+                                    integration_registry,
+                                );
+                            } else {
+                                // Tile, image and group layers: simple horizontal layout
+                                render_tile_layer(
+                                    ui,
+                                    editor_state,
+                                    result,
+                                    level,
+                                    *layer_idx,
+                                    layer_name,
+                                    *visible,
+                                    layer_selected,
+                                    integration_registry,
+                                );
+                            }
+                        });
                     }
 
                     // Add layer buttons at the bottom of each level
@@ -587,6 +598,19 @@ fn render_levels_section(
                             result.add_object_layer = Some(level_id);
                         }
                     });
+                    ui.horizontal(|ui| {
+                        if ui.small_button("+ Image Layer").clicked() {
+                            result.add_image_layer = Some(level_id);
+                        }
+                        if ui.small_button("+ Group").clicked() {
+                            result.add_group_layer = Some(level_id);
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        if ui.small_button("+ IntGrid Layer").clicked() {
+                            result.add_int_grid_layer = Some(level_id);
+                        }
+                    });
                 });
 
             // Header right-click context menu
@@ -600,6 +624,10 @@ fn render_levels_section(
                     result.rename_level = Some(level_id);
                     ui.close();
                 }
+                if ui.button("Resize...").clicked() {
+                    result.resize_level = Some(level_id);
+                    ui.close();
+                }
                 ui.separator();
                 if ui.button("Duplicate").clicked() {
                     result.duplicate_level = Some(level_id);
@@ -618,6 +646,18 @@ fn render_levels_section(
                     result.add_object_layer = Some(level_id);
                     ui.close();
                 }
+                if ui.button("Add Image Layer").clicked() {
+                    result.add_image_layer = Some(level_id);
+                    ui.close();
+                }
+                if ui.button("Add Group").clicked() {
+                    result.add_group_layer = Some(level_id);
+                    ui.close();
+                }
+                if ui.button("Add IntGrid Layer").clicked() {
+                    result.add_int_grid_layer = Some(level_id);
+                    ui.close();
+                }
             });
         }
     }
@@ -627,18 +667,95 @@ fn render_levels_section(
     }
 }
 
-/// Render a tile layer as a simple horizontal row
+/// Run `add_contents` indented once per nesting level
+fn indented(
+    ui: &mut egui::Ui,
+    depth: usize,
+    id: egui::Id,
+    add_contents: impl FnOnce(&mut egui::Ui),
+) {
+    if depth == 0 {
+        add_contents(ui);
+    } else {
+        ui.indent(id.with(depth), |ui| {
+            indented(ui, depth - 1, id, add_contents)
+        });
+    }
+}
+
+/// Get the tree label prefix for a layer
+fn layer_type_label(data: &bevy_map_core::LayerData) -> &'static str {
+    match data {
+        bevy_map_core::LayerData::Tiles { .. } => "[Tile]",
+        bevy_map_core::LayerData::Objects { .. } => "[Object]",
+        bevy_map_core::LayerData::Image { .. } => "[Image]",
+        bevy_map_core::LayerData::Group {} => "[Group]",
+        bevy_map_core::LayerData::IntGrid { .. } => "[IntGrid]",
+    }
+}
+
+/// Render the group entries of a layer's context menu
+fn render_layer_group_menu(
+    ui: &mut egui::Ui,
+    result: &mut TreeViewResult,
+    level: &bevy_map_core::Level,
+    layer_idx: usize,
+) {
+    let Some(layer) = level.layers.get(layer_idx) else {
+        return;
+    };
+
+    // A layer can't move into itself or into one of its own children
+    let groups: Vec<(Uuid, String)> = level
+        .layers
+        .iter()
+        .enumerate()
+        .filter(|(idx, l)| {
+            l.is_group() && *idx != layer_idx && !level.is_layer_in_group(*idx, layer_idx)
+        })
+        .map(|(_, l)| (l.id, l.name.clone()))
+        .collect();
+
+    ui.menu_button("Move to Group", |ui| {
+        if groups.is_empty() {
+            ui.label("(no groups)");
+        }
+        for (group_id, group_name) in &groups {
+            let is_parent = layer.parent_id == Some(*group_id);
+            if ui
+                .add_enabled(!is_parent, egui::Button::new(group_name))
+                .clicked()
+            {
+                result.set_layer_parent = Some((level.id, layer_idx, Some(*group_id)));
+                ui.close();
+            }
+        }
+    });
+    if layer.parent_id.is_some() && ui.button("Remove from Group").clicked() {
+        result.set_layer_parent = Some((level.id, layer_idx, None));
+        ui.close();
+    }
+}
+
+/// Render a tile, image or group layer as a simple horizontal row
 fn render_tile_layer(
     ui: &mut egui::Ui,
     editor_state: &mut EditorState,
     result: &mut TreeViewResult,
-    level_id: Uuid,
+    level: &bevy_map_core::Level,
     layer_idx: usize,
     layer_name: &str,
     visible: bool,
     layer_selected: bool,
     integration_registry: Option<&IntegrationRegistry>,
 ) {
+    let level_id = level.id;
+    let type_label = level
+        .layers
+        .get(layer_idx)
+        .map(|layer| layer_type_label(&layer.data))
+        .unwrap_or("[Tile]");
+
     // Check if this layer is being renamed
     let is_renaming = matches!(
         &editor_state.renaming_item,
@@ -654,7 +771,7 @@ fn render_tile_layer(
 
         if is_renaming {
             // Show inline text edit for rename
-            ui.label("[Tile]");
+            ui.label(type_label);
             let text_response = ui.text_edit_singleline(&mut editor_state.rename_buffer);
             if text_response.lost_focus() {
                 if ui.input(|i| i.key_pressed(egui::Key::Enter))
@@ -667,7 +784,7 @@ fn render_tile_layer(
             text_response.request_focus();
         } else {
             // Layer type indicator and name
-            let display_text = format!("[Tile] {}", layer_name);
+            let display_text = format!("{} {}", type_label, layer_name);
             let response = ui.selectable_label(layer_selected, display_text);
 
             if response.clicked() {
@@ -694,6 +811,7 @@ fn render_tile_layer(
                     result.move_layer_down = Some((level_id, layer_idx));
                     ui.close();
                 }
+                render_layer_group_menu(ui, result, level, layer_idx);
                 ui.separator();
                 if ui.button("Delete").clicked() {
                     result.delete_layer = Some((level_id, layer_idx));
@@ -716,7 +834,7 @@ fn render_object_layer(
     ui: &mut egui::Ui,
     editor_state: &mut EditorState,
     result: &mut TreeViewResult,
-    level_id: Uuid,
+    level: &bevy_map_core::Level,
     layer_idx: usize,
     layer_name: &str,
     visible: bool,
@@ -727,6 +845,8 @@ fn render_object_layer(
     project: &Project,
     integration_registry: Option<&IntegrationRegistry>,
 ) {
+    let level_id = level.id;
+
     // Get entities on this layer
     let layer_entities: Vec<_> = level_entities
         .iter()
@@ -881,6 +1001,7 @@ fn render_object_layer(
                     result.move_layer_down = Some((level_id, layer_idx));
                     ui.close();
                 }
+                render_layer_group_menu(ui, result, level, layer_idx);
                 ui.separator();
                 if ui.button("Delete").clicked() {
                     result.delete_layer = Some((level_id, layer_idx));
@@ -269,8 +269,8 @@ pub fn render_world_view(
             + editor_state.world_view_offset.y;
 
         // Calculate level size in screen space
-        let level_width = level.width as f32 * tile_size as f32 * editor_state.world_view_zoom;
-        let level_height = level.height as f32 * tile_size as f32 * editor_state.world_view_zoom;
+        let level_width = level.width as f32 * tile_size.0 as f32 * editor_state.world_view_zoom;
+        let level_height = level.height as f32 * tile_size.1 as f32 * editor_state.world_view_zoom;
 
         let level_rect = egui::Rect::from_min_size(
             egui::pos2(screen_x, screen_y),
@@ -475,14 +475,14 @@ pub fn render_world_view(
     // Draw pending connection visualization
     if let Some((from_id, from_dir)) = editor_state.world_connection_from {
         if let Some(from_level) = project.levels.iter().find(|l| l.id == from_id) {
-            let tile_size = get_default_tile_size(project) as f32;
+            let (tile_width, tile_height) = get_default_tile_size(project);
 
             // Calculate source edge position in screen space
             let (world_x, world_y) = get_edge_center_world(
                 from_level.world_x as f32,
                 from_level.world_y as f32,
-                from_level.width as f32 * tile_size,
-                from_level.height as f32 * tile_size,
+                (from_level.width * tile_width) as f32,
+                (from_level.height * tile_height) as f32,
                 from_dir,
             );
 
@@ -632,7 +632,7 @@ fn draw_connections(
 ) {
     use bevy_map_core::ConnectionDirection;
 
-    let tile_size = get_default_tile_size(project) as f32;
+    let (tile_width, tile_height) = get_default_tile_size(project);
 
     for connection in &project.world_config.connections {
         let from_level = project
@@ -646,15 +646,15 @@ fn draw_connections(
             let (from_x, from_y) = get_edge_center_world(
                 from.world_x as f32,
                 from.world_y as f32,
-                from.width as f32 * tile_size,
-                from.height as f32 * tile_size,
+                (from.width * tile_width) as f32,
+                (from.height * tile_height) as f32,
                 connection.from_direction,
             );
             let (to_x, to_y) = get_edge_center_world(
                 to.world_x as f32,
                 to.world_y as f32,
-                to.width as f32 * tile_size,
-                to.height as f32 * tile_size,
+                (to.width * tile_width) as f32,
+                (to.height * tile_height) as f32,
                 connection.to_direction,
             );
 
@@ -762,7 +762,7 @@ fn draw_arrowhead(
 
 /// Find an empty position for a new level
 fn find_empty_position(project: &Project) -> (i32, i32) {
-    let tile_size = get_default_tile_size(project) as i32;
+    let tile_width = get_default_tile_size(project).0 as i32;
 
     if project.levels.is_empty() {
         return (0, 0);
@@ -773,7 +773,7 @@ fn find_empty_position(project: &Project) -> (i32, i32) {
     let mut right_level_y = 0;
 
     for level in &project.levels {
-        let right_edge = level.world_x + (level.width as i32 * tile_size);
+        let right_edge = level.world_x + (level.width as i32 * tile_width);
         if right_edge > max_right {
             max_right = right_edge;
             right_level_y = level.world_y;
@@ -791,7 +791,7 @@ fn find_empty_position(project: &Project) -> (i32, i32) {
 
 /// Apply linear horizontal or vertical layout
 fn apply_linear_layout(project: &mut Project, horizontal: bool) {
-    let tile_size = get_default_tile_size(project) as i32;
+    let (tile_width, tile_height) = get_default_tile_size(project);
     let padding = 32;
 
     let mut current_pos = 0i32;
@@ -800,11 +800,11 @@ fn apply_linear_layout(project: &mut Project, horizontal: bool) {
         if horizontal {
             level.world_x = current_pos;
             level.world_y = 0;
-            current_pos += (level.width as i32 * tile_size) + padding;
+            current_pos += (level.width * tile_width) as i32 + padding;
         } else {
             level.world_x = 0;
             level.world_y = current_pos;
-            current_pos += (level.height as i32 * tile_size) + padding;
+            current_pos += (level.height * tile_height) as i32 + padding;
         }
     }
 }
@@ -828,7 +828,7 @@ fn fit_world_to_view(editor_state: &mut EditorState, project: &Project, view_siz
         return;
     }
 
-    let tile_size = get_default_tile_size(project) as f32;
+    let (tile_width, tile_height) = get_default_tile_size(project);
 
     // Calculate bounding box of all levels
     let mut min_x = f32::MAX;
@@ -839,8 +839,8 @@ fn fit_world_to_view(editor_state: &mut EditorState, project: &Project, view_siz
     for level in &project.levels {
         let x = level.world_x as f32;
         let y = level.world_y as f32;
-        let w = level.width as f32 * tile_size;
-        let h = level.height as f32 * tile_size;
+        let w = (level.width * tile_width) as f32;
+        let h = (level.height * tile_height) as f32;
 
         min_x = min_x.min(x);
         min_y = min_y.min(y);
@@ -871,9 +871,13 @@ fn fit_world_to_view(editor_state: &mut EditorState, project: &Project, view_siz
     editor_state.world_view_offset = bevy::math::Vec2::new(offset_x, offset_y);
 }
 
-/// Get the default tile size from the first tileset
-fn get_default_tile_size(project: &Project) -> u32 {
-    project.tilesets.first().map(|t| t.tile_size).unwrap_or(32)
+/// Get the default tile width and height from the first tileset
+fn get_default_tile_size(project: &Project) -> (u32, u32) {
+    project
+        .tilesets
+        .first()
+        .map(|t| t.tile_dimensions())
+        .unwrap_or((32, 32))
 }
 
 /// Detect which edge of a level rectangle was clicked
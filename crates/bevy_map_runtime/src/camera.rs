@@ -12,7 +12,7 @@
 //!
 //! commands.spawn((
 //!     Camera2d,
-//!     CameraBounds::from_level(32, 24, 16.0), // 32x24 tiles at 16px each
+//!     CameraBounds::from_level(32, 24, Vec2::splat(16.0)), // 32x24 tiles at 16px each
 //! ));
 //! ```
 
@@ -52,11 +52,11 @@ impl CameraBounds {
     /// # Arguments
     /// * `width` - Level width in tiles
     /// * `height` - Level height in tiles
-    /// * `tile_size` - Size of each tile in pixels
-    pub fn from_level(width: u32, height: u32, tile_size: f32) -> Self {
+    /// * `tile_size` - Width and height of each tile in pixels
+    pub fn from_level(width: u32, height: u32, tile_size: Vec2) -> Self {
         Self {
             min: Vec2::ZERO,
-            max: Vec2::new(width as f32 * tile_size, height as f32 * tile_size),
+            max: Vec2::new(width as f32, height as f32) * tile_size,
             padding: 0.0,
         }
     }
@@ -138,8 +138,8 @@ pub fn setup_camera_bounds_from_map(
 
             info!(
                 "Set camera bounds to {}x{} pixels ({}x{} tiles)",
-                level.width as f32 * tile_size,
-                level.height as f32 * tile_size,
+                level.width as f32 * tile_size.x,
+                level.height as f32 * tile_size.y,
                 level.width,
                 level.height
             );
@@ -0,0 +1,451 @@
+//! Chunked tile spawning for large levels
+//!
+//! By default a map spawns a tile entity for every filled cell as soon as it
+//! loads, with one tilemap per layer and tileset image spanning the whole
+//! level. Adding [`MapChunkSettings`] to a `MapHandle` entity splits tile
+//! layers into chunks instead: only chunks near a camera (or a
+//! [`StreamingAnchor`]) get tilemaps and tile entities, and chunks that move
+//! out of range are despawned again.
+//!
+//! The rest of the map is spawned for the whole level as usual: entities,
+//! image layers, IntGrids and tile colliders. Runtime tile edits and
+//! hot-reload update the level data everywhere but only redraw loaded chunks;
+//! the others are drawn from the updated data when they load.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use bevy::prelude::*;
+//! use bevy_map_runtime::{MapChunkSettings, MapHandle};
+//!
+//! fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+//!     commands.spawn((
+//!         MapHandle(asset_server.load("maps/huge.map.json")),
+//!         MapChunkSettings::new().with_chunk_size(64).with_load_radius(1024.0),
+//!     ));
+//! }
+//! ```
+
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+use bevy_map_core::{LayerData, MapProject};
+use std::collections::HashSet;
+
+use crate::edit::MapLevelData;
+use crate::streaming::{anchor_positions, distance_to_rect, StreamingAnchor};
+use crate::{spawn_layer_area, LayerStyle, LevelGrid, MapLayers, MapRoot, MultiCellTile};
+
+/// Per-map settings for chunked tile spawning
+///
+/// Add this to a `MapHandle` entity. Chunks whose bounds come within
+/// `load_radius` pixels of an anchor are spawned; they are despawned once
+/// every anchor is further away than `unload_radius`. The load radius should
+/// cover half of the camera's view, or chunks appear at the screen edges.
+///
+/// Staggered isometric and hexagonal levels need an even chunk size, so the
+/// shifted rows or columns of each chunk line up with its neighbours.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct MapChunkSettings {
+    /// Size of a chunk in tiles
+    pub chunk_size: UVec2,
+    /// Distance in pixels from a chunk's bounds at which it is spawned
+    pub load_radius: f32,
+    /// Distance in pixels from a chunk's bounds at which it is despawned
+    ///
+    /// Should be larger than `load_radius` to avoid chunks flickering in and
+    /// out at the boundary.
+    pub unload_radius: f32,
+    /// Most chunks spawned per map each frame, nearest first
+    pub max_loads_per_frame: usize,
+}
+
+impl Default for MapChunkSettings {
+    fn default() -> Self {
+        Self {
+            chunk_size: UVec2::splat(32),
+            load_radius: 1024.0,
+            unload_radius: 1536.0,
+            max_loads_per_frame: 8,
+        }
+    }
+}
+
+impl MapChunkSettings {
+    /// Create settings with 32x32 chunks and default radii
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the size of square chunks in tiles
+    pub fn with_chunk_size(mut self, size: u32) -> Self {
+        self.chunk_size = UVec2::splat(size.max(1));
+        self
+    }
+
+    /// Set the load radius (the unload radius is set to 1.5x the load radius)
+    pub fn with_load_radius(mut self, radius: f32) -> Self {
+        self.load_radius = radius;
+        self.unload_radius = radius * 1.5;
+        self
+    }
+
+    /// Set the unload radius
+    pub fn with_unload_radius(mut self, radius: f32) -> Self {
+        self.unload_radius = radius;
+        self
+    }
+
+    /// Set how many chunks a map may spawn per frame
+    pub fn with_max_loads_per_frame(mut self, count: usize) -> Self {
+        self.max_loads_per_frame = count.max(1);
+        self
+    }
+}
+
+/// Area of a level covered by a tilemap
+///
+/// Unchunked maps have a single area spanning the whole level. With
+/// [`MapChunkSettings`] every chunk gets its own tilemaps, whose tile
+/// positions are relative to the chunk's `origin`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MapChunk {
+    /// Chunk coordinates, zero for unchunked maps
+    pub coord: UVec2,
+    /// Bottom-left cell of the area
+    pub origin: UVec2,
+    /// Size of the area in cells
+    pub size: UVec2,
+}
+
+impl MapChunk {
+    /// Area spanning a whole level
+    pub fn whole(width: u32, height: u32) -> Self {
+        Self {
+            coord: UVec2::ZERO,
+            origin: UVec2::ZERO,
+            size: UVec2::new(width, height),
+        }
+    }
+
+    /// Check if a level cell lies in this area
+    pub fn contains(&self, cell: UVec2) -> bool {
+        cell.cmpge(self.origin).all() && cell.cmplt(self.origin + self.size).all()
+    }
+}
+
+/// Chunk state of a spawned map, on its root
+///
+/// Inserted when the map was spawned with [`MapChunkSettings`].
+#[derive(Component, Debug, Clone)]
+pub struct MapChunks {
+    settings: MapChunkSettings,
+    loaded: HashSet<UVec2>,
+}
+
+impl MapChunks {
+    pub(crate) fn new(settings: MapChunkSettings) -> Self {
+        Self {
+            settings,
+            loaded: HashSet::new(),
+        }
+    }
+
+    /// Get the settings the map was spawned with
+    pub fn settings(&self) -> &MapChunkSettings {
+        &self.settings
+    }
+
+    /// Check if a chunk currently has its tiles spawned
+    pub fn is_loaded(&self, coord: UVec2) -> bool {
+        self.loaded.contains(&coord)
+    }
+
+    /// Iterate over the coordinates of loaded chunks
+    pub fn loaded(&self) -> impl Iterator<Item = UVec2> + '_ {
+        self.loaded.iter().copied()
+    }
+
+    fn chunk_size(&self) -> UVec2 {
+        self.settings.chunk_size.max(UVec2::ONE)
+    }
+
+    /// Get the coordinates of the chunk containing a level cell
+    pub fn coord_of(&self, cell: UVec2) -> UVec2 {
+        cell / self.chunk_size()
+    }
+
+    /// Get the area of a chunk, clipped to the level size
+    pub fn chunk(&self, coord: UVec2, level_size: UVec2) -> MapChunk {
+        let origin = coord * self.chunk_size();
+        MapChunk {
+            coord,
+            origin,
+            size: level_size.saturating_sub(origin).min(self.chunk_size()),
+        }
+    }
+
+    /// Get the area of the chunk containing a level cell
+    pub fn chunk_at(&self, cell: UVec2, level_size: UVec2) -> MapChunk {
+        self.chunk(self.coord_of(cell), level_size)
+    }
+
+    /// Work out which chunks to spawn and despawn for anchors in map space
+    ///
+    /// Returns the chunks to load, nearest first and capped at
+    /// `max_loads_per_frame`, and the loaded chunks to unload.
+    fn plan(&self, grid: &LevelGrid, anchors: &[Vec2]) -> (Vec<UVec2>, Vec<UVec2>) {
+        let level_size = UVec2::new(grid.size.x, grid.size.y);
+        let tile_size = Vec2::new(grid.tile_size.x, grid.tile_size.y);
+        let chunk_pixels = self.chunk_size().as_vec2() * tile_size;
+        let corner = grid.cell_corner(0, 0);
+        let count = (level_size + self.chunk_size() - UVec2::ONE) / self.chunk_size();
+        if count.cmpeq(UVec2::ZERO).any() {
+            return (Vec::new(), Vec::new());
+        }
+        let rect = |coord: UVec2| {
+            let chunk = self.chunk(coord, level_size);
+            if grid.map_type != TilemapType::Square {
+                // Bound the cells at the chunk's corners
+                let last = chunk.origin + chunk.size - UVec2::ONE;
+                return [
+                    chunk.origin,
+                    last,
+                    uvec2(chunk.origin.x, last.y),
+                    uvec2(last.x, chunk.origin.y),
+                ]
+                .iter()
+                .fold(Rect::EMPTY, |rect, cell| {
+                    let min = grid.cell_corner(cell.x, cell.y);
+                    rect.union(Rect::from_corners(min, min + tile_size))
+                });
+            }
+            let min = corner + chunk.origin.as_vec2() * tile_size;
+            Rect::from_corners(min, min + chunk.size.as_vec2() * tile_size)
+        };
+        let nearest = |coord: UVec2| {
+            anchors
+                .iter()
+                .map(|anchor| distance_to_rect(rect(coord), *anchor))
+                .fold(f32::INFINITY, f32::min)
+        };
+
+        // Only chunks within reach of an anchor need checking
+        let mut to_load: Vec<(UVec2, f32)> = Vec::new();
+        let reach = self.settings.load_radius.max(0.0);
+        let mut seen: HashSet<UVec2> = HashSet::new();
+        for anchor in anchors {
+            let min = ((*anchor - corner - Vec2::splat(reach)) / chunk_pixels).floor();
+            let max = ((*anchor - corner + Vec2::splat(reach)) / chunk_pixels).floor();
+            let (min, max) = if grid.map_type != TilemapType::Square {
+                // Chunks are not laid out in a square grid, so check them all
+                (UVec2::ZERO, count - UVec2::ONE)
+            } else if max.x < 0.0 || max.y < 0.0 {
+                continue;
+            } else {
+                (
+                    min.max(Vec2::ZERO).as_uvec2(),
+                    max.as_uvec2().min(count - UVec2::ONE),
+                )
+            };
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    let coord = UVec2::new(x, y);
+                    if self.loaded.contains(&coord) || !seen.insert(coord) {
+                        continue;
+                    }
+                    let distance = nearest(coord);
+                    if distance <= self.settings.load_radius {
+                        to_load.push((coord, distance));
+                    }
+                }
+            }
+        }
+        to_load.sort_by(|a, b| a.1.total_cmp(&b.1));
+        to_load.truncate(self.settings.max_loads_per_frame.max(1));
+
+        let to_unload = self
+            .loaded
+            .iter()
+            .copied()
+            .filter(|coord| nearest(*coord) > self.settings.unload_radius)
+            .collect();
+
+        (
+            to_load.into_iter().map(|(coord, _)| coord).collect(),
+            to_unload,
+        )
+    }
+}
+
+/// System that spawns and despawns the chunks of chunked maps around anchors
+///
+/// Uses [`StreamingAnchor`] entities, or every `Camera` if there are none.
+pub fn stream_map_chunks(
+    mut commands: Commands,
+    map_assets: Res<Assets<MapProject>>,
+    mut maps: Query<(
+        Entity,
+        &MapRoot,
+        &MapLevelData,
+        &mut MapChunks,
+        Option<&MapLayers>,
+        &GlobalTransform,
+    )>,
+    anchors: Query<(Entity, &GlobalTransform), With<StreamingAnchor>>,
+    cameras: Query<(Entity, &GlobalTransform), With<Camera>>,
+    tilemaps: Query<(Entity, &ChildOf, &MapChunk, &TileStorage)>,
+    multi_cell_tiles: Query<(Entity, &ChildOf, &MultiCellTile)>,
+) {
+    for (map_entity, map_root, level_data, mut chunks, map_layers, transform) in maps.iter_mut() {
+        let Some(project) = map_assets.get(&map_root.handle) else {
+            continue;
+        };
+        let level = &level_data.0;
+        let grid = LevelGrid::new(level, map_root.textures.tile_size);
+        let positions: Vec<Vec2> = anchor_positions(transform, &anchors, &cameras)
+            .into_iter()
+            .map(|(_, position)| position)
+            .collect();
+
+        let (to_load, to_unload) = chunks.plan(&grid, &positions);
+        if to_load.is_empty() && to_unload.is_empty() {
+            continue;
+        }
+
+        if !to_unload.is_empty() {
+            let unload: HashSet<UVec2> = to_unload.into_iter().collect();
+            for (tilemap_entity, child_of, chunk, storage) in tilemaps.iter() {
+                if child_of.parent() == map_entity && unload.contains(&chunk.coord) {
+                    for tile_entity in storage.iter().flatten() {
+                        commands.entity(*tile_entity).despawn();
+                    }
+                    commands.entity(tilemap_entity).despawn();
+                }
+            }
+            for (sprite_entity, child_of, tile) in multi_cell_tiles.iter() {
+                if child_of.parent() == map_entity
+                    && unload.contains(&chunks.coord_of(UVec2::new(tile.x, tile.y)))
+                {
+                    commands.entity(sprite_entity).despawn();
+                }
+            }
+            chunks.loaded.retain(|coord| !unload.contains(coord));
+        }
+
+        let layer_settings = map_layers.map(|l| l.settings).unwrap_or_default();
+        let level_size = UVec2::new(level.width, level.height);
+        for coord in to_load {
+            let chunk = chunks.chunk(coord, level_size);
+            for (layer_index, layer) in level.layers.iter().enumerate() {
+                let LayerData::Tiles {
+                    tileset_id, tiles, ..
+                } = &layer.data
+                else {
+                    continue;
+                };
+                let Some(effective) = level.effective_layer(layer_index) else {
+                    continue;
+                };
+                if !layer_settings.should_spawn(effective.visible) {
+                    continue;
+                }
+                let Some(tileset) = project.get_tileset(*tileset_id) else {
+                    continue;
+                };
+                spawn_layer_area(
+                    &mut commands,
+                    map_entity,
+                    &grid,
+                    layer,
+                    layer_index,
+                    tiles,
+                    tileset,
+                    &map_root.textures,
+                    &layer_settings,
+                    &LayerStyle::new(layer, &effective),
+                    chunk,
+                );
+            }
+            chunks.loaded.insert(coord);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_map_core::Level;
+
+    fn chunks(settings: MapChunkSettings) -> (MapChunks, LevelGrid) {
+        let level = Level::new("Huge".to_string(), 1000, 1000);
+        (
+            MapChunks::new(settings),
+            LevelGrid::new(&level, Vec2::splat(16.0)),
+        )
+    }
+
+    #[test]
+    fn test_chunk_areas() {
+        let (chunks, _) = chunks(MapChunkSettings::new().with_chunk_size(64));
+        let level_size = UVec2::new(1000, 1000);
+
+        assert_eq!(chunks.coord_of(UVec2::new(130, 5)), UVec2::new(2, 0));
+        let chunk = chunks.chunk_at(UVec2::new(130, 5), level_size);
+        assert_eq!(chunk.origin, UVec2::new(128, 0));
+        assert_eq!(chunk.size, UVec2::splat(64));
+        assert!(chunk.contains(UVec2::new(191, 63)));
+        assert!(!chunk.contains(UVec2::new(192, 63)));
+
+        // Chunks at the far edges are clipped to the level
+        let edge = chunks.chunk(UVec2::new(15, 15), level_size);
+        assert_eq!(edge.origin, UVec2::splat(960));
+        assert_eq!(edge.size, UVec2::splat(40));
+        assert!(MapChunk::whole(1000, 1000).contains(UVec2::new(999, 0)));
+    }
+
+    #[test]
+    fn test_chunks_load_near_anchors_and_unload_far() {
+        let settings = MapChunkSettings {
+            chunk_size: UVec2::splat(32),
+            load_radius: 100.0,
+            unload_radius: 300.0,
+            max_loads_per_frame: 100,
+        };
+        let (mut chunks, grid) = chunks(settings);
+        // 32 tiles of 16 pixels: chunks are 512 pixels wide
+        let corner = grid.cell_corner(0, 0);
+        let anchor = corner + Vec2::new(600.0, 40.0);
+
+        let (to_load, to_unload) = chunks.plan(&grid, &[anchor]);
+        assert!(to_unload.is_empty());
+        assert_eq!(to_load[0], UVec2::new(1, 0));
+        let mut sorted = to_load.clone();
+        sorted.sort_by_key(|c| (c.x, c.y));
+        assert_eq!(sorted, vec![UVec2::new(0, 0), UVec2::new(1, 0)]);
+        chunks.loaded.extend(to_load);
+
+        // Nothing new near the same spot
+        assert_eq!(chunks.plan(&grid, &[anchor]), (Vec::new(), Vec::new()));
+
+        // Chunk 0 stays loaded until the anchor passes the unload radius
+        let (_, to_unload) = chunks.plan(&grid, &[anchor + Vec2::new(150.0, 0.0)]);
+        assert!(to_unload.is_empty());
+        let (to_load, to_unload) = chunks.plan(&grid, &[anchor + Vec2::new(2000.0, 0.0)]);
+        assert_eq!(to_load, vec![UVec2::new(5, 0), UVec2::new(4, 0)]);
+        let mut to_unload = to_unload;
+        to_unload.sort_by_key(|c| c.x);
+        assert_eq!(to_unload, vec![UVec2::new(0, 0), UVec2::new(1, 0)]);
+
+        // Loads per frame are capped, nearest first
+        let (chunks, grid) = self::chunks(MapChunkSettings {
+            max_loads_per_frame: 1,
+            ..settings
+        });
+        let (to_load, _) = chunks.plan(&grid, &[anchor]);
+        assert_eq!(to_load, vec![UVec2::new(1, 0)]);
+
+        // Anchors outside the level load nothing
+        let (to_load, _) = chunks.plan(&grid, &[corner - Vec2::splat(1000.0)]);
+        assert!(to_load.is_empty());
+    }
+}
@@ -7,8 +7,10 @@
 //!
 //! - Automatic collider spawning for tiles with collision shapes
 //! - Support for all collision shapes (Full, Rectangle, Circle, Polygon)
-//! - One-way platform support
+//! - One-way platforms in all four directions, with drop-through
 //! - Collision layers and masks
+//! - Merging of `Full` and axis-aligned `Rectangle` tiles into larger colliders
+//! - Rebuilding colliders after tiles are edited with `MapEditCommands`
 //!
 //! # Usage
 //!
@@ -26,16 +28,56 @@
 //!
 //! app.add_plugins(MapCollisionPlugin);
 //! ```
+//!
+//! # Collider Merging
+//!
+//! By default, neighbouring tiles with the same `CollisionData` are merged
+//! into as few rectangles as possible, which keeps the number of bodies low
+//! and removes most of the seams characters can snag on. Insert
+//! [`MapCollisionSettings`] to spawn one collider per tile instead, e.g. to
+//! inspect individual tile shapes while debugging:
+//!
+//! ```rust,ignore
+//! use bevy_map_runtime::collision::{ColliderMerge, MapCollisionSettings};
+//!
+//! app.insert_resource(MapCollisionSettings::new().with_merge(ColliderMerge::PerTile));
+//! ```
+//!
+//! # One-Way Platforms
+//!
+//! Colliders with a `OneWayDirection` only block bodies arriving from that
+//! side; everything else passes through. A platformer character can fall
+//! through `Top` platforms by inserting [`DropThrough`]:
+//!
+//! ```rust,ignore
+//! fn drop_down(mut commands: Commands, player: Single<Entity, With<Player>>, keys: Res<ButtonInput<KeyCode>>) {
+//!     if keys.just_pressed(KeyCode::KeyS) {
+//!         commands.entity(*player).insert(DropThrough::new(0.25));
+//!     }
+//! }
+//! ```
+//!
+//! Pass-through is implemented with Avian collision hooks. An app can only
+//! have one hooks type, so if you add `PhysicsPlugins` yourself, register
+//! [`MapCollisionHooks`] with it:
+//!
+//! ```rust,ignore
+//! app.add_plugins(PhysicsPlugins::default().with_collision_hooks::<MapCollisionHooks>())
+//!     .add_plugins(MapCollisionPlugin);
+//! ```
 
 use bevy::prelude::*;
-use bevy_map_core::CollisionData;
+use bevy_map_core::{CollisionData, CollisionShape};
 
 #[cfg(feature = "physics")]
-use bevy_map_core::{CollisionShape, OneWayDirection, PhysicsBody};
+use bevy_map_core::{OneWayDirection, PhysicsBody};
 
 #[cfg(feature = "physics")]
 use avian2d::{prelude::*, schedule::PhysicsSchedulePlugin};
 
+#[cfg(feature = "physics")]
+use bevy::ecs::{entity::EntityHashSet, message::MessageReader, system::SystemParam};
+
 #[cfg(feature = "physics")]
 use bevy_ecs_tilemap::prelude::*;
 
@@ -51,10 +93,145 @@ impl Plugin for MapCollisionPlugin {
     fn build(&self, app: &mut App) {
         if !app.is_plugin_added::<PhysicsSchedulePlugin>() {
             // Only add the plugin if it hasn't been added yet
-            app.add_plugins(avian2d::PhysicsPlugins::default());
+            app.add_plugins(
+                avian2d::PhysicsPlugins::default().with_collision_hooks::<MapCollisionHooks>(),
+            );
+        }
+        app.init_resource::<MapCollisionSettings>().add_systems(
+            Update,
+            (
+                spawn_tile_colliders,
+                rebuild_tile_colliders.after(super::edit::apply_map_edits),
+                tick_drop_through,
+            ),
+        );
+    }
+}
+
+/// How tile colliders are spawned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColliderMerge {
+    /// One collider per tile
+    PerTile,
+    /// Merge neighbouring `Full` and axis-aligned `Rectangle` tiles with the
+    /// same collision data into greedy rectangles
+    ///
+    /// Only orthogonal levels are merged; isometric and hexagonal levels
+    /// always get one collider per tile.
+    #[default]
+    Rectangles,
+}
+
+/// Settings for tile collider spawning
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MapCollisionSettings {
+    /// How tile colliders are spawned
+    pub merge: ColliderMerge,
+}
+
+impl MapCollisionSettings {
+    /// Create settings with the default merging
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how tile colliders are spawned
+    pub fn with_merge(mut self, merge: ColliderMerge) -> Self {
+        self.merge = merge;
+        self
+    }
+}
+
+/// A rectangle of tiles that share one collider
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergedCollider {
+    /// Bottom-left tile column
+    pub x: u32,
+    /// Bottom-left tile row
+    pub y: u32,
+    /// Width in tiles
+    pub width: u32,
+    /// Height in tiles
+    pub height: u32,
+    /// Collision data shared by every tile in the rectangle
+    pub collision: CollisionData,
+}
+
+/// Axes along which tiles with this shape can be merged, as `(x, y)`
+///
+/// A `Rectangle` can only be joined to its neighbour along an axis it fully
+/// spans, otherwise the merged collider would cover the gaps between tiles.
+/// Returns `None` for shapes that are never merged.
+fn merge_axes(shape: &CollisionShape) -> Option<(bool, bool)> {
+    const EPSILON: f32 = 1e-4;
+    match shape {
+        CollisionShape::Full => Some((true, true)),
+        CollisionShape::Rectangle { offset, size } => Some((
+            offset[0].abs() < EPSILON && (size[0] - 1.0).abs() < EPSILON,
+            offset[1].abs() < EPSILON && (size[1] - 1.0).abs() < EPSILON,
+        )),
+        _ => None,
+    }
+}
+
+/// Group tiles into as few rectangles as possible
+///
+/// `cells` holds the collision of each tile, row-major with `y = 0` at the
+/// bottom. Only tiles with a `Full` or `Rectangle` shape are merged, and only
+/// with tiles that have identical collision data. All other tiles are left
+/// out of the result and need colliders of their own.
+pub fn merge_tile_colliders(
+    width: u32,
+    height: u32,
+    cells: &[Option<CollisionData>],
+) -> Vec<MergedCollider> {
+    let cell = |x: u32, y: u32| cells.get((y * width + x) as usize).and_then(|c| c.as_ref());
+    let mut used = vec![false; (width * height) as usize];
+    let mut merged = Vec::new();
+
+    for y in 0..height {
+        for x in 0..width {
+            if used[(y * width + x) as usize] {
+                continue;
+            }
+            let Some(collision) = cell(x, y) else {
+                continue;
+            };
+            let Some((merge_x, merge_y)) = merge_axes(&collision.shape) else {
+                continue;
+            };
+            let matches = |cx: u32, cy: u32, used: &[bool]| {
+                !used[(cy * width + cx) as usize] && cell(cx, cy) == Some(collision)
+            };
+
+            // Grow right as far as the row allows
+            let mut w = 1;
+            while merge_x && x + w < width && matches(x + w, y, &used) {
+                w += 1;
+            }
+
+            // Then grow up while every tile of the next row matches
+            let mut h = 1;
+            while merge_y && y + h < height && (x..x + w).all(|cx| matches(cx, y + h, &used)) {
+                h += 1;
+            }
+
+            for cy in y..y + h {
+                for cx in x..x + w {
+                    used[(cy * width + cx) as usize] = true;
+                }
+            }
+            merged.push(MergedCollider {
+                x,
+                y,
+                width: w,
+                height: h,
+                collision: collision.clone(),
+            });
         }
-        app.add_systems(Update, spawn_tile_colliders);
     }
+
+    merged
 }
 
 /// Marker component for map collision entities
@@ -67,11 +244,145 @@ pub struct MapCollider {
 /// Marker component for one-way platforms
 #[cfg(feature = "physics")]
 #[derive(Component)]
+#[require(OneWayPassing)]
 pub struct OneWayPlatform {
     /// Direction that allows pass-through
     pub direction: OneWayDirection,
 }
 
+/// Bodies currently passing through a one-way platform
+///
+/// Added automatically next to [`OneWayPlatform`] (via `#[require]`).
+#[cfg(feature = "physics")]
+#[derive(Component, Default)]
+pub(crate) struct OneWayPassing(EntityHashSet);
+
+/// Request for a body to fall through one-way platforms
+///
+/// While present, the body passes through every `Top` one-way platform it
+/// touches. A platform it has started to pass through stays passable until
+/// the body is clear of it, even after the request expires. The component
+/// removes itself when its timer finishes.
+#[cfg(feature = "physics")]
+#[derive(Component, Debug, Clone)]
+pub struct DropThrough {
+    /// Time left before the request expires
+    pub timer: Timer,
+}
+
+#[cfg(feature = "physics")]
+impl DropThrough {
+    /// Request a drop-through that lasts `seconds`
+    pub fn new(seconds: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+        }
+    }
+}
+
+/// The outward normal of the side a one-way platform blocks
+///
+/// Bodies are stopped only when they touch the platform from this side.
+#[cfg(feature = "physics")]
+fn blocking_normal(direction: OneWayDirection) -> Option<Vec2> {
+    match direction {
+        OneWayDirection::None => None,
+        OneWayDirection::Top => Some(Vec2::Y),
+        OneWayDirection::Bottom => Some(Vec2::NEG_Y),
+        OneWayDirection::Left => Some(Vec2::NEG_X),
+        OneWayDirection::Right => Some(Vec2::X),
+    }
+}
+
+/// Avian collision hooks implementing one-way platforms
+///
+/// Added automatically when `MapCollisionPlugin` adds the physics plugins.
+#[cfg(feature = "physics")]
+#[derive(SystemParam)]
+pub struct MapCollisionHooks<'w, 's> {
+    platforms: Query<
+        'w,
+        's,
+        (
+            &'static OneWayPlatform,
+            &'static OneWayPassing,
+            &'static GlobalTransform,
+        ),
+    >,
+    drop_through: Query<'w, 's, Has<DropThrough>>,
+}
+
+#[cfg(feature = "physics")]
+impl CollisionHooks for MapCollisionHooks<'_, '_> {
+    fn modify_contacts(&self, contacts: &mut ContactPair, commands: &mut Commands) -> bool {
+        // Find the platform, and flip normals so they point from it to the other body
+        let (platform_entity, (platform, passing, transform), other, normal_sign) =
+            if let Ok(platform) = self.platforms.get(contacts.collider1) {
+                (contacts.collider1, platform, contacts.collider2, 1.0)
+            } else if let Ok(platform) = self.platforms.get(contacts.collider2) {
+                (contacts.collider2, platform, contacts.collider1, -1.0)
+            } else {
+                return true;
+            };
+        let Some(blocking) = blocking_normal(platform.direction) else {
+            return true;
+        };
+        let other_body = if other == contacts.collider1 {
+            contacts.body1.unwrap_or(other)
+        } else {
+            contacts.body2.unwrap_or(other)
+        };
+
+        // Keep passing through until the body is clear of the platform
+        if passing.0.contains(&other_body) {
+            let penetrating = contacts
+                .manifolds
+                .iter()
+                .any(|manifold| manifold.points.iter().any(|point| point.penetration > 0.0));
+            if penetrating {
+                return false;
+            }
+            commands.queue(move |world: &mut World| {
+                if let Some(mut passing) = world.get_mut::<OneWayPassing>(platform_entity) {
+                    passing.0.remove(&other_body);
+                }
+            });
+        }
+
+        let dropping = platform.direction == OneWayDirection::Top
+            && self.drop_through.get(other_body).unwrap_or(false);
+        let blocking = (transform.rotation() * blocking.extend(0.0)).truncate();
+        let from_blocking_side = contacts.manifolds.iter().all(|manifold| {
+            let normal = manifold.normal * normal_sign;
+            normal.length() > f32::EPSILON && normal.dot(blocking) >= 0.5
+        });
+        if from_blocking_side && !dropping {
+            return true;
+        }
+
+        commands.queue(move |world: &mut World| {
+            if let Some(mut passing) = world.get_mut::<OneWayPassing>(platform_entity) {
+                passing.0.insert(other_body);
+            }
+        });
+        false
+    }
+}
+
+/// Remove expired drop-through requests
+#[cfg(feature = "physics")]
+pub fn tick_drop_through(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut DropThrough)>,
+) {
+    for (entity, mut drop_through) in query.iter_mut() {
+        if drop_through.timer.tick(time.delta()).is_finished() {
+            commands.entity(entity).remove::<DropThrough>();
+        }
+    }
+}
+
 /// System to spawn tile colliders after map load
 ///
 /// This system runs when a `MapRoot` component is added and spawns
@@ -79,78 +390,179 @@ pub struct OneWayPlatform {
 #[cfg(feature = "physics")]
 pub fn spawn_tile_colliders(
     mut commands: Commands,
-    map_query: Query<(Entity, &super::MapRoot), Added<super::MapRoot>>,
+    map_query: Query<
+        (Entity, &super::MapRoot, Option<&super::MapLevelData>),
+        Added<super::MapRoot>,
+    >,
     map_assets: Res<Assets<bevy_map_core::MapProject>>,
+    settings: Res<MapCollisionSettings>,
 ) {
-    for (map_entity, map_root) in map_query.iter() {
+    for (map_entity, map_root, level_data) in map_query.iter() {
         let Some(project) = map_assets.get(&map_root.handle) else {
             continue;
         };
+        let level = level_data.map(|data| &data.0).unwrap_or(&project.level);
+
+        let total_colliders = spawn_level_colliders(
+            &mut commands,
+            map_entity,
+            project,
+            level,
+            map_root.textures.tile_size,
+            &settings,
+        );
+        if total_colliders > 0 {
+            info!("Spawned {} tile colliders from map", total_colliders);
+        }
+    }
+}
 
-        let tile_size = map_root.textures.tile_size;
-        let level = &project.level;
+/// Rebuild the tile colliders of maps whose tiles were edited at runtime
+#[cfg(feature = "physics")]
+pub fn rebuild_tile_colliders(
+    mut commands: Commands,
+    mut changes: MessageReader<super::MapTilesChanged>,
+    map_query: Query<(&super::MapRoot, &super::MapLevelData)>,
+    colliders: Query<(Entity, &ChildOf), With<MapCollider>>,
+    map_assets: Res<Assets<bevy_map_core::MapProject>>,
+    settings: Res<MapCollisionSettings>,
+) {
+    let changed_maps: EntityHashSet = changes.read().map(|change| change.map).collect();
 
-        // Build tilemap parameters for coordinate conversion
-        let map_size = TilemapSize {
-            x: level.width,
-            y: level.height,
-        };
-        let grid_size = TilemapGridSize {
-            x: tile_size,
-            y: tile_size,
+    for map_entity in changed_maps {
+        let Ok((map_root, level_data)) = map_query.get(map_entity) else {
+            continue;
         };
-        let tilemap_tile_size = TilemapTileSize {
-            x: tile_size,
-            y: tile_size,
+        let Some(project) = map_assets.get(&map_root.handle) else {
+            continue;
         };
-        let map_type = TilemapType::Square;
-        let anchor = TilemapAnchor::default(); // BottomLeft
-
-        // Iterate through all tile layers
-        let mut total_colliders = 0;
-        for layer in level.layers.iter() {
-            if let bevy_map_core::LayerData::Tiles {
-                tileset_id, tiles, ..
-            } = &layer.data
-            {
-                // Get the tileset to look up collision data
-                let Some(tileset) = project.get_tileset(*tileset_id) else {
+
+        for (collider_entity, child_of) in colliders.iter() {
+            if child_of.parent() == map_entity {
+                commands.entity(collider_entity).despawn();
+            }
+        }
+        spawn_level_colliders(
+            &mut commands,
+            map_entity,
+            project,
+            &level_data.0,
+            map_root.textures.tile_size,
+            &settings,
+        );
+    }
+}
+
+/// Spawn colliders for every tile layer of a level, returning how many were spawned
+#[cfg(feature = "physics")]
+fn spawn_level_colliders(
+    commands: &mut Commands,
+    map_entity: Entity,
+    project: &bevy_map_core::MapProject,
+    level: &bevy_map_core::Level,
+    tile_size: Vec2,
+    settings: &MapCollisionSettings,
+) -> usize {
+    // Build tilemap parameters for coordinate conversion
+    let map_size = TilemapSize {
+        x: level.width,
+        y: level.height,
+    };
+    let grid_size = TilemapGridSize {
+        x: tile_size.x,
+        y: tile_size.y,
+    };
+    let tilemap_tile_size = TilemapTileSize {
+        x: tile_size.x,
+        y: tile_size.y,
+    };
+    let map_type = crate::render::tilemap_type(level.orientation);
+    let anchor = TilemapAnchor::default(); // BottomLeft
+
+    // Iterate through all tile layers
+    let mut total_colliders = 0;
+    for layer in level.layers.iter() {
+        if let bevy_map_core::LayerData::Tiles {
+            tileset_id, tiles, ..
+        } = &layer.data
+        {
+            // Get the tileset to look up collision data
+            let Some(tileset) = project.get_tileset(*tileset_id) else {
+                continue;
+            };
+
+            // Collect the collision of each tile
+            let mut cells: Vec<Option<CollisionData>> =
+                vec![None; (level.width * level.height) as usize];
+            for (idx, cell) in cells.iter_mut().enumerate() {
+                let Some(&Some(raw)) = tiles.get(idx) else {
                     continue;
                 };
+                // Cells covered by a multi-cell tile have no collision of their own
+                if bevy_map_core::is_occupied_cell(raw) {
+                    continue;
+                }
+                let tile_index = bevy_map_core::tile_index(raw);
 
-                // Spawn colliders for each tile with collision
-                for y in 0..level.height {
-                    for x in 0..level.width {
-                        let idx = (y * level.width + x) as usize;
-                        if let Some(&Some(tile_index)) = tiles.get(idx) {
-                            // Check if this tile has collision
-                            if let Some(props) = tileset.get_tile_properties(tile_index) {
-                                if props.collision.has_collision() {
-                                    spawn_collider_for_tile(
-                                        &mut commands,
-                                        map_entity,
-                                        &props.collision,
-                                        x,
-                                        y,
-                                        tile_size,
-                                        &map_size,
-                                        &grid_size,
-                                        &tilemap_tile_size,
-                                        &map_type,
-                                        &anchor,
-                                    );
-                                    total_colliders += 1;
-                                }
-                            }
+                // Check if this tile has collision
+                if let Some(props) = tileset.get_tile_properties(tile_index) {
+                    if props.collision.has_collision() {
+                        // Mirror the shape to match how the tile is drawn
+                        *cell = Some(props.collision.flipped(
+                            bevy_map_core::tile_flip_x(raw),
+                            bevy_map_core::tile_flip_y(raw),
+                            bevy_map_core::tile_flip_diagonal(raw),
+                        ));
+                    }
+                }
+            }
+
+            // Rectangles of cells are only rectangles on an orthogonal grid
+            if settings.merge == ColliderMerge::Rectangles && level.orientation.is_orthogonal() {
+                for rect in merge_tile_colliders(level.width, level.height, &cells) {
+                    for y in rect.y..rect.y + rect.height {
+                        for x in rect.x..rect.x + rect.width {
+                            cells[(y * level.width + x) as usize] = None;
                         }
                     }
+                    spawn_merged_collider(
+                        commands,
+                        map_entity,
+                        &rect,
+                        tile_size,
+                        &map_size,
+                        &grid_size,
+                        &tilemap_tile_size,
+                        &map_type,
+                        &anchor,
+                    );
+                    total_colliders += 1;
                 }
             }
-        }
-        if total_colliders > 0 {
-            info!("Spawned {} tile colliders from map", total_colliders);
+
+            // Spawn colliders for each remaining tile with collision
+            for (idx, collision) in cells.iter().enumerate() {
+                let Some(collision) = collision else {
+                    continue;
+                };
+                spawn_collider_for_tile(
+                    commands,
+                    map_entity,
+                    collision,
+                    idx as u32 % level.width,
+                    idx as u32 / level.width,
+                    tile_size,
+                    &map_size,
+                    &grid_size,
+                    &tilemap_tile_size,
+                    &map_type,
+                    &anchor,
+                );
+                total_colliders += 1;
+            }
         }
     }
+    total_colliders
 }
 
 /// Spawn a collider entity for a single tile
@@ -161,7 +573,7 @@ fn spawn_collider_for_tile(
     collision: &CollisionData,
     tile_x: u32,
     tile_y: u32,
-    tile_size: f32,
+    tile_size: Vec2,
     map_size: &TilemapSize,
     grid_size: &TilemapGridSize,
     tilemap_tile_size: &TilemapTileSize,
@@ -182,6 +594,66 @@ fn spawn_collider_for_tile(
     // Apply offset from collision shape
     let (offset_x, offset_y) = get_shape_offset(&collision.shape, tile_size);
 
+    spawn_collider(
+        commands,
+        map_entity,
+        collision,
+        collider,
+        Vec2::new(center.x + offset_x, center.y + offset_y),
+    );
+}
+
+/// Spawn one collider covering a rectangle of merged tiles
+#[cfg(feature = "physics")]
+fn spawn_merged_collider(
+    commands: &mut Commands,
+    map_entity: Entity,
+    rect: &MergedCollider,
+    tile_size: Vec2,
+    map_size: &TilemapSize,
+    grid_size: &TilemapGridSize,
+    tilemap_tile_size: &TilemapTileSize,
+    map_type: &TilemapType,
+    anchor: &TilemapAnchor,
+) {
+    // Each tile's shape spans its full cell along every merged axis, so the
+    // merged shape is one tile's shape stretched by the extra tiles
+    let (shape_width, shape_height) = match rect.collision.shape {
+        CollisionShape::Rectangle { size, .. } => (size[0], size[1]),
+        _ => (1.0, 1.0),
+    };
+    let collider = Collider::rectangle(
+        (rect.width as f32 - 1.0 + shape_width) * tile_size.x,
+        (rect.height as f32 - 1.0 + shape_height) * tile_size.y,
+    );
+
+    // Center of the rectangle, from the center of its bottom-left tile
+    let first = TilePos {
+        x: rect.x,
+        y: rect.y,
+    };
+    let center = first.center_in_world(map_size, grid_size, tilemap_tile_size, map_type, anchor)
+        + Vec2::new((rect.width - 1) as f32, (rect.height - 1) as f32) * tile_size / 2.0;
+    let (offset_x, offset_y) = get_shape_offset(&rect.collision.shape, tile_size);
+
+    spawn_collider(
+        commands,
+        map_entity,
+        &rect.collision,
+        collider,
+        Vec2::new(center.x + offset_x, center.y + offset_y),
+    );
+}
+
+/// Spawn a collider entity as a child of the map
+#[cfg(feature = "physics")]
+fn spawn_collider(
+    commands: &mut Commands,
+    map_entity: Entity,
+    collision: &CollisionData,
+    collider: Collider,
+    position: Vec2,
+) {
     // Add collision layers using bitmasks
     // membership: the layer this collider belongs to (1 << layer)
     // filter: which layers this collider interacts with (mask)
@@ -189,7 +661,7 @@ fn spawn_collider_for_tile(
 
     let collider_entity = commands
         .spawn((
-            Transform::from_xyz(center.x + offset_x, center.y + offset_y, 0.0),
+            Transform::from_xyz(position.x, position.y, 0.0),
             Visibility::default(),
             body_type_to_rigid_body(collision.body_type),
             collider,
@@ -202,11 +674,14 @@ fn spawn_collider_for_tile(
         ))
         .id();
 
-    // Add one-way marker if applicable
+    // Add one-way marker and enable the hooks that let bodies pass through
     if collision.one_way.is_one_way() {
-        commands.entity(collider_entity).insert(OneWayPlatform {
-            direction: collision.one_way,
-        });
+        commands.entity(collider_entity).insert((
+            OneWayPlatform {
+                direction: collision.one_way,
+            },
+            ActiveCollisionHooks::MODIFY_CONTACTS,
+        ));
     }
 
     // Make it a child of the map
@@ -215,15 +690,18 @@ fn spawn_collider_for_tile(
 
 /// Convert CollisionShape to Avian Collider
 #[cfg(feature = "physics")]
-fn shape_to_collider(shape: &CollisionShape, tile_size: f32) -> Option<Collider> {
+fn shape_to_collider(shape: &CollisionShape, tile_size: Vec2) -> Option<Collider> {
     match shape {
         CollisionShape::None => None,
-        CollisionShape::Full => Some(Collider::rectangle(tile_size, tile_size)),
+        CollisionShape::Full => Some(Collider::rectangle(tile_size.x, tile_size.y)),
         CollisionShape::Rectangle { size, .. } => Some(Collider::rectangle(
-            size[0] * tile_size,
-            size[1] * tile_size,
+            size[0] * tile_size.x,
+            size[1] * tile_size.y,
         )),
-        CollisionShape::Circle { radius, .. } => Some(Collider::circle(*radius * tile_size)),
+        // Circles stay round on non-square tiles
+        CollisionShape::Circle { radius, .. } => {
+            Some(Collider::circle(*radius * tile_size.x.min(tile_size.y)))
+        }
         CollisionShape::Polygon { points } => {
             if points.len() < 3 {
                 return None;
@@ -231,7 +709,7 @@ fn shape_to_collider(shape: &CollisionShape, tile_size: f32) -> Option<Collider>
             // Note: Y is flipped because editor uses Y-down (top=0), Bevy uses Y-up (bottom=0)
             let scaled: Vec<Vec2> = points
                 .iter()
-                .map(|p| Vec2::new((p[0] - 0.5) * tile_size, (0.5 - p[1]) * tile_size))
+                .map(|p| Vec2::new(p[0] - 0.5, 0.5 - p[1]) * tile_size)
                 .collect();
             Collider::convex_hull(scaled)
         }
@@ -244,18 +722,18 @@ fn shape_to_collider(shape: &CollisionShape, tile_size: f32) -> Option<Collider>
 /// We need to convert this to a center offset from the tile center for the collider.
 /// Note: Editor uses Y-down (top=0), but Bevy uses Y-up (bottom=0), so we flip Y.
 #[cfg(feature = "physics")]
-fn get_shape_offset(shape: &CollisionShape, tile_size: f32) -> (f32, f32) {
+fn get_shape_offset(shape: &CollisionShape, tile_size: Vec2) -> (f32, f32) {
     match shape {
         CollisionShape::Rectangle { offset, size } => (
             // X: offset + size/2 = center from tile origin, -0.5 = offset from tile center
-            (offset[0] + size[0] / 2.0 - 0.5) * tile_size,
+            (offset[0] + size[0] / 2.0 - 0.5) * tile_size.x,
             // Y: flip because editor uses Y-down (top=0), Bevy uses Y-up (bottom=0)
-            (0.5 - offset[1] - size[1] / 2.0) * tile_size,
+            (0.5 - offset[1] - size[1] / 2.0) * tile_size.y,
         ),
         CollisionShape::Circle { offset, .. } => (
-            (offset[0] - 0.5) * tile_size,
+            (offset[0] - 0.5) * tile_size.x,
             // Y: flip for same reason
-            (0.5 - offset[1]) * tile_size,
+            (0.5 - offset[1]) * tile_size.y,
         ),
         _ => (0.0, 0.0),
     }
@@ -281,3 +759,76 @@ impl Plugin for MapCollisionPlugin {
         // No-op when physics feature is disabled
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_map_core::OneWayDirection;
+
+    fn grid(rows: &[&str], data: &CollisionData) -> (u32, u32, Vec<Option<CollisionData>>) {
+        // Rows are given top first, cells are stored bottom first
+        let width = rows[0].len() as u32;
+        let height = rows.len() as u32;
+        let cells = rows
+            .iter()
+            .rev()
+            .flat_map(|row| row.chars())
+            .map(|c| (c == '#').then(|| data.clone()))
+            .collect();
+        (width, height, cells)
+    }
+
+    #[test]
+    fn test_merge_full_tiles() {
+        let (width, height, cells) = grid(
+            &[
+                "#...", //
+                "####", //
+                "####",
+            ],
+            &CollisionData::new(CollisionShape::Full),
+        );
+        let merged = merge_tile_colliders(width, height, &cells);
+        assert_eq!(merged.len(), 2);
+        assert_eq!((merged[0].x, merged[0].y), (0, 0));
+        assert_eq!((merged[0].width, merged[0].height), (4, 2));
+        assert_eq!((merged[1].x, merged[1].y), (0, 2));
+        assert_eq!((merged[1].width, merged[1].height), (1, 1));
+    }
+
+    #[test]
+    fn test_merge_respects_collision_data() {
+        let solid = CollisionData::new(CollisionShape::Full);
+        let mut platform = solid.clone();
+        platform.one_way = OneWayDirection::Top;
+        let (width, height, mut cells) = grid(&["####"], &solid);
+        cells[2] = Some(platform.clone());
+        cells[3] = Some(platform);
+
+        let merged = merge_tile_colliders(width, height, &cells);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].width, 2);
+        assert_eq!(merged[1].collision.one_way, OneWayDirection::Top);
+    }
+
+    #[test]
+    fn test_merge_partial_rectangles_along_spanned_axis() {
+        // Half-height slabs join into rows but never stack into columns
+        let slab = CollisionData::new(CollisionShape::Rectangle {
+            offset: [0.0, 0.5],
+            size: [1.0, 0.5],
+        });
+        let (width, height, cells) = grid(&["###", "###"], &slab);
+        let merged = merge_tile_colliders(width, height, &cells);
+        assert_eq!(merged.len(), 2);
+        assert!(merged.iter().all(|m| m.width == 3 && m.height == 1));
+
+        // Circles are never merged
+        let circle = CollisionData::new(CollisionShape::Circle {
+            offset: [0.5, 0.5],
+            radius: 0.5,
+        });
+        let (width, height, cells) = grid(&["##"], &circle);
+        assert!(merge_tile_colliders(width, height, &cells).is_empty());
+    }
+}
@@ -0,0 +1,550 @@
+//! Runtime tile editing
+//!
+//! [`MapEditCommands`] changes the tiles of a spawned map while the game runs,
+//! for destructible terrain or player building. Every edit updates the
+//! map's [`MapLevelData`], the rendered tilemaps and sprites, and (with
+//! `MapCollisionPlugin`) the tile colliders, so all of them keep matching
+//! the data the editor wrote.
+//!
+//! Positions are in world space. The `map` of an edit is either the entity
+//! holding the `MapHandle` or the spawned map root.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use bevy::prelude::*;
+//! use bevy_map_runtime::MapEditCommands;
+//!
+//! fn dig(mut edits: MapEditCommands, map: Single<Entity, With<MapHandle>>, player: Single<&Transform, With<Player>>) {
+//!     let below = player.translation.truncate() - Vec2::new(0.0, 16.0);
+//!     edits.clear_tile(*map, "Ground", below);
+//! }
+//!
+//! fn build(mut edits: MapEditCommands, map: Single<Entity, With<MapHandle>>, cursor: Res<CursorWorldPos>) {
+//!     edits.paint_terrain(*map, "Ground", cursor.0, "Cave", "Rock");
+//! }
+//! ```
+//!
+//! Listen for [`MapTilesChanged`] to react to edits, e.g. to update pathfinding.
+
+use bevy::ecs::message::{Message, MessageReader, MessageWriter};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+use bevy_map_autotile::{get_paint_target, paint_terrain_at_target, AutotileConfig};
+use bevy_map_core::{LayerData, Level, MapProject, Tileset};
+use std::collections::HashMap;
+
+use crate::{
+    insert_layer_tilemap, spawn_multi_cell_tile, LayerStyle, LayerTile, LevelGrid, MapChunk,
+    MapChunks, MapLayerIndex, MapLayerSettings, MapLayers, MapRoot, MultiCellTile,
+    TilemapImageIndex,
+};
+
+/// Live copy of a spawned map's level data
+///
+/// Inserted on the map root. Runtime edits change this copy rather than the
+/// `MapProject` asset, so editing a map doesn't trigger a hot-reload.
+#[derive(Component, Debug, Clone)]
+pub struct MapLevelData(pub Level);
+
+/// Layer targeted by an edit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditLayer {
+    /// Layer at this index in the level
+    Index(usize),
+    /// First layer with this name
+    Name(String),
+}
+
+impl EditLayer {
+    /// Find the index of the layer in a level
+    pub fn resolve(&self, level: &Level) -> Option<usize> {
+        match self {
+            EditLayer::Index(index) => (*index < level.layers.len()).then_some(*index),
+            EditLayer::Name(name) => level.layers.iter().position(|l| &l.name == name),
+        }
+    }
+}
+
+impl From<usize> for EditLayer {
+    fn from(index: usize) -> Self {
+        EditLayer::Index(index)
+    }
+}
+
+impl From<&str> for EditLayer {
+    fn from(name: &str) -> Self {
+        EditLayer::Name(name.to_string())
+    }
+}
+
+impl From<String> for EditLayer {
+    fn from(name: String) -> Self {
+        EditLayer::Name(name)
+    }
+}
+
+/// A change to one tile layer
+#[derive(Debug, Clone, PartialEq)]
+pub enum MapEdit {
+    /// Place a tile by its tileset index, optionally with flip flags
+    ///
+    /// Multi-cell tiles are placed with their base cell at the position.
+    SetTile(u32),
+    /// Remove the tile at the position, including all cells of a multi-cell tile
+    ClearTile,
+    /// Paint a terrain at the position and autotile the tiles around it
+    PaintTerrain {
+        /// Name of the terrain set, which must belong to the layer's tileset
+        terrain_set: String,
+        /// Name of the terrain within the set
+        terrain: String,
+    },
+}
+
+/// Message requesting a tile edit on a spawned map
+///
+/// Usually written through [`MapEditCommands`].
+#[derive(Message, Debug, Clone)]
+pub struct MapEditEvent {
+    /// The `MapHandle` entity or the map root
+    pub map: Entity,
+    /// Layer to edit
+    pub layer: EditLayer,
+    /// World position of the edit
+    pub position: Vec2,
+    /// What to change
+    pub edit: MapEdit,
+}
+
+/// Message sent after an edit changed the tiles of a map
+#[derive(Message, Debug, Clone)]
+pub struct MapTilesChanged {
+    /// The map root
+    pub map: Entity,
+    /// Index of the edited layer
+    pub layer_index: usize,
+    /// Cells whose tile changed
+    pub cells: Vec<UVec2>,
+}
+
+/// System param for editing the tiles of spawned maps
+///
+/// Edits are applied in `Update` by [`apply_map_edits`].
+#[derive(SystemParam)]
+pub struct MapEditCommands<'w> {
+    edits: MessageWriter<'w, MapEditEvent>,
+}
+
+impl MapEditCommands<'_> {
+    /// Place a tile at a world position
+    pub fn set_tile(
+        &mut self,
+        map: Entity,
+        layer: impl Into<EditLayer>,
+        position: Vec2,
+        tile: u32,
+    ) {
+        self.edit(map, layer, position, MapEdit::SetTile(tile));
+    }
+
+    /// Remove the tile at a world position
+    pub fn clear_tile(&mut self, map: Entity, layer: impl Into<EditLayer>, position: Vec2) {
+        self.edit(map, layer, position, MapEdit::ClearTile);
+    }
+
+    /// Paint a terrain at a world position, autotiling its neighbours
+    pub fn paint_terrain(
+        &mut self,
+        map: Entity,
+        layer: impl Into<EditLayer>,
+        position: Vec2,
+        terrain_set: impl Into<String>,
+        terrain: impl Into<String>,
+    ) {
+        self.edit(
+            map,
+            layer,
+            position,
+            MapEdit::PaintTerrain {
+                terrain_set: terrain_set.into(),
+                terrain: terrain.into(),
+            },
+        );
+    }
+
+    /// Request any edit
+    pub fn edit(
+        &mut self,
+        map: Entity,
+        layer: impl Into<EditLayer>,
+        position: Vec2,
+        edit: MapEdit,
+    ) {
+        self.edits.write(MapEditEvent {
+            map,
+            layer: layer.into(),
+            position,
+            edit,
+        });
+    }
+}
+
+/// Apply pending [`MapEditEvent`]s to the level data, tilemaps and sprites
+pub fn apply_map_edits(
+    mut commands: Commands,
+    mut edits: MessageReader<MapEditEvent>,
+    mut changed_events: MessageWriter<MapTilesChanged>,
+    map_assets: Res<Assets<MapProject>>,
+    mut maps: Query<(
+        &MapRoot,
+        &mut MapLevelData,
+        Option<&MapLayers>,
+        &GlobalTransform,
+    )>,
+    children: Query<&Children>,
+    mut tile_entities: MapTileEntities,
+) {
+    for edit in edits.read() {
+        // Accept both the MapHandle entity and the map root
+        let map_entity = if maps.contains(edit.map) {
+            Some(edit.map)
+        } else {
+            children
+                .get(edit.map)
+                .ok()
+                .and_then(|children| children.iter().find(|child| maps.contains(*child)))
+        };
+        let Some(map_entity) = map_entity else {
+            warn!("Map edit targets {:?}, which has no spawned map", edit.map);
+            continue;
+        };
+        let Ok((map_root, mut level_data, map_layers, transform)) = maps.get_mut(map_entity) else {
+            continue;
+        };
+        let Some(project) = map_assets.get(&map_root.handle) else {
+            continue;
+        };
+        let level = &mut level_data.0;
+
+        let Some(layer_index) = edit.layer.resolve(level) else {
+            warn!("Map edit targets missing layer {:?}", edit.layer);
+            continue;
+        };
+        let Some(LayerData::Tiles {
+            tileset_id, tiles, ..
+        }) = level.layers.get(layer_index).map(|l| &l.data)
+        else {
+            warn!(
+                "Map edit targets layer {}, which is not a tile layer",
+                layer_index
+            );
+            continue;
+        };
+        let Some(tileset) = project.get_tileset(*tileset_id) else {
+            continue;
+        };
+        let Some(effective) = level.effective_layer(layer_index) else {
+            continue;
+        };
+        let style = LayerStyle::new(&level.layers[layer_index], &effective);
+        let before = tiles.clone();
+
+        // World position to the layer's map space
+        let tile_size = map_root.textures.tile_size;
+        let grid = LevelGrid::new(level, tile_size);
+        let position = transform
+            .affine()
+            .inverse()
+            .transform_point3(edit.position.extend(0.0))
+            .truncate()
+            - style.offset;
+
+        match &edit.edit {
+            MapEdit::SetTile(tile) => {
+                let Some(cell) = grid.cell_at(position) else {
+                    continue;
+                };
+                let grid_size = tileset.get_tile_grid_size(bevy_map_core::tile_index(*tile));
+                level.place_tile(layer_index, cell.x, cell.y, *tile, grid_size);
+            }
+            MapEdit::ClearTile => {
+                let Some(cell) = grid.cell_at(position) else {
+                    continue;
+                };
+                level.erase_tile(layer_index, cell.x, cell.y);
+            }
+            MapEdit::PaintTerrain {
+                terrain_set,
+                terrain,
+            } => {
+                let config: Option<AutotileConfig> = project
+                    .autotile_config
+                    .clone()
+                    .and_then(|config| serde_json::from_value(config).ok());
+                let Some(set) = config.as_ref().and_then(|config| {
+                    config
+                        .terrain_sets
+                        .iter()
+                        .find(|s| &s.name == terrain_set && s.tileset_id == tileset.id)
+                }) else {
+                    warn!(
+                        "Terrain set '{}' not found for tileset '{}'",
+                        terrain_set, tileset.name
+                    );
+                    continue;
+                };
+                let Some(terrain_index) = set.get_terrain_index(terrain) else {
+                    warn!("Terrain '{}' not found in set '{}'", terrain, set.name);
+                    continue;
+                };
+
+                // The paint target is relative to the level's bottom-left
+                // corner, in a grid of square cells
+                let corner_position = (position - grid.cell_corner(0, 0)) / tile_size * tile_size.x;
+                let target = get_paint_target(
+                    corner_position.x,
+                    corner_position.y,
+                    tile_size.x,
+                    set.set_type,
+                );
+                let (width, height) = (level.width, level.height);
+                if let Some(LayerData::Tiles { tiles, .. }) =
+                    level.layers.get_mut(layer_index).map(|l| &mut l.data)
+                {
+                    paint_terrain_at_target(tiles, width, height, target, set, terrain_index);
+                }
+            }
+        }
+
+        let Some(LayerData::Tiles { tiles, .. }) = level.layers.get(layer_index).map(|l| &l.data)
+        else {
+            continue;
+        };
+        let cells: Vec<UVec2> = tiles
+            .iter()
+            .zip(before.iter())
+            .enumerate()
+            .filter(|(_, (new, old))| new != old)
+            .map(|(idx, _)| UVec2::new(idx as u32 % level.width, idx as u32 / level.width))
+            .collect();
+        if cells.is_empty() {
+            continue;
+        }
+
+        let layer_settings = map_layers.map(|l| l.settings).unwrap_or_default();
+        tile_entities.redraw_cells(
+            &mut commands,
+            map_entity,
+            map_root,
+            &layer_settings,
+            level,
+            layer_index,
+            tileset,
+            &cells,
+        );
+
+        changed_events.write(MapTilesChanged {
+            map: map_entity,
+            layer_index,
+            cells,
+        });
+    }
+}
+
+/// Tilemaps and multi-cell tile sprites of spawned maps
+#[derive(SystemParam)]
+pub(crate) struct MapTileEntities<'w, 's> {
+    tilemaps: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static ChildOf,
+            &'static MapLayerIndex,
+            &'static TilemapImageIndex,
+            &'static MapChunk,
+            &'static mut TileStorage,
+        ),
+    >,
+    multi_cell_tiles: Query<'w, 's, (Entity, &'static ChildOf, &'static MultiCellTile)>,
+    chunks: Query<'w, 's, &'static MapChunks>,
+}
+
+impl MapTileEntities<'_, '_> {
+    /// Redraw cells of a tile layer from the level data
+    ///
+    /// Layers that were never spawned are left alone, and so are cells in
+    /// chunks that are not loaded.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn redraw_cells(
+        &mut self,
+        commands: &mut Commands,
+        map_entity: Entity,
+        map_root: &MapRoot,
+        layer_settings: &MapLayerSettings,
+        level: &Level,
+        layer_index: usize,
+        tileset: &Tileset,
+        cells: &[UVec2],
+    ) {
+        let Some(effective) = level.effective_layer(layer_index) else {
+            return;
+        };
+        if !layer_settings.should_spawn(effective.visible) {
+            return;
+        }
+        let style = LayerStyle::new(&level.layers[layer_index], &effective);
+        let grid = LevelGrid::new(level, map_root.textures.tile_size);
+        let chunks = self.chunks.get(map_entity).ok();
+        let level_size = UVec2::new(level.width, level.height);
+        let area_at = |cell: UVec2| match chunks {
+            Some(chunks) => chunks.chunk_at(cell, level_size),
+            None => MapChunk::whole(level.width, level.height),
+        };
+
+        // What is currently drawn for this layer, by image and chunk
+        let mut layer_tilemaps: HashMap<(usize, UVec2), Entity> = HashMap::new();
+        for (entity, child_of, index, image, chunk, _) in self.tilemaps.iter() {
+            if child_of.parent() == map_entity && index.0 == layer_index {
+                layer_tilemaps.insert((image.0, chunk.coord), entity);
+            }
+        }
+        let mut layer_sprites: HashMap<(u32, u32), Entity> = HashMap::new();
+        for (entity, child_of, tile) in self.multi_cell_tiles.iter() {
+            if child_of.parent() == map_entity && tile.layer_index == layer_index {
+                layer_sprites.insert((tile.x, tile.y), entity);
+            }
+        }
+        let mut new_tilemaps: HashMap<(usize, UVec2), (Entity, MapChunk, TileStorage)> =
+            HashMap::new();
+
+        for &cell in cells {
+            let area = area_at(cell);
+            let tile_pos = TilePos {
+                x: cell.x - area.origin.x,
+                y: cell.y - area.origin.y,
+            };
+
+            // Remove what is drawn at the cell now
+            for (&(_, coord), &tilemap_entity) in layer_tilemaps.iter() {
+                if coord != area.coord {
+                    continue;
+                }
+                if let Ok((_, _, _, _, _, mut storage)) = self.tilemaps.get_mut(tilemap_entity) {
+                    if let Some(tile_entity) = storage.get(&tile_pos) {
+                        commands.entity(tile_entity).despawn();
+                        storage.remove(&tile_pos);
+                    }
+                }
+            }
+            if let Some(sprite_entity) = layer_sprites.remove(&(cell.x, cell.y)) {
+                commands.entity(sprite_entity).despawn();
+            }
+
+            // Unloaded chunks are drawn from the level data when they load
+            if chunks.is_some_and(|chunks| !chunks.is_loaded(area.coord)) {
+                continue;
+            }
+
+            // Draw the new tile
+            let Some(raw) = level.get_tile(layer_index, cell.x, cell.y) else {
+                continue;
+            };
+            if bevy_map_core::is_occupied_cell(raw) {
+                continue;
+            }
+            if tileset.is_multi_cell_tile(bevy_map_core::tile_index(raw)) {
+                spawn_multi_cell_tile(
+                    commands,
+                    map_entity,
+                    &grid,
+                    tileset,
+                    &map_root.textures,
+                    layer_index,
+                    cell.x,
+                    cell.y,
+                    raw,
+                    layer_settings,
+                    &style,
+                );
+                continue;
+            }
+            let Some((image_index, tile)) = LayerTile::new(tileset, cell.x, cell.y, raw) else {
+                continue;
+            };
+
+            if let Some(&tilemap_entity) = layer_tilemaps.get(&(image_index, area.coord)) {
+                let tile_entity = tile.spawn(commands, tilemap_entity, &area, style.color);
+                if let Ok((_, _, _, _, _, mut storage)) = self.tilemaps.get_mut(tilemap_entity) {
+                    storage.set(&tile_pos, tile_entity);
+                }
+            } else {
+                // First tile of this image in the area gets a new tilemap
+                let (tilemap_entity, _, storage) = new_tilemaps
+                    .entry((image_index, area.coord))
+                    .or_insert_with(|| {
+                        let size = TilemapSize {
+                            x: area.size.x,
+                            y: area.size.y,
+                        };
+                        (commands.spawn_empty().id(), area, TileStorage::empty(size))
+                    });
+                let tile_entity = tile.spawn(commands, *tilemap_entity, &area, style.color);
+                storage.set(&tile_pos, tile_entity);
+            }
+        }
+
+        for ((image_index, _), (tilemap_entity, area, storage)) in new_tilemaps {
+            let Some(texture) = map_root.textures.get(tileset.id, image_index) else {
+                warn!(
+                    "Missing texture for tileset {} image {}",
+                    tileset.id, image_index
+                );
+                commands.entity(tilemap_entity).despawn();
+                continue;
+            };
+            insert_layer_tilemap(
+                commands,
+                map_entity,
+                tilemap_entity,
+                &grid,
+                &level.layers[layer_index],
+                layer_index,
+                tileset,
+                image_index,
+                texture.clone(),
+                storage,
+                area,
+                layer_settings.image_z(layer_index, image_index),
+                &style,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_map_core::Layer;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_edit_layer_resolve() {
+        let mut level = Level::new("Test".to_string(), 4, 4);
+        level.add_layer(Layer::new_tile_layer(
+            "Ground".to_string(),
+            Uuid::new_v4(),
+            4,
+            4,
+        ));
+        level.add_layer(Layer::new_object_layer("Objects".to_string()));
+
+        assert_eq!(EditLayer::from(1).resolve(&level), Some(1));
+        assert_eq!(EditLayer::from(2).resolve(&level), None);
+        assert_eq!(EditLayer::from("Objects").resolve(&level), Some(1));
+        assert_eq!(EditLayer::from("Ground").resolve(&level), Some(0));
+        assert_eq!(EditLayer::from("Missing").resolve(&level), None);
+    }
+}
@@ -0,0 +1,228 @@
+//! Entity references resolved to spawned entities
+//!
+//! Schema properties of type `ref`, and arrays of a data type, hold the IDs
+//! of other `EntityInstance`s: a switch pointing at a door, or a guard
+//! pointing at its patrol waypoints. Map entities with such properties get an
+//! [`EntityRefs`] component that resolves them to the spawned entities.
+//!
+//! References are looked up in the [`MapEntities`] of every spawned map, so a
+//! reference into another level of the same world resolves once both levels
+//! are loaded, and goes back to unresolved when the other level unloads.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use bevy::prelude::*;
+//! use bevy_map_runtime::EntityRefs;
+//!
+//! fn open_doors(
+//!     switches: Query<(&Switch, &EntityRefs), Changed<Switch>>,
+//!     mut doors: Query<&mut Door>,
+//! ) {
+//!     for (switch, refs) in switches.iter() {
+//!         if let Some(mut door) = refs.get("target").and_then(|e| doors.get_mut(e).ok()) {
+//!             door.open = switch.on;
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use bevy_map_core::{MapProject, Value};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{EntityProperties, MapEntities, MapEntityMarker, MapRoot};
+
+/// A reference to an `EntityInstance` and the entity spawned for it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntityRef {
+    /// ID of the referenced `EntityInstance`
+    pub instance_id: Uuid,
+    /// The spawned entity, or `None` while its level is not loaded
+    pub entity: Option<Entity>,
+}
+
+/// Reference properties of a map entity, resolved to spawned entities
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct EntityRefs {
+    refs: HashMap<String, Vec<EntityRef>>,
+}
+
+impl EntityRefs {
+    /// Get the entity a property points at
+    ///
+    /// For array properties this is the first entry. Returns `None` if the
+    /// property is unset or the referenced entity is not spawned.
+    pub fn get(&self, key: &str) -> Option<Entity> {
+        self.refs.get(key)?.first()?.entity
+    }
+
+    /// Iterate over the spawned entities a property points at, in order
+    pub fn get_all(&self, key: &str) -> impl Iterator<Item = Entity> + '_ {
+        self.refs(key).iter().filter_map(|r| r.entity)
+    }
+
+    /// Get the references of a property, including unresolved ones
+    pub fn refs(&self, key: &str) -> &[EntityRef] {
+        self.refs.get(key).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Iterate over property names and their references
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[EntityRef])> {
+        self.refs
+            .iter()
+            .map(|(key, refs)| (key.as_str(), refs.as_slice()))
+    }
+
+    /// Check if every reference points at a spawned entity
+    pub fn is_resolved(&self) -> bool {
+        self.refs.values().flatten().all(|r| r.entity.is_some())
+    }
+
+    /// Check if no reference properties are set
+    pub fn is_empty(&self) -> bool {
+        self.refs.is_empty()
+    }
+
+    /// Resolve the reference properties named by the schema
+    fn resolve(
+        properties: &EntityProperties,
+        ref_properties: &[String],
+        entities: &HashMap<Uuid, Entity>,
+    ) -> Self {
+        let mut refs = HashMap::new();
+        for key in ref_properties {
+            let ids: Vec<Uuid> = match properties.get(key) {
+                Some(Value::String(id)) => Uuid::parse_str(id).ok().into_iter().collect(),
+                Some(Value::Array(items)) => items
+                    .iter()
+                    .filter_map(|item| item.as_string())
+                    .filter_map(|id| Uuid::parse_str(id).ok())
+                    .collect(),
+                _ => Vec::new(),
+            };
+            if ids.is_empty() {
+                continue;
+            }
+            let resolved = ids
+                .into_iter()
+                .map(|instance_id| EntityRef {
+                    instance_id,
+                    entity: entities.get(&instance_id).copied(),
+                })
+                .collect();
+            refs.insert(key.clone(), resolved);
+        }
+        Self { refs }
+    }
+}
+
+/// System that keeps [`EntityRefs`] in sync with the spawned maps
+///
+/// Runs when a map spawns, changes or despawns, or when entity properties
+/// change, e.g. on hot-reload.
+pub fn resolve_entity_refs(
+    mut commands: Commands,
+    maps: Query<(&MapEntities, Option<&MapRoot>)>,
+    changed_maps: Query<(), Changed<MapEntities>>,
+    mut removed_maps: RemovedComponents<MapEntities>,
+    changed_properties: Query<(), Changed<EntityProperties>>,
+    entities: Query<(
+        Entity,
+        &ChildOf,
+        &MapEntityMarker,
+        &EntityProperties,
+        Option<&EntityRefs>,
+    )>,
+    map_assets: Res<Assets<MapProject>>,
+) {
+    let maps_removed = removed_maps.read().count() > 0;
+    if !maps_removed && changed_maps.is_empty() && changed_properties.is_empty() {
+        return;
+    }
+
+    // Instances of every spawned map, so references can cross levels
+    let spawned: HashMap<Uuid, Entity> = maps
+        .iter()
+        .flat_map(|(map_entities, _)| map_entities.iter())
+        .collect();
+
+    for (entity, child_of, marker, properties, current) in entities.iter() {
+        let ref_properties = maps
+            .get(child_of.parent())
+            .ok()
+            .and_then(|(_, map_root)| map_root)
+            .and_then(|map_root| map_assets.get(&map_root.handle))
+            .and_then(|project| project.ref_properties.get(&marker.type_name));
+        let refs = ref_properties
+            .map(|names| EntityRefs::resolve(properties, names, &spawned))
+            .unwrap_or_default();
+
+        if refs.is_empty() {
+            if current.is_some() {
+                commands.entity(entity).remove::<EntityRefs>();
+            }
+        } else if current != Some(&refs) {
+            commands.entity(entity).insert(refs);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_map_core::ComponentOverrides;
+
+    #[test]
+    fn test_resolve_entity_refs() {
+        let door = Uuid::new_v4();
+        let near = Uuid::new_v4();
+        let far = Uuid::new_v4();
+        let door_entity = Entity::from_raw_u32(1).unwrap();
+        let near_entity = Entity::from_raw_u32(2).unwrap();
+        let spawned = HashMap::from([(door, door_entity), (near, near_entity)]);
+
+        let properties = EntityProperties {
+            properties: HashMap::from([
+                ("target".to_string(), Value::String(door.to_string())),
+                (
+                    "waypoints".to_string(),
+                    Value::Array(vec![
+                        Value::String(near.to_string()),
+                        Value::Null,
+                        Value::String(far.to_string()),
+                    ]),
+                ),
+                ("unset".to_string(), Value::Null),
+                ("label".to_string(), Value::String(door.to_string())),
+            ]),
+            component_overrides: ComponentOverrides::default(),
+        };
+        let names = ["target", "waypoints", "unset", "missing"].map(String::from);
+
+        let refs = EntityRefs::resolve(&properties, &names, &spawned);
+        assert_eq!(refs.get("target"), Some(door_entity));
+        assert_eq!(
+            refs.get_all("waypoints").collect::<Vec<_>>(),
+            vec![near_entity]
+        );
+        assert_eq!(refs.refs("waypoints").len(), 2);
+        assert_eq!(refs.refs("waypoints")[1].instance_id, far);
+        assert_eq!(refs.get("unset"), None);
+        // Only properties the schema marks as references are resolved
+        assert_eq!(refs.get("label"), None);
+        assert!(!refs.is_resolved());
+
+        // Loading the other level resolves the rest
+        let mut all = spawned.clone();
+        let far_entity = Entity::from_raw_u32(3).unwrap();
+        all.insert(far, far_entity);
+        let refs = EntityRefs::resolve(&properties, &names, &all);
+        assert!(refs.is_resolved());
+        assert_eq!(
+            refs.get_all("waypoints").collect::<Vec<_>>(),
+            vec![near_entity, far_entity]
+        );
+    }
+}
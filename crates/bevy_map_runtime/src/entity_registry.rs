@@ -9,6 +9,9 @@ use std::collections::HashMap;
 use std::marker::PhantomData;
 use uuid::Uuid;
 
+use crate::hot_reload::MapReloadHooks;
+use crate::regions::MapRegion;
+
 /// Trait implemented by entities that can be spawned from map data.
 ///
 /// This trait is typically implemented via the `#[derive(MapEntity)]` macro
@@ -62,6 +65,70 @@ pub struct MapEntityMarker {
     pub type_name: String,
 }
 
+/// Width and height of an entity whose type is resizable in the schema
+///
+/// The size is in world units, before the entity's `Transform` scale.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct EntitySize(pub Vec2);
+
+impl EntitySize {
+    /// Get half the width and height
+    pub fn half_extents(&self) -> Vec2 {
+        self.0 / 2.0
+    }
+}
+
+/// Get the local transform of a placed entity
+///
+/// Combines the instance's position, rotation (degrees, counter-clockwise)
+/// and scale.
+pub fn instance_transform(instance: &EntityInstance) -> Transform {
+    Transform {
+        translation: Vec3::new(instance.position[0], instance.position[1], 0.0),
+        rotation: Quat::from_rotation_z(instance.rotation_radians()),
+        scale: Vec3::new(instance.scale[0], instance.scale[1], 1.0),
+    }
+}
+
+/// Entities spawned from a map's data, inserted on the map root
+///
+/// Spawned entities are children of the map root, so they move with the map
+/// and are despawned together with it.
+#[derive(Component, Debug, Clone, Default)]
+pub struct MapEntities {
+    entities: HashMap<Uuid, Entity>,
+}
+
+impl MapEntities {
+    /// Get the entity spawned for an `EntityInstance` ID
+    pub fn get(&self, instance_id: Uuid) -> Option<Entity> {
+        self.entities.get(&instance_id).copied()
+    }
+
+    /// Iterate over instance IDs and their spawned entities
+    pub fn iter(&self) -> impl Iterator<Item = (Uuid, Entity)> + '_ {
+        self.entities.iter().map(|(id, entity)| (*id, *entity))
+    }
+
+    /// Get the number of spawned entities
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Check if no entities were spawned
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    pub(crate) fn insert(&mut self, instance_id: Uuid, entity: Entity) {
+        self.entities.insert(instance_id, entity);
+    }
+
+    pub(crate) fn remove(&mut self, instance_id: Uuid) -> Option<Entity> {
+        self.entities.remove(&instance_id)
+    }
+}
+
 /// Raw properties from the map editor, accessible by runtime systems
 ///
 /// This component provides zero-code access to entity properties defined in the map editor.
@@ -189,7 +256,21 @@ pub struct Dialogue {
 
 /// Trait object for spawning entities
 trait EntitySpawner: Send + Sync {
-    fn spawn(&self, commands: &mut Commands, instance: &EntityInstance, transform: Transform);
+    fn spawn(
+        &self,
+        commands: &mut Commands,
+        instance: &EntityInstance,
+        transform: Transform,
+    ) -> Entity;
+
+    /// Insert a fresh typed component unless it is preserved across reloads
+    fn refresh(
+        &self,
+        commands: &mut Commands,
+        entity: Entity,
+        instance: &EntityInstance,
+        hooks: &MapReloadHooks,
+    );
 }
 
 /// Generic spawner implementation for any MapEntityType
@@ -198,7 +279,12 @@ struct TypedSpawner<T: MapEntityType> {
 }
 
 impl<T: MapEntityType> EntitySpawner for TypedSpawner<T> {
-    fn spawn(&self, commands: &mut Commands, instance: &EntityInstance, transform: Transform) {
+    fn spawn(
+        &self,
+        commands: &mut Commands,
+        instance: &EntityInstance,
+        transform: Transform,
+    ) -> Entity {
         let component = T::from_instance(instance);
 
         // Parse entity color from instance if available, otherwise use a default
@@ -207,29 +293,47 @@ impl<T: MapEntityType> EntitySpawner for TypedSpawner<T> {
             .and_then(parse_hex_color)
             .unwrap_or(Color::srgba(0.2, 0.6, 1.0, 0.8)); // Default blue
 
-        // Get marker size from instance or use default
+        // Sized entities show their area, others a marker of the configured size
         let marker_size = instance.get_float("_editor_marker_size").unwrap_or(16.0) as f32;
+        let placeholder_size = instance
+            .size
+            .map(Vec2::from)
+            .unwrap_or(Vec2::splat(marker_size));
+
+        commands
+            .spawn((
+                component,
+                transform,
+                // Required for visibility
+                Visibility::default(),
+                // Placeholder visual - colored rectangle
+                Sprite {
+                    color,
+                    custom_size: Some(placeholder_size),
+                    ..default()
+                },
+                MapEntityMarker {
+                    instance_id: instance.id,
+                    type_name: instance.type_name.clone(),
+                },
+                EntityProperties {
+                    properties: instance.properties.clone(),
+                    component_overrides: instance.component_overrides.clone(),
+                },
+            ))
+            .id()
+    }
 
-        commands.spawn((
-            component,
-            transform,
-            // Required for visibility
-            Visibility::default(),
-            // Placeholder visual - colored rectangle
-            Sprite {
-                color,
-                custom_size: Some(Vec2::splat(marker_size)),
-                ..default()
-            },
-            MapEntityMarker {
-                instance_id: instance.id,
-                type_name: instance.type_name.clone(),
-            },
-            EntityProperties {
-                properties: instance.properties.clone(),
-                component_overrides: instance.component_overrides.clone(),
-            },
-        ));
+    fn refresh(
+        &self,
+        commands: &mut Commands,
+        entity: Entity,
+        instance: &EntityInstance,
+        hooks: &MapReloadHooks,
+    ) {
+        if !hooks.is_preserved::<T>() {
+            commands.entity(entity).insert(T::from_instance(instance));
+        }
     }
 }
 
@@ -303,41 +407,83 @@ impl EntityRegistry {
     ///
     /// Returns true if the entity type was registered, false otherwise.
     /// Note: Unregistered entities are still spawned with a placeholder visual
-    /// so they're visible in the game for debugging purposes.
+    /// so they're visible in the game for debugging purposes. Regions are
+    /// areas rather than objects, so unregistered ones are spawned invisible.
     pub fn spawn(
         &self,
         commands: &mut Commands,
         instance: &EntityInstance,
         base_transform: Transform,
     ) -> bool {
-        // Create transform from instance position + base transform
-        let entity_transform =
-            base_transform * Transform::from_xyz(instance.position[0], instance.position[1], 0.0);
+        // Create transform from the instance's position, rotation and scale + base transform
+        let entity_transform = base_transform * instance_transform(instance);
+        self.spawn_instance(commands, instance, entity_transform).1
+    }
 
-        if let Some(spawner) = self.spawners.get(&instance.type_name) {
-            spawner.spawn(commands, instance, entity_transform);
-            true
+    /// Spawn an entity with its final transform, returning it and whether
+    /// its type was registered
+    fn spawn_instance(
+        &self,
+        commands: &mut Commands,
+        instance: &EntityInstance,
+        transform: Transform,
+    ) -> (Entity, bool) {
+        let (entity, registered) = if let Some(spawner) = self.spawners.get(&instance.type_name) {
+            (spawner.spawn(commands, instance, transform), true)
+        } else if instance.is_region() {
+            // Regions usually need no game component, only enter/exit events
+            let entity = commands
+                .spawn((
+                    transform,
+                    Visibility::default(),
+                    MapEntityMarker {
+                        instance_id: instance.id,
+                        type_name: instance.type_name.clone(),
+                    },
+                    EntityProperties {
+                        properties: instance.properties.clone(),
+                        component_overrides: instance.component_overrides.clone(),
+                    },
+                ))
+                .id();
+            (entity, false)
         } else {
+            warn!(
+                "Entity type '{}' not registered - spawned with red placeholder (use .register_map_entity::<YourType>() to register)",
+                instance.type_name
+            );
             // Spawn unregistered entities with a placeholder visual (red = unregistered)
-            commands.spawn((
-                entity_transform,
-                Visibility::default(),
-                Sprite {
-                    color: Color::srgba(1.0, 0.2, 0.2, 0.8), // Red for unregistered
-                    custom_size: Some(Vec2::splat(16.0)),
-                    ..default()
-                },
-                MapEntityMarker {
-                    instance_id: instance.id,
-                    type_name: instance.type_name.clone(),
-                },
-                EntityProperties {
-                    properties: instance.properties.clone(),
-                    component_overrides: instance.component_overrides.clone(),
-                },
-            ));
-            false
+            let entity = commands
+                .spawn((
+                    transform,
+                    Visibility::default(),
+                    Sprite {
+                        color: Color::srgba(1.0, 0.2, 0.2, 0.8), // Red for unregistered
+                        custom_size: Some(
+                            instance.size.map(Vec2::from).unwrap_or(Vec2::splat(16.0)),
+                        ),
+                        ..default()
+                    },
+                    MapEntityMarker {
+                        instance_id: instance.id,
+                        type_name: instance.type_name.clone(),
+                    },
+                    EntityProperties {
+                        properties: instance.properties.clone(),
+                        component_overrides: instance.component_overrides.clone(),
+                    },
+                ))
+                .id();
+            (entity, false)
+        };
+
+        if let Some(size) = instance.size {
+            commands.entity(entity).insert(EntitySize(Vec2::from(size)));
         }
+        if let Some(region) = MapRegion::from_instance(instance) {
+            commands.entity(entity).insert(region);
+        }
+        (entity, registered)
     }
 
     /// Spawn all entities from a list of instances
@@ -351,17 +497,92 @@ impl EntityRegistry {
         instances: &[EntityInstance],
         base_transform: Transform,
     ) -> usize {
-        let mut unregistered = 0;
+        instances
+            .iter()
+            .filter(|instance| !self.spawn(commands, instance, base_transform))
+            .count()
+    }
+
+    /// Spawn all entities of a map as children of its root
+    ///
+    /// Positions are relative to the map root, so the entities move with the
+    /// map and are despawned together with it.
+    pub fn spawn_map_entities(
+        &self,
+        commands: &mut Commands,
+        instances: &[EntityInstance],
+        map_entity: Entity,
+    ) -> MapEntities {
+        let mut spawned = MapEntities::default();
         for instance in instances {
-            if !self.spawn(commands, instance, base_transform) {
-                warn!(
-                    "Entity type '{}' not registered - spawned with red placeholder (use .register_map_entity::<YourType>() to register)",
-                    instance.type_name
-                );
-                unregistered += 1;
+            let (entity, _) = self.spawn_instance(commands, instance, instance_transform(instance));
+            commands.entity(map_entity).add_child(entity);
+            spawned.entities.insert(instance.id, entity);
+        }
+        spawned
+    }
+
+    /// Update a spawned entity in place after its instance changed
+    ///
+    /// `EntityProperties`, the transform, `EntitySize`, `MapRegion` and the
+    /// typed component are replaced unless they are preserved across reloads.
+    pub(crate) fn refresh_instance(
+        &self,
+        commands: &mut Commands,
+        entity: Entity,
+        previous: &EntityInstance,
+        instance: &EntityInstance,
+        hooks: &MapReloadHooks,
+    ) {
+        if !hooks.is_preserved::<EntityProperties>() {
+            // Dialogue is attached again from the new properties
+            commands
+                .entity(entity)
+                .insert(EntityProperties {
+                    properties: instance.properties.clone(),
+                    component_overrides: instance.component_overrides.clone(),
+                })
+                .remove::<Dialogue>();
+        }
+        let moved = previous.position != instance.position
+            || previous.rotation != instance.rotation
+            || previous.scale != instance.scale;
+        if moved && !hooks.is_preserved::<Transform>() {
+            // Keep the z the entity was given after spawning
+            let target = instance_transform(instance);
+            commands.queue(move |world: &mut World| {
+                if let Some(mut transform) = world.get_mut::<Transform>(entity) {
+                    transform.translation.x = target.translation.x;
+                    transform.translation.y = target.translation.y;
+                    transform.rotation = target.rotation;
+                    transform.scale = target.scale;
+                }
+            });
+        }
+        if previous.size != instance.size && !hooks.is_preserved::<EntitySize>() {
+            match instance.size {
+                Some(size) => {
+                    commands.entity(entity).insert(EntitySize(Vec2::from(size)));
+                }
+                None => {
+                    commands.entity(entity).remove::<EntitySize>();
+                }
+            }
+        }
+        let reshaped = previous.region != instance.region || previous.size != instance.size;
+        if reshaped && !hooks.is_preserved::<MapRegion>() {
+            match MapRegion::from_instance(instance) {
+                Some(region) => {
+                    commands.entity(entity).insert(region);
+                }
+                None => {
+                    commands.entity(entity).remove::<MapRegion>();
+                }
             }
         }
-        unregistered
+        if let Some(spawner) = self.spawners.get(&instance.type_name) {
+            spawner.refresh(commands, entity, instance, hooks);
+        }
     }
 }
 
@@ -451,6 +672,7 @@ pub fn attach_dialogues(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bevy_map_core::RegionShape;
 
     #[derive(Component)]
     #[allow(dead_code)]
@@ -484,4 +706,103 @@ mod tests {
         assert!(registry.is_registered("TestEntity"));
         assert!(!registry.is_registered("OtherEntity"));
     }
+
+    /// Spawn instances as children of a new map entity at `map_transform`
+    fn spawn_instances(
+        registry: &EntityRegistry,
+        instances: &[EntityInstance],
+        map_transform: Transform,
+    ) -> (World, MapEntities) {
+        let mut world = World::new();
+        let map = world.spawn(map_transform).id();
+        let mut queue = bevy::ecs::world::CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let entities = registry.spawn_map_entities(&mut commands, instances, map);
+        queue.apply(&mut world);
+        (world, entities)
+    }
+
+    #[test]
+    fn test_spawn_map_entities_as_children() {
+        let mut registry = EntityRegistry::new();
+        registry.register::<TestEntity>();
+
+        let mut chest = EntityInstance::new("TestEntity".to_string(), [32.0, 16.0]);
+        chest.set_int("health", 5);
+        let unknown = EntityInstance::new("Unknown".to_string(), [0.0, 0.0]);
+
+        let (mut world, entities) = spawn_instances(
+            &registry,
+            &[chest.clone(), unknown.clone()],
+            Transform::from_xyz(100.0, 0.0, 0.0),
+        );
+
+        assert_eq!(entities.len(), 2);
+        let chest_entity = entities.get(chest.id).unwrap();
+        let map = world.get::<ChildOf>(chest_entity).unwrap().parent();
+        assert!(entities.get(unknown.id).is_some());
+        assert_eq!(world.get::<TestEntity>(chest_entity).unwrap().health, 5);
+        // Positions are relative to the map
+        assert_eq!(
+            world.get::<Transform>(chest_entity).unwrap().translation,
+            Vec3::new(32.0, 16.0, 0.0)
+        );
+        assert_eq!(world.get::<Children>(map).unwrap().len(), 2);
+
+        world.entity_mut(map).despawn();
+        assert!(world.get_entity(chest_entity).is_err());
+    }
+
+    #[test]
+    fn test_spawn_rotated_scaled_sized_entity() {
+        let registry = EntityRegistry::new();
+        let mut zone =
+            EntityInstance::new("Trigger".to_string(), [40.0, 8.0]).with_size(64.0, 16.0);
+        zone.rotation = 90.0;
+        zone.scale = [2.0, 1.0];
+        let marker = EntityInstance::new("Marker".to_string(), [0.0, 0.0]);
+
+        let (world, entities) = spawn_instances(
+            &registry,
+            &[zone.clone(), marker.clone()],
+            Transform::default(),
+        );
+
+        let zone_entity = entities.get(zone.id).unwrap();
+        let transform = world.get::<Transform>(zone_entity).unwrap();
+        assert_eq!(transform.translation, Vec3::new(40.0, 8.0, 0.0));
+        assert_eq!(transform.scale, Vec3::new(2.0, 1.0, 1.0));
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2), 1e-6));
+        assert_eq!(
+            world.get::<EntitySize>(zone_entity),
+            Some(&EntitySize(Vec2::new(64.0, 16.0)))
+        );
+        assert!(world
+            .get::<EntitySize>(entities.get(marker.id).unwrap())
+            .is_none());
+    }
+
+    #[test]
+    fn test_spawn_region_entity() {
+        let registry = EntityRegistry::new();
+        let exit = EntityInstance::new("LevelExit".to_string(), [0.0, 0.0])
+            .with_size(32.0, 64.0)
+            .with_region(RegionShape::Rectangle);
+
+        let (world, entities) =
+            spawn_instances(&registry, std::slice::from_ref(&exit), Transform::default());
+
+        let exit_entity = entities.get(exit.id).unwrap();
+        assert_eq!(
+            world.get::<MapRegion>(exit_entity),
+            Some(&MapRegion {
+                shape: RegionShape::Rectangle,
+                size: Vec2::new(32.0, 64.0),
+            })
+        );
+        // Unregistered regions get no placeholder sprite
+        assert!(world.get::<Sprite>(exit_entity).is_none());
+    }
 }
@@ -100,24 +100,24 @@ fn spawn_entity_sprites(
 
     for (entity, marker, entity_props) in entity_query.iter() {
         // Look up the type config for this entity
-        let Some(type_config) = project.get_entity_type_config(&marker.type_name) else {
-            continue;
-        };
-
-        // Check if sprite is configured
-        let Some(base_sprite_config) = &type_config.sprite else {
-            continue;
-        };
-
-        // Apply instance-level overrides if available
-        let sprite_config: SpriteConfig = if let Some(props) = entity_props {
-            if let Some(ref sprite_overrides) = props.component_overrides.sprite {
-                base_sprite_config.with_overrides(sprite_overrides)
-            } else {
-                base_sprite_config.clone()
+        let type_config = project.get_entity_type_config(&marker.type_name);
+        let sprite_overrides =
+            entity_props.and_then(|props| props.component_overrides.sprite.as_ref());
+
+        // Apply instance-level overrides if available. An override that picks a
+        // sprite sheet (e.g. from a template) works without a type-level sprite.
+        let sprite_config: SpriteConfig = match (
+            type_config.and_then(|config| config.sprite.as_ref()),
+            sprite_overrides,
+        ) {
+            (Some(base_sprite_config), Some(overrides)) => {
+                base_sprite_config.with_overrides(overrides)
+            }
+            (Some(base_sprite_config), None) => base_sprite_config.clone(),
+            (None, Some(overrides)) if overrides.sprite_sheet_id.is_some() => {
+                SpriteConfig::default().with_overrides(overrides)
             }
-        } else {
-            base_sprite_config.clone()
+            _ => continue,
         };
 
         // Use the merged sprite config (shadowing for cleaner code below)
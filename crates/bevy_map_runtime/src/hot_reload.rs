@@ -0,0 +1,681 @@
+//! Hot-reload of spawned maps
+//!
+//! When a map asset changes on disk, [`HotReloadMode::Diff`] (the default)
+//! compares the new project against the one the map was spawned from and
+//! patches the spawned map in place:
+//!
+//! - Removed entities are despawned and added ones spawned
+//! - Changed entities get new `EntityProperties`, position and typed component
+//! - Changed tiles are redrawn, and IntGrid values and level properties updated
+//!
+//! Entities that did not change are left alone, so runtime state such as
+//! health or door states survives a live-edit save. Tiles changed at runtime
+//! with `MapEditCommands` are kept unless the same cells changed in the file.
+//!
+//! Changes that cannot be patched, such as resizing the level, adding layers
+//! or editing tilesets, respawn the whole map as [`HotReloadMode::Respawn`]
+//! always does.
+//!
+//! Components registered with [`MapHotReloadExt::preserve_on_reload`] keep
+//! their runtime value in both modes: they are not overwritten when an entity
+//! is patched, and are copied onto respawned entities with the same
+//! `EntityInstance` ID.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use bevy::prelude::*;
+//! use bevy_map_runtime::{HotReloadMode, MapHotReloadExt, MapHotReloadSettings};
+//!
+//! App::new()
+//!     .add_plugins(MapRuntimePlugin)
+//!     // Keep health and where the player walked to
+//!     .preserve_on_reload::<Health>()
+//!     .preserve_on_reload::<Transform>()
+//!     // Or always respawn the whole map
+//!     .insert_resource(MapHotReloadSettings::new().with_mode(HotReloadMode::Respawn));
+//! ```
+
+use bevy::ecs::message::MessageWriter;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_map_core::{EntityInstance, LayerData, MapProject};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::edit::{MapLevelData, MapTileEntities, MapTilesChanged};
+use crate::{
+    EntityRegistry, LevelProperties, MapDialogues, MapEntities, MapIntGrids, MapLayers, MapRoot,
+};
+
+/// How spawned maps react to their asset changing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HotReloadMode {
+    /// Despawn the map and spawn it again from the new data
+    Respawn,
+    /// Patch the spawned map with what changed, respawning only if needed
+    #[default]
+    Diff,
+}
+
+/// Settings for map hot-reload
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct MapHotReloadSettings {
+    /// How spawned maps are updated when their asset changes
+    pub mode: HotReloadMode,
+}
+
+impl MapHotReloadSettings {
+    /// Create settings with the default [`HotReloadMode::Diff`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the hot-reload mode
+    pub fn with_mode(mut self, mode: HotReloadMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+type StashedValue = Box<dyn Any + Send + Sync>;
+
+/// Copies one component type off an entity and back onto another
+#[derive(Clone, Copy)]
+struct ReloadHook {
+    type_id: TypeId,
+    extract: fn(&World, Entity) -> Option<StashedValue>,
+    restore: fn(&mut EntityWorldMut, StashedValue),
+}
+
+fn extract_component<C: Component + Clone>(world: &World, entity: Entity) -> Option<StashedValue> {
+    world
+        .get::<C>(entity)
+        .map(|component| Box::new(component.clone()) as StashedValue)
+}
+
+fn restore_component<C: Component + Clone>(entity: &mut EntityWorldMut, value: StashedValue) {
+    if let Ok(component) = value.downcast::<C>() {
+        entity.insert(*component);
+    }
+}
+
+/// Components of map entities that keep their runtime value across hot-reload
+///
+/// Usually filled through [`MapHotReloadExt::preserve_on_reload`].
+#[derive(Resource, Default)]
+pub struct MapReloadHooks {
+    hooks: Vec<ReloadHook>,
+}
+
+impl MapReloadHooks {
+    /// Create an empty set of hooks
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep the runtime value of a component across hot-reload
+    pub fn preserve<C: Component + Clone>(&mut self) {
+        if self.is_preserved::<C>() {
+            return;
+        }
+        self.hooks.push(ReloadHook {
+            type_id: TypeId::of::<C>(),
+            extract: extract_component::<C>,
+            restore: restore_component::<C>,
+        });
+    }
+
+    /// Check if a component keeps its runtime value across hot-reload
+    pub fn is_preserved<C: Component>(&self) -> bool {
+        self.preserves(TypeId::of::<C>())
+    }
+
+    pub(crate) fn preserves(&self, type_id: TypeId) -> bool {
+        self.hooks.iter().any(|hook| hook.type_id == type_id)
+    }
+}
+
+/// Extension trait for choosing components that survive map hot-reload
+pub trait MapHotReloadExt {
+    /// Keep the runtime value of a component on map entities across hot-reload
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::new()
+    ///     .add_plugins(MapRuntimePlugin)
+    ///     .preserve_on_reload::<Health>()
+    ///     .run();
+    /// ```
+    fn preserve_on_reload<C: Component + Clone>(&mut self) -> &mut Self;
+}
+
+impl MapHotReloadExt for App {
+    fn preserve_on_reload<C: Component + Clone>(&mut self) -> &mut Self {
+        // Ensure MapReloadHooks resource exists
+        if !self.world().contains_resource::<MapReloadHooks>() {
+            self.insert_resource(MapReloadHooks::new());
+        }
+
+        self.world_mut()
+            .resource_mut::<MapReloadHooks>()
+            .preserve::<C>();
+
+        self
+    }
+}
+
+/// The project a map root was spawned from, compared against on reload
+#[derive(Component)]
+pub(crate) struct MapReloadSource(pub(crate) MapProject);
+
+/// Preserved components of a map being respawned, keyed by instance ID
+///
+/// Inserted on the `MapHandle` entity until the new map root spawns.
+#[derive(Component)]
+pub(crate) struct PreservedComponents {
+    values: HashMap<Uuid, Vec<(usize, StashedValue)>>,
+}
+
+/// Entities added, removed and changed between two versions of a level
+#[derive(Debug, Default)]
+struct EntityDiff<'a> {
+    removed: Vec<Uuid>,
+    added: Vec<&'a EntityInstance>,
+    changed: Vec<(&'a EntityInstance, &'a EntityInstance)>,
+}
+
+impl<'a> EntityDiff<'a> {
+    fn new(old: &'a [EntityInstance], new: &'a [EntityInstance]) -> Self {
+        let old_by_id: HashMap<Uuid, &EntityInstance> =
+            old.iter().map(|instance| (instance.id, instance)).collect();
+        let new_by_id: HashMap<Uuid, &EntityInstance> =
+            new.iter().map(|instance| (instance.id, instance)).collect();
+
+        let mut diff = Self {
+            removed: old
+                .iter()
+                .filter(|instance| !new_by_id.contains_key(&instance.id))
+                .map(|instance| instance.id)
+                .collect(),
+            ..default()
+        };
+        for instance in new {
+            match old_by_id.get(&instance.id) {
+                None => diff.added.push(instance),
+                Some(previous) => {
+                    if serde_json::to_value(previous).ok() != serde_json::to_value(instance).ok() {
+                        diff.changed.push((previous, instance));
+                    }
+                }
+            }
+        }
+        diff
+    }
+}
+
+/// Everything about a project that cannot be patched in place
+///
+/// Tiles, IntGrid values, entities, level properties and dialogues are left
+/// out, as [`MapReloader::patch`] updates those.
+fn structure(project: &MapProject) -> Option<serde_json::Value> {
+    let mut value = serde_json::to_value(project).ok()?;
+    let root = value.as_object_mut()?;
+    root.remove("dialogues");
+    let level = root.get_mut("level")?.as_object_mut()?;
+    level.remove("entities");
+    level.remove("properties");
+    for layer in level.get_mut("layers")?.as_array_mut()? {
+        let Some(data) = layer.get_mut("data").and_then(|data| data.as_object_mut()) else {
+            continue;
+        };
+        for (kind, fields) in data.iter_mut() {
+            let Some(fields) = fields.as_object_mut() else {
+                continue;
+            };
+            match kind.as_str() {
+                "Tiles" => {
+                    fields.remove("tiles");
+                    fields.remove("occupied_cells");
+                }
+                "Objects" => {
+                    fields.remove("entities");
+                }
+                "IntGrid" => {
+                    fields.remove("values");
+                }
+                _ => {}
+            }
+        }
+    }
+    Some(value)
+}
+
+/// Check if a spawned map can be patched from `old` to `new` in place
+fn can_patch(old: &MapProject, new: &MapProject) -> bool {
+    match (structure(old), structure(new)) {
+        (Some(old), Some(new)) => old == new,
+        _ => false,
+    }
+}
+
+/// Indices of the cells that differ between two versions of a layer
+fn changed_cells<T: PartialEq>(old: &[T], new: &[T]) -> Vec<usize> {
+    old.iter()
+        .zip(new)
+        .enumerate()
+        .filter(|(_, (old, new))| old != new)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Patches spawned maps to match their modified asset
+#[derive(SystemParam)]
+pub(crate) struct MapReloader<'w, 's> {
+    settings: Res<'w, MapHotReloadSettings>,
+    hooks: Res<'w, MapReloadHooks>,
+    registry: Res<'w, EntityRegistry>,
+    dialogues: ResMut<'w, MapDialogues>,
+    maps: Query<
+        'w,
+        's,
+        (
+            &'static MapRoot,
+            &'static mut MapLevelData,
+            &'static mut MapReloadSource,
+            Option<&'static MapLayers>,
+            Option<&'static mut MapEntities>,
+        ),
+    >,
+    tile_entities: MapTileEntities<'w, 's>,
+    changed_events: MessageWriter<'w, MapTilesChanged>,
+}
+
+impl MapReloader<'_, '_> {
+    /// Whether maps should be patched rather than respawned
+    pub(crate) fn diffing(&self) -> bool {
+        self.settings.mode == HotReloadMode::Diff
+    }
+
+    /// Patch a spawned map to match a modified project
+    ///
+    /// Returns false without changing anything if the map must be respawned.
+    pub(crate) fn patch(
+        &mut self,
+        commands: &mut Commands,
+        map_entity: Entity,
+        project: &MapProject,
+    ) -> bool {
+        let Ok((map_root, mut level_data, mut source, map_layers, mut map_entities)) =
+            self.maps.get_mut(map_entity)
+        else {
+            return false;
+        };
+        if !can_patch(&source.0, project) {
+            return false;
+        }
+        let old = &source.0.level;
+        let new = &project.level;
+        let live = &mut level_data.0;
+
+        // Copy cells that changed in the file, keeping runtime edits elsewhere
+        let mut changed_layers: Vec<(usize, Vec<usize>)> = Vec::new();
+        for (layer_index, (old_layer, new_layer)) in old.layers.iter().zip(&new.layers).enumerate()
+        {
+            let Some(live_data) = live.layers.get_mut(layer_index).map(|l| &mut l.data) else {
+                continue;
+            };
+            let cells = match (&old_layer.data, &new_layer.data, live_data) {
+                (
+                    LayerData::Tiles {
+                        tiles: old_tiles, ..
+                    },
+                    LayerData::Tiles {
+                        tiles: new_tiles,
+                        occupied_cells: new_occupied,
+                        ..
+                    },
+                    LayerData::Tiles {
+                        tiles,
+                        occupied_cells,
+                        ..
+                    },
+                ) => {
+                    let cells = changed_cells(old_tiles, new_tiles);
+                    for &index in &cells {
+                        if let Some(tile) = tiles.get_mut(index) {
+                            *tile = new_tiles[index];
+                        }
+                        match new_occupied.get(&index) {
+                            Some(&base) => occupied_cells.insert(index, base),
+                            None => occupied_cells.remove(&index),
+                        };
+                    }
+                    cells
+                }
+                (
+                    LayerData::IntGrid {
+                        values: old_values, ..
+                    },
+                    LayerData::IntGrid {
+                        values: new_values, ..
+                    },
+                    LayerData::IntGrid { values, .. },
+                ) => {
+                    let cells = changed_cells(old_values, new_values);
+                    for &index in &cells {
+                        if let Some(value) = values.get_mut(index) {
+                            *value = new_values[index];
+                        }
+                    }
+                    cells
+                }
+                (_, LayerData::Objects { .. }, live_data) => {
+                    *live_data = new_layer.data.clone();
+                    continue;
+                }
+                _ => continue,
+            };
+            if !cells.is_empty() {
+                changed_layers.push((layer_index, cells));
+            }
+        }
+        live.entities = new.entities.clone();
+        live.properties = new.properties.clone();
+
+        let tile_size = map_root.textures.tile_size;
+        commands.entity(map_entity).insert((
+            LevelProperties::new(new.properties.clone()),
+            MapIntGrids::from_level(live, tile_size),
+        ));
+        self.dialogues.load_from_project(project);
+
+        // Redraw changed tiles and tell colliders and navigation about them
+        let layer_settings = map_layers.map(|l| l.settings).unwrap_or_default();
+        for (layer_index, indices) in changed_layers {
+            let cells: Vec<UVec2> = indices
+                .into_iter()
+                .map(|index| UVec2::new(index as u32 % live.width, index as u32 / live.width))
+                .collect();
+            if let Some(LayerData::Tiles { tileset_id, .. }) =
+                live.layers.get(layer_index).map(|l| &l.data)
+            {
+                if let Some(tileset) = project.get_tileset(*tileset_id) {
+                    self.tile_entities.redraw_cells(
+                        commands,
+                        map_entity,
+                        map_root,
+                        &layer_settings,
+                        live,
+                        layer_index,
+                        tileset,
+                        &cells,
+                    );
+                }
+            }
+            self.changed_events.write(MapTilesChanged {
+                map: map_entity,
+                layer_index,
+                cells,
+            });
+        }
+
+        // Only entities that changed in the file are touched
+        if let Some(map_entities) = map_entities.as_deref_mut() {
+            let diff = EntityDiff::new(&old.entities, &new.entities);
+            for id in diff.removed {
+                if let Some(entity) = map_entities.remove(id) {
+                    commands.entity(entity).despawn();
+                }
+            }
+            for (previous, instance) in diff.changed {
+                let Some(entity) = map_entities.get(instance.id) else {
+                    continue;
+                };
+                if previous.type_name == instance.type_name {
+                    self.registry.refresh_instance(
+                        commands,
+                        entity,
+                        previous,
+                        instance,
+                        &self.hooks,
+                    );
+                    continue;
+                }
+                // A new type needs a new entity; preserved components move over
+                let respawned = self.registry.spawn_map_entities(
+                    commands,
+                    std::slice::from_ref(instance),
+                    map_entity,
+                );
+                let Some(new_entity) = respawned.get(instance.id) else {
+                    continue;
+                };
+                map_entities.insert(instance.id, new_entity);
+                let hooks = self.hooks.hooks.clone();
+                commands.queue(move |world: &mut World| {
+                    for hook in hooks {
+                        let Some(value) = (hook.extract)(world, entity) else {
+                            continue;
+                        };
+                        if let Ok(mut new_entity) = world.get_entity_mut(new_entity) {
+                            (hook.restore)(&mut new_entity, value);
+                        }
+                    }
+                    world.despawn(entity);
+                });
+            }
+            let added: Vec<EntityInstance> = diff.added.into_iter().cloned().collect();
+            let spawned = self
+                .registry
+                .spawn_map_entities(commands, &added, map_entity);
+            for (id, entity) in spawned.iter() {
+                map_entities.insert(id, entity);
+            }
+        }
+
+        source.0 = project.clone();
+        true
+    }
+}
+
+/// Stash the preserved components of a map's entities before it is respawned
+///
+/// The stash is kept on the `MapHandle` entity and applied by
+/// [`restore_preserved_components`] once the new map root has spawned.
+pub(crate) fn stash_preserved_components(
+    commands: &mut Commands,
+    handle_entity: Entity,
+    map_entity: Entity,
+) {
+    commands.queue(move |world: &mut World| {
+        let Some(hooks) = world
+            .get_resource::<MapReloadHooks>()
+            .map(|hooks| hooks.hooks.clone())
+        else {
+            return;
+        };
+        let Some(entities) = world.get::<MapEntities>(map_entity).cloned() else {
+            return;
+        };
+
+        let mut values = HashMap::new();
+        for (id, entity) in entities.iter() {
+            let stashed: Vec<(usize, StashedValue)> = hooks
+                .iter()
+                .enumerate()
+                .filter_map(|(index, hook)| (hook.extract)(world, entity).map(|v| (index, v)))
+                .collect();
+            if !stashed.is_empty() {
+                values.insert(id, stashed);
+            }
+        }
+        if values.is_empty() {
+            return;
+        }
+        if let Ok(mut handle) = world.get_entity_mut(handle_entity) {
+            handle.insert(PreservedComponents { values });
+        }
+    });
+}
+
+/// System that puts stashed components back onto respawned map entities
+pub(crate) fn restore_preserved_components(
+    mut commands: Commands,
+    maps: Query<(Entity, &ChildOf), Added<MapEntities>>,
+    stashes: Query<(), With<PreservedComponents>>,
+) {
+    for (map_entity, child_of) in maps.iter() {
+        let handle_entity = child_of.parent();
+        if !stashes.contains(handle_entity) {
+            continue;
+        }
+        commands.queue(move |world: &mut World| {
+            let Some(stash) = world
+                .get_entity_mut(handle_entity)
+                .ok()
+                .and_then(|mut handle| handle.take::<PreservedComponents>())
+            else {
+                return;
+            };
+            let Some(hooks) = world
+                .get_resource::<MapReloadHooks>()
+                .map(|hooks| hooks.hooks.clone())
+            else {
+                return;
+            };
+            let Some(entities) = world.get::<MapEntities>(map_entity).cloned() else {
+                return;
+            };
+
+            // Entities removed from the map drop their stashed components
+            for (id, stashed) in stash.values {
+                let Some(entity) = entities.get(id) else {
+                    continue;
+                };
+                let Ok(mut entity) = world.get_entity_mut(entity) else {
+                    continue;
+                };
+                for (index, value) in stashed {
+                    if let Some(hook) = hooks.get(index) {
+                        (hook.restore)(&mut entity, value);
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_map_core::{Layer, Level};
+
+    fn project() -> MapProject {
+        let mut level = Level::new("Test".to_string(), 4, 4);
+        level.add_layer(Layer::new_tile_layer(
+            "Ground".to_string(),
+            Uuid::new_v4(),
+            4,
+            4,
+        ));
+        level.add_entity(EntityInstance::new("Npc".to_string(), [8.0, 8.0]));
+        level.add_entity(EntityInstance::new("Door".to_string(), [24.0, 8.0]));
+        MapProject::new(level, Vec::new())
+    }
+
+    #[test]
+    fn test_entity_diff() {
+        let old = project();
+        let mut new = old.clone();
+        let removed = new.level.entities.remove(1).id;
+        new.level.entities[0].set_int("health", 5);
+        new.level
+            .entities
+            .push(EntityInstance::new("Chest".to_string(), [0.0, 0.0]));
+
+        let diff = EntityDiff::new(&old.level.entities, &new.level.entities);
+        assert_eq!(diff.removed, vec![removed]);
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].type_name, "Chest");
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].1.get_int("health"), Some(5));
+
+        let unchanged = EntityDiff::new(&old.level.entities, &old.level.entities);
+        assert!(unchanged.removed.is_empty());
+        assert!(unchanged.added.is_empty());
+        assert!(unchanged.changed.is_empty());
+    }
+
+    #[test]
+    fn test_can_patch() {
+        let old = project();
+
+        // Tiles, entities and properties are patched in place
+        let mut new = old.clone();
+        new.level.set_tile(0, 1, 1, Some(3));
+        new.level.entities.clear();
+        new.level.properties.insert(
+            "music".to_string(),
+            bevy_map_core::Value::String("cave".to_string()),
+        );
+        assert!(can_patch(&old, &new));
+
+        // Structural changes respawn the map
+        let mut resized = old.clone();
+        resized.level.width = 8;
+        assert!(!can_patch(&old, &resized));
+
+        let mut new_layer = old.clone();
+        new_layer
+            .level
+            .add_layer(Layer::new_object_layer("Objects".to_string()));
+        assert!(!can_patch(&old, &new_layer));
+
+        let mut hidden = old.clone();
+        hidden.level.layers[0].visible = false;
+        assert!(!can_patch(&old, &hidden));
+    }
+
+    #[test]
+    fn test_preserved_components_survive_respawn() {
+        #[derive(Component, Clone, Debug, PartialEq)]
+        struct Health(i32);
+
+        let mut world = World::new();
+        let mut hooks = MapReloadHooks::new();
+        hooks.preserve::<Health>();
+        assert!(hooks.is_preserved::<Health>());
+        assert!(!hooks.is_preserved::<Transform>());
+        world.insert_resource(hooks);
+
+        let id = Uuid::new_v4();
+        let handle = world.spawn_empty().id();
+        let old_entity = world.spawn(Health(3)).id();
+        let mut old_entities = MapEntities::default();
+        old_entities.insert(id, old_entity);
+        let old_map = world.spawn(old_entities).id();
+
+        let mut queue = bevy::ecs::world::CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        stash_preserved_components(&mut commands, handle, old_map);
+        queue.apply(&mut world);
+        world.despawn(old_map);
+        world.despawn(old_entity);
+
+        // The respawned map gets a fresh entity for the same instance
+        let new_entity = world.spawn(Health(10)).id();
+        let mut new_entities = MapEntities::default();
+        new_entities.insert(id, new_entity);
+        world.spawn((new_entities, ChildOf(handle)));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(restore_preserved_components);
+        schedule.run(&mut world);
+
+        assert_eq!(world.get::<Health>(new_entity), Some(&Health(3)));
+        assert!(world.get::<PreservedComponents>(handle).is_none());
+    }
+}
@@ -0,0 +1,197 @@
+//! IntGrid layers at runtime
+//!
+//! IntGrid layers hold gameplay data such as water, ladders or no-spawn zones
+//! as small integer values. They are not rendered; instead the spawned map
+//! root gets a [`MapIntGrids`] component that can be queried by layer name and
+//! tile position. Values are available even for layers hidden in the editor.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use bevy::prelude::*;
+//! use bevy_ecs_tilemap::prelude::TilePos;
+//! use bevy_map_runtime::MapIntGrids;
+//!
+//! fn check_water(grids: Query<&MapIntGrids>) {
+//!     for grids in grids.iter() {
+//!         let pos = TilePos { x: 3, y: 5 };
+//!         if grids.int_grid_name("Gameplay", pos) == Some("water") {
+//!             info!("Splash!");
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::TilePos;
+use bevy_map_core::{IntGridValue, LayerData, Level};
+
+/// One IntGrid layer of a spawned map
+#[derive(Debug, Clone)]
+pub struct MapIntGrid {
+    /// Index of the layer in the level
+    pub index: usize,
+    /// Layer name from the editor
+    pub name: String,
+    /// Width in tiles
+    pub width: u32,
+    /// Height in tiles
+    pub height: u32,
+    /// Cell values in row-major order, 0 means empty
+    pub values: Vec<u32>,
+    /// Named values of the layer
+    pub palette: Vec<IntGridValue>,
+}
+
+impl MapIntGrid {
+    /// Get the value at a tile position, or `None` if out of bounds
+    pub fn value(&self, tile_pos: TilePos) -> Option<u32> {
+        if tile_pos.x >= self.width || tile_pos.y >= self.height {
+            return None;
+        }
+        let index = (tile_pos.y * self.width + tile_pos.x) as usize;
+        self.values.get(index).copied()
+    }
+
+    /// Get the palette entry for the value at a tile position
+    pub fn value_def(&self, tile_pos: TilePos) -> Option<&IntGridValue> {
+        let value = self.value(tile_pos)?;
+        self.palette.iter().find(|def| def.value == value)
+    }
+
+    /// Iterate over the positions of all cells holding a value
+    pub fn positions_of(&self, value: u32) -> impl Iterator<Item = TilePos> + '_ {
+        let width = self.width.max(1);
+        self.values
+            .iter()
+            .enumerate()
+            .filter(move |(_, v)| **v == value)
+            .map(move |(index, _)| TilePos {
+                x: index as u32 % width,
+                y: index as u32 / width,
+            })
+    }
+}
+
+/// IntGrid layers of a spawned map, inserted on the map root
+#[derive(Component, Debug, Clone, Default)]
+pub struct MapIntGrids {
+    /// Width and height of a grid cell in pixels
+    pub tile_size: Vec2,
+    /// One entry per IntGrid layer, in level order
+    pub layers: Vec<MapIntGrid>,
+}
+
+impl MapIntGrids {
+    /// Collect the IntGrid layers of a level
+    pub fn from_level(level: &Level, tile_size: Vec2) -> Self {
+        let layers = level
+            .layers
+            .iter()
+            .enumerate()
+            .filter_map(|(index, layer)| match &layer.data {
+                LayerData::IntGrid { values, palette } => Some(MapIntGrid {
+                    index,
+                    name: layer.name.clone(),
+                    width: level.width,
+                    height: level.height,
+                    values: values.clone(),
+                    palette: palette.clone(),
+                }),
+                _ => None,
+            })
+            .collect();
+        Self { tile_size, layers }
+    }
+
+    /// Get a layer by name
+    pub fn get(&self, layer: &str) -> Option<&MapIntGrid> {
+        self.layers.iter().find(|grid| grid.name == layer)
+    }
+
+    /// Get the value of a named layer at a tile position
+    ///
+    /// Returns `None` if the layer doesn't exist or the position is out of
+    /// bounds, and `Some(0)` for empty cells.
+    pub fn int_grid_value(&self, layer: &str, tile_pos: TilePos) -> Option<u32> {
+        self.get(layer)?.value(tile_pos)
+    }
+
+    /// Get the name of the value of a named layer at a tile position
+    pub fn int_grid_name(&self, layer: &str, tile_pos: TilePos) -> Option<&str> {
+        self.get(layer)?
+            .value_def(tile_pos)
+            .map(|def| def.name.as_str())
+    }
+
+    /// Get the tile position under a point in map space
+    ///
+    /// Tile centers sit on multiples of the tile size, matching how the map's
+    /// tilemaps are spawned.
+    pub fn tile_pos_at(&self, local: Vec2) -> Option<TilePos> {
+        if self.tile_size.cmple(Vec2::ZERO).any() {
+            return None;
+        }
+        let cell = (local / self.tile_size + Vec2::splat(0.5)).floor();
+        (cell.x >= 0.0 && cell.y >= 0.0).then_some(TilePos {
+            x: cell.x as u32,
+            y: cell.y as u32,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_map_core::Layer;
+
+    fn grids() -> MapIntGrids {
+        let mut level = Level::new("Test".to_string(), 4, 4);
+        let mut layer = Layer::new_int_grid_layer("Gameplay".to_string(), 4, 4);
+        if let LayerData::IntGrid { palette, .. } = &mut layer.data {
+            palette.push(IntGridValue::new(
+                1,
+                "water".to_string(),
+                "#3080FF".to_string(),
+            ));
+        }
+        level.add_layer(Layer::new_object_layer("Entities".to_string()));
+        level.add_layer(layer);
+        level.set_int_grid_value(1, 2, 3, 1);
+        MapIntGrids::from_level(&level, Vec2::splat(16.0))
+    }
+
+    #[test]
+    fn test_int_grid_value_lookup() {
+        let grids = grids();
+        assert_eq!(grids.layers.len(), 1);
+        assert_eq!(grids.layers[0].index, 1);
+
+        let pos = TilePos { x: 2, y: 3 };
+        assert_eq!(grids.int_grid_value("Gameplay", pos), Some(1));
+        assert_eq!(grids.int_grid_name("Gameplay", pos), Some("water"));
+        assert_eq!(
+            grids.int_grid_value("Gameplay", TilePos { x: 0, y: 0 }),
+            Some(0)
+        );
+        assert_eq!(
+            grids.int_grid_value("Gameplay", TilePos { x: 4, y: 0 }),
+            None
+        );
+        assert_eq!(grids.int_grid_value("Missing", pos), None);
+
+        let water: Vec<_> = grids.layers[0].positions_of(1).collect();
+        assert_eq!(water, vec![pos]);
+    }
+
+    #[test]
+    fn test_tile_pos_at() {
+        let grids = grids();
+        // Tile (2, 3) is centered at (32, 48) and spans 8 pixels either way
+        assert_eq!(
+            grids.tile_pos_at(Vec2::new(39.0, 41.0)),
+            Some(TilePos { x: 2, y: 3 })
+        );
+        assert_eq!(grids.tile_pos_at(Vec2::new(-9.0, 0.0)), None);
+    }
+}
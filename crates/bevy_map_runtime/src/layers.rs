@@ -0,0 +1,457 @@
+//! Layer ordering and visibility for spawned maps
+//!
+//! Each layer of a map is drawn at `z_base + layer_index * z_step` in map space.
+//! Multi-image layers stack their images within the layer's step. The spacing
+//! can be changed per map with [`MapLayerSettings`], and [`MapLayers`] on the
+//! spawned map root reports the depth of every layer by name, so entities and
+//! particles can be placed between layers without hardcoding z values.
+//!
+//! Layer pixel offsets are applied at spawn. Layers with a parallax factor
+//! other than 1.0 get a [`LayerParallax`] component and are moved every frame
+//! relative to the active 2D camera.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use bevy::prelude::*;
+//! use bevy_map_runtime::{MapHandle, MapLayerSettings, MapLayers};
+//!
+//! fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+//!     commands.spawn((
+//!         MapHandle(asset_server.load("maps/level1.map.json")),
+//!         MapLayerSettings::new().with_z_base(-10.0).with_z_step(1.0),
+//!     ));
+//! }
+//!
+//! fn place_player(layers: Query<&MapLayers, Added<MapLayers>>) {
+//!     for layers in layers.iter() {
+//!         let z = layers.z_between("Ground", "Foreground");
+//!         info!("Player z: {:?}", z);
+//!     }
+//! }
+//! ```
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+use bevy::transform::helper::TransformHelper;
+
+/// Fraction of a layer step used to separate the images of a multi-image layer
+const IMAGE_Z_FRACTION: f32 = 0.1;
+/// Fraction of a layer step that lifts multi-cell tile sprites above the tilemaps
+const SPRITE_Z_FRACTION: f32 = 0.01;
+
+/// Parallax scrolling for a layer's tilemaps and sprites
+///
+/// The entity is placed at `base + offset + camera * (1 - factor)`, where
+/// `camera` is the active camera's position relative to the map root.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct LayerParallax {
+    /// Parallax factor per axis (1.0 scrolls with the map, 0.0 follows the camera)
+    pub factor: Vec2,
+    /// Pixel offset of the layer
+    pub offset: Vec2,
+    /// Position of the entity without offset or parallax, in map space
+    pub base: Vec2,
+}
+
+impl LayerParallax {
+    /// Create parallax settings from a core layer's factors
+    ///
+    /// `offset` is the layer's offset combined with its parent groups. The base
+    /// is zero, which fits tilemaps; sprites set their own position.
+    pub fn from_layer(layer: &bevy_map_core::Layer, offset: Vec2) -> Self {
+        Self {
+            factor: Vec2::new(layer.parallax_x, layer.parallax_y),
+            offset,
+            base: Vec2::ZERO,
+        }
+    }
+
+    /// Get the entity position for a camera position relative to the map
+    pub fn position(&self, camera: Vec2) -> Vec2 {
+        self.base + self.offset + camera * (Vec2::ONE - self.factor)
+    }
+}
+
+/// Get the color of a layer: its tint with the opacity applied to alpha
+///
+/// `opacity` is the layer's opacity combined with its parent groups.
+pub fn layer_color(layer: &bevy_map_core::Layer, opacity: f32) -> Color {
+    let [r, g, b, a] = layer.tint_rgba();
+    Color::srgba(r, g, b, a * opacity.clamp(0.0, 1.0))
+}
+
+/// An image layer drawn as a single sprite
+///
+/// The sprite is sized once its image has loaded: repeated axes cover the
+/// whole level, the others keep the image's own size.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ImageLayer {
+    /// Index of the layer in the level
+    pub layer_index: usize,
+    /// Repeat the image horizontally across the level
+    pub repeat_x: bool,
+    /// Repeat the image vertically across the level
+    pub repeat_y: bool,
+    /// Level size in pixels
+    pub level_size: Vec2,
+}
+
+impl ImageLayer {
+    /// Get the sprite size for an image of the given size
+    pub fn sprite_size(&self, image_size: Vec2) -> Vec2 {
+        Vec2::new(
+            if self.repeat_x {
+                self.level_size.x
+            } else {
+                image_size.x
+            },
+            if self.repeat_y {
+                self.level_size.y
+            } else {
+                image_size.y
+            },
+        )
+    }
+}
+
+/// System that sizes image layer sprites once their images are loaded
+pub fn fit_image_layers(images: Res<Assets<Image>>, mut query: Query<(&ImageLayer, &mut Sprite)>) {
+    for (image_layer, mut sprite) in query.iter_mut() {
+        if sprite.custom_size.is_some() {
+            continue;
+        }
+        let Some(image) = images.get(&sprite.image) else {
+            continue;
+        };
+
+        sprite.custom_size = Some(image_layer.sprite_size(image.size_f32()));
+        if image_layer.repeat_x || image_layer.repeat_y {
+            sprite.image_mode = SpriteImageMode::Tiled {
+                tile_x: image_layer.repeat_x,
+                tile_y: image_layer.repeat_y,
+                stretch_value: 1.0,
+            };
+        }
+    }
+}
+
+/// System that moves parallax layers relative to the active 2D camera
+///
+/// Uses the camera with the highest order among active cameras. Runs before
+/// transform propagation, so world positions of the camera (which may be
+/// parented, e.g. to the player) and map roots are computed from this frame's
+/// transforms.
+pub fn apply_layer_parallax(
+    cameras: Query<(Entity, &Camera), With<Camera2d>>,
+    mut transforms: ParamSet<(
+        TransformHelper,
+        Query<(&LayerParallax, &ChildOf, &mut Transform)>,
+    )>,
+) {
+    let Some((camera_entity, _)) = cameras
+        .iter()
+        .filter(|(_, camera)| camera.is_active)
+        .max_by_key(|(_, camera)| camera.order)
+    else {
+        return;
+    };
+    let Ok(camera_world) = transforms.p0().compute_global_transform(camera_entity) else {
+        return;
+    };
+    let camera_world = camera_world.translation();
+
+    // Camera position relative to each map root
+    let map_roots: Vec<Entity> = transforms
+        .p1()
+        .iter()
+        .map(|(_, child_of, _)| child_of.parent())
+        .collect();
+    let mut cameras_in_map = EntityHashMap::default();
+    for map_root in map_roots {
+        if let Ok(map) = transforms.p0().compute_global_transform(map_root) {
+            let camera = map.affine().inverse().transform_point3(camera_world);
+            cameras_in_map.insert(map_root, camera.truncate());
+        }
+    }
+
+    for (parallax, child_of, mut transform) in transforms.p1().iter_mut() {
+        let camera = cameras_in_map
+            .get(&child_of.parent())
+            .copied()
+            .unwrap_or(camera_world.truncate());
+        let position = parallax.position(camera);
+        if transform.translation.truncate() != position {
+            transform.translation.x = position.x;
+            transform.translation.y = position.y;
+        }
+    }
+}
+
+/// How layers marked invisible in the editor are spawned
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HiddenLayers {
+    /// Spawn the layer with `Visibility::Hidden`, so it can be shown later
+    #[default]
+    Hide,
+    /// Don't spawn invisible layers at all
+    Skip,
+}
+
+/// Per-map settings for layer depth and visibility
+///
+/// Add this to a `MapHandle` entity, or pass it to
+/// `spawn_map_project_with_settings`. Maps without it use the defaults: a
+/// base of 0.0 and a step of 0.1 between layers.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct MapLayerSettings {
+    /// Z of the first layer, in map space
+    pub z_base: f32,
+    /// Z distance between consecutive layers
+    pub z_step: f32,
+    /// How invisible layers are handled
+    pub hidden_layers: HiddenLayers,
+}
+
+impl Default for MapLayerSettings {
+    fn default() -> Self {
+        Self {
+            z_base: 0.0,
+            z_step: 0.1,
+            hidden_layers: HiddenLayers::Hide,
+        }
+    }
+}
+
+impl MapLayerSettings {
+    /// Create settings with the default depth and visibility handling
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the z of the first layer
+    pub fn with_z_base(mut self, z_base: f32) -> Self {
+        self.z_base = z_base;
+        self
+    }
+
+    /// Set the z distance between consecutive layers
+    pub fn with_z_step(mut self, z_step: f32) -> Self {
+        self.z_step = z_step;
+        self
+    }
+
+    /// Set how invisible layers are handled
+    pub fn with_hidden_layers(mut self, hidden_layers: HiddenLayers) -> Self {
+        self.hidden_layers = hidden_layers;
+        self
+    }
+
+    /// Get the z of a layer
+    pub fn layer_z(&self, layer_index: usize) -> f32 {
+        self.z_base + layer_index as f32 * self.z_step
+    }
+
+    /// Get the z of one image's tilemap within a multi-image layer
+    pub fn image_z(&self, layer_index: usize, image_index: usize) -> f32 {
+        self.layer_z(layer_index) + image_index as f32 * self.z_step * IMAGE_Z_FRACTION
+    }
+
+    /// Get the z of multi-cell tile sprites drawn from one image of a layer
+    pub fn sprite_z(&self, layer_index: usize, image_index: usize) -> f32 {
+        self.image_z(layer_index, image_index) + self.z_step * SPRITE_Z_FRACTION
+    }
+
+    /// Whether a layer should be spawned at all
+    pub fn should_spawn(&self, visible: bool) -> bool {
+        visible || self.hidden_layers == HiddenLayers::Hide
+    }
+}
+
+/// Depth and display state of one layer of a spawned map
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapLayerDepth {
+    /// Index of the layer in the level
+    pub index: usize,
+    /// Layer name from the editor
+    pub name: String,
+    /// Z of the layer's first tilemap, in map space
+    pub z: f32,
+    /// Highest z used by the layer's tilemaps and sprites
+    pub top_z: f32,
+    /// Whether the layer is visible
+    pub visible: bool,
+    /// Layer opacity (0.0 - 1.0)
+    pub opacity: f32,
+}
+
+/// Layer depths of a spawned map, inserted on the map root
+///
+/// All z values are in map space. Add the map's own z when placing entities
+/// that are not children of the map.
+#[derive(Component, Debug, Clone, Default)]
+pub struct MapLayers {
+    /// Settings the map was spawned with
+    pub settings: MapLayerSettings,
+    /// One entry per layer, in level order
+    pub layers: Vec<MapLayerDepth>,
+}
+
+impl MapLayers {
+    /// Create an empty layer list for the given settings
+    pub fn new(settings: MapLayerSettings) -> Self {
+        Self {
+            settings,
+            layers: Vec::new(),
+        }
+    }
+
+    /// Get a layer by name
+    pub fn get(&self, name: &str) -> Option<&MapLayerDepth> {
+        self.layers.iter().find(|layer| layer.name == name)
+    }
+
+    /// Get a layer by index
+    pub fn get_index(&self, index: usize) -> Option<&MapLayerDepth> {
+        self.layers.iter().find(|layer| layer.index == index)
+    }
+
+    /// Get the z of a named layer
+    pub fn layer_z(&self, name: &str) -> Option<f32> {
+        self.get(name).map(|layer| layer.z)
+    }
+
+    /// Get a z just above all content of a named layer and below the next layer
+    pub fn z_above(&self, name: &str) -> Option<f32> {
+        let layer = self.get(name)?;
+        let next_z = self.settings.layer_z(layer.index + 1);
+        Some((layer.top_z + next_z) / 2.0)
+    }
+
+    /// Get a z between the content of `lower` and the layer `upper`
+    ///
+    /// Returns `None` if either layer is missing or `lower` is not below `upper`.
+    pub fn z_between(&self, lower: &str, upper: &str) -> Option<f32> {
+        let lower = self.get(lower)?;
+        let upper = self.get(upper)?;
+        (lower.top_z < upper.z).then(|| (lower.top_z + upper.z) / 2.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layers(settings: MapLayerSettings) -> MapLayers {
+        let mut layers = MapLayers::new(settings);
+        for (index, name) in ["Background", "Ground", "Foreground"].iter().enumerate() {
+            layers.layers.push(MapLayerDepth {
+                index,
+                name: name.to_string(),
+                z: settings.layer_z(index),
+                top_z: settings.sprite_z(index, 1),
+                visible: true,
+                opacity: 1.0,
+            });
+        }
+        layers
+    }
+
+    #[test]
+    fn test_layer_z_defaults() {
+        let settings = MapLayerSettings::default();
+        assert_eq!(settings.layer_z(0), 0.0);
+        assert!((settings.layer_z(2) - 0.2).abs() < 1e-6);
+        assert!((settings.image_z(1, 1) - 0.11).abs() < 1e-6);
+        assert!((settings.sprite_z(1, 1) - 0.111).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_z_between_named_layers() {
+        let settings = MapLayerSettings::new().with_z_base(10.0).with_z_step(1.0);
+        let layers = layers(settings);
+
+        assert_eq!(layers.layer_z("Ground"), Some(11.0));
+
+        let between = layers.z_between("Ground", "Foreground").unwrap();
+        assert!(between > settings.sprite_z(1, 1) && between < 12.0);
+        assert_eq!(layers.z_above("Ground"), Some(between));
+
+        assert_eq!(layers.z_between("Foreground", "Ground"), None);
+        assert_eq!(layers.z_between("Ground", "Missing"), None);
+    }
+
+    #[test]
+    fn test_layer_parallax_position() {
+        let parallax = LayerParallax {
+            factor: Vec2::new(0.5, 1.0),
+            offset: Vec2::new(4.0, -2.0),
+            base: Vec2::new(16.0, 16.0),
+        };
+        assert_eq!(parallax.position(Vec2::ZERO), Vec2::new(20.0, 14.0));
+        // Half-speed horizontally, no vertical parallax
+        assert_eq!(
+            parallax.position(Vec2::new(100.0, 100.0)),
+            Vec2::new(70.0, 14.0)
+        );
+    }
+
+    #[test]
+    fn test_parallax_with_parented_camera() {
+        let mut world = World::new();
+        // Camera following a player, with both moved this frame
+        let player = world.spawn(Transform::from_xyz(100.0, 40.0, 0.0)).id();
+        let camera = world
+            .spawn((
+                Camera2d,
+                Transform::from_xyz(10.0, 0.0, 0.0),
+                ChildOf(player),
+            ))
+            .id();
+        let map = world.spawn(Transform::from_xyz(-50.0, 0.0, 0.0)).id();
+        let layer = world
+            .spawn((
+                LayerParallax {
+                    factor: Vec2::splat(0.5),
+                    offset: Vec2::ZERO,
+                    base: Vec2::ZERO,
+                },
+                Transform::default(),
+                ChildOf(map),
+            ))
+            .id();
+        assert!(world.get::<Camera>(camera).is_some());
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(apply_layer_parallax);
+        schedule.run(&mut world);
+
+        // Camera at (110, 40) in the world is (160, 40) relative to the map
+        assert_eq!(
+            world.get::<Transform>(layer).unwrap().translation,
+            Vec3::new(80.0, 20.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_image_layer_size() {
+        let image_layer = ImageLayer {
+            layer_index: 0,
+            repeat_x: true,
+            repeat_y: false,
+            level_size: Vec2::new(640.0, 320.0),
+        };
+        assert_eq!(
+            image_layer.sprite_size(Vec2::new(128.0, 64.0)),
+            Vec2::new(640.0, 64.0)
+        );
+    }
+
+    #[test]
+    fn test_hidden_layer_handling() {
+        assert!(MapLayerSettings::default().should_spawn(false));
+        let skip = MapLayerSettings::new().with_hidden_layers(HiddenLayers::Skip);
+        assert!(!skip.should_spawn(false));
+        assert!(skip.should_spawn(true));
+    }
+}
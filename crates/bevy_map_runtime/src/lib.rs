@@ -31,6 +31,16 @@
 //! }
 //! ```
 //!
+//! Projects with several levels can be loaded as a `WorldProject` and spawned
+//! one level at a time:
+//!
+//! ```rust,ignore
+//! fn load_level(mut commands: Commands, asset_server: Res<AssetServer>) {
+//!     let world = asset_server.load("maps/world.map.json");
+//!     commands.spawn(LevelHandle::new(world, "Cave"));
+//! }
+//! ```
+//!
 //! To enable hot-reloading during development:
 //! ```bash
 //! cargo run --features bevy/file_watcher
@@ -68,7 +78,7 @@ use bevy::asset::AssetEvent;
 use bevy::ecs::message::{Message, MessageReader, MessageWriter};
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
-use bevy_map_core::MapProject;
+use bevy_map_core::{LevelSelector, MapProject, Tileset, WorldProject};
 use std::collections::HashMap;
 use std::path::Path;
 use uuid::Uuid;
@@ -127,29 +137,67 @@ pub use bevy_map_core;
 pub use bevy_map_dialogue;
 
 pub mod camera;
+pub mod chunks;
 pub mod collision;
+pub mod edit;
 pub mod entity_input;
 pub mod entity_physics;
+pub mod entity_refs;
 pub mod entity_registry;
 pub mod entity_sprite;
+pub mod hot_reload;
+pub mod int_grid;
+pub mod layers;
 pub mod loader;
+pub mod navigation;
+pub mod properties;
+pub mod query;
+pub mod regions;
 pub mod render;
+pub mod streaming;
+pub mod tile_animation;
+pub mod world;
 
 // Re-export commonly used types
 pub use camera::{clamp_camera_to_bounds, setup_camera_bounds_from_map, CameraBounds};
-pub use collision::{MapCollider, MapCollisionPlugin};
+pub use chunks::{MapChunk, MapChunkSettings, MapChunks};
+pub use collision::{ColliderMerge, MapCollider, MapCollisionPlugin, MapCollisionSettings};
+#[cfg(feature = "physics")]
+pub use collision::{DropThrough, MapCollisionHooks, OneWayPlatform};
+pub use edit::{EditLayer, MapEdit, MapEditCommands, MapEditEvent, MapLevelData, MapTilesChanged};
 pub use entity_input::{
     CustomInput, EntityInputSpawned, MapEntityInputPlugin, PlatformerInput, TopDownInput,
     TwinStickInput,
 };
 pub use entity_physics::{EntityPhysicsSpawned, MapEntityPhysicsPlugin};
+pub use entity_refs::{EntityRef, EntityRefs};
 pub use entity_registry::{
-    attach_dialogues, Dialogue, EntityProperties, EntityRegistry, MapEntityExt, MapEntityMarker,
-    MapEntityType,
+    attach_dialogues, instance_transform, Dialogue, EntityProperties, EntityRegistry, EntitySize,
+    MapEntities, MapEntityExt, MapEntityMarker, MapEntityType,
 };
 pub use entity_sprite::{EntitySpriteSetup, EntitySpriteSpawned, MapEntitySpritePlugin};
-pub use loader::{MapLoadError, MapProjectLoader};
+pub use hot_reload::{HotReloadMode, MapHotReloadExt, MapHotReloadSettings, MapReloadHooks};
+pub use int_grid::{MapIntGrid, MapIntGrids};
+pub use layers::{
+    HiddenLayers, ImageLayer, LayerParallax, MapLayerDepth, MapLayerSettings, MapLayers,
+};
+pub use loader::{MapLoadError, MapProjectLoader, WorldProjectLoader};
+pub use navigation::{
+    MapNavigation, MapNavigationPlugin, MapNavigationSettings, NavGrid, NavMovement, PathAlgorithm,
+    PlatformerJumps, PlatformerMove,
+};
+pub use properties::{LayerProperties, LevelProperties, TilesetProperties};
+pub use query::{MapQuery, MapTile};
+pub use regions::{
+    MapRegion, MapRegionPlugin, RegionEnterEvent, RegionExitEvent, RegionOccupant, RegionOccupants,
+};
 pub use render::{complete_sprite_loads, spawn_sprite_components, SpriteSlot};
+pub use streaming::{
+    level_world_rect, LevelTransitionEvent, StreamedLevel, StreamedWorld, StreamingAnchor,
+    WorldStreamingPlugin,
+};
+pub use tile_animation::TileFrameAnimation;
+pub use world::LevelHandle;
 
 // Re-export key dialogue types for convenience
 pub use bevy_map_dialogue::{
@@ -171,8 +219,15 @@ pub use bevy_map_animation::{
 /// This plugin provides:
 /// - Asset loading for `.map.json` files
 /// - Automatic map spawning when `MapHandle` components are added
-/// - Hot-reload support when using Bevy's `file_watcher` feature
+/// - Multi-level projects via `WorldProject` and `LevelHandle`
+/// - Animated tiles from tileset animation frames
+/// - Layer visibility, opacity, tint, offsets and parallax
+/// - Image layers and layer groups
+/// - Hot-reload support when using Bevy's `file_watcher` feature, patching
+///   spawned maps in place (see `MapHotReloadSettings`)
 /// - Manual spawning via `SpawnMapEvent` and `SpawnMapProjectEvent`
+/// - Runtime tile editing via `MapEditCommands`
+/// - Chunked tile spawning for large levels via `MapChunkSettings`
 pub struct MapRuntimePlugin;
 
 impl Plugin for MapRuntimePlugin {
@@ -182,34 +237,64 @@ impl Plugin for MapRuntimePlugin {
             .add_plugins(bevy_map_animation::SpriteAnimationPlugin)
             // Asset loading
             .init_asset::<MapProject>()
+            .init_asset::<WorldProject>()
+            .init_asset_loader::<WorldProjectLoader>()
             .init_asset_loader::<MapProjectLoader>()
             // Resources
             .init_resource::<EntityRegistry>()
             .init_resource::<MapDialogues>()
+            .init_resource::<MapHotReloadSettings>()
+            .init_resource::<MapReloadHooks>()
             // Events
             .add_message::<SpawnMapEvent>()
             .add_message::<SpawnMapProjectEvent>()
             .add_message::<MapSpawnedEvent>()
+            .add_message::<MapEditEvent>()
+            .add_message::<MapTilesChanged>()
             // Systems
             .add_systems(Update, handle_spawn_map_events)
             .add_systems(Update, handle_spawn_map_project_events)
             .add_systems(
                 Update,
                 (
+                    world::initialize_level_handles,
+                    world::resolve_level_handles,
                     initialize_map_handles,
                     handle_map_handle_spawning,
+                    hot_reload::restore_preserved_components,
                     handle_map_hot_reload,
                 )
                     .chain(),
             )
+            // Runtime tile edits
+            .add_systems(Update, edit::apply_map_edits)
+            // Chunks of chunked maps stream in around cameras
+            .add_systems(
+                Update,
+                chunks::stream_map_chunks
+                    .after(edit::apply_map_edits)
+                    .after(handle_map_hot_reload),
+            )
+            // Image layers are sized once their pictures load
+            .add_systems(Update, layers::fit_image_layers)
+            // Animated tiles without a contiguous frame range
+            .add_systems(Update, tile_animation::animate_tile_frames)
             // Sprite spawning systems
             .add_systems(Update, spawn_sprite_components)
             .add_systems(Update, complete_sprite_loads)
             // Dialogue attachment system
             .add_systems(Update, attach_dialogues)
+            // Entity reference properties
+            .add_systems(Update, entity_refs::resolve_entity_refs)
             // Camera bounds systems
             .add_systems(Update, setup_camera_bounds_from_map)
             .add_systems(PostUpdate, clamp_camera_to_bounds)
+            .add_systems(
+                PostUpdate,
+                layers::apply_layer_parallax
+                    .after(clamp_camera_to_bounds)
+                    .before(bevy::transform::TransformSystems::Propagate),
+            )
             // Animated sprite auto-loading systems (opt-in)
             .add_systems(
                 Update,
@@ -238,8 +323,8 @@ impl Plugin for MapRuntimePlugin {
 /// Component for loading maps via the Bevy asset system
 ///
 /// Attach this component to an entity to load and spawn a map. The map will
-/// be automatically spawned once the asset is loaded, and will be respawned
-/// if the asset changes (hot-reload).
+/// be automatically spawned once the asset is loaded, and will be updated
+/// if the asset changes (hot-reload, see [`MapHotReloadSettings`]).
 ///
 /// # Example
 ///
@@ -289,11 +374,18 @@ fn handle_map_handle_spawning(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     map_assets: Res<Assets<MapProject>>,
-    mut query: Query<(Entity, &MapHandle, &mut MapHandleState, Option<&Transform>)>,
+    mut query: Query<(
+        Entity,
+        &MapHandle,
+        &mut MapHandleState,
+        Option<&MapLayerSettings>,
+        Option<&MapChunkSettings>,
+    )>,
     entity_registry: Res<EntityRegistry>,
     mut map_dialogues: ResMut<MapDialogues>,
+    mut images: ResMut<Assets<Image>>,
 ) {
-    for (entity, map_handle, mut state, _transform) in query.iter_mut() {
+    for (entity, map_handle, mut state, layer_settings, chunk_settings) in query.iter_mut() {
         // Check if asset is loaded
         let Some(project) = map_assets.get(&map_handle.0) else {
             continue;
@@ -341,6 +433,13 @@ fn handle_map_handle_spawning(
             continue;
         }
 
+        if let Some(textures) = state.textures.as_mut() {
+            textures.pack_tileset_images(project, &mut images);
+        }
+        let Some(textures) = &state.textures else {
+            continue;
+        };
+
         info!(
             "Spawning map '{}' with {} layers, {} tilesets",
             project.level.name,
@@ -351,19 +450,24 @@ fn handle_map_handle_spawning(
         // Load dialogues from the project
         map_dialogues.load_from_project(project);
 
-        let map_entity = spawn_map_project(
+        let map_entity = spawn_map_project_impl(
             &mut commands,
             project,
             textures,
             Transform::default(), // Map is relative to parent
             Some(&entity_registry),
+            &layer_settings.copied().unwrap_or_default(),
+            chunk_settings.copied(),
         );
 
         // Add MapRoot marker and make it a child
-        commands.entity(map_entity).insert(MapRoot {
-            handle: map_handle.0.clone(),
-            textures: textures.clone(),
-        });
+        commands.entity(map_entity).insert((
+            MapRoot {
+                handle: map_handle.0.clone(),
+                textures: textures.clone(),
+            },
+            hot_reload::MapReloadSource(project.clone()),
+        ));
 
         commands.entity(entity).add_child(map_entity);
 
@@ -376,9 +480,11 @@ fn handle_map_handle_spawning(
 fn handle_map_hot_reload(
     mut commands: Commands,
     mut asset_events: MessageReader<AssetEvent<MapProject>>,
+    map_assets: Res<Assets<MapProject>>,
     mut query: Query<(Entity, &MapHandle, &mut MapHandleState)>,
     children_query: Query<&Children>,
     map_root_query: Query<(Entity, &MapRoot)>,
+    mut reloader: hot_reload::MapReloader,
 ) {
     for event in asset_events.read() {
         let AssetEvent::Modified { id } = event else {
@@ -391,12 +497,26 @@ fn handle_map_hot_reload(
                 continue;
             }
 
+            // Patch the spawned map in place when possible
+            let map_entity = children_query.get(entity).ok().and_then(|children| {
+                children
+                    .iter()
+                    .find(|child| map_root_query.contains(*child))
+            });
+            if let (Some(map_entity), Some(project)) = (map_entity, map_assets.get(&map_handle.0)) {
+                if reloader.diffing() && reloader.patch(&mut commands, map_entity, project) {
+                    info!("Hot-reloaded map '{}' in place", project.level.name);
+                    continue;
+                }
+            }
+
             info!("Hot-reloading map asset");
 
-            // Find and despawn existing map root
+            // Find and despawn existing map root, keeping preserved components
             if let Ok(children) = children_query.get(entity) {
                 for child in children.iter() {
                     if map_root_query.get(child).is_ok() {
+                        hot_reload::stash_preserved_components(&mut commands, entity, child);
                         commands.entity(child).despawn();
                     }
                 }
@@ -416,6 +536,16 @@ pub trait MapCommandsExt {
     ///
     /// Returns the entity that will contain the map once loaded.
     fn spawn_map(&mut self, asset_server: &AssetServer, path: impl Into<String>) -> Entity;
+
+    /// Spawn a single level of a multi-level project by name or UUID
+    ///
+    /// Returns the entity that will contain the level once loaded.
+    fn spawn_level(
+        &mut self,
+        asset_server: &AssetServer,
+        path: impl Into<String>,
+        level: impl Into<LevelSelector>,
+    ) -> Entity;
 }
 
 impl MapCommandsExt for Commands<'_, '_> {
@@ -428,6 +558,20 @@ impl MapCommandsExt for Commands<'_, '_> {
         ))
         .id()
     }
+
+    fn spawn_level(
+        &mut self,
+        asset_server: &AssetServer,
+        path: impl Into<String>,
+        level: impl Into<LevelSelector>,
+    ) -> Entity {
+        self.spawn((
+            LevelHandle::new(asset_server.load(path.into()), level),
+            Transform::default(),
+            Visibility::default(),
+        ))
+        .id()
+    }
 }
 
 /// Manages loaded tileset and sprite sheet textures for a map
@@ -440,8 +584,10 @@ pub struct TilesetTextures {
     images: HashMap<(Uuid, usize), Handle<Image>>,
     /// Map from sprite_sheet_id to texture handle
     sprite_sheet_images: HashMap<Uuid, Handle<Image>>,
-    /// Tile size from the project (cached for convenience)
-    pub tile_size: f32,
+    /// Map from image layer ID to texture handle
+    layer_images: HashMap<Uuid, Handle<Image>>,
+    /// Tile width and height from the project (cached for convenience)
+    pub tile_size: Vec2,
 }
 
 impl TilesetTextures {
@@ -472,9 +618,54 @@ impl TilesetTextures {
             self.sprite_sheet_images.insert(sprite_sheet_id, handle);
         }
 
+        // Load image layer pictures
+        for layer in &project.level.layers {
+            if let bevy_map_core::LayerData::Image { path, .. } = &layer.data {
+                // New image layers have no picture until one is picked
+                if path.is_empty() {
+                    continue;
+                }
+                let handle = asset_server.load(normalize_asset_path(path));
+                self.layer_images.insert(layer.id, handle);
+            }
+        }
+
         // Get tile size from the first tileset
         if let Some(tileset) = project.tilesets.values().next() {
-            self.tile_size = tileset.tile_size as f32;
+            let (width, height) = tileset.tile_dimensions();
+            self.tile_size = Vec2::new(width as f32, height as f32);
+        }
+    }
+
+    /// Repack tileset images that have a margin or spacing
+    ///
+    /// bevy_ecs_tilemap expects tiles packed edge to edge, so each such image
+    /// is replaced by a packed copy. Call this once every image has loaded;
+    /// images that can't be repacked keep their original texture.
+    pub fn pack_tileset_images(
+        &mut self,
+        project: &bevy_map_core::MapProject,
+        images: &mut Assets<Image>,
+    ) {
+        for tileset in project.tilesets.values() {
+            for (image_index, tileset_image) in tileset.images.iter().enumerate() {
+                if tileset_image.is_packed() {
+                    continue;
+                }
+                let Some(handle) = self.images.get_mut(&(tileset.id, image_index)) else {
+                    continue;
+                };
+                let packed = images.get(&*handle).and_then(|image| {
+                    render::pack_tileset_image(image, tileset_image, tileset.tile_dimensions())
+                });
+                match packed {
+                    Some(packed) => *handle = images.add(packed),
+                    None => warn!(
+                        "Could not repack image '{}' of tileset '{}', ignoring its margin and spacing",
+                        tileset_image.path, tileset.name
+                    ),
+                }
+            }
         }
     }
 
@@ -498,6 +689,16 @@ impl TilesetTextures {
         self.sprite_sheet_images.insert(sprite_sheet_id, handle);
     }
 
+    /// Get texture handle for an image layer
+    pub fn get_layer_image(&self, layer_id: Uuid) -> Option<&Handle<Image>> {
+        self.layer_images.get(&layer_id)
+    }
+
+    /// Insert an image layer texture handle manually
+    pub fn insert_layer_image(&mut self, layer_id: Uuid, handle: Handle<Image>) {
+        self.layer_images.insert(layer_id, handle);
+    }
+
     /// Check if all textures (tilesets and sprite sheets) are loaded
     pub fn all_loaded(&self, asset_server: &AssetServer) -> bool {
         use bevy::asset::LoadState;
@@ -514,12 +715,16 @@ impl TilesetTextures {
             }
         });
 
-        let sprite_sheets_loaded = self.sprite_sheet_images.values().all(|handle| {
-            matches!(
-                asset_server.get_load_state(handle.id()),
-                Some(LoadState::Loaded) | Some(LoadState::Failed(_))
-            )
-        });
+        let sprite_sheets_loaded = self
+            .sprite_sheet_images
+            .values()
+            .chain(self.layer_images.values())
+            .all(|handle| {
+                matches!(
+                    asset_server.get_load_state(handle.id()),
+                    Some(LoadState::Loaded) | Some(LoadState::Failed(_))
+                )
+            });
 
         tilesets_loaded && sprite_sheets_loaded
     }
@@ -935,6 +1140,28 @@ pub struct RuntimeMap {
 #[derive(Component)]
 pub struct MapLayerIndex(pub usize);
 
+/// Component linking a tilemap to the tileset image it draws from
+///
+/// Layers using a multi-image tileset get one tilemap per image.
+#[derive(Component)]
+pub struct TilemapImageIndex(pub usize);
+
+/// A multi-cell tile drawn as a sprite instead of a tilemap tile
+///
+/// Tiles larger than one grid cell (e.g. a 2x3 tree) are rendered as a single
+/// sprite anchored at their base cell, matching the editor.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct MultiCellTile {
+    /// Index of the layer this tile belongs to
+    pub layer_index: usize,
+    /// Base cell X coordinate
+    pub x: u32,
+    /// Base cell Y coordinate
+    pub y: u32,
+    /// Virtual tile index in the layer's tileset
+    pub tile_index: u32,
+}
+
 fn handle_spawn_map_events(
     mut commands: Commands,
     mut spawn_events: MessageReader<SpawnMapEvent>,
@@ -957,7 +1184,8 @@ fn handle_spawn_map_events(
 /// Spawn a map from a Level with the given tileset textures
 ///
 /// If an `EntityRegistry` is provided, entities from the level will be
-/// automatically spawned with the appropriate components.
+/// automatically spawned with the appropriate components, as children of the
+/// map root.
 pub fn spawn_map(
     commands: &mut Commands,
     level: &bevy_map_core::Level,
@@ -971,6 +1199,7 @@ pub fn spawn_map(
             RuntimeMap {
                 level_name: level.name.clone(),
             },
+            LevelProperties::new(level.properties.clone()),
             transform,
             Visibility::default(),
         ))
@@ -1020,6 +1249,7 @@ pub fn spawn_map(
                                 position: tile_pos,
                                 tilemap_id: TilemapId(tilemap_entity),
                                 texture_index: TileTextureIndex(tile_index),
+                                color: TileColor(layers::layer_color(layer, layer.opacity)),
                                 ..default()
                             })
                             .id();
@@ -1028,10 +1258,10 @@ pub fn spawn_map(
                 }
             }
 
-            let map_type = TilemapType::Square;
+            let map_type = render::tilemap_type(level.orientation);
 
             // Calculate layer z-offset based on layer index
-            let layer_z = layer_index as f32 * 0.1;
+            let layer_z = MapLayerSettings::default().layer_z(layer_index);
 
             commands.entity(tilemap_entity).insert((
                 TilemapBundle {
@@ -1042,24 +1272,34 @@ pub fn spawn_map(
                     texture: TilemapTexture::Single(texture_handle),
                     tile_size: tilemap_tile_size,
                     transform: Transform::from_xyz(0.0, 0.0, layer_z),
+                    visibility: if layer.visible {
+                        Visibility::Inherited
+                    } else {
+                        Visibility::Hidden
+                    },
                     ..default()
                 },
                 MapLayerIndex(layer_index),
+                LayerProperties::new(layer.properties.clone()),
             ));
 
             commands.entity(map_entity).add_child(tilemap_entity);
         }
     }
 
-    // Spawn entities if registry is provided
+    // Spawn entities as children of the map if registry is provided
     if let Some(registry) = entity_registry {
-        registry.spawn_all(commands, &level.entities, transform);
+        let entities = registry.spawn_map_entities(commands, &level.entities, map_entity);
+        commands.entity(map_entity).insert(entities);
     }
 
     map_entity
 }
 
 /// Update a tile at runtime
+///
+/// This only swaps the tile entity in one tilemap. Use [`MapEditCommands`]
+/// to also update the level data, terrain and colliders.
 pub fn set_tile(
     commands: &mut Commands,
     tile_storage: &mut TileStorage,
@@ -1114,15 +1354,19 @@ fn handle_spawn_map_project_events(
     mut spawned_events: MessageWriter<MapSpawnedEvent>,
     entity_registry: Res<EntityRegistry>,
     mut map_dialogues: ResMut<MapDialogues>,
+    mut images: ResMut<Assets<Image>>,
 ) {
     for event in spawn_events.read() {
         // Load dialogues from the project
         map_dialogues.load_from_project(&event.project);
 
+        let mut textures = event.textures.clone();
+        textures.pack_tileset_images(&event.project, &mut images);
+
         let map_entity = spawn_map_project(
             &mut commands,
             &event.project,
-            &event.textures,
+            &textures,
             event.transform,
             Some(&entity_registry),
         );
@@ -1130,12 +1374,454 @@ fn handle_spawn_map_project_events(
     }
 }
 
+/// A single-cell tile collected for one tilemap of a layer
+struct LayerTile {
+    x: u32,
+    y: u32,
+    local_tile_index: u32,
+    flip: TileFlip,
+    /// Local animation frames and frames per second
+    animation: Option<(Vec<u32>, f32)>,
+}
+
+impl LayerTile {
+    /// Resolve a raw tile to the image it is drawn from
+    ///
+    /// Animated tiles go to the image of their first frame.
+    fn new(tileset: &Tileset, x: u32, y: u32, raw: u32) -> Option<(usize, Self)> {
+        let virtual_tile_index = bevy_map_core::tile_index(raw);
+        let flip = render::tile_flip_from_raw(raw);
+
+        if let Some((image_index, frames)) = tileset.animation_frames_local(virtual_tile_index) {
+            let fps = tileset
+                .get_tile_properties(virtual_tile_index)
+                .map(tile_animation::tile_animation_fps)
+                .unwrap_or(tile_animation::DEFAULT_TILE_ANIMATION_FPS);
+            let local_tile_index = frames[0];
+            Some((
+                image_index,
+                Self {
+                    x,
+                    y,
+                    local_tile_index,
+                    flip,
+                    animation: Some((frames, fps)),
+                },
+            ))
+        } else {
+            let (image_index, local_tile_index) = tileset.virtual_to_local(virtual_tile_index)?;
+            Some((
+                image_index,
+                Self {
+                    x,
+                    y,
+                    local_tile_index,
+                    flip,
+                    animation: None,
+                },
+            ))
+        }
+    }
+
+    /// Position of the tile in a tilemap covering `area`
+    fn position(&self, area: &MapChunk) -> TilePos {
+        TilePos {
+            x: self.x - area.origin.x,
+            y: self.y - area.origin.y,
+        }
+    }
+
+    /// Spawn the tile into a tilemap covering `area`
+    fn spawn(
+        self,
+        commands: &mut Commands,
+        tilemap_entity: Entity,
+        area: &MapChunk,
+        color: TileColor,
+    ) -> Entity {
+        let mut tile_commands = commands.spawn(TileBundle {
+            position: self.position(area),
+            tilemap_id: TilemapId(tilemap_entity),
+            texture_index: TileTextureIndex(self.local_tile_index),
+            flip: self.flip,
+            color,
+            ..default()
+        });
+        if let Some((frames, fps)) = self.animation {
+            tile_animation::insert_tile_animation(&mut tile_commands, frames, fps);
+        }
+        tile_commands.id()
+    }
+}
+
+/// Tilemap geometry shared by every layer of a level
+pub(crate) struct LevelGrid {
+    pub size: TilemapSize,
+    pub grid_size: TilemapGridSize,
+    pub tile_size: TilemapTileSize,
+    pub map_type: TilemapType,
+    pub anchor: TilemapAnchor,
+}
+
+impl LevelGrid {
+    pub(crate) fn new(level: &bevy_map_core::Level, tile_size: Vec2) -> Self {
+        let tilemap_tile_size = TilemapTileSize {
+            x: tile_size.x,
+            y: tile_size.y,
+        };
+        Self {
+            size: TilemapSize {
+                x: level.width,
+                y: level.height,
+            },
+            grid_size: tilemap_tile_size.into(),
+            tile_size: tilemap_tile_size,
+            map_type: render::tilemap_type(level.orientation),
+            anchor: TilemapAnchor::default(),
+        }
+    }
+
+    /// Bottom-left corner of a cell in map space
+    ///
+    /// For isometric and hexagonal levels this is the corner of the cell's
+    /// bounding box.
+    pub(crate) fn cell_corner(&self, x: u32, y: u32) -> Vec2 {
+        TilePos { x, y }.center_in_world(
+            &self.size,
+            &self.grid_size,
+            &self.tile_size,
+            &self.map_type,
+            &self.anchor,
+        ) - Vec2::new(self.tile_size.x, self.tile_size.y) / 2.0
+    }
+
+    /// Cell containing a point in map space, or `None` outside the level
+    pub(crate) fn cell_at(&self, point: Vec2) -> Option<UVec2> {
+        if self.map_type != TilemapType::Square {
+            return TilePos::from_world_pos(
+                &point,
+                &self.size,
+                &self.grid_size,
+                &self.tile_size,
+                &self.map_type,
+                &self.anchor,
+            )
+            .map(|pos| UVec2::new(pos.x, pos.y));
+        }
+        let cell = ((point - self.cell_corner(0, 0))
+            / Vec2::new(self.tile_size.x, self.tile_size.y))
+        .floor();
+        (cell.x >= 0.0
+            && cell.y >= 0.0
+            && (cell.x as u32) < self.size.x
+            && (cell.y as u32) < self.size.y)
+            .then(|| UVec2::new(cell.x as u32, cell.y as u32))
+    }
+}
+
+/// How a layer is drawn, including the settings of its parent groups
+pub(crate) struct LayerStyle {
+    pub visibility: Visibility,
+    pub color: TileColor,
+    pub offset: Vec2,
+    pub parallax: Option<LayerParallax>,
+}
+
+impl LayerStyle {
+    pub(crate) fn new(
+        layer: &bevy_map_core::Layer,
+        effective: &bevy_map_core::EffectiveLayer,
+    ) -> Self {
+        let offset = Vec2::new(effective.offset_x, effective.offset_y);
+        Self {
+            visibility: if effective.visible {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            },
+            color: TileColor(layers::layer_color(layer, effective.opacity)),
+            offset,
+            parallax: layer
+                .has_parallax()
+                .then(|| LayerParallax::from_layer(layer, offset)),
+        }
+    }
+}
+
+/// Turn `tilemap_entity` into the tilemap for one image of a tile layer
+///
+/// The tilemap covers `area` of the level.
+pub(crate) fn insert_layer_tilemap(
+    commands: &mut Commands,
+    map_entity: Entity,
+    tilemap_entity: Entity,
+    grid: &LevelGrid,
+    layer: &bevy_map_core::Layer,
+    layer_index: usize,
+    tileset: &Tileset,
+    image_index: usize,
+    texture: Handle<Image>,
+    storage: TileStorage,
+    area: MapChunk,
+    z: f32,
+    style: &LayerStyle,
+) {
+    // Tile (0, 0) of the tilemap sits on the area's origin cell
+    let base = grid.cell_corner(area.origin.x, area.origin.y) - grid.cell_corner(0, 0);
+    commands.entity(tilemap_entity).insert((
+        TilemapBundle {
+            grid_size: grid.grid_size,
+            map_type: grid.map_type,
+            size: TilemapSize {
+                x: area.size.x,
+                y: area.size.y,
+            },
+            storage,
+            texture: TilemapTexture::Single(texture),
+            tile_size: grid.tile_size,
+            transform: Transform::from_translation((base + style.offset).extend(z)),
+            visibility: style.visibility,
+            anchor: grid.anchor,
+            ..default()
+        },
+        MapLayerIndex(layer_index),
+        TilemapImageIndex(image_index),
+        area,
+        LayerProperties::new(layer.properties.clone()),
+        TilesetProperties::new(tileset.properties.clone()),
+    ));
+    if let Some(parallax) = style.parallax {
+        commands
+            .entity(tilemap_entity)
+            .insert(LayerParallax { base, ..parallax });
+    }
+
+    commands.entity(map_entity).add_child(tilemap_entity);
+}
+
+/// Spawn a multi-cell tile as a sprite spanning its full grid size
+///
+/// Returns the sprite's z, or `None` if the tile or its texture is missing.
+pub(crate) fn spawn_multi_cell_tile(
+    commands: &mut Commands,
+    map_entity: Entity,
+    grid: &LevelGrid,
+    tileset: &Tileset,
+    textures: &TilesetTextures,
+    layer_index: usize,
+    x: u32,
+    y: u32,
+    raw: u32,
+    layer_settings: &MapLayerSettings,
+    style: &LayerStyle,
+) -> Option<f32> {
+    let virtual_tile_index = bevy_map_core::tile_index(raw);
+    let (image_index, local_tile_index) = tileset.virtual_to_local(virtual_tile_index)?;
+    let texture_handle = textures.get(tileset.id, image_index)?;
+    let columns = tileset
+        .get_image(image_index)
+        .map(|image| image.columns)
+        .unwrap_or(tileset.columns)
+        .max(1);
+    let (grid_width, grid_height) = tileset.get_tile_grid_size(virtual_tile_index);
+    let props = tileset
+        .get_tile_properties(virtual_tile_index)
+        .cloned()
+        .unwrap_or_default();
+
+    // Slightly above regular tiles in the same layer, like the editor
+    let sprite_z = layer_settings.sprite_z(layer_index, image_index);
+
+    let (mut sprite, mut sprite_transform) = render::multi_cell_tile_sprite(
+        texture_handle.clone(),
+        local_tile_index,
+        columns,
+        tileset.tile_dimensions(),
+        (grid_width, grid_height),
+        &props,
+        render::tile_flip_from_raw(raw),
+        grid.cell_corner(x, y).extend(sprite_z),
+    );
+    sprite.color = style.color.0;
+    let sprite_base = sprite_transform.translation.truncate();
+    sprite_transform.translation += style.offset.extend(0.0);
+
+    let sprite_entity = commands
+        .spawn((
+            sprite,
+            sprite_transform,
+            style.visibility,
+            MapLayerIndex(layer_index),
+            MultiCellTile {
+                layer_index,
+                x,
+                y,
+                tile_index: virtual_tile_index,
+            },
+        ))
+        .id();
+    if let Some(parallax) = style.parallax {
+        commands.entity(sprite_entity).insert(LayerParallax {
+            base: sprite_base,
+            ..parallax
+        });
+    }
+    commands.entity(map_entity).add_child(sprite_entity);
+
+    Some(sprite_z)
+}
+
+/// Spawn the tiles of a tile layer that lie in one area of the level
+///
+/// Tiles go into one tilemap per tileset image covering the area, and
+/// multi-cell tiles based in the area become sprites. Returns the highest z
+/// used, or `None` if nothing was spawned.
+pub(crate) fn spawn_layer_area(
+    commands: &mut Commands,
+    map_entity: Entity,
+    grid: &LevelGrid,
+    layer: &bevy_map_core::Layer,
+    layer_index: usize,
+    tiles: &[Option<u32>],
+    tileset: &Tileset,
+    textures: &TilesetTextures,
+    layer_settings: &MapLayerSettings,
+    style: &LayerStyle,
+    area: MapChunk,
+) -> Option<f32> {
+    // For multi-image tilesets, we need to create separate tilemaps per image
+    // because bevy_ecs_tilemap uses a single texture per tilemap.
+    // Group tiles by which image they belong to. Multi-cell tiles are
+    // drawn as sprites instead, matching the editor.
+    let mut tiles_by_image: HashMap<usize, Vec<LayerTile>> = HashMap::new();
+    let mut multi_cell_tiles: Vec<(u32, u32, u32)> = Vec::new();
+
+    for y in area.origin.y..area.origin.y + area.size.y {
+        for x in area.origin.x..area.origin.x + area.size.x {
+            let idx = (y * grid.size.x + x) as usize;
+            let Some(&Some(raw)) = tiles.get(idx) else {
+                continue;
+            };
+            // Cells covered by a multi-cell tile are drawn by its base cell
+            if bevy_map_core::is_occupied_cell(raw) {
+                continue;
+            }
+
+            if tileset.is_multi_cell_tile(bevy_map_core::tile_index(raw)) {
+                multi_cell_tiles.push((x, y, raw));
+            } else if let Some((image_index, tile)) = LayerTile::new(tileset, x, y, raw) {
+                tiles_by_image.entry(image_index).or_default().push(tile);
+            }
+        }
+    }
+
+    let mut top_z: Option<f32> = None;
+
+    // Spawn a tilemap for each image used in this area
+    for (image_index, image_tiles) in tiles_by_image {
+        debug!(
+            "Layer {}: Spawning {} tiles from tileset {} image {}",
+            layer_index,
+            image_tiles.len(),
+            tileset.id,
+            image_index
+        );
+        let Some(texture_handle) = textures.get(tileset.id, image_index) else {
+            warn!(
+                "Missing texture for tileset {} image {}",
+                tileset.id, image_index
+            );
+            continue;
+        };
+
+        let mut tile_storage = TileStorage::empty(TilemapSize {
+            x: area.size.x,
+            y: area.size.y,
+        });
+        let tilemap_entity = commands.spawn_empty().id();
+
+        // Spawn tiles for this image
+        for tile in image_tiles {
+            let tile_pos = tile.position(&area);
+            let tile_entity = tile.spawn(commands, tilemap_entity, &area, style.color);
+            tile_storage.set(&tile_pos, tile_entity);
+        }
+
+        // All images of a layer stay within its z step, so ordering
+        // between layers is preserved
+        let image_z = layer_settings.image_z(layer_index, image_index);
+        top_z = Some(top_z.map_or(image_z, |z| z.max(image_z)));
+
+        insert_layer_tilemap(
+            commands,
+            map_entity,
+            tilemap_entity,
+            grid,
+            layer,
+            layer_index,
+            tileset,
+            image_index,
+            texture_handle.clone(),
+            tile_storage,
+            area,
+            image_z,
+            style,
+        );
+    }
+
+    // Spawn multi-cell tiles as sprites spanning their full grid size
+    for (x, y, raw) in multi_cell_tiles {
+        if let Some(sprite_z) = spawn_multi_cell_tile(
+            commands,
+            map_entity,
+            grid,
+            tileset,
+            textures,
+            layer_index,
+            x,
+            y,
+            raw,
+            layer_settings,
+            style,
+        ) {
+            top_z = Some(top_z.map_or(sprite_z, |z| z.max(sprite_z)));
+        }
+    }
+
+    top_z
+}
+
+/// Highest z the tiles of a layer are drawn at, without spawning them
+fn layer_top_z(
+    tiles: &[Option<u32>],
+    tileset: &Tileset,
+    layer_index: usize,
+    layer_settings: &MapLayerSettings,
+) -> Option<f32> {
+    tiles
+        .iter()
+        .flatten()
+        .filter(|raw| !bevy_map_core::is_occupied_cell(**raw))
+        .filter_map(|&raw| {
+            let virtual_tile_index = bevy_map_core::tile_index(raw);
+            if tileset.is_multi_cell_tile(virtual_tile_index) {
+                let (image_index, _) = tileset.virtual_to_local(virtual_tile_index)?;
+                Some(layer_settings.sprite_z(layer_index, image_index))
+            } else {
+                let (image_index, _) = LayerTile::new(tileset, 0, 0, raw)?;
+                Some(layer_settings.image_z(layer_index, image_index))
+            }
+        })
+        .reduce(f32::max)
+}
+
 /// Spawn a map from a MapProject with proper tileset handling
 ///
 /// This function properly handles:
 /// - Multi-image tilesets (tiles referencing correct image by virtual index)
+/// - Animated tiles and flip flags
 /// - Tileset metadata embedded in the project
-/// - Entity spawning via EntityRegistry
+/// - Entity spawning via EntityRegistry, parented under the map root
 ///
 /// # Example
 ///
@@ -1165,6 +1851,50 @@ pub fn spawn_map_project(
     textures: &TilesetTextures,
     transform: Transform,
     entity_registry: Option<&EntityRegistry>,
+) -> Entity {
+    spawn_map_project_with_settings(
+        commands,
+        project,
+        textures,
+        transform,
+        entity_registry,
+        &MapLayerSettings::default(),
+    )
+}
+
+/// Spawn a map from a MapProject with custom layer depth and visibility settings
+///
+/// See [`spawn_map_project`]. The spawned map root gets a [`MapLayers`]
+/// component describing where each layer was placed, and a [`MapIntGrids`]
+/// component holding the level's IntGrid layers.
+pub fn spawn_map_project_with_settings(
+    commands: &mut Commands,
+    project: &bevy_map_core::MapProject,
+    textures: &TilesetTextures,
+    transform: Transform,
+    entity_registry: Option<&EntityRegistry>,
+    layer_settings: &MapLayerSettings,
+) -> Entity {
+    spawn_map_project_impl(
+        commands,
+        project,
+        textures,
+        transform,
+        entity_registry,
+        layer_settings,
+        None,
+    )
+}
+
+/// Spawn a map, leaving its tiles to chunk streaming if `chunk_settings` is set
+fn spawn_map_project_impl(
+    commands: &mut Commands,
+    project: &bevy_map_core::MapProject,
+    textures: &TilesetTextures,
+    transform: Transform,
+    entity_registry: Option<&EntityRegistry>,
+    layer_settings: &MapLayerSettings,
+    chunk_settings: Option<MapChunkSettings>,
 ) -> Entity {
     let level = &project.level;
     let tile_size = textures.tile_size;
@@ -1174,15 +1904,38 @@ pub fn spawn_map_project(
             RuntimeMap {
                 level_name: level.name.clone(),
             },
+            LevelProperties::new(level.properties.clone()),
             transform,
             Visibility::default(),
         ))
         .id();
+    let mut map_layers = MapLayers::new(*layer_settings);
+    let grid = LevelGrid::new(level, tile_size);
 
     // Spawn each tile layer
     for (layer_index, layer) in level.layers.iter().enumerate() {
         info!("Processing layer {}: '{}'", layer_index, layer.name);
 
+        // Visibility, opacity and offset include the layer's parent groups
+        let Some(effective) = level.effective_layer(layer_index) else {
+            continue;
+        };
+        let layer_z = layer_settings.layer_z(layer_index);
+        map_layers.layers.push(MapLayerDepth {
+            index: layer_index,
+            name: layer.name.clone(),
+            z: layer_z,
+            top_z: layer_z,
+            visible: effective.visible,
+            opacity: effective.opacity,
+        });
+
+        if !layer_settings.should_spawn(effective.visible) {
+            info!("  Layer {} is hidden, skipping", layer_index);
+            continue;
+        }
+        let style = LayerStyle::new(layer, &effective);
+
         if let bevy_map_core::LayerData::Tiles {
             tileset_id, tiles, ..
         } = &layer.data
@@ -1214,93 +1967,72 @@ pub fn spawn_map_project(
                 tileset.images.len()
             );
 
-            // For multi-image tilesets, we need to create separate tilemaps per image
-            // because bevy_ecs_tilemap uses a single texture per tilemap.
-            // Group tiles by which image they belong to.
-            let mut tiles_by_image: HashMap<usize, Vec<(u32, u32, u32)>> = HashMap::new();
-
-            for y in 0..level.height {
-                for x in 0..level.width {
-                    let idx = (y * level.width + x) as usize;
-                    if let Some(&Some(virtual_tile_index)) = tiles.get(idx) {
-                        if let Some((image_index, local_tile_index)) =
-                            tileset.virtual_to_local(virtual_tile_index)
-                        {
-                            tiles_by_image.entry(image_index).or_default().push((
-                                x,
-                                y,
-                                local_tile_index,
-                            ));
-                        }
-                    }
-                }
-            }
-
-            // Spawn a tilemap for each image used in this layer
-            for (image_index, image_tiles) in tiles_by_image {
-                info!(
-                    "Layer {}: Spawning {} tiles from tileset {} image {}",
+            // Chunked maps spawn their tiles as chunks come near a camera
+            let top_z = if chunk_settings.is_some() {
+                layer_top_z(tiles, tileset, layer_index, layer_settings)
+            } else {
+                spawn_layer_area(
+                    commands,
+                    map_entity,
+                    &grid,
+                    layer,
                     layer_index,
-                    image_tiles.len(),
-                    tileset_id,
-                    image_index
-                );
-                let Some(texture_handle) = textures.get(*tileset_id, image_index) else {
-                    warn!(
-                        "Missing texture for tileset {} image {}",
-                        tileset_id, image_index
-                    );
-                    continue;
-                };
-
-                let map_size = TilemapSize {
-                    x: level.width,
-                    y: level.height,
-                };
-
-                let tilemap_tile_size = TilemapTileSize {
-                    x: tile_size,
-                    y: tile_size,
-                };
+                    tiles,
+                    tileset,
+                    textures,
+                    layer_settings,
+                    &style,
+                    MapChunk::whole(level.width, level.height),
+                )
+            };
+            if let (Some(top_z), Some(depth)) = (top_z, map_layers.layers.last_mut()) {
+                depth.top_z = depth.top_z.max(top_z);
+            }
+        } else if let bevy_map_core::LayerData::Image {
+            path,
+            repeat_x,
+            repeat_y,
+        } = &layer.data
+        {
+            if path.is_empty() {
+                continue;
+            }
+            let Some(texture_handle) = textures.get_layer_image(layer.id) else {
+                warn!("Missing texture for image layer {}", layer_index);
+                continue;
+            };
 
-                let grid_size: TilemapGridSize = tilemap_tile_size.into();
-                let mut tile_storage = TileStorage::empty(map_size);
-                let tilemap_entity = commands.spawn_empty().id();
-
-                // Spawn tiles for this image
-                for (x, y, local_tile_index) in image_tiles {
-                    let tile_pos = TilePos { x, y };
-                    let tile_entity = commands
-                        .spawn(TileBundle {
-                            position: tile_pos,
-                            tilemap_id: TilemapId(tilemap_entity),
-                            texture_index: TileTextureIndex(local_tile_index),
-                            ..default()
-                        })
-                        .id();
-                    tile_storage.set(&tile_pos, tile_entity);
-                }
+            // The image's top-left corner sits on the level's top-left corner
+            let top_left =
+                grid.cell_corner(0, level.height.saturating_sub(1)) + Vec2::new(0.0, tile_size.y);
 
-                // Z-offset: layer_index * 0.1 + image_index * 0.01
-                // This ensures proper ordering: all images in layer 0 render before layer 1
-                let layer_z = layer_index as f32 * 0.1 + image_index as f32 * 0.01;
-
-                commands.entity(tilemap_entity).insert((
-                    TilemapBundle {
-                        grid_size,
-                        map_type: TilemapType::Square,
-                        size: map_size,
-                        storage: tile_storage,
-                        texture: TilemapTexture::Single(texture_handle.clone()),
-                        tile_size: tilemap_tile_size,
-                        transform: Transform::from_xyz(0.0, 0.0, layer_z),
+            let sprite_entity = commands
+                .spawn((
+                    Sprite {
+                        image: texture_handle.clone(),
+                        color: style.color.0,
                         ..default()
                     },
+                    bevy::sprite::Anchor::TOP_LEFT,
+                    Transform::from_translation((top_left + style.offset).extend(layer_z)),
+                    style.visibility,
                     MapLayerIndex(layer_index),
-                ));
-
-                commands.entity(map_entity).add_child(tilemap_entity);
+                    LayerProperties::new(layer.properties.clone()),
+                    ImageLayer {
+                        layer_index,
+                        repeat_x: *repeat_x,
+                        repeat_y: *repeat_y,
+                        level_size: Vec2::new(level.width as f32, level.height as f32) * tile_size,
+                    },
+                ))
+                .id();
+            if let Some(parallax) = style.parallax {
+                commands.entity(sprite_entity).insert(LayerParallax {
+                    base: top_left,
+                    ..parallax
+                });
             }
+            commands.entity(map_entity).add_child(sprite_entity);
         } else {
             info!(
                 "  Layer {} is not a tile layer (entity layer or other)",
@@ -1309,9 +2041,19 @@ pub fn spawn_map_project(
         }
     }
 
-    // Spawn entities if registry is provided
+    commands.entity(map_entity).insert((
+        map_layers,
+        MapIntGrids::from_level(level, tile_size),
+        MapLevelData(level.clone()),
+    ));
+    if let Some(settings) = chunk_settings {
+        commands.entity(map_entity).insert(MapChunks::new(settings));
+    }
+
+    // Spawn entities as children of the map if registry is provided
     if let Some(registry) = entity_registry {
-        registry.spawn_all(commands, &level.entities, transform);
+        let entities = registry.spawn_map_entities(commands, &level.entities, map_entity);
+        commands.entity(map_entity).insert(entities);
     }
 
     map_entity
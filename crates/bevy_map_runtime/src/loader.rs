@@ -1,6 +1,7 @@
 //! Asset loader for MapProject files
 //!
-//! This module provides a Bevy AssetLoader implementation for loading `.map.json` files.
+//! This module provides a Bevy AssetLoader implementation for loading `.map.json` files,
+//! and their compact binary counterpart `.map.bin` (see the editor's "Export Binary").
 //! When combined with Bevy's `file_watcher` feature, this enables hot-reloading of maps
 //! during development.
 //!
@@ -28,7 +29,10 @@
 use bevy::asset::io::Reader;
 use bevy::asset::{AssetLoader, LoadContext};
 use bevy::reflect::TypePath;
-use bevy_map_core::{EditorProject, MapProject};
+use bevy_map_core::{
+    binary_to_json, is_binary, BinaryError, EditorProject, MapProject, WorldProject,
+};
+use serde::Deserialize;
 use thiserror::Error;
 
 /// Error type for map loading failures
@@ -38,13 +42,15 @@ pub enum MapLoadError {
     Io(#[from] std::io::Error),
     #[error("Failed to parse JSON: {0}")]
     Json(#[from] serde_json::Error),
+    #[error("Failed to decode binary map: {0}")]
+    Binary(#[from] BinaryError),
     #[error("Invalid map format: {0}")]
     InvalidFormat(String),
 }
 
 /// Asset loader for MapProject JSON files
 ///
-/// Supports `.map.json` file extension. The loader parses the JSON and returns
+/// Supports the `.map.json` and `.map.bin` file extensions. The loader parses the file and returns
 /// a `MapProject` asset that can be used with `SpawnMapCommand` or the
 /// `handle_map_spawning` system.
 #[derive(Default, TypePath)]
@@ -64,26 +70,44 @@ impl AssetLoader for MapProjectLoader {
         // Read the entire file
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
+        load_map_from_bytes(&bytes)
+    }
 
-        // Try EditorProject format first (what the editor exports)
-        // EditorProject uses Vec collections (levels, tilesets arrays)
-        if let Ok(editor_project) = serde_json::from_slice::<EditorProject>(&bytes) {
-            return editor_project
-                .to_map_project()
-                .ok_or_else(|| MapLoadError::InvalidFormat("No levels in project".to_string()));
-        }
+    fn extensions(&self) -> &[&str] {
+        &["map.json", "map.bin"]
+    }
+}
 
-        // Fall back to MapProject format (hand-crafted JSON with HashMap collections)
-        let project: MapProject = serde_json::from_slice(&bytes)?;
+/// Asset loader for whole-project `.map.json` and `.map.bin` files
+///
+/// Loads every level of an editor project as a `WorldProject`, together with the
+/// world layout and level connections. Shares its extensions with
+/// `MapProjectLoader`; Bevy picks the loader from the requested asset type:
+///
+/// ```rust,ignore
+/// let world: Handle<WorldProject> = asset_server.load("maps/world.map.json");
+/// ```
+#[derive(Default, TypePath)]
+pub struct WorldProjectLoader;
 
-        // Validate the project
-        project.validate().map_err(MapLoadError::InvalidFormat)?;
+impl AssetLoader for WorldProjectLoader {
+    type Asset = WorldProject;
+    type Settings = ();
+    type Error = MapLoadError;
 
-        Ok(project)
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        load_world_from_bytes(&bytes)
     }
 
     fn extensions(&self) -> &[&str] {
-        &["map.json"]
+        &["map.json", "map.bin"]
     }
 }
 
@@ -113,3 +137,85 @@ pub fn load_project_from_str(json: &str) -> Result<MapProject, serde_json::Error
 pub fn load_project_from_bytes(bytes: &[u8]) -> Result<MapProject, serde_json::Error> {
     serde_json::from_slice(bytes)
 }
+
+/// Load a MapProject from JSON or binary bytes
+///
+/// Accepts both the editor project format, from which the first level is
+/// taken, and a single-level `MapProject`.
+pub fn load_map_from_bytes(bytes: &[u8]) -> Result<MapProject, MapLoadError> {
+    match parse_project(bytes)? {
+        ProjectFile::Editor(editor_project) => editor_project
+            .to_map_project()
+            .ok_or_else(|| MapLoadError::InvalidFormat("No levels in project".to_string())),
+        ProjectFile::Map(project) => Ok(project),
+    }
+}
+
+/// Load a WorldProject from JSON or binary bytes
+///
+/// Accepts both the editor project format and a single-level `MapProject`,
+/// which becomes a world with one level.
+pub fn load_world_from_bytes(bytes: &[u8]) -> Result<WorldProject, MapLoadError> {
+    match parse_project(bytes)? {
+        ProjectFile::Editor(editor_project) => Ok(editor_project.to_world_project()),
+        ProjectFile::Map(project) => Ok(WorldProject::from(project)),
+    }
+}
+
+/// A parsed project file in either of its formats
+enum ProjectFile {
+    Editor(EditorProject),
+    Map(MapProject),
+}
+
+fn parse_project(bytes: &[u8]) -> Result<ProjectFile, MapLoadError> {
+    // Try EditorProject format first (what the editor exports)
+    // EditorProject uses Vec collections (levels, tilesets arrays)
+    // Fall back to MapProject format (hand-crafted JSON with HashMap collections)
+    let project: MapProject = if is_binary(bytes) {
+        let document = binary_to_json(bytes)?;
+        if let Ok(editor_project) = EditorProject::deserialize(&document) {
+            return Ok(ProjectFile::Editor(editor_project));
+        }
+        MapProject::deserialize(&document)?
+    } else {
+        if let Ok(editor_project) = serde_json::from_slice::<EditorProject>(bytes) {
+            return Ok(ProjectFile::Editor(editor_project));
+        }
+        serde_json::from_slice(bytes)?
+    };
+
+    project.validate().map_err(MapLoadError::InvalidFormat)?;
+    Ok(ProjectFile::Map(project))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_map_core::{to_binary, Layer, Level, Tileset};
+
+    #[test]
+    fn test_binary_loads_like_json() {
+        let tileset = Tileset::new("Ground".to_string(), "tiles.png".to_string(), 16, 4, 4);
+        let mut level = Level::new("Level".to_string(), 32, 32);
+        level.add_layer(Layer::new_tile_layer(
+            "Ground".to_string(),
+            tileset.id,
+            32,
+            32,
+        ));
+        level.set_tile(0, 3, 4, Some(5));
+        let project = MapProject::new(level, vec![tileset]);
+
+        let json = serde_json::to_vec(&project).unwrap();
+        let binary = to_binary(&project).unwrap();
+        assert_eq!(
+            serde_json::to_value(load_map_from_bytes(&binary).unwrap()).unwrap(),
+            serde_json::to_value(load_map_from_bytes(&json).unwrap()).unwrap()
+        );
+        assert_eq!(
+            serde_json::to_value(load_world_from_bytes(&binary).unwrap()).unwrap(),
+            serde_json::to_value(load_world_from_bytes(&json).unwrap()).unwrap()
+        );
+    }
+}
@@ -0,0 +1,1097 @@
+//! Navigation grids and pathfinding built from tile collision
+//!
+//! [`MapNavigationPlugin`] builds a [`NavGrid`] for every spawned map from the
+//! `TileProperties::collision` of its tile layers. Tiles with collision are
+//! blocked, except one-way platforms, which can be walked through. Tiles can
+//! be made slower to cross with a numeric custom property, e.g. `cost = 3.0`
+//! on mud tiles.
+//!
+//! Paths are queried through the [`MapNavigation`] resource. Grids follow
+//! tiles changed with `MapEditCommands`, updating only the edited cells.
+//! World positions follow the level's orientation, but steps always go to the
+//! four (or eight) neighbouring cells of the level's grid, which matches
+//! isometric levels and not hexagonal or staggered ones.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use bevy::prelude::*;
+//! use bevy_map_runtime::navigation::{MapNavigationPlugin, MapNavigationSettings};
+//!
+//! app.add_plugins(MapNavigationPlugin).insert_resource(
+//!     MapNavigationSettings::new()
+//!         .with_layers(["Walls", "Ground"])
+//!         .with_cost_property("cost"),
+//! );
+//!
+//! fn chase(
+//!     navigation: Res<MapNavigation>,
+//!     map: Single<Entity, With<MapHandle>>,
+//!     player: Single<&Transform, With<Player>>,
+//!     mut enemies: Query<(&Transform, &mut Waypoints), With<Enemy>>,
+//! ) {
+//!     for (transform, mut waypoints) in enemies.iter_mut() {
+//!         let start = transform.translation.truncate();
+//!         let goal = player.translation.truncate();
+//!         if let Some(path) = navigation.find_path(*map, start, goal) {
+//!             waypoints.0 = path;
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! # Platformers
+//!
+//! [`MapNavigation::find_platformer_path`] only stands on cells with ground
+//! below them and links them by walking, falling and jumping, limited by
+//! [`PlatformerJumps`]. Each step says how it is reached, so a character
+//! controller knows when to jump.
+
+use bevy::ecs::message::MessageReader;
+use bevy::math::Affine3A;
+use bevy::prelude::*;
+use bevy_map_core::{LayerData, Level, MapProject};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::{edit, LevelGrid, MapLevelData, MapRoot, MapTilesChanged};
+
+/// Plugin that builds navigation grids for spawned maps
+///
+/// Requires `MapRuntimePlugin`.
+pub struct MapNavigationPlugin;
+
+impl Plugin for MapNavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MapNavigationSettings>()
+            .init_resource::<MapNavigation>()
+            .add_systems(
+                Update,
+                (
+                    build_navigation_grids,
+                    update_navigation_grids.after(edit::apply_map_edits),
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// How agents move between cells
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NavMovement {
+    /// Up, down, left and right only
+    Cardinal,
+    /// Also diagonally, without cutting past blocked corners
+    #[default]
+    Diagonal,
+}
+
+/// Search algorithm for grid paths
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PathAlgorithm {
+    /// Plain A*
+    AStar,
+    /// Jump point search, which skips over open areas
+    ///
+    /// Only used for diagonal movement on grids where every cell costs the
+    /// same; otherwise paths fall back to A*.
+    #[default]
+    JumpPoint,
+}
+
+/// Movement limits for platformer paths, in cells
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlatformerJumps {
+    /// Highest jump
+    pub height: u32,
+    /// Widest jump
+    pub distance: u32,
+    /// Longest fall
+    pub fall: u32,
+}
+
+impl Default for PlatformerJumps {
+    fn default() -> Self {
+        Self {
+            height: 3,
+            distance: 4,
+            fall: 12,
+        }
+    }
+}
+
+/// Settings for navigation grids
+///
+/// Changing this resource rebuilds the grids of all spawned maps.
+#[derive(Resource, Debug, Clone, PartialEq, Default)]
+pub struct MapNavigationSettings {
+    /// Names of the tile layers whose collision blocks movement, or empty for all
+    pub layers: Vec<String>,
+    /// Numeric custom tile property holding the cost of entering a tile
+    ///
+    /// Tiles without it cost 1.0. Where several layers set a cost, the
+    /// highest wins. Values that are not positive are ignored.
+    pub cost_property: Option<String>,
+    /// How agents move between cells
+    pub movement: NavMovement,
+    /// Search algorithm for grid paths
+    pub algorithm: PathAlgorithm,
+    /// Movement limits for platformer paths
+    pub jumps: PlatformerJumps,
+}
+
+impl MapNavigationSettings {
+    /// Create settings that use every tile layer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only use the collision of these layers
+    pub fn with_layers<S: Into<String>>(mut self, layers: impl IntoIterator<Item = S>) -> Self {
+        self.layers = layers.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Weight tiles by a numeric custom property
+    pub fn with_cost_property(mut self, property: impl Into<String>) -> Self {
+        self.cost_property = Some(property.into());
+        self
+    }
+
+    /// Set how agents move between cells
+    pub fn with_movement(mut self, movement: NavMovement) -> Self {
+        self.movement = movement;
+        self
+    }
+
+    /// Set the search algorithm for grid paths
+    pub fn with_algorithm(mut self, algorithm: PathAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Set the movement limits for platformer paths
+    pub fn with_jumps(mut self, jumps: PlatformerJumps) -> Self {
+        self.jumps = jumps;
+        self
+    }
+
+    fn uses_layer(&self, name: &str) -> bool {
+        self.layers.is_empty() || self.layers.iter().any(|layer| layer == name)
+    }
+}
+
+/// How a platformer path reaches a cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlatformerMove {
+    /// Walk onto a neighbouring cell at the same height
+    Walk,
+    /// Jump to a higher cell or across a gap
+    Jump,
+    /// Walk off an edge and fall
+    Fall,
+}
+
+/// One cell of a platformer path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlatformerStep {
+    /// Cell the step ends on
+    pub cell: UVec2,
+    /// How the cell is reached; `Walk` for the start cell
+    pub movement: PlatformerMove,
+}
+
+/// Walkability and cost of every cell of a level
+#[derive(Debug, Clone, PartialEq)]
+pub struct NavGrid {
+    width: u32,
+    height: u32,
+    /// Cost of entering each cell, `None` if blocked
+    costs: Vec<Option<f32>>,
+    /// Cells holding a one-way platform, which platformer paths can stand on
+    platforms: Vec<bool>,
+}
+
+impl NavGrid {
+    /// Create an open grid where every cell costs 1.0
+    pub fn new(width: u32, height: u32) -> Self {
+        let size = (width * height) as usize;
+        Self {
+            width,
+            height,
+            costs: vec![Some(1.0); size],
+            platforms: vec![false; size],
+        }
+    }
+
+    /// Build a grid from the tile collision of a level
+    pub fn from_level(
+        level: &Level,
+        project: &MapProject,
+        settings: &MapNavigationSettings,
+    ) -> Self {
+        let mut grid = Self::new(level.width, level.height);
+        for y in 0..level.height {
+            for x in 0..level.width {
+                grid.update_cell(level, project, settings, UVec2::new(x, y));
+            }
+        }
+        grid
+    }
+
+    /// Width in cells
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Height in cells
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    fn index(&self, cell: IVec2) -> Option<usize> {
+        (cell.x >= 0
+            && cell.y >= 0
+            && (cell.x as u32) < self.width
+            && (cell.y as u32) < self.height)
+            .then(|| (cell.y as u32 * self.width + cell.x as u32) as usize)
+    }
+
+    fn cell(&self, index: usize) -> UVec2 {
+        UVec2::new(index as u32 % self.width, index as u32 / self.width)
+    }
+
+    /// Cost of entering a cell, or `None` if it is blocked or outside the grid
+    pub fn cost(&self, cell: UVec2) -> Option<f32> {
+        self.index(cell.as_ivec2())
+            .and_then(|index| self.costs[index])
+    }
+
+    /// Set the cost of entering a cell, `None` to block it
+    pub fn set_cost(&mut self, cell: UVec2, cost: Option<f32>) {
+        if let Some(index) = self.index(cell.as_ivec2()) {
+            self.costs[index] = cost;
+        }
+    }
+
+    /// Whether a cell can be entered
+    pub fn is_walkable(&self, cell: UVec2) -> bool {
+        self.cost(cell).is_some()
+    }
+
+    fn walkable(&self, cell: IVec2) -> bool {
+        self.index(cell)
+            .is_some_and(|index| self.costs[index].is_some())
+    }
+
+    /// Whether a platformer can stand in a cell
+    fn standable(&self, cell: IVec2) -> bool {
+        let below = cell - IVec2::Y;
+        self.walkable(cell)
+            && self
+                .index(below)
+                .is_some_and(|index| self.costs[index].is_none() || self.platforms[index])
+    }
+
+    /// Recompute cells from the level, e.g. after they were edited
+    pub fn update_cells(
+        &mut self,
+        level: &Level,
+        project: &MapProject,
+        settings: &MapNavigationSettings,
+        cells: &[UVec2],
+    ) {
+        for &cell in cells {
+            self.update_cell(level, project, settings, cell);
+        }
+    }
+
+    fn update_cell(
+        &mut self,
+        level: &Level,
+        project: &MapProject,
+        settings: &MapNavigationSettings,
+        cell: UVec2,
+    ) {
+        let Some(index) = self.index(cell.as_ivec2()) else {
+            return;
+        };
+        let mut blocked = false;
+        let mut platform = false;
+        let mut cost: Option<f32> = None;
+
+        for layer in &level.layers {
+            let LayerData::Tiles {
+                tileset_id, tiles, ..
+            } = &layer.data
+            else {
+                continue;
+            };
+            if !settings.uses_layer(&layer.name) {
+                continue;
+            }
+            let Some(&Some(raw)) = tiles.get(index) else {
+                continue;
+            };
+            // Cells covered by a multi-cell tile have no collision of their own
+            if bevy_map_core::is_occupied_cell(raw) {
+                continue;
+            }
+            let Some(props) = project
+                .get_tileset(*tileset_id)
+                .and_then(|tileset| tileset.get_tile_properties(bevy_map_core::tile_index(raw)))
+            else {
+                continue;
+            };
+
+            if props.collision.has_collision() {
+                if props.collision.one_way.is_one_way() {
+                    platform = true;
+                } else {
+                    blocked = true;
+                }
+            }
+            if let Some(tile_cost) = settings
+                .cost_property
+                .as_ref()
+                .and_then(|key| props.custom.get(key))
+                .and_then(|value| value.as_f64())
+                .filter(|value| *value > 0.0)
+            {
+                let tile_cost = tile_cost as f32;
+                cost = Some(cost.map_or(tile_cost, |cost| cost.max(tile_cost)));
+            }
+        }
+
+        self.costs[index] = (!blocked).then(|| cost.unwrap_or(1.0));
+        self.platforms[index] = platform;
+    }
+
+    /// Lowest cost of any cell, which keeps the search heuristics admissible
+    fn min_cost(&self) -> f32 {
+        self.costs
+            .iter()
+            .flatten()
+            .copied()
+            .fold(f32::INFINITY, f32::min)
+            .min(1.0)
+    }
+
+    /// Find a path between two cells, including both
+    ///
+    /// Returns `None` if either cell is blocked or no path exists.
+    pub fn find_path(
+        &self,
+        start: UVec2,
+        goal: UVec2,
+        movement: NavMovement,
+        algorithm: PathAlgorithm,
+    ) -> Option<Vec<UVec2>> {
+        if !self.is_walkable(start) || !self.is_walkable(goal) {
+            return None;
+        }
+        let start_index = self.index(start.as_ivec2())?;
+        let goal_index = self.index(goal.as_ivec2())?;
+        let min_cost = self.min_cost();
+        let heuristic = |index: usize| distance(self.cell(index), goal, movement) * min_cost;
+
+        let uniform = self.costs.iter().flatten().all(|cost| *cost == 1.0);
+        if algorithm == PathAlgorithm::JumpPoint && movement == NavMovement::Diagonal && uniform {
+            let goal_cell = goal.as_ivec2();
+            let jump_points = search(
+                start_index,
+                goal_index,
+                |index, parent, successors| {
+                    let cell = self.cell(index).as_ivec2();
+                    for neighbour in self.pruned_neighbours(cell, parent.map(|p| self.cell(p))) {
+                        let direction = neighbour - cell;
+                        if let Some(jump_point) = self.jump(neighbour, direction, goal_cell) {
+                            let jump_cell = jump_point.as_uvec2();
+                            successors.push((
+                                self.cell_index(jump_cell),
+                                distance(cell.as_uvec2(), jump_cell, movement),
+                            ));
+                        }
+                    }
+                },
+                heuristic,
+            )?;
+            return Some(self.expand_jump_points(&jump_points));
+        }
+
+        let path = search(
+            start_index,
+            goal_index,
+            |index, _, successors| {
+                let cell = self.cell(index).as_ivec2();
+                for direction in directions(movement) {
+                    let next = cell + direction;
+                    if !self.can_step(cell, direction) {
+                        continue;
+                    }
+                    let Some(next_index) = self.index(next) else {
+                        continue;
+                    };
+                    let Some(cost) = self.costs[next_index] else {
+                        continue;
+                    };
+                    let length = if direction.x != 0 && direction.y != 0 {
+                        std::f32::consts::SQRT_2
+                    } else {
+                        1.0
+                    };
+                    successors.push((next_index, cost * length));
+                }
+            },
+            heuristic,
+        )?;
+        Some(path.into_iter().map(|index| self.cell(index)).collect())
+    }
+
+    fn cell_index(&self, cell: UVec2) -> usize {
+        (cell.y * self.width + cell.x) as usize
+    }
+
+    /// Whether a single step is allowed, without cutting past blocked corners
+    fn can_step(&self, cell: IVec2, direction: IVec2) -> bool {
+        self.walkable(cell + direction)
+            && (direction.x == 0
+                || direction.y == 0
+                || (self.walkable(cell + IVec2::new(direction.x, 0))
+                    && self.walkable(cell + IVec2::new(0, direction.y))))
+    }
+
+    /// Neighbours worth exploring in jump point search, given the parent jump point
+    fn pruned_neighbours(&self, cell: IVec2, parent: Option<UVec2>) -> Vec<IVec2> {
+        let Some(parent) = parent else {
+            return directions(NavMovement::Diagonal)
+                .into_iter()
+                .filter(|direction| self.can_step(cell, *direction))
+                .map(|direction| cell + direction)
+                .collect();
+        };
+
+        let d = (cell - parent.as_ivec2()).signum();
+        let mut neighbours = Vec::new();
+        if d.x != 0 && d.y != 0 {
+            let vertical = self.walkable(cell + IVec2::new(0, d.y));
+            let horizontal = self.walkable(cell + IVec2::new(d.x, 0));
+            if vertical {
+                neighbours.push(cell + IVec2::new(0, d.y));
+            }
+            if horizontal {
+                neighbours.push(cell + IVec2::new(d.x, 0));
+            }
+            if vertical && horizontal {
+                neighbours.push(cell + d);
+            }
+        } else {
+            // Perpendicular to the direction of travel
+            let side = IVec2::new(d.y, d.x);
+            let next = self.walkable(cell + d);
+            let left = self.walkable(cell + side);
+            let right = self.walkable(cell - side);
+            if next {
+                neighbours.push(cell + d);
+                if left {
+                    neighbours.push(cell + d + side);
+                }
+                if right {
+                    neighbours.push(cell + d - side);
+                }
+            }
+            if left {
+                neighbours.push(cell + side);
+            }
+            if right {
+                neighbours.push(cell - side);
+            }
+        }
+        neighbours
+    }
+
+    /// Walk from `cell` in `direction` until reaching a jump point
+    fn jump(&self, mut cell: IVec2, direction: IVec2, goal: IVec2) -> Option<IVec2> {
+        let d = direction;
+        loop {
+            if !self.walkable(cell) {
+                return None;
+            }
+            if cell == goal {
+                return Some(cell);
+            }
+
+            if d.x != 0 && d.y != 0 {
+                // Diagonal moves stop where a straight move finds a jump point
+                if self
+                    .jump(cell + IVec2::new(d.x, 0), IVec2::new(d.x, 0), goal)
+                    .is_some()
+                    || self
+                        .jump(cell + IVec2::new(0, d.y), IVec2::new(0, d.y), goal)
+                        .is_some()
+                {
+                    return Some(cell);
+                }
+                if !(self.walkable(cell + IVec2::new(d.x, 0))
+                    && self.walkable(cell + IVec2::new(0, d.y)))
+                {
+                    return None;
+                }
+            } else {
+                // Straight moves stop next to a corner that opens up
+                let side = IVec2::new(d.y, d.x);
+                if (self.walkable(cell + side) && !self.walkable(cell + side - d))
+                    || (self.walkable(cell - side) && !self.walkable(cell - side - d))
+                {
+                    return Some(cell);
+                }
+            }
+            cell += d;
+        }
+    }
+
+    /// Fill in the cells between consecutive jump points
+    fn expand_jump_points(&self, jump_points: &[usize]) -> Vec<UVec2> {
+        let mut path = Vec::new();
+        for pair in jump_points.windows(2) {
+            let mut cell = self.cell(pair[0]).as_ivec2();
+            let to = self.cell(pair[1]).as_ivec2();
+            let d = (to - cell).signum();
+            while cell != to {
+                path.push(cell.as_uvec2());
+                cell += d;
+            }
+        }
+        if let Some(&last) = jump_points.last() {
+            path.push(self.cell(last));
+        }
+        path
+    }
+
+    /// Find a platformer path between two cells
+    ///
+    /// Both cells are first dropped to the ground below them. Each step is a
+    /// cell to stand on and the move that reaches it.
+    pub fn find_platformer_path(
+        &self,
+        start: UVec2,
+        goal: UVec2,
+        jumps: &PlatformerJumps,
+    ) -> Option<Vec<PlatformerStep>> {
+        let start = self.ground_below(start.as_ivec2())?;
+        let goal = self.ground_below(goal.as_ivec2())?;
+        let start_index = self.index(start)?;
+        let goal_index = self.index(goal)?;
+        let min_cost = self.min_cost();
+
+        let mut moves: HashMap<(usize, usize), PlatformerMove> = HashMap::new();
+        let path = search(
+            start_index,
+            goal_index,
+            |index, _, successors| {
+                let cell = self.cell(index).as_ivec2();
+                for (next, movement, length) in self.platformer_moves(cell, jumps) {
+                    let Some(next_index) = self.index(next) else {
+                        continue;
+                    };
+                    let Some(cost) = self.costs[next_index] else {
+                        continue;
+                    };
+                    moves.insert((index, next_index), movement);
+                    successors.push((next_index, cost * length));
+                }
+            },
+            |index| {
+                let cell = self.cell(index).as_ivec2();
+                ((cell - goal).abs().element_sum() as f32) * min_cost
+            },
+        )?;
+
+        let mut steps = vec![PlatformerStep {
+            cell: self.cell(path[0]),
+            movement: PlatformerMove::Walk,
+        }];
+        for pair in path.windows(2) {
+            steps.push(PlatformerStep {
+                cell: self.cell(pair[1]),
+                movement: moves[&(pair[0], pair[1])],
+            });
+        }
+        Some(steps)
+    }
+
+    /// First cell at or below `cell` that can be stood on
+    fn ground_below(&self, mut cell: IVec2) -> Option<IVec2> {
+        while self.walkable(cell) {
+            if self.standable(cell) {
+                return Some(cell);
+            }
+            cell.y -= 1;
+        }
+        None
+    }
+
+    /// Cells reachable from a standing cell, with the move and its length
+    fn platformer_moves(
+        &self,
+        cell: IVec2,
+        jumps: &PlatformerJumps,
+    ) -> Vec<(IVec2, PlatformerMove, f32)> {
+        let mut moves = Vec::new();
+        for dx in [-1, 1] {
+            let next = cell + IVec2::new(dx, 0);
+            if self.standable(next) {
+                moves.push((next, PlatformerMove::Walk, 1.0));
+            } else if self.walkable(next) {
+                // Walk off the edge and fall to the ground below
+                let mut landing = next;
+                while self.walkable(landing - IVec2::Y) && (cell.y - landing.y) < jumps.fall as i32
+                {
+                    landing.y -= 1;
+                    if self.standable(landing) {
+                        let drop = (cell.y - landing.y) as f32;
+                        moves.push((landing, PlatformerMove::Fall, 1.0 + drop));
+                        break;
+                    }
+                }
+            }
+        }
+
+        // Jumps rise straight up, cross at the peak and drop onto the target
+        let (height, distance, fall) = (
+            jumps.height as i32,
+            jumps.distance as i32,
+            jumps.fall as i32,
+        );
+        for dy in -fall..=height {
+            for dx in -distance..=distance {
+                if dx == 0 || (dx.abs() == 1 && dy <= 0) {
+                    continue;
+                }
+                let target = cell + IVec2::new(dx, dy);
+                if !self.standable(target) {
+                    continue;
+                }
+                let peak = cell.y.max(target.y);
+                let rise_clear = (cell.y..=peak).all(|y| self.walkable(IVec2::new(cell.x, y)));
+                let cross_clear = (cell.x.min(target.x)..=cell.x.max(target.x))
+                    .all(|x| self.walkable(IVec2::new(x, peak)));
+                let drop_clear = (target.y..=peak).all(|y| self.walkable(IVec2::new(target.x, y)));
+                if rise_clear && cross_clear && drop_clear {
+                    let length = (dx.abs() + dy.abs() + 1) as f32;
+                    moves.push((target, PlatformerMove::Jump, length));
+                }
+            }
+        }
+        moves
+    }
+}
+
+/// The eight (or four) steps to neighbouring cells
+fn directions(movement: NavMovement) -> Vec<IVec2> {
+    let mut directions = vec![IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y];
+    if movement == NavMovement::Diagonal {
+        directions.extend([
+            IVec2::new(1, 1),
+            IVec2::new(1, -1),
+            IVec2::new(-1, 1),
+            IVec2::new(-1, -1),
+        ]);
+    }
+    directions
+}
+
+/// Shortest distance between two cells on an open grid
+fn distance(from: UVec2, to: UVec2, movement: NavMovement) -> f32 {
+    let delta = (from.as_ivec2() - to.as_ivec2()).abs();
+    let (dx, dy) = (delta.x as f32, delta.y as f32);
+    match movement {
+        NavMovement::Cardinal => dx + dy,
+        NavMovement::Diagonal => dx.max(dy) + (std::f32::consts::SQRT_2 - 1.0) * dx.min(dy),
+    }
+}
+
+/// Entry of the A* open set, ordered so the lowest estimate pops first
+struct OpenNode {
+    estimate: f32,
+    node: usize,
+}
+
+impl PartialEq for OpenNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OpenNode {}
+
+impl PartialOrd for OpenNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OpenNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.total_cmp(&self.estimate)
+    }
+}
+
+/// A* over graph nodes
+///
+/// `successors` receives a node and its parent and pushes the reachable
+/// nodes with the cost of reaching them.
+fn search(
+    start: usize,
+    goal: usize,
+    mut successors: impl FnMut(usize, Option<usize>, &mut Vec<(usize, f32)>),
+    heuristic: impl Fn(usize) -> f32,
+) -> Option<Vec<usize>> {
+    let mut open = BinaryHeap::new();
+    let mut best: HashMap<usize, (f32, Option<usize>)> = HashMap::new();
+    let mut closed = HashSet::new();
+    let mut buffer = Vec::new();
+
+    best.insert(start, (0.0, None));
+    open.push(OpenNode {
+        estimate: heuristic(start),
+        node: start,
+    });
+
+    while let Some(OpenNode { node, .. }) = open.pop() {
+        if node == goal {
+            let mut path = vec![goal];
+            let mut current = goal;
+            while let Some(parent) = best[&current].1 {
+                path.push(parent);
+                current = parent;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        if !closed.insert(node) {
+            continue;
+        }
+
+        let (cost, parent) = best[&node];
+        buffer.clear();
+        successors(node, parent, &mut buffer);
+        for &(next, step_cost) in &buffer {
+            if closed.contains(&next) {
+                continue;
+            }
+            let next_cost = cost + step_cost;
+            let improved = match best.get(&next) {
+                Some(&(known, _)) => next_cost < known,
+                None => true,
+            };
+            if improved {
+                best.insert(next, (next_cost, Some(node)));
+                open.push(OpenNode {
+                    estimate: next_cost + heuristic(next),
+                    node: next,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Navigation grid of one spawned map
+struct MapNavGrid {
+    grid: NavGrid,
+    /// Entity holding the map's `MapHandle`, if any
+    parent: Option<Entity>,
+    /// Cell geometry in map space
+    level_grid: LevelGrid,
+    /// Map space to world space
+    to_world: Affine3A,
+}
+
+impl MapNavGrid {
+    fn cell_at(&self, world: Vec2) -> Option<UVec2> {
+        let local = self
+            .to_world
+            .inverse()
+            .transform_point3(world.extend(0.0))
+            .truncate();
+        self.level_grid
+            .cell_at(local)
+            .filter(|cell| cell.x < self.grid.width && cell.y < self.grid.height)
+    }
+
+    fn cell_center(&self, cell: UVec2) -> Vec2 {
+        let tile_size = self.level_grid.tile_size;
+        let local =
+            self.level_grid.cell_corner(cell.x, cell.y) + Vec2::new(tile_size.x, tile_size.y) / 2.0;
+        self.to_world.transform_point3(local.extend(0.0)).truncate()
+    }
+}
+
+/// Navigation grids of all spawned maps, and path queries over them
+///
+/// Positions are in world space and paths run through cell centers. Layer
+/// offsets and parallax are ignored.
+#[derive(Resource, Default)]
+pub struct MapNavigation {
+    settings: MapNavigationSettings,
+    maps: HashMap<Entity, MapNavGrid>,
+}
+
+impl MapNavigation {
+    fn map(&self, map: Entity) -> Option<&MapNavGrid> {
+        self.maps.get(&map).or_else(|| {
+            self.maps
+                .values()
+                .find(|nav_grid| nav_grid.parent == Some(map))
+        })
+    }
+
+    /// Get the grid of a map, from its root or `MapHandle` entity
+    pub fn grid(&self, map: Entity) -> Option<&NavGrid> {
+        self.map(map).map(|nav_grid| &nav_grid.grid)
+    }
+
+    /// Get the cell of a map under a world position
+    pub fn cell_at(&self, map: Entity, world: Vec2) -> Option<UVec2> {
+        self.map(map)?.cell_at(world)
+    }
+
+    /// Get the world position of a cell's center
+    pub fn cell_center(&self, map: Entity, cell: UVec2) -> Option<Vec2> {
+        Some(self.map(map)?.cell_center(cell))
+    }
+
+    /// Find a path between two world positions, as cell centers
+    pub fn find_path(&self, map: Entity, start: Vec2, goal: Vec2) -> Option<Vec<Vec2>> {
+        let nav_grid = self.map(map)?;
+        let path = nav_grid.grid.find_path(
+            nav_grid.cell_at(start)?,
+            nav_grid.cell_at(goal)?,
+            self.settings.movement,
+            self.settings.algorithm,
+        )?;
+        Some(
+            path.into_iter()
+                .map(|cell| nav_grid.cell_center(cell))
+                .collect(),
+        )
+    }
+
+    /// Find a path between two cells of a map
+    pub fn find_cell_path(&self, map: Entity, start: UVec2, goal: UVec2) -> Option<Vec<UVec2>> {
+        self.grid(map)?
+            .find_path(start, goal, self.settings.movement, self.settings.algorithm)
+    }
+
+    /// Find a platformer path between two world positions, as cell centers
+    /// and the moves that reach them
+    pub fn find_platformer_path(
+        &self,
+        map: Entity,
+        start: Vec2,
+        goal: Vec2,
+    ) -> Option<Vec<(Vec2, PlatformerMove)>> {
+        let nav_grid = self.map(map)?;
+        let steps = nav_grid.grid.find_platformer_path(
+            nav_grid.cell_at(start)?,
+            nav_grid.cell_at(goal)?,
+            &self.settings.jumps,
+        )?;
+        Some(
+            steps
+                .into_iter()
+                .map(|step| (nav_grid.cell_center(step.cell), step.movement))
+                .collect(),
+        )
+    }
+}
+
+/// Build grids for new maps, rebuild all of them when the settings change,
+/// and follow map transforms
+pub fn build_navigation_grids(
+    mut navigation: ResMut<MapNavigation>,
+    settings: Res<MapNavigationSettings>,
+    maps: Query<(
+        Entity,
+        &MapRoot,
+        &MapLevelData,
+        &GlobalTransform,
+        Option<&ChildOf>,
+    )>,
+    map_assets: Res<Assets<MapProject>>,
+) {
+    let rebuild = settings.is_changed();
+    if rebuild {
+        navigation.settings = settings.clone();
+    }
+    navigation.maps.retain(|entity, _| maps.contains(*entity));
+
+    for (entity, map_root, level_data, transform, child_of) in maps.iter() {
+        if let Some(nav_grid) = navigation.maps.get_mut(&entity) {
+            nav_grid.to_world = transform.affine();
+            if !rebuild {
+                continue;
+            }
+        }
+        let Some(project) = map_assets.get(&map_root.handle) else {
+            continue;
+        };
+        let level = &level_data.0;
+        let grid = NavGrid::from_level(level, project, &settings);
+        navigation.maps.insert(
+            entity,
+            MapNavGrid {
+                grid,
+                parent: child_of.map(|child_of| child_of.parent()),
+                level_grid: LevelGrid::new(level, map_root.textures.tile_size),
+                to_world: transform.affine(),
+            },
+        );
+    }
+}
+
+/// Update the cells of grids whose tiles were edited at runtime
+pub fn update_navigation_grids(
+    mut navigation: ResMut<MapNavigation>,
+    mut changes: MessageReader<MapTilesChanged>,
+    maps: Query<(&MapRoot, &MapLevelData)>,
+    map_assets: Res<Assets<MapProject>>,
+) {
+    let navigation = &mut *navigation;
+    for change in changes.read() {
+        let Some(nav_grid) = navigation.maps.get_mut(&change.map) else {
+            continue;
+        };
+        let Ok((map_root, level_data)) = maps.get(change.map) else {
+            continue;
+        };
+        let Some(project) = map_assets.get(&map_root.handle) else {
+            continue;
+        };
+        nav_grid
+            .grid
+            .update_cells(&level_data.0, project, &navigation.settings, &change.cells);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a grid from rows given top first, `#` blocked, `=` platform
+    fn grid(rows: &[&str]) -> NavGrid {
+        let mut grid = NavGrid::new(rows[0].len() as u32, rows.len() as u32);
+        for (row, line) in rows.iter().rev().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let cell = UVec2::new(x as u32, row as u32);
+                match c {
+                    '#' => grid.set_cost(cell, None),
+                    '=' => {
+                        let index = grid.cell_index(cell);
+                        grid.platforms[index] = true;
+                    }
+                    '~' => grid.set_cost(cell, Some(5.0)),
+                    _ => {}
+                }
+            }
+        }
+        grid
+    }
+
+    fn path_length(path: &[UVec2]) -> f32 {
+        path.windows(2)
+            .map(|pair| distance(pair[0], pair[1], NavMovement::Diagonal))
+            .sum()
+    }
+
+    #[test]
+    fn test_a_star_and_jump_point_agree() {
+        let grid = grid(&[
+            "..........",
+            "..#####...",
+            "......#...",
+            "..#...#...",
+            "..#.......",
+        ]);
+        let (start, goal) = (UVec2::new(0, 0), UVec2::new(9, 4));
+        let a_star = grid
+            .find_path(start, goal, NavMovement::Diagonal, PathAlgorithm::AStar)
+            .unwrap();
+        let jump_point = grid
+            .find_path(start, goal, NavMovement::Diagonal, PathAlgorithm::JumpPoint)
+            .unwrap();
+
+        for path in [&a_star, &jump_point] {
+            assert_eq!(path.first(), Some(&start));
+            assert_eq!(path.last(), Some(&goal));
+            for pair in path.windows(2) {
+                let step = pair[1].as_ivec2() - pair[0].as_ivec2();
+                assert!(step.abs().max_element() == 1);
+                assert!(grid.can_step(pair[0].as_ivec2(), step));
+            }
+        }
+        assert!((path_length(&a_star) - path_length(&jump_point)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_blocked_and_weighted_paths() {
+        let walled = grid(&[
+            "..#..", //
+            "..#..", //
+            "..#..",
+        ]);
+        assert!(walled
+            .find_path(
+                UVec2::new(0, 0),
+                UVec2::new(4, 0),
+                NavMovement::Cardinal,
+                PathAlgorithm::AStar
+            )
+            .is_none());
+
+        // Going around the mud is cheaper than crossing it
+        let mud = grid(&[
+            ".....", //
+            ".~~~.", //
+            ".....",
+        ]);
+        let path = mud
+            .find_path(
+                UVec2::new(0, 1),
+                UVec2::new(4, 1),
+                NavMovement::Cardinal,
+                PathAlgorithm::JumpPoint,
+            )
+            .unwrap();
+        assert_eq!(path.len(), 7);
+        assert!(path.iter().all(|cell| mud.cost(*cell) == Some(1.0)));
+    }
+
+    #[test]
+    fn test_platformer_path() {
+        let grid = grid(&[
+            "..........",
+            ".......===",
+            "..........",
+            "####...###",
+            "####...###",
+        ]);
+        let steps = grid
+            .find_platformer_path(
+                UVec2::new(0, 4),
+                UVec2::new(9, 4),
+                &PlatformerJumps::default(),
+            )
+            .unwrap();
+
+        assert_eq!(steps[0].cell, UVec2::new(0, 2));
+        assert_eq!(steps.last().unwrap().cell, UVec2::new(9, 4));
+        assert!(steps
+            .iter()
+            .any(|step| step.movement == PlatformerMove::Jump));
+        assert!(steps
+            .iter()
+            .all(|step| grid.standable(step.cell.as_ivec2())));
+    }
+}
@@ -0,0 +1,93 @@
+//! Custom properties of levels, layers and tilesets at runtime
+//!
+//! The schema's `level_properties`, `layer_properties` and
+//! `tileset_properties` are edited in the inspector and stored on the map
+//! data. When a map spawns, they are copied onto its entities:
+//!
+//! - [`LevelProperties`] on the map root (the entity with [`RuntimeMap`](crate::RuntimeMap))
+//! - [`LayerProperties`] on every tilemap and image layer sprite
+//! - [`TilesetProperties`] on every tilemap, from the tileset it draws
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use bevy::prelude::*;
+//! use bevy_map_runtime::{LayerProperties, LevelProperties};
+//!
+//! fn play_music(maps: Query<&LevelProperties, Added<LevelProperties>>) {
+//!     for properties in maps.iter() {
+//!         if let Some(track) = properties.get_string("music") {
+//!             info!("Now playing {}", track);
+//!         }
+//!     }
+//! }
+//!
+//! fn raise_foreground(mut layers: Query<(&LayerProperties, &mut Transform), Added<LayerProperties>>) {
+//!     for (properties, mut transform) in layers.iter_mut() {
+//!         if properties.get_bool("is_foreground") == Some(true) {
+//!             transform.translation.z += 100.0;
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use bevy_map_core::Value;
+use std::collections::HashMap;
+
+macro_rules! property_component {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Component, Debug, Clone, Default)]
+        pub struct $name {
+            /// All properties as they were set in the map editor
+            pub properties: HashMap<String, Value>,
+        }
+
+        impl $name {
+            pub fn new(properties: HashMap<String, Value>) -> Self {
+                Self { properties }
+            }
+
+            /// Get a property value
+            pub fn get(&self, key: &str) -> Option<&Value> {
+                self.properties.get(key)
+            }
+
+            /// Get a string property value
+            pub fn get_string(&self, key: &str) -> Option<&str> {
+                self.properties.get(key).and_then(|v| v.as_string())
+            }
+
+            /// Get an integer property value
+            pub fn get_int(&self, key: &str) -> Option<i64> {
+                self.properties.get(key).and_then(|v| v.as_int())
+            }
+
+            /// Get a float property value
+            pub fn get_float(&self, key: &str) -> Option<f64> {
+                self.properties.get(key).and_then(|v| v.as_float())
+            }
+
+            /// Get a boolean property value
+            pub fn get_bool(&self, key: &str) -> Option<bool> {
+                self.properties.get(key).and_then(|v| v.as_bool())
+            }
+        }
+    };
+}
+
+property_component!(
+    /// Custom properties of the spawned level, inserted on the map root
+    LevelProperties
+);
+
+property_component!(
+    /// Custom properties of a layer, inserted on its tilemaps and sprites
+    LayerProperties
+);
+
+property_component!(
+    /// Custom properties of the tileset a tilemap draws from
+    TilesetProperties
+);
@@ -0,0 +1,546 @@
+//! Spatial queries over the tiles of spawned maps
+//!
+//! [`MapQuery`] answers gameplay questions such as "what tile is under this
+//! world position on layer Ground" or "is the tile at (x, y) tagged ladder".
+//! Results are [`MapTile`]s, which carry the tile's level, layer, cell and
+//! `TileProperties` including its custom properties.
+//!
+//! Queries read the live level data, so they see tiles changed with
+//! `MapEditCommands`. World positions account for the map's transform and
+//! the layer's offset; parallax is ignored. On isometric and hexagonal levels,
+//! rectangle and ray queries match tiles by their center.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use bevy::prelude::*;
+//! use bevy_map_runtime::MapQuery;
+//!
+//! fn climb(maps: MapQuery, player: Single<&Transform, With<Player>>) {
+//!     let position = player.translation.truncate();
+//!     if maps
+//!         .tile_at(position, "Ground")
+//!         .is_some_and(|tile| tile.has_property("ladder"))
+//!     {
+//!         info!("Climbing");
+//!     }
+//! }
+//!
+//! fn line_of_sight(maps: MapQuery, guard: Single<&Transform, With<Guard>>) {
+//!     let origin = guard.translation.truncate();
+//!     let blocked = maps
+//!         .tiles_along_ray("Walls", origin, Dir2::X, 200.0)
+//!         .iter()
+//!         .any(|tile| tile.has_property("opaque"));
+//! }
+//! ```
+
+use bevy::ecs::system::SystemParam;
+use bevy::math::Affine3A;
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::TilePos;
+use bevy_map_core::{Layer, LayerData, Level, MapProject, TileProperties, Tileset};
+
+use crate::{EditLayer, LevelGrid, MapLevelData, MapRoot};
+
+/// A tile found by a [`MapQuery`]
+#[derive(Debug, Clone, Copy)]
+pub struct MapTile<'a> {
+    /// The map root
+    pub map: Entity,
+    /// Level the tile belongs to
+    pub level: &'a Level,
+    /// Index of the tile's layer in the level
+    pub layer_index: usize,
+    /// The tile's layer
+    pub layer: &'a Layer,
+    /// Tileset of the layer
+    pub tileset: &'a Tileset,
+    /// Cell that was queried
+    pub position: TilePos,
+    /// Base cell of the tile, which differs from `position` inside multi-cell tiles
+    pub base: TilePos,
+    /// Raw tile value, including flip flags
+    pub raw: u32,
+    /// Properties of the tile, if any were set in the editor
+    pub properties: Option<&'a TileProperties>,
+}
+
+impl<'a> MapTile<'a> {
+    /// Index of the tile in its tileset, without flip flags
+    pub fn tile_index(&self) -> u32 {
+        bevy_map_core::tile_index(self.raw)
+    }
+
+    /// Get a custom property of the tile
+    pub fn property(&self, key: &str) -> Option<&'a serde_json::Value> {
+        self.properties?.custom.get(key)
+    }
+
+    /// Whether the tile has a custom property, e.g. a `ladder` tag
+    ///
+    /// A property set to `false` or `null` counts as missing.
+    pub fn has_property(&self, key: &str) -> bool {
+        !matches!(
+            self.property(key),
+            None | Some(serde_json::Value::Null) | Some(serde_json::Value::Bool(false))
+        )
+    }
+}
+
+/// A spawned map as seen by [`MapQuery`]
+struct MapView<'a> {
+    entity: Entity,
+    project: &'a MapProject,
+    level: &'a Level,
+    grid: LevelGrid,
+    /// Map space to world space
+    transform: Affine3A,
+    /// World space to map space
+    inverse: Affine3A,
+}
+
+impl<'a> MapView<'a> {
+    /// Offset of a layer in map space
+    fn layer_offset(&self, layer_index: usize) -> Vec2 {
+        self.level
+            .effective_layer(layer_index)
+            .map(|effective| Vec2::new(effective.offset_x, effective.offset_y))
+            .unwrap_or_default()
+    }
+
+    /// Convert a world position to a layer's cell space, where cell (x, y)
+    /// covers `x..x + 1` and `y..y + 1` on an orthogonal level
+    fn cell_space(&self, layer_index: usize, world: Vec2) -> Vec2 {
+        let local = self.inverse.transform_point3(world.extend(0.0)).truncate()
+            - self.layer_offset(layer_index);
+        (local - self.grid.cell_corner(0, 0))
+            / Vec2::new(self.grid.tile_size.x, self.grid.tile_size.y)
+    }
+
+    /// Get the cell of a layer under a world position
+    fn cell_at(&self, layer_index: usize, world: Vec2) -> Option<TilePos> {
+        let local = self.inverse.transform_point3(world.extend(0.0)).truncate()
+            - self.layer_offset(layer_index);
+        let cell = self.grid.cell_at(local)?;
+        Some(TilePos {
+            x: cell.x,
+            y: cell.y,
+        })
+    }
+
+    /// World position of the center of a layer's cell
+    fn cell_center(&self, layer_index: usize, x: u32, y: u32) -> Vec2 {
+        let half = Vec2::new(self.grid.tile_size.x, self.grid.tile_size.y) / 2.0;
+        let local = self.grid.cell_corner(x, y) + half + self.layer_offset(layer_index);
+        self.transform
+            .transform_point3(local.extend(0.0))
+            .truncate()
+    }
+
+    /// Get the tile drawn at a cell
+    fn tile(&self, layer_index: usize, position: TilePos) -> Option<MapTile<'a>> {
+        let layer = self.level.layers.get(layer_index)?;
+        let LayerData::Tiles {
+            tileset_id,
+            tiles,
+            occupied_cells,
+        } = &layer.data
+        else {
+            return None;
+        };
+        if position.x >= self.level.width || position.y >= self.level.height {
+            return None;
+        }
+
+        // Cells covered by a multi-cell tile resolve to its base cell
+        let cell = (position.y * self.level.width + position.x) as usize;
+        let base = occupied_cells.get(&cell).copied().unwrap_or(cell);
+        let raw = (*tiles.get(base)?)?;
+        if bevy_map_core::is_occupied_cell(raw) {
+            return None;
+        }
+
+        let tileset = self.project.get_tileset(*tileset_id)?;
+        Some(MapTile {
+            map: self.entity,
+            level: self.level,
+            layer_index,
+            layer,
+            tileset,
+            position,
+            base: TilePos {
+                x: base as u32 % self.level.width,
+                y: base as u32 / self.level.width,
+            },
+            raw,
+            properties: tileset.get_tile_properties(bevy_map_core::tile_index(raw)),
+        })
+    }
+}
+
+/// System param for spatial queries over the tiles of spawned maps
+///
+/// Covers every map spawned from a `MapProject`, including the levels of a
+/// streamed world.
+#[derive(SystemParam)]
+pub struct MapQuery<'w, 's> {
+    maps: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static MapRoot,
+            &'static MapLevelData,
+            &'static GlobalTransform,
+        ),
+    >,
+    children: Query<'w, 's, &'static Children>,
+    projects: Res<'w, Assets<MapProject>>,
+}
+
+impl MapQuery<'_, '_> {
+    fn views(&self) -> impl Iterator<Item = MapView<'_>> {
+        self.maps
+            .iter()
+            .filter_map(|(entity, map_root, level_data, transform)| {
+                let project = self.projects.get(&map_root.handle)?;
+                let level = &level_data.0;
+                Some(MapView {
+                    entity,
+                    project,
+                    level,
+                    grid: LevelGrid::new(level, map_root.textures.tile_size),
+                    transform: transform.affine(),
+                    inverse: transform.affine().inverse(),
+                })
+            })
+    }
+
+    /// Find the view of a map from its root or `MapHandle` entity
+    fn view(&self, map: Entity) -> Option<MapView<'_>> {
+        let root = if self.maps.contains(map) {
+            map
+        } else {
+            self.children
+                .get(map)
+                .ok()?
+                .iter()
+                .find(|child| self.maps.contains(*child))?
+        };
+        self.views().find(|view| view.entity == root)
+    }
+
+    /// Get the tile at a cell of one map
+    ///
+    /// `map` is the map root or the entity holding its `MapHandle`.
+    pub fn tile(
+        &self,
+        map: Entity,
+        layer: impl Into<EditLayer>,
+        position: TilePos,
+    ) -> Option<MapTile<'_>> {
+        let view = self.view(map)?;
+        let layer_index = layer.into().resolve(view.level)?;
+        view.tile(layer_index, position)
+    }
+
+    /// Get the tile under a world position on a layer
+    ///
+    /// Checks every spawned map and returns the first tile found.
+    pub fn tile_at(&self, world: Vec2, layer: impl Into<EditLayer>) -> Option<MapTile<'_>> {
+        let layer = layer.into();
+        self.views().find_map(|view| {
+            let layer_index = layer.resolve(view.level)?;
+            view.tile(layer_index, view.cell_at(layer_index, world)?)
+        })
+    }
+
+    /// Get the tiles under a world position on every tile layer, in level order
+    pub fn tiles_at(&self, world: Vec2) -> Vec<MapTile<'_>> {
+        let mut found = Vec::new();
+        for view in self.views() {
+            for layer_index in 0..view.level.layers.len() {
+                if let Some(cell) = view.cell_at(layer_index, world) {
+                    found.extend(view.tile(layer_index, cell));
+                }
+            }
+        }
+        found
+    }
+
+    /// Get the tiles of a layer overlapping a world-space rectangle
+    pub fn tiles_in_rect(&self, layer: impl Into<EditLayer>, rect: Rect) -> Vec<MapTile<'_>> {
+        let layer = layer.into();
+        let mut found = Vec::new();
+        for view in self.views() {
+            let Some(layer_index) = layer.resolve(view.level) else {
+                continue;
+            };
+            if !view.level.orientation.is_orthogonal() {
+                for y in 0..view.level.height {
+                    for x in 0..view.level.width {
+                        if rect.contains(view.cell_center(layer_index, x, y)) {
+                            found.extend(view.tile(layer_index, TilePos { x, y }));
+                        }
+                    }
+                }
+                continue;
+            }
+            // The map may be rotated or scaled, so bound all four corners
+            let corners = [
+                rect.min,
+                rect.max,
+                Vec2::new(rect.min.x, rect.max.y),
+                Vec2::new(rect.max.x, rect.min.y),
+            ]
+            .map(|corner| view.cell_space(layer_index, corner));
+            let min = corners.iter().fold(Vec2::MAX, |acc, c| acc.min(*c));
+            let max = corners.iter().fold(Vec2::MIN, |acc, c| acc.max(*c));
+            let Some((from, to)) =
+                rect_cells(min, max, UVec2::new(view.level.width, view.level.height))
+            else {
+                continue;
+            };
+            for y in from.y..=to.y {
+                for x in from.x..=to.x {
+                    found.extend(view.tile(layer_index, TilePos { x, y }));
+                }
+            }
+        }
+        found
+    }
+
+    /// Get the tiles of a layer crossed by a ray, nearest first
+    ///
+    /// Empty cells are skipped. Use `.iter().find(...)` on the result to get
+    /// the first tile that blocks the ray.
+    pub fn tiles_along_ray(
+        &self,
+        layer: impl Into<EditLayer>,
+        origin: Vec2,
+        direction: Dir2,
+        max_distance: f32,
+    ) -> Vec<MapTile<'_>> {
+        let layer = layer.into();
+        let mut found: Vec<(f32, MapTile)> = Vec::new();
+        for view in self.views() {
+            let Some(layer_index) = layer.resolve(view.level) else {
+                continue;
+            };
+            // Cell space is an affine map of world space, so distances along
+            // the ray carry over unchanged
+            let start = view.cell_space(layer_index, origin);
+            let step = view.cell_space(layer_index, origin + *direction) - start;
+            if !view.level.orientation.is_orthogonal() {
+                // Sample the ray a few times per cell
+                let spacing = 0.25 / step.length().max(f32::EPSILON);
+                let mut last = None;
+                let mut distance = 0.0;
+                while distance <= max_distance {
+                    let cell = view.cell_at(layer_index, origin + *direction * distance);
+                    if cell.is_some() && cell != last {
+                        found.extend(
+                            cell.and_then(|cell| view.tile(layer_index, cell))
+                                .map(|tile| (distance, tile)),
+                        );
+                    }
+                    last = cell;
+                    distance += spacing;
+                }
+                continue;
+            }
+            let size = UVec2::new(view.level.width, view.level.height);
+            for (distance, cell) in ray_cells(start, step, max_distance, size) {
+                if let Some(tile) = view.tile(
+                    layer_index,
+                    TilePos {
+                        x: cell.x,
+                        y: cell.y,
+                    },
+                ) {
+                    found.push((distance, tile));
+                }
+            }
+        }
+        found.sort_by(|a, b| a.0.total_cmp(&b.0));
+        found.into_iter().map(|(_, tile)| tile).collect()
+    }
+
+    /// Find all tiles of a layer with a custom property set to a value
+    pub fn find_tiles(
+        &self,
+        layer: impl Into<EditLayer>,
+        key: &str,
+        value: &serde_json::Value,
+    ) -> Vec<MapTile<'_>> {
+        self.filter_tiles(layer, |tile| tile.property(key) == Some(value))
+    }
+
+    /// Find all tiles of a layer that have a custom property, e.g. a `ladder` tag
+    pub fn tiles_with_property(&self, layer: impl Into<EditLayer>, key: &str) -> Vec<MapTile<'_>> {
+        self.filter_tiles(layer, |tile| tile.has_property(key))
+    }
+
+    /// Collect the tiles of a layer matching a predicate, one entry per tile
+    fn filter_tiles(
+        &self,
+        layer: impl Into<EditLayer>,
+        predicate: impl Fn(&MapTile) -> bool,
+    ) -> Vec<MapTile<'_>> {
+        let layer = layer.into();
+        let mut found = Vec::new();
+        for view in self.views() {
+            let Some(layer_index) = layer.resolve(view.level) else {
+                continue;
+            };
+            for y in 0..view.level.height {
+                for x in 0..view.level.width {
+                    // Multi-cell tiles are reported once, at their base cell
+                    let Some(tile) = view.tile(layer_index, TilePos { x, y }) else {
+                        continue;
+                    };
+                    if tile.base == tile.position && predicate(&tile) {
+                        found.push(tile);
+                    }
+                }
+            }
+        }
+        found
+    }
+}
+
+/// Range of cells overlapping a cell-space rectangle, clamped to the grid
+fn rect_cells(min: Vec2, max: Vec2, size: UVec2) -> Option<(UVec2, UVec2)> {
+    if size.x == 0 || size.y == 0 || max.x < 0.0 || max.y < 0.0 {
+        return None;
+    }
+    let from = min.floor().max(Vec2::ZERO);
+    let to = max.floor().min((size - UVec2::ONE).as_vec2());
+    (from.x <= to.x && from.y <= to.y).then(|| (from.as_uvec2(), to.as_uvec2()))
+}
+
+/// Cells of a grid crossed by a ray in cell space, with the distance at
+/// which the ray enters each of them
+///
+/// `step` is how far the ray moves through cell space per unit of distance.
+fn ray_cells(origin: Vec2, step: Vec2, max_distance: f32, size: UVec2) -> Vec<(f32, UVec2)> {
+    let mut cells = Vec::new();
+    if step == Vec2::ZERO || size.x == 0 || size.y == 0 {
+        return cells;
+    }
+
+    // Clip the ray to the grid
+    let bounds = size.as_vec2();
+    let (mut enter, mut exit) = (0.0f32, max_distance);
+    for axis in 0..2 {
+        if step[axis] == 0.0 {
+            if origin[axis] < 0.0 || origin[axis] >= bounds[axis] {
+                return cells;
+            }
+        } else {
+            let a = -origin[axis] / step[axis];
+            let b = (bounds[axis] - origin[axis]) / step[axis];
+            enter = enter.max(a.min(b));
+            exit = exit.min(a.max(b));
+        }
+    }
+    if enter > exit {
+        return cells;
+    }
+
+    // Walk the grid one cell boundary at a time
+    let start = origin + step * enter;
+    let mut cell = start
+        .floor()
+        .as_ivec2()
+        .clamp(IVec2::ZERO, size.as_ivec2() - IVec2::ONE);
+    let mut next = Vec2::INFINITY;
+    let mut delta = Vec2::INFINITY;
+    let mut direction = IVec2::ZERO;
+    for axis in 0..2 {
+        if step[axis] > 0.0 {
+            direction[axis] = 1;
+            next[axis] = (cell[axis] as f32 + 1.0 - origin[axis]) / step[axis];
+            delta[axis] = 1.0 / step[axis];
+        } else if step[axis] < 0.0 {
+            direction[axis] = -1;
+            next[axis] = (cell[axis] as f32 - origin[axis]) / step[axis];
+            delta[axis] = -1.0 / step[axis];
+        }
+    }
+
+    let mut distance = enter;
+    loop {
+        cells.push((distance, cell.as_uvec2()));
+        let axis = if next.x < next.y { 0 } else { 1 };
+        distance = next[axis];
+        if distance > exit {
+            break;
+        }
+        cell[axis] += direction[axis];
+        next[axis] += delta[axis];
+        if cell[axis] < 0 || cell[axis] >= size.as_ivec2()[axis] {
+            break;
+        }
+    }
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ray_cells_diagonal() {
+        let cells: Vec<UVec2> = ray_cells(
+            Vec2::new(0.25, 0.5),
+            Vec2::new(1.0, 1.0).normalize(),
+            10.0,
+            UVec2::new(3, 3),
+        )
+        .into_iter()
+        .map(|(_, cell)| cell)
+        .collect();
+        assert_eq!(
+            cells,
+            vec![
+                UVec2::new(0, 0),
+                UVec2::new(0, 1),
+                UVec2::new(1, 1),
+                UVec2::new(1, 2),
+                UVec2::new(2, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ray_cells_clipped_to_grid_and_distance() {
+        // Starts left of the grid and stops halfway through cell 2
+        let cells = ray_cells(Vec2::new(-2.0, 0.5), Vec2::X, 4.5, UVec2::new(5, 1));
+        let positions: Vec<u32> = cells.iter().map(|(_, cell)| cell.x).collect();
+        assert_eq!(positions, vec![0, 1, 2]);
+        assert_eq!(cells[0].0, 2.0);
+        assert_eq!(cells[2].0, 4.0);
+
+        // Pointing away from the grid
+        assert!(ray_cells(Vec2::new(-2.0, 0.5), Vec2::NEG_X, 10.0, UVec2::new(5, 1)).is_empty());
+    }
+
+    #[test]
+    fn test_rect_cells() {
+        let size = UVec2::new(4, 4);
+        assert_eq!(
+            rect_cells(Vec2::new(0.5, 1.5), Vec2::new(2.2, 1.9), size),
+            Some((UVec2::new(0, 1), UVec2::new(2, 1)))
+        );
+        assert_eq!(
+            rect_cells(Vec2::new(-3.0, -3.0), Vec2::new(10.0, 10.0), size),
+            Some((UVec2::new(0, 0), UVec2::new(3, 3)))
+        );
+        assert_eq!(
+            rect_cells(Vec2::new(5.0, 0.0), Vec2::new(6.0, 1.0), size),
+            None
+        );
+    }
+}
@@ -0,0 +1,441 @@
+//! Region entities with enter/exit events
+//!
+//! Entities whose schema kind is `region` (level exits, cutscene triggers,
+//! damage volumes, music zones) are spawned with a [`MapRegion`] component
+//! holding their rectangle or polygon shape. The [`MapRegionPlugin`] turns
+//! them into sensors and fires [`RegionEnterEvent`] and [`RegionExitEvent`]
+//! when a [`RegionOccupant`] (usually the player) enters or leaves one.
+//!
+//! With the `physics` feature, regions get an Avian `Sensor` collider that
+//! follows their rotation, scale and exact shape, and occupants need a
+//! collider and a dynamic or kinematic body. Without it, a cheap built-in
+//! check compares the world-space bounding boxes of regions and occupants (an
+//! occupant's [`EntitySize`], or just its position).
+//!
+//! # Usage
+//!
+//! ```rust,ignore
+//! use bevy::prelude::*;
+//! use bevy_map_runtime::{MapRegionPlugin, MapRuntimePlugin, RegionEnterEvent, RegionOccupant};
+//!
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins(MapRuntimePlugin)
+//!     .add_plugins(MapRegionPlugin)
+//!     .add_systems(Startup, setup)
+//!     .add_systems(Update, use_level_exits)
+//!     .run();
+//!
+//! fn setup(mut commands: Commands) {
+//!     commands.spawn((Player, RegionOccupant, Transform::default()));
+//! }
+//!
+//! fn use_level_exits(mut entered: MessageReader<RegionEnterEvent>) {
+//!     for event in entered.read().filter(|e| e.type_name == "LevelExit") {
+//!         let target = event.properties.get_string("target_level");
+//!         // ...
+//!     }
+//! }
+//! ```
+
+use bevy::ecs::entity::EntityHashSet;
+use bevy::ecs::message::{Message, MessageWriter};
+use bevy::prelude::*;
+use bevy_map_core::{EntityInstance, RegionShape};
+
+#[cfg(feature = "physics")]
+use avian2d::prelude::*;
+
+#[cfg(not(feature = "physics"))]
+use crate::entity_registry::EntitySize;
+use crate::entity_registry::{EntityProperties, MapEntityMarker};
+
+/// Plugin that fires enter/exit events for region entities
+///
+/// With the `physics` feature, Avian's `PhysicsPlugins` must also be added
+/// (`MapCollisionPlugin` adds them).
+pub struct MapRegionPlugin;
+
+impl Plugin for MapRegionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<RegionEnterEvent>()
+            .add_message::<RegionExitEvent>()
+            .add_systems(
+                Update,
+                (initialize_regions, detect_region_occupants).chain(),
+            );
+    }
+}
+
+/// Shape of a spawned region entity, in its local space
+///
+/// Inserted on every entity spawned from a region instance, whether or not
+/// [`MapRegionPlugin`] is added.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct MapRegion {
+    /// Rectangle or polygon covered by the region
+    pub shape: RegionShape,
+    /// Width and height, used by rectangles
+    pub size: Vec2,
+}
+
+impl MapRegion {
+    /// Get the region of a placed entity, if it is one
+    pub fn from_instance(instance: &EntityInstance) -> Option<Self> {
+        Some(Self {
+            shape: instance.region.clone()?,
+            size: instance.size.map(Vec2::from).unwrap_or_default(),
+        })
+    }
+
+    /// Check whether a point in the region's local space is inside it
+    pub fn contains_local(&self, point: Vec2) -> bool {
+        self.shape.contains(self.size.into(), point.into())
+    }
+
+    /// Get the world-space bounding box of the region
+    pub fn world_aabb(&self, transform: &GlobalTransform) -> Rect {
+        let (min, max) = self.shape.bounds(self.size.into());
+        transformed_aabb(transform, Vec2::from(min), Vec2::from(max))
+    }
+}
+
+/// Marker for entities that trigger regions (e.g. the player)
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct RegionOccupant;
+
+/// Occupants currently inside a region
+#[derive(Component, Debug, Clone, Default)]
+pub struct RegionOccupants(EntityHashSet);
+
+impl RegionOccupants {
+    /// Check if an occupant is inside the region
+    pub fn contains(&self, occupant: Entity) -> bool {
+        self.0.contains(&occupant)
+    }
+
+    /// Iterate over the occupants inside the region
+    pub fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Get the number of occupants inside the region
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check if the region is empty
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Fired when a [`RegionOccupant`] enters a region
+#[derive(Message, Debug, Clone)]
+pub struct RegionEnterEvent {
+    /// The region entity
+    pub region: Entity,
+    /// The occupant that entered
+    pub occupant: Entity,
+    /// Entity type name of the region (e.g., "LevelExit")
+    pub type_name: String,
+    /// The region's properties from the map editor
+    pub properties: EntityProperties,
+}
+
+/// Fired when a [`RegionOccupant`] leaves a region
+///
+/// Also fired when an occupant inside a region is despawned or loses its
+/// [`RegionOccupant`] marker.
+#[derive(Message, Debug, Clone)]
+pub struct RegionExitEvent {
+    /// The region entity
+    pub region: Entity,
+    /// The occupant that left
+    pub occupant: Entity,
+    /// Entity type name of the region (e.g., "LevelExit")
+    pub type_name: String,
+    /// The region's properties from the map editor
+    pub properties: EntityProperties,
+}
+
+/// Get the world-space bounding box of a local-space box under a transform
+fn transformed_aabb(transform: &GlobalTransform, min: Vec2, max: Vec2) -> Rect {
+    [
+        Vec2::new(min.x, min.y),
+        Vec2::new(max.x, min.y),
+        Vec2::new(max.x, max.y),
+        Vec2::new(min.x, max.y),
+    ]
+    .into_iter()
+    .map(|corner| transform.transform_point(corner.extend(0.0)).truncate())
+    .fold(
+        Rect {
+            min: Vec2::MAX,
+            max: Vec2::MIN,
+        },
+        |aabb, point| Rect {
+            min: aabb.min.min(point),
+            max: aabb.max.max(point),
+        },
+    )
+}
+
+/// Check whether two boxes overlap, counting touching edges and zero-size boxes
+#[cfg(not(feature = "physics"))]
+fn aabbs_overlap(a: Rect, b: Rect) -> bool {
+    a.min.x <= b.max.x && a.max.x >= b.min.x && a.min.y <= b.max.y && a.max.y >= b.min.y
+}
+
+/// Build the sensor collider of a region
+#[cfg(feature = "physics")]
+fn region_collider(region: &MapRegion) -> Option<Collider> {
+    match &region.shape {
+        RegionShape::Rectangle if region.size.cmpgt(Vec2::ZERO).all() => {
+            Some(Collider::rectangle(region.size.x, region.size.y))
+        }
+        // Concave polygons are split into convex parts
+        RegionShape::Polygon { points } if points.len() >= 3 => {
+            let vertices: Vec<Vec2> = points.iter().map(|p| Vec2::from(*p)).collect();
+            let count = vertices.len() as u32;
+            let indices = (0..count).map(|i| [i, (i + 1) % count]).collect();
+            Some(Collider::convex_decomposition(vertices, indices))
+        }
+        _ => None,
+    }
+}
+
+/// System that prepares new or changed regions for occupant tracking
+fn initialize_regions(
+    mut commands: Commands,
+    regions: Query<(Entity, &MapRegion, Has<RegionOccupants>), Changed<MapRegion>>,
+) {
+    for (entity, _region, has_occupants) in regions.iter() {
+        if !has_occupants {
+            commands.entity(entity).insert(RegionOccupants::default());
+        }
+
+        #[cfg(feature = "physics")]
+        match region_collider(_region) {
+            Some(collider) => {
+                commands
+                    .entity(entity)
+                    .insert((Sensor, collider, CollidingEntities::default()));
+            }
+            None => {
+                // Drop the sensor of the previous shape
+                commands
+                    .entity(entity)
+                    .remove::<(Sensor, Collider, CollidingEntities)>();
+                warn!(
+                    "Region {:?} has an empty shape, it will never be entered",
+                    entity
+                );
+            }
+        }
+    }
+}
+
+/// System that tracks the occupants of each region from Avian's sensor contacts
+#[cfg(feature = "physics")]
+fn detect_region_occupants(
+    mut regions: Query<
+        (
+            Entity,
+            &MapEntityMarker,
+            &EntityProperties,
+            &CollidingEntities,
+            &mut RegionOccupants,
+        ),
+        With<MapRegion>,
+    >,
+    occupants: Query<(), With<RegionOccupant>>,
+    mut entered: MessageWriter<RegionEnterEvent>,
+    mut exited: MessageWriter<RegionExitEvent>,
+) {
+    for (region, marker, properties, colliding, mut inside) in regions.iter_mut() {
+        let current: EntityHashSet = colliding
+            .iter()
+            .copied()
+            .filter(|&entity| occupants.contains(entity))
+            .collect();
+        update_occupants(
+            region,
+            marker,
+            properties,
+            &mut inside,
+            current,
+            &mut entered,
+            &mut exited,
+        );
+    }
+}
+
+/// System that tracks the occupants of each region with bounding box checks
+#[cfg(not(feature = "physics"))]
+fn detect_region_occupants(
+    mut regions: Query<(
+        Entity,
+        &MapRegion,
+        &GlobalTransform,
+        &MapEntityMarker,
+        &EntityProperties,
+        &mut RegionOccupants,
+    )>,
+    occupants: Query<(Entity, &GlobalTransform, Option<&EntitySize>), With<RegionOccupant>>,
+    mut entered: MessageWriter<RegionEnterEvent>,
+    mut exited: MessageWriter<RegionExitEvent>,
+) {
+    for (region, shape, transform, marker, properties, mut inside) in regions.iter_mut() {
+        let region_aabb = shape.world_aabb(transform);
+        let current: EntityHashSet = occupants
+            .iter()
+            .filter(|(entity, ..)| *entity != region)
+            .filter(|(_, occupant_transform, size)| {
+                let half = size.map(EntitySize::half_extents).unwrap_or_default();
+                let occupant_aabb = transformed_aabb(occupant_transform, -half, half);
+                aabbs_overlap(region_aabb, occupant_aabb)
+            })
+            .map(|(entity, ..)| entity)
+            .collect();
+        update_occupants(
+            region,
+            marker,
+            properties,
+            &mut inside,
+            current,
+            &mut entered,
+            &mut exited,
+        );
+    }
+}
+
+/// Replace a region's occupants, firing events for the ones that changed
+fn update_occupants(
+    region: Entity,
+    marker: &MapEntityMarker,
+    properties: &EntityProperties,
+    inside: &mut RegionOccupants,
+    current: EntityHashSet,
+    entered: &mut MessageWriter<RegionEnterEvent>,
+    exited: &mut MessageWriter<RegionExitEvent>,
+) {
+    if inside.0 == current {
+        return;
+    }
+    for &occupant in current.difference(&inside.0) {
+        entered.write(RegionEnterEvent {
+            region,
+            occupant,
+            type_name: marker.type_name.clone(),
+            properties: properties.clone(),
+        });
+    }
+    for &occupant in inside.0.difference(&current) {
+        exited.write(RegionExitEvent {
+            region,
+            occupant,
+            type_name: marker.type_name.clone(),
+            properties: properties.clone(),
+        });
+    }
+    inside.0 = current;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+
+    #[test]
+    fn test_region_world_aabb() {
+        let region = MapRegion {
+            shape: RegionShape::Rectangle,
+            size: Vec2::new(64.0, 16.0),
+        };
+        let transform = GlobalTransform::from(
+            Transform::from_xyz(100.0, 0.0, 0.0)
+                .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2)),
+        );
+        let aabb = region.world_aabb(&transform);
+        assert!(aabb.min.abs_diff_eq(Vec2::new(92.0, -32.0), 1e-4));
+        assert!(aabb.max.abs_diff_eq(Vec2::new(108.0, 32.0), 1e-4));
+        assert!(region.contains_local(Vec2::new(30.0, 0.0)));
+        assert!(!region.contains_local(Vec2::new(0.0, 30.0)));
+    }
+
+    #[cfg(not(feature = "physics"))]
+    #[test]
+    fn test_region_enter_and_exit_events() {
+        let mut world = World::new();
+        world.init_resource::<Messages<RegionEnterEvent>>();
+        world.init_resource::<Messages<RegionExitEvent>>();
+
+        let exit = EntityInstance::new("LevelExit".to_string(), [0.0, 0.0])
+            .with_size(32.0, 32.0)
+            .with_region(RegionShape::Rectangle);
+        let mut properties = std::collections::HashMap::new();
+        properties.insert(
+            "target".to_string(),
+            bevy_map_core::Value::String("Cave".to_string()),
+        );
+        let region = world
+            .spawn((
+                MapRegion::from_instance(&exit).unwrap(),
+                GlobalTransform::default(),
+                MapEntityMarker {
+                    instance_id: exit.id,
+                    type_name: exit.type_name.clone(),
+                },
+                EntityProperties {
+                    properties,
+                    component_overrides: Default::default(),
+                },
+            ))
+            .id();
+        let player = world
+            .spawn((RegionOccupant, GlobalTransform::from_xyz(100.0, 0.0, 0.0)))
+            .id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((initialize_regions, detect_region_occupants).chain());
+
+        // Outside: nothing happens
+        schedule.run(&mut world);
+        assert!(world.resource::<Messages<RegionEnterEvent>>().is_empty());
+
+        // Walk in
+        *world.get_mut::<GlobalTransform>(player).unwrap() =
+            GlobalTransform::from_xyz(10.0, 0.0, 0.0);
+        schedule.run(&mut world);
+        let entered: Vec<_> = world
+            .resource_mut::<Messages<RegionEnterEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(entered.len(), 1);
+        assert_eq!(entered[0].region, region);
+        assert_eq!(entered[0].occupant, player);
+        assert_eq!(entered[0].type_name, "LevelExit");
+        assert_eq!(entered[0].properties.get_string("target"), Some("Cave"));
+        assert!(world
+            .get::<RegionOccupants>(region)
+            .unwrap()
+            .contains(player));
+
+        // Staying inside fires nothing new
+        schedule.run(&mut world);
+        assert!(world.resource::<Messages<RegionEnterEvent>>().is_empty());
+
+        // Walk out
+        *world.get_mut::<GlobalTransform>(player).unwrap() =
+            GlobalTransform::from_xyz(50.0, 0.0, 0.0);
+        schedule.run(&mut world);
+        let exited: Vec<_> = world
+            .resource_mut::<Messages<RegionExitEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(exited.len(), 1);
+        assert_eq!(exited[0].occupant, player);
+        assert!(world.get::<RegionOccupants>(region).unwrap().is_empty());
+    }
+}
@@ -6,13 +6,48 @@ use crate::entity_registry::EntityProperties;
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
 use bevy_map_animation::{AnimatedSprite, SpriteData};
-use bevy_map_core::Value;
+use bevy_map_core::{MapOrientation, TileProperties, TilesetImage, Value};
 
 /// Helper to create a TilemapTexture from an image handle
 pub fn tilemap_texture_from_image(image: Handle<Image>) -> TilemapTexture {
     TilemapTexture::Single(image)
 }
 
+/// Copy a tileset image into a new image with its tiles packed edge to edge
+///
+/// bevy_ecs_tilemap slices textures into a plain grid, so images with a margin
+/// or spacing are repacked before use. Returns `None` if the pixels are not
+/// kept on the CPU or the format is compressed.
+pub fn pack_tileset_image(
+    image: &Image,
+    tileset_image: &TilesetImage,
+    tile_size: (u32, u32),
+) -> Option<Image> {
+    let pixel_size = image.texture_descriptor.format.pixel_size().ok()?;
+    let data = image.data.as_ref()?;
+    let (tile_width, tile_height) = tile_size;
+
+    let mut packed = image.clone();
+    packed.data =
+        Some(tileset_image.pack_pixels(data, image.width(), pixel_size, tile_width, tile_height));
+    packed.texture_descriptor.size.width = tileset_image.columns * tile_width;
+    packed.texture_descriptor.size.height = tileset_image.rows * tile_height;
+    Some(packed)
+}
+
+/// Get the tilemap layout for a level orientation
+///
+/// Odd rows or columns are the shifted ones, matching the editor.
+pub fn tilemap_type(orientation: MapOrientation) -> TilemapType {
+    match orientation {
+        MapOrientation::Orthogonal => TilemapType::Square,
+        MapOrientation::Isometric => TilemapType::Isometric(IsoCoordSystem::Diamond),
+        MapOrientation::IsometricStaggered => TilemapType::Isometric(IsoCoordSystem::Staggered),
+        MapOrientation::HexRow => TilemapType::Hexagon(HexCoordSystem::RowOdd),
+        MapOrientation::HexColumn => TilemapType::Hexagon(HexCoordSystem::ColumnOdd),
+    }
+}
+
 /// Calculate the world position of a tile
 pub fn tile_to_world_pos(
     tile_pos: TilePos,
@@ -53,6 +88,80 @@ pub fn world_to_tile_pos(
     )
 }
 
+/// Decode the Tiled-style flip bits of a raw tile value into a `TileFlip`
+///
+/// The caller must skip `OCCUPIED_CELL` first, since it has every flip bit set.
+pub fn tile_flip_from_raw(raw: u32) -> TileFlip {
+    TileFlip {
+        x: bevy_map_core::tile_flip_x(raw),
+        y: bevy_map_core::tile_flip_y(raw),
+        d: bevy_map_core::tile_flip_diagonal(raw),
+    }
+}
+
+/// Build the sprite and transform for a multi-cell tile
+///
+/// `cell_corner` is the bottom-left corner of the base cell in map space (z is
+/// the layer depth). The sprite covers `grid_size` tiles of a packed tileset
+/// image (see [`pack_tileset_image`]) starting at the tile's column and row,
+/// and is placed so that its origin (from `TileProperties`, center by default)
+/// sits on the base cell corner.
+///
+/// Sprites have no diagonal flip, so it is expressed as a 90° rotation.
+pub fn multi_cell_tile_sprite(
+    image: Handle<Image>,
+    local_tile_index: u32,
+    columns: u32,
+    tile_size: (u32, u32),
+    grid_size: (u32, u32),
+    props: &TileProperties,
+    flip: TileFlip,
+    cell_corner: Vec3,
+) -> (Sprite, Transform) {
+    let (tile_width, tile_height) = tile_size;
+    let (grid_width, grid_height) = grid_size;
+    let col = local_tile_index % columns;
+    let row = local_tile_index / columns;
+
+    let src_x = (col * tile_width) as f32;
+    let src_y = (row * tile_height) as f32;
+    let width = grid_width * tile_width;
+    let height = grid_height * tile_height;
+    let rect = Rect::new(src_x, src_y, src_x + width as f32, src_y + height as f32);
+
+    let (origin_x, origin_y) = props.get_origin(width, height);
+
+    // Diagonal flip = rotate 90° after mirroring X; combined with X/Y flips
+    // this reduces to one of four mirror/rotation pairs
+    let (flip_x, flip_y, rotation) = if flip.d {
+        match (flip.x, flip.y) {
+            (false, false) => (true, false, 90f32),
+            (true, false) => (false, false, -90.0),
+            (false, true) => (false, false, 90.0),
+            (true, true) => (true, false, -90.0),
+        }
+    } else {
+        (flip.x, flip.y, 0.0)
+    };
+
+    let sprite = Sprite {
+        image,
+        rect: Some(rect),
+        custom_size: Some(Vec2::new(width as f32, height as f32)),
+        flip_x,
+        flip_y,
+        ..default()
+    };
+    let transform = Transform::from_xyz(
+        cell_corner.x + origin_x as f32,
+        cell_corner.y + origin_y as f32,
+        cell_corner.z,
+    )
+    .with_rotation(Quat::from_rotation_z(rotation.to_radians()));
+
+    (sprite, transform)
+}
+
 // ============================================================================
 // Sprite spawning systems for automatic sprite component creation
 // ============================================================================
@@ -190,3 +299,38 @@ pub fn complete_sprite_loads(mut query: Query<(&SpriteSlot, &mut Sprite)>) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multi_cell_tile_diagonal_flip() {
+        let cases = [
+            ((false, false), true, 90.0f32),
+            ((true, false), false, -90.0),
+            ((false, true), false, 90.0),
+            ((true, true), true, -90.0),
+        ];
+        for ((x, y), expected_flip_x, expected_rotation) in cases {
+            let (sprite, transform) = multi_cell_tile_sprite(
+                Handle::default(),
+                0,
+                4,
+                (16, 16),
+                (2, 1),
+                &TileProperties::default(),
+                TileFlip { x, y, d: true },
+                Vec3::ZERO,
+            );
+            assert_eq!(sprite.flip_x, expected_flip_x, "flip x={x} y={y}");
+            assert!(!sprite.flip_y);
+            assert!(
+                transform
+                    .rotation
+                    .abs_diff_eq(Quat::from_rotation_z(expected_rotation.to_radians()), 1e-6),
+                "rotation x={x} y={y}"
+            );
+        }
+    }
+}
@@ -0,0 +1,511 @@
+//! World streaming for multi-level projects
+//!
+//! This module provides the `WorldStreamingPlugin`, which spawns and despawns
+//! the levels of a [`WorldProject`] around a tracked entity (or the camera) using
+//! each level's world position, and fires a [`LevelTransitionEvent`] when the
+//! tracked entity leaves a level through an edge that has a [`LevelConnection`].
+//!
+//! # Usage
+//!
+//! ```rust,ignore
+//! use bevy::prelude::*;
+//! use bevy_map_runtime::{MapRuntimePlugin, StreamedWorld, StreamingAnchor, WorldStreamingPlugin};
+//!
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins(MapRuntimePlugin)
+//!     .add_plugins(WorldStreamingPlugin)
+//!     .add_systems(Startup, setup)
+//!     .run();
+//!
+//! fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+//!     commands.spawn(StreamedWorld::new(asset_server.load("maps/world.map.json")));
+//!     // Levels stream in around this entity. Without an anchor, cameras are used.
+//!     commands.spawn((Player, StreamingAnchor, Transform::default()));
+//! }
+//! ```
+//!
+//! # Coordinates
+//!
+//! The editor's world view stores `Level::world_x/world_y` in pixels with Y
+//! pointing down. Streamed levels are placed so that the editor layout is
+//! preserved in Bevy's Y-up world: a level's top-left corner in the editor ends
+//! up at `(world_x, -world_y)` relative to the `StreamedWorld` entity.
+
+use crate::world::LevelHandle;
+use bevy::ecs::message::{Message, MessageWriter};
+use bevy::prelude::*;
+use bevy_map_core::{ConnectionDirection, Level, LevelSelector, WorldProject};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// Plugin that streams levels of a [`StreamedWorld`] in and out around anchors
+///
+/// Requires `MapRuntimePlugin`, which spawns the individual levels.
+pub struct WorldStreamingPlugin;
+
+impl Plugin for WorldStreamingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<LevelTransitionEvent>().add_systems(
+            Update,
+            (
+                initialize_streamed_worlds,
+                stream_world_levels,
+                detect_level_transitions,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Component for streaming the levels of a multi-level project
+///
+/// Levels whose bounds come within `load_radius` pixels of a
+/// [`StreamingAnchor`] are spawned as children of this entity. They are
+/// despawned once every anchor is further away than `unload_radius`.
+#[derive(Component, Debug, Clone)]
+pub struct StreamedWorld {
+    /// Handle to the whole-project asset
+    pub world: Handle<WorldProject>,
+    /// Distance in pixels from a level's bounds at which it is spawned
+    pub load_radius: f32,
+    /// Distance in pixels from a level's bounds at which it is despawned
+    ///
+    /// Should be larger than `load_radius` to avoid levels flickering in and
+    /// out at the boundary.
+    pub unload_radius: f32,
+    /// Also keep levels connected to the anchor's current level loaded
+    pub preload_connections: bool,
+}
+
+impl StreamedWorld {
+    /// Create a streamed world with default radii
+    pub fn new(world: Handle<WorldProject>) -> Self {
+        Self {
+            world,
+            load_radius: 256.0,
+            unload_radius: 384.0,
+            preload_connections: true,
+        }
+    }
+
+    /// Set the load radius (the unload radius is set to 1.5x the load radius)
+    pub fn with_load_radius(mut self, radius: f32) -> Self {
+        self.load_radius = radius;
+        self.unload_radius = radius * 1.5;
+        self
+    }
+
+    /// Set the unload radius
+    pub fn with_unload_radius(mut self, radius: f32) -> Self {
+        self.unload_radius = radius;
+        self
+    }
+
+    /// Enable or disable preloading of connected levels
+    pub fn with_preload_connections(mut self, preload: bool) -> Self {
+        self.preload_connections = preload;
+        self
+    }
+}
+
+/// Marker for entities that levels stream around (usually the player)
+///
+/// If no entity has this component, every `Camera` is used as an anchor.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct StreamingAnchor;
+
+/// Component on each level entity spawned by world streaming
+#[derive(Component, Debug, Clone, Copy)]
+pub struct StreamedLevel {
+    /// The streamed world entity this level belongs to
+    pub world_entity: Entity,
+    /// ID of the level in the world project
+    pub level_id: Uuid,
+}
+
+/// Event fired when an anchor leaves a level through a connected edge
+///
+/// `direction` is the edge of `from_level` that was crossed. The target level
+/// is not necessarily adjacent in world space; games can use this event to
+/// teleport the player for non-contiguous layouts.
+#[derive(Message, Debug, Clone)]
+pub struct LevelTransitionEvent {
+    /// The streamed world entity
+    pub world_entity: Entity,
+    /// The anchor that crossed the edge
+    pub anchor: Entity,
+    /// The level that was left
+    pub from_level: Uuid,
+    /// The level the connection leads to
+    pub to_level: Uuid,
+    /// The edge of `from_level` that was crossed
+    pub direction: ConnectionDirection,
+    /// The edge of `to_level` the connection arrives at
+    pub to_direction: ConnectionDirection,
+    /// ID of the connection that was crossed
+    pub connection_id: Uuid,
+}
+
+/// Internal state tracking for StreamedWorld entities
+#[derive(Component, Default)]
+struct StreamedWorldState {
+    /// Spawned level entities by level ID
+    loaded: HashMap<Uuid, Entity>,
+    /// The level each anchor was last inside
+    anchor_levels: HashMap<Entity, Uuid>,
+}
+
+/// Get the bounds of a level in the Y-up space of its streamed world
+pub fn level_world_rect(level: &Level, tile_size: Vec2) -> Rect {
+    let size = Vec2::new(level.width as f32, level.height as f32) * tile_size;
+    let min = Vec2::new(level.world_x as f32, -(level.world_y as f32) - size.y);
+    Rect::from_corners(min, min + size)
+}
+
+/// Get the tile width and height a level is laid out with
+fn level_tile_size(world: &WorldProject, level: &Level) -> Vec2 {
+    let (width, height) = world.level_tile_dimensions(level);
+    Vec2::new(width as f32, height as f32)
+}
+
+/// Distance from a point to a rectangle (zero inside)
+pub(crate) fn distance_to_rect(rect: Rect, point: Vec2) -> f32 {
+    let clamped = point.clamp(rect.min, rect.max);
+    clamped.distance(point)
+}
+
+/// Work out which edge of `rect` a point outside it has left through
+fn exit_direction(rect: Rect, point: Vec2) -> Option<ConnectionDirection> {
+    if rect.contains(point) {
+        return None;
+    }
+    // Pick the axis with the largest overshoot so corners resolve sensibly
+    let dx = if point.x < rect.min.x {
+        rect.min.x - point.x
+    } else {
+        point.x - rect.max.x
+    };
+    let dy = if point.y < rect.min.y {
+        rect.min.y - point.y
+    } else {
+        point.y - rect.max.y
+    };
+    if dx >= dy {
+        if point.x < rect.min.x {
+            Some(ConnectionDirection::West)
+        } else {
+            Some(ConnectionDirection::East)
+        }
+    } else if point.y > rect.max.y {
+        Some(ConnectionDirection::North)
+    } else {
+        Some(ConnectionDirection::South)
+    }
+}
+
+/// Find the connection leaving `level_id` through `direction`
+///
+/// Connections are treated as two-way: a connection arriving at this edge of
+/// the level can also be crossed backwards. Returns the target level, the
+/// target edge and the connection ID.
+fn find_connection(
+    world: &WorldProject,
+    level_id: Uuid,
+    direction: ConnectionDirection,
+) -> Option<(Uuid, ConnectionDirection, Uuid)> {
+    world
+        .world_config
+        .connections
+        .iter()
+        .find_map(|connection| {
+            if connection.from_level == level_id && connection.from_direction == direction {
+                Some((connection.to_level, connection.to_direction, connection.id))
+            } else if connection.to_level == level_id && connection.to_direction == direction {
+                Some((
+                    connection.from_level,
+                    connection.from_direction,
+                    connection.id,
+                ))
+            } else {
+                None
+            }
+        })
+}
+
+/// Collect anchor positions in the local space of a streamed world
+pub(crate) fn anchor_positions(
+    world_transform: &GlobalTransform,
+    anchors: &Query<(Entity, &GlobalTransform), With<StreamingAnchor>>,
+    cameras: &Query<(Entity, &GlobalTransform), With<Camera>>,
+) -> Vec<(Entity, Vec2)> {
+    let inverse = world_transform.affine().inverse();
+    let to_local = |(entity, transform): (Entity, &GlobalTransform)| {
+        (
+            entity,
+            inverse.transform_point3(transform.translation()).truncate(),
+        )
+    };
+
+    if anchors.is_empty() {
+        cameras.iter().map(to_local).collect()
+    } else {
+        anchors.iter().map(to_local).collect()
+    }
+}
+
+/// System that initializes newly added StreamedWorld components
+fn initialize_streamed_worlds(mut commands: Commands, query: Query<Entity, Added<StreamedWorld>>) {
+    for entity in query.iter() {
+        commands
+            .entity(entity)
+            .insert(StreamedWorldState::default());
+    }
+}
+
+/// System that spawns and despawns levels around the anchors
+fn stream_world_levels(
+    mut commands: Commands,
+    world_assets: Res<Assets<WorldProject>>,
+    mut worlds: Query<(
+        Entity,
+        &StreamedWorld,
+        &mut StreamedWorldState,
+        &GlobalTransform,
+    )>,
+    anchors: Query<(Entity, &GlobalTransform), With<StreamingAnchor>>,
+    cameras: Query<(Entity, &GlobalTransform), With<Camera>>,
+    mut level_transforms: Query<&mut Transform, With<StreamedLevel>>,
+) {
+    for (world_entity, streamed, mut state, world_transform) in worlds.iter_mut() {
+        let Some(world) = world_assets.get(&streamed.world) else {
+            continue;
+        };
+
+        let positions = anchor_positions(world_transform, &anchors, &cameras);
+
+        // Levels the anchors are currently in keep their connections loaded
+        let mut connected: HashSet<Uuid> = HashSet::new();
+        if streamed.preload_connections {
+            for level_id in state.anchor_levels.values() {
+                for connection in &world.world_config.connections {
+                    if connection.from_level == *level_id {
+                        connected.insert(connection.to_level);
+                    } else if connection.to_level == *level_id {
+                        connected.insert(connection.from_level);
+                    }
+                }
+            }
+        }
+
+        let mut keep: HashSet<Uuid> = HashSet::new();
+        for level in &world.levels {
+            let rect = level_world_rect(level, level_tile_size(world, level));
+            let nearest = positions
+                .iter()
+                .map(|(_, pos)| distance_to_rect(rect, *pos))
+                .fold(f32::INFINITY, f32::min);
+
+            let loaded = state.loaded.get(&level.id).copied();
+            let wanted = connected.contains(&level.id)
+                || if loaded.is_some() {
+                    nearest <= streamed.unload_radius
+                } else {
+                    nearest <= streamed.load_radius
+                };
+            if !wanted {
+                continue;
+            }
+            keep.insert(level.id);
+
+            let transform = Transform::from_xyz(rect.min.x, rect.min.y, 0.0);
+            match loaded {
+                Some(level_entity) => {
+                    // Keep the placement in sync with hot-reloaded world positions
+                    if let Ok(mut current) = level_transforms.get_mut(level_entity) {
+                        if current.translation != transform.translation {
+                            *current = transform;
+                        }
+                    }
+                }
+                None => {
+                    info!("Streaming in level '{}'", level.name);
+                    let level_entity = commands
+                        .spawn((
+                            LevelHandle::new(streamed.world.clone(), LevelSelector::Id(level.id)),
+                            StreamedLevel {
+                                world_entity,
+                                level_id: level.id,
+                            },
+                            transform,
+                            Visibility::default(),
+                        ))
+                        .id();
+                    commands.entity(world_entity).add_child(level_entity);
+                    state.loaded.insert(level.id, level_entity);
+                }
+            }
+        }
+
+        // Despawn levels that are out of range or no longer in the project
+        state.loaded.retain(|level_id, level_entity| {
+            if keep.contains(level_id) {
+                return true;
+            }
+            info!("Streaming out level {}", level_id);
+            if let Ok(mut entity) = commands.get_entity(*level_entity) {
+                entity.despawn();
+            }
+            false
+        });
+    }
+}
+
+/// System that tracks which level each anchor is in and fires transition events
+fn detect_level_transitions(
+    world_assets: Res<Assets<WorldProject>>,
+    mut worlds: Query<(
+        Entity,
+        &StreamedWorld,
+        &mut StreamedWorldState,
+        &GlobalTransform,
+    )>,
+    anchors: Query<(Entity, &GlobalTransform), With<StreamingAnchor>>,
+    cameras: Query<(Entity, &GlobalTransform), With<Camera>>,
+    mut transitions: MessageWriter<LevelTransitionEvent>,
+) {
+    for (world_entity, streamed, mut state, world_transform) in worlds.iter_mut() {
+        let Some(world) = world_assets.get(&streamed.world) else {
+            continue;
+        };
+
+        let positions = anchor_positions(world_transform, &anchors, &cameras);
+        let present: HashSet<Entity> = positions.iter().map(|(entity, _)| *entity).collect();
+        state
+            .anchor_levels
+            .retain(|anchor, _| present.contains(anchor));
+
+        for (anchor, pos) in positions {
+            let previous = state.anchor_levels.get(&anchor).copied();
+
+            // Still inside the previous level: nothing to do
+            if let Some(level) = previous.and_then(|id| world.level_by_id(id)) {
+                let rect = level_world_rect(level, level_tile_size(world, level));
+                if rect.contains(pos) {
+                    continue;
+                }
+
+                if let Some(direction) = exit_direction(rect, pos) {
+                    if let Some((to_level, to_direction, connection_id)) =
+                        find_connection(world, level.id, direction)
+                    {
+                        transitions.write(LevelTransitionEvent {
+                            world_entity,
+                            anchor,
+                            from_level: level.id,
+                            to_level,
+                            direction,
+                            to_direction,
+                            connection_id,
+                        });
+                    }
+                }
+            }
+
+            let current = world
+                .levels
+                .iter()
+                .find(|level| level_world_rect(level, level_tile_size(world, level)).contains(pos))
+                .map(|level| level.id);
+
+            match current {
+                Some(level_id) => {
+                    state.anchor_levels.insert(anchor, level_id);
+                }
+                None => {
+                    state.anchor_levels.remove(&anchor);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_map_core::{LevelConnection, MapProject, WorldConfig};
+
+    fn world_with_levels(levels: Vec<Level>, connections: Vec<LevelConnection>) -> WorldProject {
+        let mut world = WorldProject::from(MapProject::new(levels[0].clone(), vec![]));
+        world.levels = levels;
+        world.world_config = WorldConfig::default();
+        for connection in connections {
+            world.world_config.add_connection(connection);
+        }
+        world
+    }
+
+    #[test]
+    fn test_level_world_rect_flips_y() {
+        let level = Level::new_at("A".to_string(), 10, 5, 64, 32);
+        let rect = level_world_rect(&level, Vec2::splat(16.0));
+        assert_eq!(rect.min, Vec2::new(64.0, -32.0 - 80.0));
+        assert_eq!(rect.max, Vec2::new(64.0 + 160.0, -32.0));
+
+        // Non-square tiles stretch the level along each axis separately
+        let rect = level_world_rect(&level, Vec2::new(16.0, 24.0));
+        assert_eq!(rect.min, Vec2::new(64.0, -32.0 - 120.0));
+        assert_eq!(rect.max, Vec2::new(64.0 + 160.0, -32.0));
+    }
+
+    #[test]
+    fn test_exit_direction() {
+        let rect = Rect::new(0.0, 0.0, 100.0, 100.0);
+        assert_eq!(exit_direction(rect, Vec2::new(50.0, 50.0)), None);
+        assert_eq!(
+            exit_direction(rect, Vec2::new(101.0, 50.0)),
+            Some(ConnectionDirection::East)
+        );
+        assert_eq!(
+            exit_direction(rect, Vec2::new(-1.0, 50.0)),
+            Some(ConnectionDirection::West)
+        );
+        assert_eq!(
+            exit_direction(rect, Vec2::new(50.0, 101.0)),
+            Some(ConnectionDirection::North)
+        );
+        assert_eq!(
+            exit_direction(rect, Vec2::new(50.0, -1.0)),
+            Some(ConnectionDirection::South)
+        );
+    }
+
+    #[test]
+    fn test_distance_to_rect() {
+        let rect = Rect::new(0.0, 0.0, 100.0, 100.0);
+        assert_eq!(distance_to_rect(rect, Vec2::new(50.0, 50.0)), 0.0);
+        assert_eq!(distance_to_rect(rect, Vec2::new(130.0, 50.0)), 30.0);
+    }
+
+    #[test]
+    fn test_find_connection_both_ways() {
+        let a = Level::new_at("A".to_string(), 10, 10, 0, 0);
+        let b = Level::new_at("B".to_string(), 10, 10, 320, 0);
+        let connection = LevelConnection::auto_direction(a.id, ConnectionDirection::East, b.id);
+        let connection_id = connection.id;
+        let world = world_with_levels(vec![a.clone(), b.clone()], vec![connection]);
+
+        assert_eq!(
+            find_connection(&world, a.id, ConnectionDirection::East),
+            Some((b.id, ConnectionDirection::West, connection_id))
+        );
+        assert_eq!(
+            find_connection(&world, b.id, ConnectionDirection::West),
+            Some((a.id, ConnectionDirection::East, connection_id))
+        );
+        assert_eq!(
+            find_connection(&world, a.id, ConnectionDirection::North),
+            None
+        );
+    }
+}
@@ -0,0 +1,144 @@
+//! Animated tiles
+//!
+//! Tiles whose `TileProperties::animation_frames` hold two or more frames are
+//! animated at runtime. Frames that form a contiguous run in the tileset image
+//! use bevy_ecs_tilemap's GPU [`AnimatedTile`]; any other frame order falls back
+//! to [`TileFrameAnimation`], which steps the tile's `TileTextureIndex` on the CPU.
+//!
+//! Every tile keeps its own speed and flip flags. On multi-image tilesets all
+//! frames must live in the same image as the first frame.
+
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+use bevy_map_core::TileProperties;
+
+/// Default animation speed in frames per second, matching the editor
+pub const DEFAULT_TILE_ANIMATION_FPS: f32 = 10.0;
+
+/// CPU-driven animation for tiles whose frames are not contiguous
+///
+/// `frames` are local indices into the tilemap texture.
+#[derive(Component, Debug, Clone)]
+pub struct TileFrameAnimation {
+    /// Local texture indices, played in order
+    pub frames: Vec<u32>,
+    /// Playback speed in frames per second
+    pub fps: f32,
+    /// Time since the animation started, in seconds
+    pub elapsed: f32,
+}
+
+impl TileFrameAnimation {
+    /// Create an animation starting at its first frame
+    pub fn new(frames: Vec<u32>, fps: f32) -> Self {
+        Self {
+            frames,
+            fps,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Get the texture index to show at the current elapsed time
+    pub fn current_frame(&self) -> Option<u32> {
+        if self.frames.is_empty() {
+            return None;
+        }
+        let step = (self.elapsed * self.fps.max(0.0)).floor() as usize;
+        Some(self.frames[step % self.frames.len()])
+    }
+}
+
+/// Get the frame rate of a tile animation, falling back to the editor default
+pub fn tile_animation_fps(props: &TileProperties) -> f32 {
+    props
+        .animation_speed
+        .filter(|speed| *speed > 0.0)
+        .unwrap_or(DEFAULT_TILE_ANIMATION_FPS)
+}
+
+/// Get the `start..end` range of frames that follow each other in the texture
+///
+/// Returns `None` if the frames are not a contiguous, increasing run, in which
+/// case the GPU animation cannot be used.
+pub fn contiguous_frame_range(frames: &[u32]) -> Option<(u32, u32)> {
+    let (&start, rest) = frames.split_first()?;
+    let contiguous = rest
+        .iter()
+        .enumerate()
+        .all(|(i, &frame)| frame == start + i as u32 + 1);
+    contiguous.then_some((start, start + frames.len() as u32))
+}
+
+/// Insert the animation components for a tile
+///
+/// `frames` are local texture indices; the tile's `TileTextureIndex` should
+/// already be set to the first frame.
+pub fn insert_tile_animation(tile: &mut EntityCommands, frames: Vec<u32>, fps: f32) {
+    match contiguous_frame_range(&frames) {
+        Some((start, end)) => {
+            tile.insert(AnimatedTile {
+                start,
+                end,
+                speed: fps,
+            });
+        }
+        None => {
+            tile.insert(TileFrameAnimation::new(frames, fps));
+        }
+    }
+}
+
+/// System that advances CPU tile animations
+pub fn animate_tile_frames(
+    time: Res<Time>,
+    mut query: Query<(&mut TileFrameAnimation, &mut TileTextureIndex)>,
+) {
+    let delta = time.delta_secs();
+    for (mut animation, mut texture_index) in query.iter_mut() {
+        animation.elapsed += delta;
+        if let Some(frame) = animation.current_frame() {
+            if texture_index.0 != frame {
+                texture_index.0 = frame;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contiguous_frame_range() {
+        assert_eq!(contiguous_frame_range(&[4, 5, 6]), Some((4, 7)));
+        assert_eq!(contiguous_frame_range(&[4, 6, 5]), None);
+        assert_eq!(contiguous_frame_range(&[4, 4]), None);
+        assert_eq!(contiguous_frame_range(&[]), None);
+    }
+
+    #[test]
+    fn test_frame_animation_steps() {
+        let mut animation = TileFrameAnimation::new(vec![3, 9, 1], 4.0);
+        assert_eq!(animation.current_frame(), Some(3));
+
+        animation.elapsed = 0.3;
+        assert_eq!(animation.current_frame(), Some(9));
+
+        // Wraps around after the last frame
+        animation.elapsed = 0.8;
+        assert_eq!(animation.current_frame(), Some(3));
+    }
+
+    #[test]
+    fn test_tile_animation_fps_default() {
+        let props = TileProperties {
+            animation_frames: Some(vec![0, 1]),
+            ..default()
+        };
+        assert_eq!(tile_animation_fps(&props), DEFAULT_TILE_ANIMATION_FPS);
+        assert_eq!(
+            tile_animation_fps(&TileProperties::new().with_animation(vec![0, 1], 6.0)),
+            6.0
+        );
+    }
+}
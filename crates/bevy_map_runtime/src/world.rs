@@ -0,0 +1,136 @@
+//! Multi-level world support
+//!
+//! A `.map.json` exported by the editor can contain many levels. Loading it as a
+//! [`WorldProject`] keeps every level, the world layout and level connections,
+//! and any level can be spawned by name or UUID with a [`LevelHandle`].
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use bevy::prelude::*;
+//! use bevy_map_runtime::{LevelHandle, MapRuntimePlugin};
+//!
+//! fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+//!     let world = asset_server.load("maps/world.map.json");
+//!
+//!     // Spawn two levels from the same project
+//!     commands.spawn(LevelHandle::new(world.clone(), "Cave"));
+//!     commands.spawn((
+//!         LevelHandle::new(world, "Forest"),
+//!         Transform::from_xyz(512.0, 0.0, 0.0),
+//!     ));
+//! }
+//! ```
+//!
+//! Changing [`LevelHandle::level`] on an existing entity swaps the spawned level.
+
+use crate::MapHandle;
+use bevy::asset::AssetEvent;
+use bevy::ecs::message::MessageReader;
+use bevy::prelude::*;
+use bevy_map_core::{LevelSelector, MapProject, WorldProject};
+use std::collections::HashSet;
+
+/// Component for spawning a single level out of a multi-level [`WorldProject`]
+///
+/// Once the world asset is loaded, the selected level is extracted into a
+/// [`MapProject`] asset and a [`MapHandle`] is inserted on the same entity, so
+/// the map spawns through the regular asset pipeline. Hot-reloading the world
+/// asset or changing `level` respawns the map.
+#[derive(Component, Debug, Clone)]
+pub struct LevelHandle {
+    /// Handle to the whole-project asset
+    pub world: Handle<WorldProject>,
+    /// Which level to spawn
+    pub level: LevelSelector,
+}
+
+impl LevelHandle {
+    /// Create a level handle from a world asset and a level name or UUID
+    pub fn new(world: Handle<WorldProject>, level: impl Into<LevelSelector>) -> Self {
+        Self {
+            world,
+            level: level.into(),
+        }
+    }
+}
+
+/// Internal state tracking for LevelHandle entities
+#[derive(Component, Default)]
+pub(crate) struct LevelHandleState {
+    /// Whether the selected level has been extracted from the world asset
+    resolved: bool,
+}
+
+/// System that initializes newly added LevelHandle components
+pub(crate) fn initialize_level_handles(
+    mut commands: Commands,
+    query: Query<Entity, Added<LevelHandle>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).insert(LevelHandleState::default());
+    }
+}
+
+/// System that extracts the selected level into a MapProject asset
+///
+/// Runs again when the world asset is modified or the selector changes. The
+/// existing MapProject asset is replaced in place, which triggers the regular
+/// map hot-reload.
+pub(crate) fn resolve_level_handles(
+    mut commands: Commands,
+    mut world_events: MessageReader<AssetEvent<WorldProject>>,
+    world_assets: Res<Assets<WorldProject>>,
+    mut map_assets: ResMut<Assets<MapProject>>,
+    mut query: Query<(
+        Entity,
+        Ref<LevelHandle>,
+        &mut LevelHandleState,
+        Option<&MapHandle>,
+    )>,
+) {
+    let modified: HashSet<AssetId<WorldProject>> = world_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    for (entity, level_handle, mut state, map_handle) in query.iter_mut() {
+        let dirty = !state.resolved
+            || level_handle.is_changed()
+            || modified.contains(&level_handle.world.id());
+        if !dirty {
+            continue;
+        }
+
+        let Some(world) = world_assets.get(&level_handle.world) else {
+            continue;
+        };
+        state.resolved = true;
+
+        let Some(project) = world.to_map_project(&level_handle.level) else {
+            warn!(
+                "Level {} not found in world project (available: {:?})",
+                level_handle.level,
+                world.level_names().collect::<Vec<_>>()
+            );
+            continue;
+        };
+
+        match map_handle {
+            Some(map_handle) => {
+                info!("Reloading level {}", level_handle.level);
+                if let Err(err) = map_assets.insert(&map_handle.0, project) {
+                    warn!("Failed to replace level asset: {}", err);
+                }
+            }
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(MapHandle(map_assets.add(project)));
+            }
+        }
+    }
+}
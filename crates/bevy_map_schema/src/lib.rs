@@ -109,6 +109,10 @@ mod tests {
                         { "name": "name", "type": "string", "required": true },
                         { "name": "itemType", "type": "enum", "enumType": "ItemType" }
                     ]
+                },
+                "MusicZone": {
+                    "placeable": true,
+                    "kind": "region"
                 }
             },
             "embedded_types": {}
@@ -120,6 +124,8 @@ mod tests {
 
         let item_type = schema.get_type("Item").unwrap();
         assert_eq!(item_type.properties.len(), 2);
+        assert_eq!(item_type.kind, EntityKind::Point);
+        assert!(schema.get_type("MusicZone").unwrap().is_sized());
     }
 
     #[test]
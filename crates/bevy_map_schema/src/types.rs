@@ -14,6 +14,15 @@ pub struct Schema {
     pub data_types: HashMap<String, TypeDef>,
     #[serde(default)]
     pub embedded_types: HashMap<String, TypeDef>,
+    /// Custom properties available on every level
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub level_properties: Vec<PropertyDef>,
+    /// Custom properties available on every layer
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub layer_properties: Vec<PropertyDef>,
+    /// Custom properties available on every tileset
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tileset_properties: Vec<PropertyDef>,
 }
 
 impl Schema {
@@ -83,6 +92,18 @@ pub enum ViewportDisplayMode {
     Sprite,
 }
 
+/// What placed entities of a type represent
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityKind {
+    /// A single point (NPCs, chests, spawn points)
+    #[default]
+    Point,
+    /// An area with a rectangle or polygon shape that fires enter/exit
+    /// events at runtime
+    Region,
+}
+
 /// Definition of a type (from schema)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TypeDef {
@@ -97,10 +118,33 @@ pub struct TypeDef {
     /// How this entity type should be displayed in the viewport
     #[serde(default)]
     pub viewport_display: ViewportDisplayMode,
+    /// Whether placed entities have a width and height
+    ///
+    /// For types that cover an area rather than a point, such as trigger
+    /// zones or camera regions.
+    #[serde(default)]
+    pub resizable: bool,
+    /// Whether placed entities are points or regions
+    #[serde(default)]
+    pub kind: EntityKind,
     #[serde(default)]
     pub properties: Vec<PropertyDef>,
 }
 
+impl TypeDef {
+    /// Check if placed entities of this type are regions
+    pub fn is_region(&self) -> bool {
+        self.kind == EntityKind::Region
+    }
+
+    /// Check if placed entities have a width and height
+    ///
+    /// Regions are always sized.
+    pub fn is_sized(&self) -> bool {
+        self.resizable || self.is_region()
+    }
+}
+
 fn default_color() -> String {
     "#808080".to_string()
 }
@@ -113,6 +157,8 @@ impl Default for TypeDef {
             placeable: false,
             marker_size: None,
             viewport_display: ViewportDisplayMode::default(),
+            resizable: false,
+            kind: EntityKind::default(),
             properties: Vec::new(),
         }
     }
@@ -1,37 +1,53 @@
 //! Schema validation logic
 
-use crate::{Schema, SchemaError};
+use crate::{PropertyDef, Schema, SchemaError};
+use bevy_map_core::{Level, Tileset, Value};
+use std::collections::HashMap;
 
 /// Validate that the schema is internally consistent
 pub fn validate_schema(schema: &Schema) -> Result<(), SchemaError> {
-    // Check that all enum references point to valid enums
     for (type_name, type_def) in schema.data_types.iter().chain(schema.embedded_types.iter()) {
-        for prop in &type_def.properties {
-            if let Some(enum_type) = &prop.enum_type {
-                if !schema.enums.contains_key(enum_type) {
-                    return Err(SchemaError::ValidationError(format!(
-                        "Type '{}' property '{}' references unknown enum '{}'",
-                        type_name, prop.name, enum_type
-                    )));
-                }
+        validate_property_defs(schema, type_name, &type_def.properties)?;
+    }
+    validate_property_defs(schema, "level", &schema.level_properties)?;
+    validate_property_defs(schema, "layer", &schema.layer_properties)?;
+    validate_property_defs(schema, "tileset", &schema.tileset_properties)?;
+
+    Ok(())
+}
+
+/// Check that a list of property definitions only references known types
+fn validate_property_defs(
+    schema: &Schema,
+    type_name: &str,
+    properties: &[PropertyDef],
+) -> Result<(), SchemaError> {
+    // Check that all enum references point to valid enums
+    for prop in properties {
+        if let Some(enum_type) = &prop.enum_type {
+            if !schema.enums.contains_key(enum_type) {
+                return Err(SchemaError::ValidationError(format!(
+                    "Type '{}' property '{}' references unknown enum '{}'",
+                    type_name, prop.name, enum_type
+                )));
             }
+        }
 
-            if let Some(ref_type) = &prop.ref_type {
-                if !schema.data_types.contains_key(ref_type) {
-                    return Err(SchemaError::ValidationError(format!(
-                        "Type '{}' property '{}' references unknown type '{}'",
-                        type_name, prop.name, ref_type
-                    )));
-                }
+        if let Some(ref_type) = &prop.ref_type {
+            if !schema.data_types.contains_key(ref_type) {
+                return Err(SchemaError::ValidationError(format!(
+                    "Type '{}' property '{}' references unknown type '{}'",
+                    type_name, prop.name, ref_type
+                )));
             }
+        }
 
-            if let Some(embedded_type) = &prop.embedded_type {
-                if !schema.embedded_types.contains_key(embedded_type) {
-                    return Err(SchemaError::ValidationError(format!(
-                        "Type '{}' property '{}' references unknown embedded type '{}'",
-                        type_name, prop.name, embedded_type
-                    )));
-                }
+        if let Some(embedded_type) = &prop.embedded_type {
+            if !schema.embedded_types.contains_key(embedded_type) {
+                return Err(SchemaError::ValidationError(format!(
+                    "Type '{}' property '{}' references unknown embedded type '{}'",
+                    type_name, prop.name, embedded_type
+                )));
             }
         }
     }
@@ -49,19 +65,36 @@ pub fn validate_instance(
         .get_type(type_name)
         .ok_or_else(|| SchemaError::ValidationError(format!("Unknown type: {}", type_name)))?;
 
+    validate_properties(
+        schema,
+        &format!("type '{}'", type_name),
+        &type_def.properties,
+        properties,
+    )
+}
+
+/// Validate property values against a list of definitions
+///
+/// `owner` names what the properties belong to in error messages.
+pub fn validate_properties(
+    schema: &Schema,
+    owner: &str,
+    defs: &[PropertyDef],
+    properties: &HashMap<String, serde_json::Value>,
+) -> Result<(), SchemaError> {
     // Check required properties are present
-    for prop_def in &type_def.properties {
+    for prop_def in defs {
         if prop_def.required && !properties.contains_key(&prop_def.name) {
             return Err(SchemaError::ValidationError(format!(
-                "Missing required property '{}' for type '{}'",
-                prop_def.name, type_name
+                "Missing required property '{}' for {}",
+                prop_def.name, owner
             )));
         }
     }
 
     // Validate property values
     for (prop_name, value) in properties {
-        if let Some(prop_def) = type_def.properties.iter().find(|p| &p.name == prop_name) {
+        if let Some(prop_def) = defs.iter().find(|p| &p.name == prop_name) {
             validate_property_value(schema, prop_def, value)?;
         }
     }
@@ -69,6 +102,42 @@ pub fn validate_instance(
     Ok(())
 }
 
+/// Validate the custom properties of a level and all of its layers
+pub fn validate_level_properties(schema: &Schema, level: &Level) -> Result<(), SchemaError> {
+    validate_properties(
+        schema,
+        &format!("level '{}'", level.name),
+        &schema.level_properties,
+        &to_json_map(&level.properties),
+    )?;
+    for layer in &level.layers {
+        validate_properties(
+            schema,
+            &format!("layer '{}'", layer.name),
+            &schema.layer_properties,
+            &to_json_map(&layer.properties),
+        )?;
+    }
+    Ok(())
+}
+
+/// Validate the custom properties of a tileset
+pub fn validate_tileset_properties(schema: &Schema, tileset: &Tileset) -> Result<(), SchemaError> {
+    validate_properties(
+        schema,
+        &format!("tileset '{}'", tileset.name),
+        &schema.tileset_properties,
+        &to_json_map(&tileset.properties),
+    )
+}
+
+fn to_json_map(properties: &HashMap<String, Value>) -> HashMap<String, serde_json::Value> {
+    properties
+        .iter()
+        .map(|(name, value)| (name.clone(), value.to_json()))
+        .collect()
+}
+
 /// Validate a single property value against its definition
 fn validate_property_value(
     schema: &Schema,
@@ -259,6 +328,63 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_validate_level_properties() {
+        let schema = parse_schema(
+            r#"{
+            "version": 1,
+            "project": { "name": "Test" },
+            "enums": {
+                "Difficulty": ["Easy", "Hard"]
+            },
+            "level_properties": [
+                { "name": "music", "type": "string", "required": true },
+                { "name": "difficulty", "type": "enum", "enumType": "Difficulty" }
+            ],
+            "layer_properties": [
+                { "name": "is_foreground", "type": "bool" }
+            ]
+        }"#,
+        )
+        .unwrap();
+
+        let mut level = Level::new("Cave".to_string(), 4, 4);
+        level.add_layer(bevy_map_core::Layer::new_object_layer(
+            "Entities".to_string(),
+        ));
+
+        // Missing required property
+        assert!(validate_level_properties(&schema, &level).is_err());
+
+        level
+            .properties
+            .insert("music".to_string(), Value::String("cave.ogg".to_string()));
+        level
+            .properties
+            .insert("difficulty".to_string(), Value::String("Hard".to_string()));
+        assert!(validate_level_properties(&schema, &level).is_ok());
+
+        // Layer properties are checked too
+        level.layers[0]
+            .properties
+            .insert("is_foreground".to_string(), Value::Int(1));
+        assert!(validate_level_properties(&schema, &level).is_err());
+    }
+
+    #[test]
+    fn test_validate_unknown_enum_in_tileset_properties() {
+        let result = parse_schema(
+            r#"{
+            "version": 1,
+            "project": { "name": "Test" },
+            "tileset_properties": [
+                { "name": "material", "type": "enum", "enumType": "Material" }
+            ]
+        }"#,
+        );
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_validate_enum() {
         let schema = parse_schema(